@@ -0,0 +1,218 @@
+//! Hierarchical cooperative cancellation, so a single app-level shutdown
+//! signal can fan out to every subsystem that derived a token from it.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+use tokio::sync::Notify;
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    notify: Notify,
+    children: Mutex<Vec<Weak<Inner>>>,
+}
+
+/// A cancellation signal that can be cloned, awaited, and derived into
+/// child tokens.
+///
+/// Cancelling a token cancels itself and every (still-live) token
+/// descended from it via [`CancellationToken::child_token`], but never
+/// propagates upward — a child's cancellation is local to that
+/// subsystem. This lets an app hold one root token, hand a child to
+/// each of the web server, the scheduler, the messaging consumers, and
+/// the stream tasks, and cancel all of them from a single `Ctrl-C`
+/// handler without any of those subsystems being able to shut down the
+/// others.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+impl CancellationToken {
+    /// Creates a new, uncancelled root token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that is cancelled whenever `self` is (immediately,
+    /// if `self` is already cancelled), but whose own cancellation has
+    /// no effect on `self` or on sibling tokens.
+    pub fn child_token(&self) -> CancellationToken {
+        let child = CancellationToken::new();
+        if self.is_cancelled() {
+            child.cancel();
+        } else {
+            self.inner.children.lock().unwrap().push(Arc::downgrade(&child.inner));
+        }
+        child
+    }
+
+    /// Cancels this token and every live token descended from it.
+    /// Idempotent — cancelling an already-cancelled token is a no-op.
+    pub fn cancel(&self) {
+        if self.inner.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        self.inner.notify.notify_waiters();
+
+        let children = std::mem::take(&mut *self.inner.children.lock().unwrap());
+        for child in children.into_iter().filter_map(|weak| weak.upgrade()) {
+            CancellationToken { inner: child }.cancel();
+        }
+    }
+
+    /// Returns whether this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once this token is cancelled, immediately if it already
+    /// is.
+    pub async fn cancelled(&self) {
+        if self.is_cancelled() {
+            return;
+        }
+        let notified = self.inner.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+/// Races `fut` against `token`, returning `fut`'s output if it finishes
+/// first, or `None` if `token` is cancelled first (in which case `fut`
+/// is dropped, cancelling it if it's still in progress).
+pub async fn run_until_cancelled<F: Future>(fut: F, token: &CancellationToken) -> Option<F::Output> {
+    tokio::select! {
+        result = fut => Some(result),
+        _ = token.cancelled() => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    #[test]
+    fn a_new_token_is_not_cancelled() {
+        assert!(!CancellationToken::new().is_cancelled());
+    }
+
+    #[test]
+    fn cancel_is_idempotent() {
+        let token = CancellationToken::new();
+        token.cancel();
+        token.cancel();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn cloned_tokens_share_cancellation_state() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[test]
+    fn cancelling_a_parent_cancels_its_children() {
+        let parent = CancellationToken::new();
+        let child = parent.child_token();
+        let grandchild = child.child_token();
+
+        parent.cancel();
+
+        assert!(child.is_cancelled());
+        assert!(grandchild.is_cancelled());
+    }
+
+    #[test]
+    fn cancelling_a_child_does_not_cancel_its_parent() {
+        let parent = CancellationToken::new();
+        let child = parent.child_token();
+
+        child.cancel();
+
+        assert!(!parent.is_cancelled());
+    }
+
+    #[test]
+    fn a_child_of_an_already_cancelled_parent_starts_cancelled() {
+        let parent = CancellationToken::new();
+        parent.cancel();
+
+        assert!(parent.child_token().is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn cancelled_resolves_immediately_once_already_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_millis(50), token.cancelled())
+            .await
+            .expect("cancelled() should not block once the token is cancelled");
+    }
+
+    #[tokio::test]
+    async fn cancelled_resolves_once_cancel_is_called() {
+        let token = CancellationToken::new();
+        let token_for_cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            token_for_cancel.cancel();
+        });
+
+        tokio::time::timeout(Duration::from_secs(1), token.cancelled())
+            .await
+            .expect("cancelled() should resolve once cancel() is called");
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled_returns_the_future_output_when_it_finishes_first() {
+        let token = CancellationToken::new();
+        let result = run_until_cancelled(async { 42 }, &token).await;
+        assert_eq!(result, Some(42));
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled_returns_none_once_the_token_is_cancelled_first() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = run_until_cancelled(std::future::pending::<()>(), &token).await;
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled_drops_the_future_once_cancelled() {
+        struct DropCounter(Arc<AtomicUsize>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let counter = DropCounter(drops.clone());
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = run_until_cancelled(
+            async move {
+                let _counter = counter;
+                std::future::pending::<()>().await
+            },
+            &token,
+        )
+        .await;
+
+        assert_eq!(result, None);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+}
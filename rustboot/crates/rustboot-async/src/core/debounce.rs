@@ -0,0 +1,124 @@
+//! Coalescing repeated calls into one, run only after the caller has
+//! gone quiet for a while — the same idea as `rustboot_streams`'s
+//! stream `debounce` combinator, for code that isn't already working
+//! with an `EventReceiver`.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+struct State<T> {
+    pending: Option<T>,
+    generation: u64,
+}
+
+/// Coalesces calls to [`Debouncer::call`] within `delay` of each other,
+/// running the handler given to [`Debouncer::new`] once with only the
+/// most recent value, `delay` after the last call.
+pub struct Debouncer<T> {
+    delay: Duration,
+    handler: Arc<dyn Fn(T) + Send + Sync>,
+    state: Arc<Mutex<State<T>>>,
+}
+
+impl<T> Debouncer<T>
+where
+    T: Send + 'static,
+{
+    /// Creates a debouncer that waits for `delay` of silence before
+    /// invoking `handler` with the latest value passed to
+    /// [`Debouncer::call`].
+    pub fn new(delay: Duration, handler: impl Fn(T) + Send + Sync + 'static) -> Self {
+        Self {
+            delay,
+            handler: Arc::new(handler),
+            state: Arc::new(Mutex::new(State {
+                pending: None,
+                generation: 0,
+            })),
+        }
+    }
+
+    /// Records `value` as the latest call and restarts the quiet-period
+    /// timer. If another call arrives before `delay` elapses, this
+    /// value is discarded in favor of the newer one.
+    pub fn call(&self, value: T) {
+        let generation = {
+            let mut state = self.state.lock().unwrap();
+            state.pending = Some(value);
+            state.generation += 1;
+            state.generation
+        };
+
+        let state = self.state.clone();
+        let handler = self.handler.clone();
+        let delay = self.delay;
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+
+            let fired = {
+                let mut state = state.lock().unwrap();
+                if state.generation == generation {
+                    state.pending.take()
+                } else {
+                    None
+                }
+            };
+            if let Some(value) = fired {
+                handler(value);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn coalesces_repeated_calls_into_the_latest_value() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_for_handler = seen.clone();
+        let debouncer = Debouncer::new(Duration::from_millis(20), move |value: i32| {
+            seen_for_handler.lock().unwrap().push(value);
+        });
+
+        debouncer.call(1);
+        debouncer.call(2);
+        debouncer.call(3);
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        assert_eq!(*seen.lock().unwrap(), vec![3]);
+    }
+
+    #[tokio::test]
+    async fn fires_again_after_a_quiet_period_between_two_batches() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_for_handler = calls.clone();
+        let debouncer = Debouncer::new(Duration::from_millis(20), move |_: i32| {
+            calls_for_handler.fetch_add(1, Ordering::SeqCst);
+        });
+
+        debouncer.call(1);
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        debouncer.call(2);
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_fire_before_the_delay_elapses() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_for_handler = calls.clone();
+        let debouncer = Debouncer::new(Duration::from_millis(50), move |_: i32| {
+            calls_for_handler.fetch_add(1, Ordering::SeqCst);
+        });
+
+        debouncer.call(1);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,173 @@
+//! A mutex per key, created on first use and reclaimed once uncontended,
+//! so callers serialize on a resource identified by a key (an order id,
+//! a tenant, a file path) without pre-registering every possible key or
+//! holding one lock per key forever.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+struct Entry {
+    mutex: Arc<tokio::sync::Mutex<()>>,
+    ref_count: usize,
+}
+
+/// A registry of per-key async mutexes.
+///
+/// [`KeyedMutex::lock`] creates a key's mutex the first time it's
+/// locked and removes it once the returned guard (and every other
+/// outstanding guard or waiter for that key) is dropped, so the
+/// registry only ever holds entries for keys currently in use.
+pub struct KeyedMutex<K> {
+    entries: Mutex<HashMap<K, Entry>>,
+}
+
+impl<K> Default for KeyedMutex<K> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K> KeyedMutex<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Acquires the mutex for `key`, waiting for any other holder of or
+    /// waiter for the same key to release it first. Locks for distinct
+    /// keys never block each other.
+    pub async fn lock(&self, key: K) -> KeyedMutexGuard<'_, K> {
+        let mutex = {
+            let mut entries = self.entries.lock().unwrap();
+            let entry = entries.entry(key.clone()).or_insert_with(|| Entry {
+                mutex: Arc::new(tokio::sync::Mutex::new(())),
+                ref_count: 0,
+            });
+            entry.ref_count += 1;
+            entry.mutex.clone()
+        };
+
+        let registration = Registration { keyed: self, key };
+        let guard = mutex.lock_owned().await;
+        KeyedMutexGuard {
+            _guard: guard,
+            _registration: registration,
+        }
+    }
+
+    /// Returns the number of keys currently locked or being waited on.
+    pub fn active_keys(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+}
+
+/// Holds the lock for one key of a [`KeyedMutex`]; releases it on drop.
+pub struct KeyedMutexGuard<'a, K: Eq + Hash> {
+    _guard: tokio::sync::OwnedMutexGuard<()>,
+    _registration: Registration<'a, K>,
+}
+
+/// One holder's or waiter's reference to a key's entry, released on drop
+/// whether or not the lock was ever acquired.
+struct Registration<'a, K: Eq + Hash> {
+    keyed: &'a KeyedMutex<K>,
+    key: K,
+}
+
+impl<K: Eq + Hash> Drop for Registration<'_, K> {
+    fn drop(&mut self) {
+        let mut entries = self.keyed.entries.lock().unwrap();
+        if let Some(entry) = entries.get_mut(&self.key) {
+            entry.ref_count -= 1;
+            if entry.ref_count == 0 {
+                entries.remove(&self.key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn locks_for_different_keys_do_not_block_each_other() {
+        let keyed = KeyedMutex::new();
+
+        let a = keyed.lock("a").await;
+        let b = tokio::time::timeout(Duration::from_millis(50), keyed.lock("b"))
+            .await
+            .expect("locking a different key should not block");
+
+        drop(a);
+        drop(b);
+    }
+
+    #[tokio::test]
+    async fn a_second_lock_for_the_same_key_waits_for_the_first_to_release() {
+        let keyed = Arc::new(KeyedMutex::new());
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let first = keyed.lock("order-1").await;
+
+        let keyed_for_second = keyed.clone();
+        let order_for_second = order.clone();
+        let second = tokio::spawn(async move {
+            let _guard = keyed_for_second.lock("order-1").await;
+            order_for_second.lock().unwrap().push(2);
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        order.lock().unwrap().push(1);
+        drop(first);
+        second.await.unwrap();
+
+        assert_eq!(*order.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn active_keys_reflects_locks_currently_held_or_awaited() {
+        let keyed = KeyedMutex::new();
+        assert_eq!(keyed.active_keys(), 0);
+
+        let guard = keyed.lock("a").await;
+        assert_eq!(keyed.active_keys(), 1);
+
+        drop(guard);
+        assert_eq!(keyed.active_keys(), 0);
+    }
+
+    #[tokio::test]
+    async fn many_keys_can_be_locked_concurrently() {
+        let keyed = Arc::new(KeyedMutex::new());
+        let concurrent = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut handles = Vec::new();
+        for key in 0..8 {
+            let keyed = keyed.clone();
+            let concurrent = concurrent.clone();
+            let peak = peak.clone();
+            handles.push(tokio::spawn(async move {
+                let _guard = keyed.lock(key).await;
+                let now = concurrent.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                concurrent.fetch_sub(1, Ordering::SeqCst);
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert!(peak.load(Ordering::SeqCst) > 1);
+    }
+}
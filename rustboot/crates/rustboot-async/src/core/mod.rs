@@ -0,0 +1,7 @@
+//! Implementation details for the async module.
+
+pub mod cancellation;
+pub mod debounce;
+pub mod keyed_mutex;
+pub mod singleflight;
+pub mod task;
@@ -0,0 +1,209 @@
+//! Coalescing concurrent calls for the same key into one in-flight
+//! call, so N callers asking for the same (not-yet-cached) resource at
+//! the same time cost one upstream call instead of N.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use tokio::sync::watch;
+
+/// A registry of in-flight calls, keyed by `K`.
+///
+/// [`Singleflight::run`] makes the first caller for a key the "leader",
+/// which actually runs the given future; every other caller for the
+/// same key while the leader is in flight waits for it and receives a
+/// clone of its result instead of starting its own call.
+pub struct Singleflight<K, V> {
+    inflight: Mutex<HashMap<K, watch::Receiver<Option<V>>>>,
+}
+
+impl<K, V> Default for Singleflight<K, V> {
+    fn default() -> Self {
+        Self {
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K, V> Singleflight<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `f` for `key` if no call for that key is already in
+    /// flight; otherwise waits for the in-flight call to finish and
+    /// returns a clone of its result.
+    pub async fn run<F, Fut>(&self, key: K, f: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        enum Role<V> {
+            Leader(watch::Sender<Option<V>>),
+            Follower(watch::Receiver<Option<V>>),
+        }
+
+        let role = {
+            let mut inflight = self.inflight.lock().unwrap();
+            match inflight.get(&key) {
+                Some(rx) => Role::Follower(rx.clone()),
+                None => {
+                    let (tx, rx) = watch::channel(None);
+                    inflight.insert(key.clone(), rx);
+                    Role::Leader(tx)
+                }
+            }
+        };
+
+        match role {
+            Role::Leader(tx) => {
+                let guard = LeaderGuard {
+                    inflight: &self.inflight,
+                    key: Some(key),
+                };
+                let value = f().await;
+                drop(guard);
+                let _ = tx.send(Some(value.clone()));
+                value
+            }
+            Role::Follower(mut rx) => loop {
+                if let Some(value) = rx.borrow_and_update().clone() {
+                    return value;
+                }
+                if rx.changed().await.is_err() {
+                    panic!("singleflight leader for this key panicked before producing a result");
+                }
+            },
+        }
+    }
+}
+
+/// Removes `key`'s in-flight entry when dropped, whether [`run`]'s
+/// future ran to completion or was unwound by a panic in `f`.
+///
+/// [`run`]: Singleflight::run
+struct LeaderGuard<'a, K: Eq + Hash, V> {
+    inflight: &'a Mutex<HashMap<K, watch::Receiver<Option<V>>>>,
+    key: Option<K>,
+}
+
+impl<K: Eq + Hash, V> Drop for LeaderGuard<'_, K, V> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.inflight.lock().unwrap().remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Notify;
+
+    #[tokio::test]
+    async fn concurrent_calls_for_the_same_key_share_one_execution() {
+        let singleflight = Arc::new(Singleflight::new());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let started = Arc::new(Notify::new());
+        let release = Arc::new(Notify::new());
+
+        let singleflight_for_first = singleflight.clone();
+        let calls_for_first = calls.clone();
+        let started_for_first = started.clone();
+        let release_for_first = release.clone();
+        let first = tokio::spawn(async move {
+            singleflight_for_first
+                .run("resource", move || {
+                    let calls = calls_for_first.clone();
+                    let started = started_for_first.clone();
+                    let release = release_for_first.clone();
+                    async move {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        started.notify_one();
+                        release.notified().await;
+                        "value"
+                    }
+                })
+                .await
+        });
+
+        started.notified().await;
+
+        let singleflight_for_second = singleflight.clone();
+        let second = tokio::spawn(async move {
+            singleflight_for_second
+                .run("resource", || async { unreachable!("the follower must not run its own closure") })
+                .await
+        });
+
+        tokio::task::yield_now().await;
+        release.notify_one();
+        let first_result = first.await.unwrap();
+        let second_result = second.await.unwrap();
+
+        assert_eq!(first_result, "value");
+        assert_eq!(second_result, "value");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn different_keys_run_independently() {
+        let singleflight = Singleflight::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let calls_for_a = calls.clone();
+        let a = singleflight
+            .run("a", move || {
+                let calls = calls_for_a.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    1
+                }
+            })
+            .await;
+        let calls_for_b = calls.clone();
+        let b = singleflight
+            .run("b", move || {
+                let calls = calls_for_b.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    2
+                }
+            })
+            .await;
+
+        assert_eq!((a, b), (1, 2));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn a_call_after_the_first_completes_runs_again() {
+        let singleflight = Singleflight::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..2 {
+            let calls_for_run = calls.clone();
+            singleflight
+                .run("resource", move || {
+                    let calls = calls_for_run.clone();
+                    async move {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(1)).await;
+                    }
+                })
+                .await;
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,164 @@
+//! Named, tracked task spawning: wraps [`tokio::spawn`] so a running
+//! process can list its background tasks — their name, spawn location,
+//! and running time — to diagnose stuck workers.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::Location;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::task::JoinHandle;
+
+/// A task's lifecycle state, as tracked by a [`TaskRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// The task hasn't returned yet.
+    ///
+    /// A panicked task is also reported as `Running`: its wrapping
+    /// future unwinds before the registry can be updated, so from the
+    /// registry's perspective it looks the same as one still working —
+    /// which is the right signal either way when hunting stuck workers.
+    Running,
+    /// The task returned (successfully or not).
+    Completed,
+}
+
+/// A point-in-time description of one task tracked by a [`TaskRegistry`].
+#[derive(Debug, Clone)]
+pub struct TaskSnapshot {
+    /// The task's registry-assigned id.
+    pub id: u64,
+    /// The name it was spawned with.
+    pub name: String,
+    /// Its current status.
+    pub status: TaskStatus,
+    /// The `file:line` of the [`TaskRegistry::spawn`] call that started it.
+    pub spawn_location: String,
+    /// Time elapsed since it was spawned; for a completed task this keeps
+    /// counting past the moment it finished.
+    pub running_for: Duration,
+}
+
+struct TaskEntry {
+    name: String,
+    status: TaskStatus,
+    spawn_location: String,
+    started_at: Instant,
+}
+
+/// Tracks named tasks spawned via [`TaskRegistry::spawn`], so a running
+/// process can list them at runtime to diagnose stuck workers.
+///
+/// Cloning a `TaskRegistry` shares the same underlying tracking state.
+#[derive(Default, Clone)]
+pub struct TaskRegistry {
+    tasks: Arc<Mutex<HashMap<u64, TaskEntry>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl TaskRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns `future` on the current tokio runtime, tracking it under
+    /// `name` until it completes.
+    #[track_caller]
+    pub fn spawn<F>(&self, name: impl Into<String>, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let location = Location::caller();
+        self.tasks.lock().unwrap().insert(
+            id,
+            TaskEntry {
+                name: name.into(),
+                status: TaskStatus::Running,
+                spawn_location: format!("{}:{}", location.file(), location.line()),
+                started_at: Instant::now(),
+            },
+        );
+
+        let tasks = self.tasks.clone();
+        tokio::spawn(async move {
+            let result = future.await;
+            if let Some(entry) = tasks.lock().unwrap().get_mut(&id) {
+                entry.status = TaskStatus::Completed;
+            }
+            result
+        })
+    }
+
+    /// Lists every tracked task and its current status/running time.
+    pub fn snapshot(&self) -> Vec<TaskSnapshot> {
+        self.tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, entry)| TaskSnapshot {
+                id: *id,
+                name: entry.name.clone(),
+                status: entry.status,
+                spawn_location: entry.spawn_location.clone(),
+                running_for: entry.started_at.elapsed(),
+            })
+            .collect()
+    }
+
+    /// Drops every tracked task that has already completed, keeping
+    /// only currently-running ones.
+    pub fn retain_running(&self) {
+        self.tasks.lock().unwrap().retain(|_, entry| entry.status == TaskStatus::Running);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn snapshot_reports_a_running_task() {
+        let registry = TaskRegistry::new();
+        let (tx, rx) = oneshot::channel::<()>();
+        registry.spawn("worker", async move {
+            let _ = rx.await;
+        });
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].name, "worker");
+        assert_eq!(snapshot[0].status, TaskStatus::Running);
+        assert!(snapshot[0].spawn_location.contains("task.rs"));
+
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    async fn snapshot_reports_a_completed_task() {
+        let registry = TaskRegistry::new();
+        let handle = registry.spawn("quick", async { 1 + 1 });
+        handle.await.unwrap();
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot[0].status, TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn retain_running_drops_completed_tasks() {
+        let registry = TaskRegistry::new();
+        registry.spawn("quick", async {}).await.unwrap();
+        registry.spawn("slow", std::future::pending::<()>());
+
+        registry.retain_running();
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].name, "slow");
+    }
+}
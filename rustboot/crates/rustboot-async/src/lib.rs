@@ -0,0 +1,26 @@
+//! Cooperative cancellation for the rustboot framework.
+//!
+//! - [`CancellationToken`]: a hierarchical cancellation signal — cancel
+//!   one root token and every child derived from it (in the web server,
+//!   the scheduler, messaging consumers, stream tasks, ...) is cancelled
+//!   too, without those subsystems being able to cancel each other.
+//! - [`run_until_cancelled`]: races a future against a token, so a task
+//!   loop can stop waiting on I/O or a timer the moment shutdown is
+//!   requested instead of only checking between iterations.
+//! - [`KeyedMutex`]: a mutex per key, created on first use and reclaimed
+//!   once uncontended.
+//! - [`Debouncer`]: coalesces repeated calls into one, run only once the
+//!   caller has gone quiet for a while.
+//! - [`Singleflight`]: coalesces concurrent calls for the same key into
+//!   one in-flight call, sharing its result with every caller.
+//! - [`TaskRegistry`]: wraps [`tokio::spawn`] to track named tasks'
+//!   status, spawn location, and running time, so a running process can
+//!   list them to diagnose stuck workers.
+
+pub mod core;
+
+pub use core::cancellation::{run_until_cancelled, CancellationToken};
+pub use core::debounce::Debouncer;
+pub use core::keyed_mutex::{KeyedMutex, KeyedMutexGuard};
+pub use core::singleflight::Singleflight;
+pub use core::task::{TaskRegistry, TaskSnapshot, TaskStatus};
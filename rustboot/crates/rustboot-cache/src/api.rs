@@ -0,0 +1,19 @@
+//! Public types for the cache module.
+
+use std::time::Duration;
+
+/// Errors produced by a [`crate::spi::Cache`] implementation.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum CacheError {
+    /// The backing store could not be reached.
+    #[error("cache unavailable: {0}")]
+    Unavailable(String),
+    /// A counter operation (`incr`/`decr`) was attempted on a value that
+    /// isn't a valid integer.
+    #[error("value for key '{0}' is not an integer")]
+    NotAnInteger(String),
+}
+
+/// Caps how long an entry is retained before it expires. `None` means
+/// the entry never expires on its own.
+pub type Ttl = Option<Duration>;
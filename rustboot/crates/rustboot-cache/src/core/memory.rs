@@ -0,0 +1,246 @@
+//! An in-process [`Cache`] backed by a locked hash map, for tests and
+//! single-instance deployments.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::api::{CacheError, Ttl};
+use crate::spi::Cache;
+
+struct Entry {
+    value: serde_json::Value,
+    expires_at: Option<Instant>,
+}
+
+/// An in-memory [`Cache`] with no persistence or cross-process sharing.
+///
+/// Counter (`incr`/`decr`) and [`Cache::compare_and_swap`] operations
+/// hold the map's lock for their whole read-modify-write, so they are
+/// correctly atomic with respect to concurrent callers of this instance.
+#[derive(Default)]
+pub struct InMemoryCache {
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+impl InMemoryCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn expires_at(ttl: Ttl) -> Option<Instant> {
+        ttl.map(|duration| Instant::now() + duration)
+    }
+}
+
+#[async_trait]
+impl Cache for InMemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<serde_json::Value>, CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        Ok(live_value(&mut entries, key))
+    }
+
+    async fn set(&self, key: &str, value: serde_json::Value, ttl: Ttl) -> Result<(), CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                expires_at: Self::expires_at(ttl),
+            },
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, CacheError> {
+        Ok(self.entries.lock().unwrap().remove(key).is_some())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        Ok(live_value(&mut entries, key).is_some())
+    }
+
+    async fn clear(&self) -> Result<(), CacheError> {
+        self.entries.lock().unwrap().clear();
+        Ok(())
+    }
+
+    async fn incr(&self, key: &str, delta: i64) -> Result<i64, CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        let current = match live_value(&mut entries, key) {
+            Some(value) => value
+                .as_i64()
+                .ok_or_else(|| CacheError::NotAnInteger(key.to_string()))?,
+            None => 0,
+        };
+        let updated = current + delta;
+        let expires_at = entries.get(key).and_then(|entry| entry.expires_at);
+        entries.insert(
+            key.to_string(),
+            Entry {
+                value: updated.into(),
+                expires_at,
+            },
+        );
+        Ok(updated)
+    }
+
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        if live_value(&mut entries, key).is_some() {
+            return Ok(false);
+        }
+        entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                expires_at: Self::expires_at(ttl),
+            },
+        );
+        Ok(true)
+    }
+
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        expected: &serde_json::Value,
+        new: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        if live_value(&mut entries, key).as_ref() != Some(expected) {
+            return Ok(false);
+        }
+        entries.insert(
+            key.to_string(),
+            Entry {
+                value: new,
+                expires_at: Self::expires_at(ttl),
+            },
+        );
+        Ok(true)
+    }
+}
+
+/// Returns the live (unexpired) value at `key`, evicting it first if it
+/// has expired.
+fn live_value(entries: &mut HashMap<String, Entry>, key: &str) -> Option<serde_json::Value> {
+    let expired = entries.get(key).is_some_and(|entry| {
+        entry
+            .expires_at
+            .is_some_and(|expires_at| Instant::now() > expires_at)
+    });
+    if expired {
+        entries.remove(key);
+        return None;
+    }
+    entries.get(key).map(|entry| entry.value.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn set_then_get_round_trips_the_value() {
+        let cache = InMemoryCache::new();
+        cache.set("k", json!("v"), None).await.unwrap();
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!("v")));
+    }
+
+    #[tokio::test]
+    async fn get_on_a_missing_key_is_none() {
+        let cache = InMemoryCache::new();
+        assert_eq!(cache.get("missing").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn entries_expire_after_their_ttl() {
+        let cache = InMemoryCache::new();
+        cache
+            .set("k", json!("v"), Some(Duration::from_millis(1)))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(cache.get("k").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn incr_treats_a_missing_key_as_zero() {
+        let cache = InMemoryCache::new();
+        assert_eq!(cache.incr("counter", 5).await.unwrap(), 5);
+        assert_eq!(cache.incr("counter", 3).await.unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn decr_is_incr_with_a_negated_delta() {
+        let cache = InMemoryCache::new();
+        cache.incr("counter", 10).await.unwrap();
+        assert_eq!(cache.decr("counter", 4).await.unwrap(), 6);
+    }
+
+    #[tokio::test]
+    async fn incr_on_a_non_integer_value_errors() {
+        let cache = InMemoryCache::new();
+        cache.set("k", json!("not a number"), None).await.unwrap();
+        assert_eq!(
+            cache.incr("k", 1).await,
+            Err(CacheError::NotAnInteger("k".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn set_if_absent_only_stores_when_no_live_entry_exists() {
+        let cache = InMemoryCache::new();
+        assert!(cache.set_if_absent("k", json!(1), None).await.unwrap());
+        assert!(!cache.set_if_absent("k", json!(2), None).await.unwrap());
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!(1)));
+    }
+
+    #[tokio::test]
+    async fn compare_and_swap_only_swaps_on_a_matching_expected_value() {
+        let cache = InMemoryCache::new();
+        cache.set("k", json!(1), None).await.unwrap();
+
+        assert!(!cache
+            .compare_and_swap("k", &json!(99), json!(2), None)
+            .await
+            .unwrap());
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!(1)));
+
+        assert!(cache
+            .compare_and_swap("k", &json!(1), json!(2), None)
+            .await
+            .unwrap());
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!(2)));
+    }
+
+    #[tokio::test]
+    async fn delete_reports_whether_a_key_was_present() {
+        let cache = InMemoryCache::new();
+        cache.set("k", json!(1), None).await.unwrap();
+        assert!(cache.delete("k").await.unwrap());
+        assert!(!cache.delete("k").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn clear_removes_every_entry() {
+        let cache = InMemoryCache::new();
+        cache.set("a", json!(1), None).await.unwrap();
+        cache.set("b", json!(2), None).await.unwrap();
+        cache.clear().await.unwrap();
+        assert!(!cache.exists("a").await.unwrap());
+        assert!(!cache.exists("b").await.unwrap());
+    }
+}
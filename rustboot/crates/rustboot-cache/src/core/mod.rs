@@ -0,0 +1,4 @@
+//! Implementation details for the cache module.
+
+pub mod memory;
+pub mod redis;
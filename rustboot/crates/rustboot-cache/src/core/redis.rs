@@ -0,0 +1,193 @@
+//! A [`Cache`] over a pluggable [`RedisTransport`], so real Redis
+//! clients (or a fake one, in tests) can back the same [`Cache`] API as
+//! [`crate::core::memory::InMemoryCache`].
+
+use async_trait::async_trait;
+
+use crate::api::{CacheError, Ttl};
+use crate::spi::{Cache, RedisTransport};
+
+/// A [`Cache`] backed by Redis, via any [`RedisTransport`] implementation.
+///
+/// Values round-trip through JSON text, so `GET`/`SET` on the
+/// underlying connection stay plain strings; only `incr`/`decr` assume
+/// the stored value is an integer, matching Redis's own `INCRBY`
+/// semantics.
+pub struct RedisCache<T: RedisTransport> {
+    transport: T,
+}
+
+impl<T: RedisTransport> RedisCache<T> {
+    /// Wraps `transport` as a [`Cache`].
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+}
+
+#[async_trait]
+impl<T: RedisTransport> Cache for RedisCache<T> {
+    async fn get(&self, key: &str) -> Result<Option<serde_json::Value>, CacheError> {
+        match self.transport.get(key).await? {
+            Some(raw) => Ok(Some(decode(&raw)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: serde_json::Value, ttl: Ttl) -> Result<(), CacheError> {
+        self.transport.set(key, &encode(&value), ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, CacheError> {
+        self.transport.del(key).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, CacheError> {
+        Ok(self.transport.get(key).await?.is_some())
+    }
+
+    async fn clear(&self) -> Result<(), CacheError> {
+        self.transport.flush().await
+    }
+
+    async fn incr(&self, key: &str, delta: i64) -> Result<i64, CacheError> {
+        self.transport.incr_by(key, delta).await
+    }
+
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError> {
+        self.transport.set_nx(key, &encode(&value), ttl).await
+    }
+
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        expected: &serde_json::Value,
+        new: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError> {
+        self.transport
+            .compare_and_swap(key, &encode(expected), &encode(&new), ttl)
+            .await
+    }
+}
+
+fn encode(value: &serde_json::Value) -> String {
+    value.to_string()
+}
+
+fn decode(raw: &str) -> Result<serde_json::Value, CacheError> {
+    serde_json::from_str(raw).map_err(|err| CacheError::Unavailable(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct FakeRedisTransport {
+        store: Mutex<std::collections::HashMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl RedisTransport for FakeRedisTransport {
+        async fn get(&self, key: &str) -> Result<Option<String>, CacheError> {
+            Ok(self.store.lock().unwrap().get(key).cloned())
+        }
+
+        async fn set(&self, key: &str, value: &str, _ttl: Ttl) -> Result<(), CacheError> {
+            self.store
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        async fn del(&self, key: &str) -> Result<bool, CacheError> {
+            Ok(self.store.lock().unwrap().remove(key).is_some())
+        }
+
+        async fn flush(&self) -> Result<(), CacheError> {
+            self.store.lock().unwrap().clear();
+            Ok(())
+        }
+
+        async fn incr_by(&self, key: &str, delta: i64) -> Result<i64, CacheError> {
+            let mut store = self.store.lock().unwrap();
+            let current: i64 = store
+                .get(key)
+                .map(|raw| raw.parse())
+                .transpose()
+                .map_err(|_| CacheError::NotAnInteger(key.to_string()))?
+                .unwrap_or(0);
+            let updated = current + delta;
+            store.insert(key.to_string(), updated.to_string());
+            Ok(updated)
+        }
+
+        async fn set_nx(&self, key: &str, value: &str, _ttl: Ttl) -> Result<bool, CacheError> {
+            let mut store = self.store.lock().unwrap();
+            if store.contains_key(key) {
+                return Ok(false);
+            }
+            store.insert(key.to_string(), value.to_string());
+            Ok(true)
+        }
+
+        async fn compare_and_swap(
+            &self,
+            key: &str,
+            expected: &str,
+            new: &str,
+            _ttl: Ttl,
+        ) -> Result<bool, CacheError> {
+            let mut store = self.store.lock().unwrap();
+            if store.get(key).map(String::as_str) != Some(expected) {
+                return Ok(false);
+            }
+            store.insert(key.to_string(), new.to_string());
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn set_then_get_round_trips_through_json_encoding() {
+        let cache = RedisCache::new(FakeRedisTransport::default());
+        cache.set("k", json!({"a": 1}), None).await.unwrap();
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!({"a": 1})));
+    }
+
+    #[tokio::test]
+    async fn incr_delegates_to_the_transport_s_incr_by() {
+        let cache = RedisCache::new(FakeRedisTransport::default());
+        assert_eq!(cache.incr("counter", 5).await.unwrap(), 5);
+        assert_eq!(cache.decr("counter", 2).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn set_if_absent_refuses_to_overwrite_an_existing_key() {
+        let cache = RedisCache::new(FakeRedisTransport::default());
+        assert!(cache.set_if_absent("k", json!(1), None).await.unwrap());
+        assert!(!cache.set_if_absent("k", json!(2), None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn compare_and_swap_only_swaps_on_a_matching_expected_value() {
+        let cache = RedisCache::new(FakeRedisTransport::default());
+        cache.set("k", json!(1), None).await.unwrap();
+        assert!(!cache
+            .compare_and_swap("k", &json!(99), json!(2), None)
+            .await
+            .unwrap());
+        assert!(cache
+            .compare_and_swap("k", &json!(1), json!(2), None)
+            .await
+            .unwrap());
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!(2)));
+    }
+}
@@ -0,0 +1,19 @@
+//! Cache abstraction for the rustboot framework.
+//!
+//! - [`spi::Cache`]: get/set/delete plus atomic `incr`/`decr`,
+//!   `set_if_absent`, and `compare_and_swap`, enough to build counters,
+//!   distributed semaphores, and idempotency markers on top of a cache.
+//! - [`core::memory::InMemoryCache`]: a locked-hash-map implementation
+//!   with correct atomic semantics, for tests and single-instance use.
+//! - [`core::redis::RedisCache`]: a [`spi::Cache`] over any
+//!   [`spi::RedisTransport`], so a real Redis client can be plugged in
+//!   without this crate depending on one.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{CacheError, Ttl};
+pub use core::memory::InMemoryCache;
+pub use core::redis::RedisCache;
+pub use spi::{Cache, RedisTransport};
@@ -0,0 +1,94 @@
+//! Service provider interfaces for the cache module.
+//!
+//! Implement [`Cache`] directly for a backend, or implement
+//! [`RedisTransport`] and wrap it in [`crate::core::redis::RedisCache`]
+//! to get [`Cache`] for free.
+
+use async_trait::async_trait;
+
+use crate::api::{CacheError, Ttl};
+
+/// A cache of string keys to JSON values, with atomic counter and
+/// compare-and-swap operations on top of the usual get/set/delete.
+#[async_trait]
+pub trait Cache: Send + Sync {
+    /// Retrieves the value stored at `key`, if present and unexpired.
+    async fn get(&self, key: &str) -> Result<Option<serde_json::Value>, CacheError>;
+
+    /// Stores `value` at `key`, replacing any existing entry, expiring
+    /// after `ttl` if set.
+    async fn set(&self, key: &str, value: serde_json::Value, ttl: Ttl) -> Result<(), CacheError>;
+
+    /// Removes the entry at `key`. Returns `true` if an entry was removed.
+    async fn delete(&self, key: &str) -> Result<bool, CacheError>;
+
+    /// Returns whether `key` has an unexpired entry.
+    async fn exists(&self, key: &str) -> Result<bool, CacheError>;
+
+    /// Removes every entry.
+    async fn clear(&self) -> Result<(), CacheError>;
+
+    /// Atomically adds `delta` to the integer stored at `key`, treating
+    /// a missing key as `0`, and returns the new value.
+    async fn incr(&self, key: &str, delta: i64) -> Result<i64, CacheError>;
+
+    /// Atomically subtracts `delta` from the integer stored at `key`,
+    /// treating a missing key as `0`, and returns the new value.
+    async fn decr(&self, key: &str, delta: i64) -> Result<i64, CacheError> {
+        self.incr(key, -delta).await
+    }
+
+    /// Stores `value` at `key` only if no unexpired entry already
+    /// exists there. Returns `true` if the value was stored.
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError>;
+
+    /// Atomically replaces the value at `key` with `new` only if its
+    /// current value equals `expected`. Returns `true` if the swap
+    /// happened.
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        expected: &serde_json::Value,
+        new: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError>;
+}
+
+/// Low-level Redis command execution, abstracted so [`crate::core::redis::RedisCache`]
+/// stays testable without linking a real Redis client.
+#[async_trait]
+pub trait RedisTransport: Send + Sync {
+    /// `GET key`.
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError>;
+
+    /// `SET key value [PX ttl_ms]`.
+    async fn set(&self, key: &str, value: &str, ttl: Ttl) -> Result<(), CacheError>;
+
+    /// `DEL key`, returning whether a key was removed.
+    async fn del(&self, key: &str) -> Result<bool, CacheError>;
+
+    /// `FLUSHDB` (or equivalent keyspace-scoped flush).
+    async fn flush(&self) -> Result<(), CacheError>;
+
+    /// `INCRBY key delta`.
+    async fn incr_by(&self, key: &str, delta: i64) -> Result<i64, CacheError>;
+
+    /// `SET key value NX [PX ttl_ms]`, returning whether the key was set.
+    async fn set_nx(&self, key: &str, value: &str, ttl: Ttl) -> Result<bool, CacheError>;
+
+    /// Atomically swaps `key` from `expected` to `new` (e.g. via a Lua
+    /// script or `WATCH`/`MULTI`/`EXEC`), returning whether the current
+    /// value matched `expected`.
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        expected: &str,
+        new: &str,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError>;
+}
@@ -0,0 +1,492 @@
+//! Public types for the CLI.
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// `rustboot` — command-line tools for developing with the rustboot
+/// framework.
+#[derive(Debug, Parser)]
+#[command(name = "rustboot", version, about)]
+pub struct Cli {
+    /// The subcommand to run.
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// A `rustboot` subcommand.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Converts a data file between JSON, YAML, TOML, and MessagePack.
+    Convert(ConvertArgs),
+    /// Scaffolds a new rustboot project.
+    New(NewArgs),
+    /// Generates a correctly-wired file (handler, entity, migration, or
+    /// middleware) in an existing project.
+    Generate(GenerateArgs),
+    /// Inspects a project for common misconfigurations.
+    Doctor(DoctorArgs),
+    /// Runs a project, rebuilding and restarting it on source changes.
+    Dev(DevArgs),
+    /// Works with a project's OpenAPI specification.
+    Openapi(OpenapiArgs),
+}
+
+/// Arguments for `rustboot openapi`.
+#[derive(Debug, Args)]
+pub struct OpenapiArgs {
+    /// The `rustboot openapi` action to perform.
+    #[command(subcommand)]
+    pub action: OpenapiAction,
+}
+
+/// A `rustboot openapi` subcommand.
+#[derive(Debug, Subcommand)]
+pub enum OpenapiAction {
+    /// Builds the project and writes its OpenAPI spec to disk.
+    Export(OpenapiExportArgs),
+}
+
+/// Arguments for `rustboot openapi export`.
+#[derive(Debug, Args)]
+pub struct OpenapiExportArgs {
+    /// Root of the project to build and export from.
+    #[arg(long, default_value = ".")]
+    pub project: PathBuf,
+
+    /// Where to write the spec. Its extension (`.json` or `.yaml`)
+    /// selects the output format.
+    #[arg(long, default_value = "openapi.json")]
+    pub out: PathBuf,
+
+    /// Compare the exported spec against a previously committed one and
+    /// report breaking changes (removed paths or operations; changes to
+    /// request/response fields are not compared) instead of failing
+    /// quietly in review.
+    #[arg(long)]
+    pub diff: Option<PathBuf>,
+}
+
+/// The two formats `rustboot openapi export` writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenapiFormat {
+    Json,
+    Yaml,
+}
+
+impl OpenapiFormat {
+    /// Guesses a format from a file's extension, defaulting to JSON.
+    pub fn from_extension(extension: Option<&str>) -> Self {
+        match extension {
+            Some("yaml") | Some("yml") => OpenapiFormat::Yaml,
+            _ => OpenapiFormat::Json,
+        }
+    }
+}
+
+/// Arguments for `rustboot dev`.
+#[derive(Debug, Args)]
+pub struct DevArgs {
+    /// Root of the project to run.
+    #[arg(long, default_value = ".")]
+    pub project: PathBuf,
+
+    /// How long to wait, after the first change, for a burst of saves
+    /// to settle before rebuilding.
+    #[arg(long, default_value_t = 300)]
+    pub delay_ms: u64,
+
+    /// Address of a front-end dev server (e.g. `localhost:5173`) to
+    /// proxy alongside the app.
+    #[arg(long)]
+    pub proxy: Option<String>,
+
+    /// Local port to listen on for traffic forwarded to `--proxy`.
+    #[arg(long, default_value_t = 4000)]
+    pub proxy_port: u16,
+}
+
+/// Arguments for `rustboot doctor`.
+#[derive(Debug, Args)]
+pub struct DoctorArgs {
+    /// Root of the project to inspect.
+    #[arg(long, default_value = ".")]
+    pub project: PathBuf,
+}
+
+/// One misconfiguration `rustboot doctor` knows to look for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctorCheck {
+    /// `Cargo.toml`'s `[features]` enable more than one database
+    /// feature by default. A declared database feature that nothing
+    /// uses is not flagged.
+    FeatureFlags,
+    /// A database feature is declared in `[features]` but the project
+    /// has no migrations.
+    MissingMigrationsTable,
+    /// A key in the project's config file is never read from source.
+    UnreachableConfigKey,
+    /// A debug-only feature or setting is enabled in the release
+    /// profile.
+    DebugInRelease,
+}
+
+/// One issue found by `rustboot doctor`, with a suggested fix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Which check raised this diagnostic.
+    pub check: DoctorCheck,
+    /// What's wrong.
+    pub message: String,
+    /// How to fix it.
+    pub fix: String,
+}
+
+/// Arguments for `rustboot generate`.
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// What to generate.
+    #[command(subcommand)]
+    pub target: GenerateTarget,
+}
+
+/// A `rustboot generate` subcommand.
+#[derive(Debug, Subcommand)]
+pub enum GenerateTarget {
+    /// Generates an HTTP handler under `src/handlers/` and registers
+    /// its route in `src/routes.rs`.
+    Handler(GenerateNameArgs),
+    /// Generates a data entity under `src/entities/`.
+    Entity(GenerateNameArgs),
+    /// Generates a numbered SQL migration under `migrations/`.
+    Migration(GenerateNameArgs),
+    /// Generates a middleware under `src/middleware/` and registers it
+    /// in `src/middleware.rs`.
+    Middleware(GenerateNameArgs),
+}
+
+/// Arguments shared by every `rustboot generate` subcommand.
+#[derive(Debug, Args)]
+pub struct GenerateNameArgs {
+    /// Name of the generated item, in `snake_case`.
+    pub name: String,
+
+    /// Root of the project to generate into.
+    #[arg(long, default_value = ".")]
+    pub project: PathBuf,
+}
+
+/// Arguments for `rustboot new`.
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    /// Directory to create the project in. Also used as the generated
+    /// crate's name.
+    pub path: PathBuf,
+
+    /// Project template. Prompted for interactively when omitted.
+    #[arg(long, value_enum)]
+    pub template: Option<ProjectTemplate>,
+
+    /// Skip interactive prompts, filling anything not given on the
+    /// command line with the template's defaults.
+    #[arg(long)]
+    pub non_interactive: bool,
+
+    /// Database to wire up. Only meaningful for templates that use
+    /// one; prompted for interactively when omitted and applicable.
+    #[arg(long, value_enum)]
+    pub database: Option<Database>,
+
+    /// Generate an authentication module. Prompted for interactively
+    /// when omitted and applicable.
+    #[arg(long)]
+    pub auth: Option<bool>,
+
+    /// Generate a `Dockerfile` and `docker-compose.yml`. Prompted for
+    /// interactively when omitted.
+    #[arg(long)]
+    pub docker: Option<bool>,
+}
+
+/// A selectable `rustboot new` project template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ProjectTemplate {
+    /// An HTTP API built on `rustboot-web`.
+    RestApi,
+    /// A background job/scheduler service built on `rustboot-scheduler`.
+    Worker,
+    /// A `rustboot-cli`-style command-line tool with no server or
+    /// database wiring.
+    CliTool,
+    /// A `rest-api` paired with a server-rendered frontend.
+    FullStack,
+}
+
+impl ProjectTemplate {
+    /// Whether this template's generated project has anywhere to wire
+    /// a database into.
+    pub fn wants_database(self) -> bool {
+        matches!(self, ProjectTemplate::RestApi | ProjectTemplate::FullStack)
+    }
+
+    /// Whether this template's generated project has anywhere to wire
+    /// authentication into.
+    pub fn wants_auth(self) -> bool {
+        matches!(self, ProjectTemplate::RestApi | ProjectTemplate::FullStack)
+    }
+}
+
+/// A database `rustboot new` can wire a generated project up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Database {
+    /// PostgreSQL.
+    Postgres,
+    /// SQLite.
+    Sqlite,
+    /// MySQL.
+    Mysql,
+    /// No database.
+    None,
+}
+
+/// The fully-resolved answers `rustboot new` needs to generate a
+/// project, whether they came from flags, prompts, or template
+/// defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaffoldConfig {
+    /// The selected template.
+    pub template: ProjectTemplate,
+    /// The selected database, or [`Database::None`] for templates that
+    /// don't use one.
+    pub database: Database,
+    /// Whether to generate an authentication module.
+    pub auth: bool,
+    /// Whether to generate `Dockerfile`/`docker-compose.yml`.
+    pub docker: bool,
+}
+
+impl ScaffoldConfig {
+    /// The config `--non-interactive` falls back to for a template:
+    /// SQLite (or no database, for templates without one), no auth,
+    /// no Docker files.
+    pub fn defaults_for(template: ProjectTemplate) -> Self {
+        Self {
+            template,
+            database: if template.wants_database() { Database::Sqlite } else { Database::None },
+            auth: false,
+            docker: false,
+        }
+    }
+}
+
+/// Arguments for `rustboot convert`.
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+    /// Files to convert. Accepts glob patterns for batch mode. Omit to
+    /// read a single document from stdin.
+    pub inputs: Vec<String>,
+
+    /// Format to convert each input from. Inferred from the input's
+    /// file extension when omitted; required when reading from stdin.
+    #[arg(long)]
+    pub from: Option<DataFormat>,
+
+    /// Format to convert each input to.
+    #[arg(long)]
+    pub to: DataFormat,
+
+    /// Pretty-print the output where the target format supports it
+    /// (JSON only).
+    #[arg(long)]
+    pub pretty: bool,
+
+    /// Write each converted file next to its input, with the target
+    /// format's extension, instead of printing to stdout. Ignored when
+    /// reading from stdin.
+    #[arg(long)]
+    pub in_place: bool,
+}
+
+/// A data format accepted by `--from`/`--to`, mapping onto
+/// [`rustboot_serialization::Format`].
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DataFormat {
+    /// JSON.
+    Json,
+    /// YAML.
+    Yaml,
+    /// TOML.
+    Toml,
+    /// MessagePack.
+    Msgpack,
+}
+
+impl DataFormat {
+    /// The file extension (without a leading dot) this format is
+    /// conventionally saved under.
+    pub fn extension(self) -> &'static str {
+        match self {
+            DataFormat::Json => "json",
+            DataFormat::Yaml => "yaml",
+            DataFormat::Toml => "toml",
+            DataFormat::Msgpack => "msgpack",
+        }
+    }
+
+    /// Guesses a format from a file's extension.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "json" => Some(DataFormat::Json),
+            "yaml" | "yml" => Some(DataFormat::Yaml),
+            "toml" => Some(DataFormat::Toml),
+            "msgpack" | "mp" => Some(DataFormat::Msgpack),
+            _ => None,
+        }
+    }
+}
+
+impl From<DataFormat> for rustboot_serialization::Format {
+    fn from(format: DataFormat) -> Self {
+        match format {
+            DataFormat::Json => rustboot_serialization::Format::Json,
+            DataFormat::Yaml => rustboot_serialization::Format::Yaml,
+            DataFormat::Toml => rustboot_serialization::Format::Toml,
+            DataFormat::Msgpack => rustboot_serialization::Format::MessagePack,
+        }
+    }
+}
+
+/// Errors from running a `rustboot` CLI command.
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    /// An input file couldn't be read.
+    #[error("failed to read '{path}': {source}")]
+    Read {
+        /// The file that couldn't be read.
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A converted document couldn't be written to its destination.
+    #[error("failed to write '{path}': {source}")]
+    Write {
+        /// The file that couldn't be written.
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A glob pattern given as an input was malformed.
+    #[error("glob pattern '{pattern}' is invalid: {source}")]
+    Glob {
+        /// The malformed pattern.
+        pattern: String,
+        #[source]
+        source: glob::PatternError,
+    },
+    /// A glob pattern matched a path that isn't valid UTF-8 or couldn't
+    /// otherwise be read as a file.
+    #[error("glob pattern '{pattern}' failed to match: {source}")]
+    GlobMatch {
+        /// The pattern being matched.
+        pattern: String,
+        #[source]
+        source: glob::GlobError,
+    },
+    /// The format of an input couldn't be inferred and wasn't given
+    /// explicitly with `--from`.
+    #[error("cannot infer the format of '{path}'; pass --from explicitly")]
+    UnknownFormat {
+        /// The input whose format is ambiguous.
+        path: PathBuf,
+    },
+    /// Reading from stdin without `--from` given.
+    #[error("reading from stdin requires --from")]
+    MissingFromForStdin,
+    /// The underlying transcode between formats failed.
+    #[error(transparent)]
+    Serialization(#[from] rustboot_serialization::SerializationError),
+    /// `rustboot new --non-interactive` without `--template`.
+    #[error("--non-interactive requires --template")]
+    MissingTemplateForNonInteractive,
+    /// `rustboot new`'s target directory already exists.
+    #[error("'{path}' already exists")]
+    ProjectAlreadyExists {
+        /// The directory that already exists.
+        path: PathBuf,
+    },
+    /// Reading an interactive prompt's answer failed (e.g. stdin isn't
+    /// a terminal).
+    #[error("failed to read prompt: {0}")]
+    Prompt(#[from] dialoguer::Error),
+    /// `rustboot generate` would overwrite an existing file.
+    #[error("'{path}' already exists")]
+    GeneratedFileAlreadyExists {
+        /// The file that already exists.
+        path: PathBuf,
+    },
+    /// A registration file (`src/routes.rs`, `src/middleware.rs`) is
+    /// missing the marker comment `rustboot generate` inserts new
+    /// registrations above.
+    #[error("'{path}' is missing the `{marker}` marker; add it manually before generating")]
+    MissingRegistrationMarker {
+        /// The registration file missing its marker.
+        path: PathBuf,
+        /// The marker comment that should be present.
+        marker: String,
+    },
+    /// `rustboot doctor` couldn't parse a project's `Cargo.toml` or
+    /// config file.
+    #[error("failed to parse '{path}': {message}")]
+    Malformed {
+        /// The file that failed to parse.
+        path: PathBuf,
+        /// What went wrong.
+        message: String,
+    },
+    /// `rustboot doctor` found one or more issues.
+    #[error("found {0} issue(s); see above for fixes")]
+    DoctorFoundIssues(usize),
+    /// `rustboot dev` couldn't spawn a child process (the app or a
+    /// `--proxy` target).
+    #[error("failed to run '{command}': {source}")]
+    Spawn {
+        /// The command that failed to spawn.
+        command: String,
+        #[source]
+        source: std::io::Error,
+    },
+    /// `rustboot dev`'s file watcher failed.
+    #[error(transparent)]
+    Watch(#[from] rustboot_fileio::FileIoError),
+    /// `rustboot dev --proxy` wasn't a valid `host:port` address.
+    #[error("'--proxy {0}' is not a valid host:port address")]
+    InvalidProxyTarget(String),
+    /// `rustboot dev --proxy`'s local listener couldn't bind.
+    #[error("failed to listen on {addr}: {source}")]
+    Bind {
+        /// The address that couldn't be bound.
+        addr: String,
+        #[source]
+        source: std::io::Error,
+    },
+    /// `cargo build` failed while exporting the OpenAPI spec.
+    #[error("`cargo build` failed for '{project}'")]
+    BuildFailed {
+        /// The project that failed to build.
+        project: PathBuf,
+    },
+    /// The built binary didn't print a spec when run with
+    /// `--print-openapi-spec` (rustboot-openapi's well-known entry
+    /// point), or printed something that wasn't valid JSON.
+    #[error("'{binary}' did not print a valid OpenAPI spec: {message}")]
+    NoOpenapiSpec {
+        /// The binary that was run.
+        binary: PathBuf,
+        /// What went wrong.
+        message: String,
+    },
+    /// `rustboot openapi export --diff` found breaking changes against
+    /// the committed spec.
+    #[error("found {0} breaking change(s) against the committed spec")]
+    OpenapiBreakingChanges(usize),
+}
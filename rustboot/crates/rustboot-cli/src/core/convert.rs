@@ -0,0 +1,139 @@
+//! Implementation of `rustboot convert`.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use rustboot_serialization::{transcode, SerializationError};
+
+use crate::api::{CliError, ConvertArgs, DataFormat};
+
+/// Runs `rustboot convert` with the given arguments: converts each
+/// input (or, with no inputs, a single document read from stdin) from
+/// its source format to `args.to`.
+pub fn run(args: &ConvertArgs) -> Result<(), CliError> {
+    if args.inputs.is_empty() {
+        return convert_stdin(args);
+    }
+
+    for input in &args.inputs {
+        if is_glob_pattern(input) {
+            let paths = glob::glob(input).map_err(|source| CliError::Glob { pattern: input.clone(), source })?;
+            for path in paths {
+                let path = path.map_err(|source| CliError::GlobMatch { pattern: input.clone(), source })?;
+                convert_file(&path, args)?;
+            }
+        } else {
+            convert_file(Path::new(input), args)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_glob_pattern(input: &str) -> bool {
+    input.contains(['*', '?', '['])
+}
+
+fn convert_stdin(args: &ConvertArgs) -> Result<(), CliError> {
+    let from = args.from.ok_or(CliError::MissingFromForStdin)?;
+    let mut input = Vec::new();
+    io::stdin()
+        .read_to_end(&mut input)
+        .map_err(|source| CliError::Read { path: PathBuf::from("<stdin>"), source })?;
+    let output = convert_bytes(&input, from, args)?;
+    write_stdout(&output)
+}
+
+fn convert_file(path: &Path, args: &ConvertArgs) -> Result<(), CliError> {
+    let from = args
+        .from
+        .or_else(|| path.extension().and_then(|ext| ext.to_str()).and_then(DataFormat::from_extension))
+        .ok_or_else(|| CliError::UnknownFormat { path: path.to_path_buf() })?;
+
+    let input = fs::read(path).map_err(|source| CliError::Read { path: path.to_path_buf(), source })?;
+    let output = convert_bytes(&input, from, args)?;
+
+    if args.in_place {
+        let destination = path.with_extension(args.to.extension());
+        fs::write(&destination, output).map_err(|source| CliError::Write { path: destination, source })
+    } else {
+        write_stdout(&output)
+    }
+}
+
+fn convert_bytes(input: &[u8], from: DataFormat, args: &ConvertArgs) -> Result<Vec<u8>, CliError> {
+    let output = transcode(input, from.into(), args.to.into())?;
+    if args.pretty && matches!(args.to, DataFormat::Json) {
+        let value: serde_json::Value = serde_json::from_slice(&output).map_err(SerializationError::from)?;
+        return Ok(serde_json::to_vec_pretty(&value).map_err(SerializationError::from)?);
+    }
+    Ok(output)
+}
+
+fn write_stdout(output: &[u8]) -> Result<(), CliError> {
+    io::stdout().write_all(output).map_err(|source| CliError::Write { path: PathBuf::from("<stdout>"), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_fileio::TempDir;
+
+    fn args(inputs: Vec<&str>, to: DataFormat) -> ConvertArgs {
+        ConvertArgs {
+            inputs: inputs.into_iter().map(String::from).collect(),
+            from: None,
+            to,
+            pretty: false,
+            in_place: false,
+        }
+    }
+
+    #[test]
+    fn is_glob_pattern_recognizes_wildcards() {
+        assert!(is_glob_pattern("*.yaml"));
+        assert!(is_glob_pattern("config?.yaml"));
+        assert!(!is_glob_pattern("config.yaml"));
+    }
+
+    #[test]
+    fn convert_file_infers_format_from_extension_and_writes_in_place() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("config.yaml");
+        fs::write(&input, "name: Ada\n").unwrap();
+
+        let mut convert_args = args(vec![], DataFormat::Json);
+        convert_args.in_place = true;
+        convert_file(&input, &convert_args).unwrap();
+
+        let output = fs::read_to_string(dir.path().join("config.json")).unwrap();
+        assert_eq!(output, r#"{"name":"Ada"}"#);
+    }
+
+    #[test]
+    fn convert_file_rejects_an_unrecognized_extension_without_from() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("config.txt");
+        fs::write(&input, "name: Ada\n").unwrap();
+
+        let err = convert_file(&input, &args(vec![], DataFormat::Json)).unwrap_err();
+        assert!(matches!(err, CliError::UnknownFormat { .. }));
+    }
+
+    #[test]
+    fn convert_bytes_pretty_prints_json_output() {
+        let output = convert_bytes(b"name: Ada\n", DataFormat::Yaml, &{
+            let mut a = args(vec![], DataFormat::Json);
+            a.pretty = true;
+            a
+        })
+        .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "{\n  \"name\": \"Ada\"\n}");
+    }
+
+    #[test]
+    fn run_requires_from_when_reading_from_stdin() {
+        let err = convert_stdin(&args(vec![], DataFormat::Json)).unwrap_err();
+        assert!(matches!(err, CliError::MissingFromForStdin));
+    }
+}
@@ -0,0 +1,181 @@
+//! Implementation of `rustboot dev`: runs a project with `cargo run`,
+//! restarting it whenever a source file changes, and optionally proxies
+//! a front-end dev server alongside it so a developer only has one
+//! address to point a browser at.
+
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+
+use rustboot_async::{run_until_cancelled, CancellationToken};
+use rustboot_streams::StreamItem;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::process::{Child, Command};
+
+use crate::api::{CliError, DevArgs};
+
+/// How long to give the app to exit after a graceful stop signal before
+/// killing it outright.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
+/// Runs `rustboot dev` until the user sends `Ctrl-C`: starts the app,
+/// watches `project/src` for changes, and restarts it on every change.
+/// If `args.proxy` is set, also forwards `args.proxy_port` to it.
+pub async fn run(args: &DevArgs) -> Result<(), CliError> {
+    let token = CancellationToken::new();
+    let ctrl_c_token = token.clone();
+    tokio::spawn(async move {
+        let _ = tokio::signal::ctrl_c().await;
+        ctrl_c_token.cancel();
+    });
+
+    if let Some(proxy) = &args.proxy {
+        let target = parse_proxy_target(proxy)?;
+        let proxy_token = token.child_token();
+        let listen_addr = format!("127.0.0.1:{}", args.proxy_port);
+        let listener =
+            TcpListener::bind(&listen_addr).await.map_err(|source| CliError::Bind { addr: listen_addr, source })?;
+        tokio::spawn(async move {
+            run_proxy(listener, target, proxy_token).await;
+        });
+    }
+
+    let mut events = rustboot_fileio::watch_path(args.project.join("src"), Duration::from_millis(args.delay_ms))?;
+    let mut app = spawn_app(&args.project)?;
+
+    loop {
+        let Some(outcome) = run_until_cancelled(events.recv(), &token).await else {
+            break;
+        };
+        match outcome {
+            Some(StreamItem::Item(_)) => {
+                println!("rustboot dev: change detected, restarting");
+                stop(&mut app).await;
+                app = spawn_app(&args.project)?;
+            }
+            _ => break,
+        }
+    }
+
+    stop(&mut app).await;
+    Ok(())
+}
+
+/// Starts `cargo run` for the project rooted at `project`.
+fn spawn_app(project: &Path) -> Result<Child, CliError> {
+    Command::new("cargo")
+        .arg("run")
+        .arg("--manifest-path")
+        .arg(project.join("Cargo.toml"))
+        .stdin(Stdio::null())
+        .spawn()
+        .map_err(|source| CliError::Spawn { command: "cargo run".to_string(), source })
+}
+
+/// Stops `child`, giving it [`SHUTDOWN_GRACE`] to exit after a graceful
+/// stop signal (`SIGTERM` on Unix) before killing it outright.
+async fn stop(child: &mut Child) {
+    request_graceful_stop(child);
+    if tokio::time::timeout(SHUTDOWN_GRACE, child.wait()).await.is_err() {
+        let _ = child.start_kill();
+        let _ = child.wait().await;
+    }
+}
+
+#[cfg(unix)]
+fn request_graceful_stop(child: &Child) {
+    if let Some(pid) = child.id() {
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn request_graceful_stop(child: &mut Child) {
+    let _ = child.start_kill();
+}
+
+/// Parses a `--proxy` value (an optional `http://`/`https://` scheme
+/// followed by `host:port`) into a `host:port` pair suitable for
+/// [`TcpStream::connect`].
+fn parse_proxy_target(spec: &str) -> Result<String, CliError> {
+    let without_scheme = spec.split_once("://").map_or(spec, |(_, rest)| rest);
+    let target = without_scheme.trim_end_matches('/');
+    if target.rsplit_once(':').is_some_and(|(_, port)| port.parse::<u16>().is_ok()) {
+        Ok(target.to_string())
+    } else {
+        Err(CliError::InvalidProxyTarget(spec.to_string()))
+    }
+}
+
+/// Accepts connections on `listener` and forwards each, byte for byte,
+/// to `target`, until `token` is cancelled.
+async fn run_proxy(listener: TcpListener, target: String, token: CancellationToken) {
+    loop {
+        let Some(accepted) = run_until_cancelled(listener.accept(), &token).await else {
+            return;
+        };
+        let Ok((inbound, _)) = accepted else { continue };
+        let target = target.clone();
+        tokio::spawn(async move {
+            let _ = proxy_one(inbound, &target).await;
+        });
+    }
+}
+
+async fn proxy_one(mut inbound: TcpStream, target: &str) -> std::io::Result<()> {
+    let mut outbound = TcpStream::connect(target).await?;
+    let result = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+    let _ = outbound.shutdown().await;
+    result.map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_proxy_target_strips_a_scheme() {
+        assert_eq!(parse_proxy_target("http://localhost:5173").unwrap(), "localhost:5173");
+    }
+
+    #[test]
+    fn parse_proxy_target_accepts_a_bare_host_and_port() {
+        assert_eq!(parse_proxy_target("localhost:5173").unwrap(), "localhost:5173");
+    }
+
+    #[test]
+    fn parse_proxy_target_rejects_a_missing_port() {
+        assert!(matches!(parse_proxy_target("localhost"), Err(CliError::InvalidProxyTarget(_))));
+    }
+
+    #[tokio::test]
+    async fn run_proxy_forwards_bytes_to_the_target() {
+        let target_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let target_addr = target_listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            let (mut socket, _) = target_listener.accept().await.unwrap();
+            let mut buf = [0u8; 5];
+            tokio::io::AsyncReadExt::read_exact(&mut socket, &mut buf).await.unwrap();
+            socket.write_all(&buf).await.unwrap();
+        });
+
+        let front_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let front_addr = front_listener.local_addr().unwrap();
+        let token = CancellationToken::new();
+        let proxy_token = token.clone();
+        tokio::spawn(async move {
+            run_proxy(front_listener, target_addr, proxy_token).await;
+        });
+
+        let mut client = TcpStream::connect(front_addr).await.unwrap();
+        client.write_all(b"hello").await.unwrap();
+        let mut echoed = [0u8; 5];
+        tokio::io::AsyncReadExt::read_exact(&mut client, &mut echoed).await.unwrap();
+        assert_eq!(&echoed, b"hello");
+
+        token.cancel();
+    }
+}
@@ -0,0 +1,315 @@
+//! Implementation of `rustboot doctor`: static checks over a project
+//! generated by `rustboot new`, each independent and returning zero or
+//! more [`Diagnostic`]s rather than failing the whole run.
+
+use std::fs;
+use std::path::Path;
+
+use crate::api::{CliError, Diagnostic, DoctorArgs, DoctorCheck};
+
+const DATABASE_FEATURES: [&str; 3] = ["postgres", "sqlite", "mysql"];
+
+/// Runs every `rustboot doctor` check against `args.project`, returning
+/// every issue found (an empty list means a clean bill of health).
+pub fn run(args: &DoctorArgs) -> Result<Vec<Diagnostic>, CliError> {
+    let cargo_toml_path = args.project.join("Cargo.toml");
+    let cargo_toml_text = fs::read_to_string(&cargo_toml_path)
+        .map_err(|source| CliError::Read { path: cargo_toml_path.clone(), source })?;
+    let cargo_toml: toml::Table = cargo_toml_text
+        .parse()
+        .map_err(|err: toml::de::Error| CliError::Malformed { path: cargo_toml_path.clone(), message: err.to_string() })?;
+
+    let mut diagnostics = Vec::new();
+    diagnostics.extend(check_feature_flags(&cargo_toml));
+    diagnostics.extend(check_missing_migrations_table(&cargo_toml, &args.project)?);
+    diagnostics.extend(check_unreachable_config_keys(&args.project)?);
+    diagnostics.extend(check_debug_in_release(&cargo_toml));
+    Ok(diagnostics)
+}
+
+fn default_features(cargo_toml: &toml::Table) -> Vec<String> {
+    cargo_toml
+        .get("features")
+        .and_then(|features| features.get("default"))
+        .and_then(|default| default.as_array())
+        .map(|entries| entries.iter().filter_map(|entry| entry.as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+fn declared_features(cargo_toml: &toml::Table) -> Vec<String> {
+    cargo_toml
+        .get("features")
+        .and_then(|features| features.as_table())
+        .map(|table| table.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn check_feature_flags(cargo_toml: &toml::Table) -> Vec<Diagnostic> {
+    let default = default_features(cargo_toml);
+    let enabled_databases: Vec<&str> =
+        DATABASE_FEATURES.iter().copied().filter(|feature| default.iter().any(|d| d == feature)).collect();
+
+    if enabled_databases.len() > 1 {
+        vec![Diagnostic {
+            check: DoctorCheck::FeatureFlags,
+            message: format!("multiple database features enabled by default: {}", enabled_databases.join(", ")),
+            fix: "keep only one database feature in [features].default".to_string(),
+        }]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_missing_migrations_table(cargo_toml: &toml::Table, project: &Path) -> Result<Vec<Diagnostic>, CliError> {
+    let declared = declared_features(cargo_toml);
+    let has_database_feature = DATABASE_FEATURES.iter().any(|feature| declared.iter().any(|d| d == feature));
+    if !has_database_feature {
+        return Ok(Vec::new());
+    }
+
+    let migrations = project.join("migrations");
+    let has_migrations = migrations.is_dir()
+        && fs::read_dir(&migrations)
+            .map_err(|source| CliError::Read { path: migrations.clone(), source })?
+            .filter_map(Result::ok)
+            .any(|entry| entry.path().extension().is_some_and(|ext| ext == "sql"));
+
+    if has_migrations {
+        Ok(Vec::new())
+    } else {
+        Ok(vec![Diagnostic {
+            check: DoctorCheck::MissingMigrationsTable,
+            message: "a database feature is declared but no migrations were found".to_string(),
+            fix: "run `rustboot generate migration <name>` to create the first migration".to_string(),
+        }])
+    }
+}
+
+fn config_file(project: &Path) -> Option<(std::path::PathBuf, ConfigFormat)> {
+    for (name, format) in
+        [("config.toml", ConfigFormat::Toml), ("config.yaml", ConfigFormat::Yaml), ("config.json", ConfigFormat::Json)]
+    {
+        let path = project.join(name);
+        if path.is_file() {
+            return Some((path, format));
+        }
+    }
+    None
+}
+
+enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+fn check_unreachable_config_keys(project: &Path) -> Result<Vec<Diagnostic>, CliError> {
+    let Some((path, format)) = config_file(project) else { return Ok(Vec::new()) };
+    let text = fs::read_to_string(&path).map_err(|source| CliError::Read { path: path.clone(), source })?;
+
+    let keys = match format {
+        ConfigFormat::Toml => {
+            let value: toml::Table = text
+                .parse()
+                .map_err(|err: toml::de::Error| CliError::Malformed { path: path.clone(), message: err.to_string() })?;
+            dotted_toml_keys(&toml::Value::Table(value), "")
+        }
+        ConfigFormat::Yaml => {
+            let value: serde_yaml::Value = serde_yaml::from_str(&text)
+                .map_err(|err| CliError::Malformed { path: path.clone(), message: err.to_string() })?;
+            dotted_yaml_keys(&value, "")
+        }
+        ConfigFormat::Json => {
+            let value: serde_json::Value = serde_json::from_str(&text)
+                .map_err(|err| CliError::Malformed { path: path.clone(), message: err.to_string() })?;
+            dotted_json_keys(&value, "")
+        }
+    };
+
+    let source = read_source(&project.join("src"))?;
+    let mut diagnostics = Vec::new();
+    for key in keys {
+        if !source.contains(&key) {
+            diagnostics.push(Diagnostic {
+                check: DoctorCheck::UnreachableConfigKey,
+                message: format!("config key '{key}' is never read from src/"),
+                fix: format!("remove '{key}' from {}, or read it with config.get(\"{key}\")", path.display()),
+            });
+        }
+    }
+    Ok(diagnostics)
+}
+
+fn dotted_toml_keys(value: &toml::Value, prefix: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(table) = value.as_table() {
+        for (key, value) in table {
+            let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+            if value.is_table() {
+                keys.extend(dotted_toml_keys(value, &path));
+            } else {
+                keys.push(path);
+            }
+        }
+    }
+    keys
+}
+
+fn dotted_yaml_keys(value: &serde_yaml::Value, prefix: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(mapping) = value.as_mapping() {
+        for (key, value) in mapping {
+            let Some(key) = key.as_str() else { continue };
+            let path = if prefix.is_empty() { key.to_string() } else { format!("{prefix}.{key}") };
+            if value.is_mapping() {
+                keys.extend(dotted_yaml_keys(value, &path));
+            } else {
+                keys.push(path);
+            }
+        }
+    }
+    keys
+}
+
+fn dotted_json_keys(value: &serde_json::Value, prefix: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(object) = value.as_object() {
+        for (key, value) in object {
+            let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+            if value.is_object() {
+                keys.extend(dotted_json_keys(value, &path));
+            } else {
+                keys.push(path);
+            }
+        }
+    }
+    keys
+}
+
+fn read_source(src: &Path) -> Result<String, CliError> {
+    let mut combined = String::new();
+    if !src.is_dir() {
+        return Ok(combined);
+    }
+    let entries = fs::read_dir(src).map_err(|source| CliError::Read { path: src.to_path_buf(), source })?;
+    for entry in entries {
+        let entry = entry.map_err(|source| CliError::Read { path: src.to_path_buf(), source })?;
+        let path = entry.path();
+        if path.is_dir() {
+            combined.push_str(&read_source(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            combined.push_str(&fs::read_to_string(&path).map_err(|source| CliError::Read { path, source })?);
+        }
+    }
+    Ok(combined)
+}
+
+fn check_debug_in_release(cargo_toml: &toml::Table) -> Vec<Diagnostic> {
+    let debug_enabled = cargo_toml
+        .get("profile")
+        .and_then(|profile| profile.get("release"))
+        .and_then(|release| release.get("debug"))
+        .and_then(toml::Value::as_bool)
+        .unwrap_or(false);
+
+    let debug_feature_default = default_features(cargo_toml).iter().any(|feature| feature == "debug");
+
+    let mut diagnostics = Vec::new();
+    if debug_enabled {
+        diagnostics.push(Diagnostic {
+            check: DoctorCheck::DebugInRelease,
+            message: "[profile.release] has debug = true".to_string(),
+            fix: "remove debug = true from [profile.release], or set it to false".to_string(),
+        });
+    }
+    if debug_feature_default {
+        diagnostics.push(Diagnostic {
+            check: DoctorCheck::DebugInRelease,
+            message: "the 'debug' feature is enabled by default".to_string(),
+            fix: "remove 'debug' from [features].default and enable it explicitly for dev builds".to_string(),
+        });
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_fileio::TempDir;
+
+    fn write(dir: &Path, relative: &str, contents: &str) {
+        let path = dir.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn flags_multiple_default_database_features() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[features]\ndefault = [\"postgres\", \"sqlite\"]\npostgres = []\nsqlite = []\n",
+        );
+        let diagnostics = run(&DoctorArgs { project: dir.path().to_path_buf() }).unwrap();
+        assert!(diagnostics.iter().any(|d| d.check == DoctorCheck::FeatureFlags));
+    }
+
+    #[test]
+    fn flags_a_database_feature_without_migrations() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[features]\npostgres = []\n",
+        );
+        let diagnostics = run(&DoctorArgs { project: dir.path().to_path_buf() }).unwrap();
+        assert!(diagnostics.iter().any(|d| d.check == DoctorCheck::MissingMigrationsTable));
+    }
+
+    #[test]
+    fn passes_a_database_feature_with_migrations() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[features]\npostgres = []\n",
+        );
+        write(dir.path(), "migrations/0001_init.sql", "-- init\n");
+        let diagnostics = run(&DoctorArgs { project: dir.path().to_path_buf() }).unwrap();
+        assert!(!diagnostics.iter().any(|d| d.check == DoctorCheck::MissingMigrationsTable));
+    }
+
+    #[test]
+    fn flags_an_unreachable_config_key() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "Cargo.toml", "[package]\nname = \"app\"\nversion = \"0.1.0\"\n");
+        write(dir.path(), "config.toml", "[server]\nport = 8080\nunused = 1\n");
+        write(dir.path(), "src/main.rs", "fn main() { let _ = config.get::<u16>(\"server.port\"); }\n");
+
+        let diagnostics = run(&DoctorArgs { project: dir.path().to_path_buf() }).unwrap();
+        assert!(diagnostics.iter().any(|d| d.check == DoctorCheck::UnreachableConfigKey
+            && d.message.contains("server.unused")));
+        assert!(!diagnostics.iter().any(|d| d.message.contains("server.port")));
+    }
+
+    #[test]
+    fn flags_debug_enabled_in_release_profile() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[profile.release]\ndebug = true\n",
+        );
+        let diagnostics = run(&DoctorArgs { project: dir.path().to_path_buf() }).unwrap();
+        assert!(diagnostics.iter().any(|d| d.check == DoctorCheck::DebugInRelease));
+    }
+
+    #[test]
+    fn returns_no_diagnostics_for_a_clean_project() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "Cargo.toml", "[package]\nname = \"app\"\nversion = \"0.1.0\"\n");
+        let diagnostics = run(&DoctorArgs { project: dir.path().to_path_buf() }).unwrap();
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -0,0 +1,245 @@
+//! Implementation of `rustboot generate`: adds a correctly-wired file
+//! to an existing project — a handler or middleware with its
+//! registration inserted into the project's registry file, a bare
+//! entity, or a numbered migration.
+
+use std::fs;
+use std::path::Path;
+
+use crate::api::{CliError, GenerateArgs, GenerateNameArgs, GenerateTarget};
+
+const ROUTES_MARKER: &str = "// rustboot:generate:routes";
+const MIDDLEWARE_MARKER: &str = "// rustboot:generate:middleware";
+
+/// Runs `rustboot generate` with the given arguments.
+pub fn run(args: &GenerateArgs) -> Result<(), CliError> {
+    match &args.target {
+        GenerateTarget::Handler(args) => generate_handler(args),
+        GenerateTarget::Entity(args) => generate_entity(args),
+        GenerateTarget::Migration(args) => generate_migration(args),
+        GenerateTarget::Middleware(args) => generate_middleware(args),
+    }
+}
+
+fn generate_handler(args: &GenerateNameArgs) -> Result<(), CliError> {
+    let src = args.project.join("src");
+    let name = &args.name;
+
+    write_module_file(
+        &src.join("handlers"),
+        name,
+        &format!("//! The `{name}` handler.\n\npub async fn {name}() -> &'static str {{\n    \"{name}\"\n}}\n"),
+    )?;
+    register_in(
+        &src.join("routes.rs"),
+        ROUTES_MARKER,
+        "//! Routes registered by `rustboot generate handler`.\n\nuse crate::handlers;\n\n",
+        &format!("router.route(\"/{name}\", handlers::{name});\n"),
+    )
+}
+
+fn generate_entity(args: &GenerateNameArgs) -> Result<(), CliError> {
+    let src = args.project.join("src");
+    let name = &args.name;
+    let type_name = to_pascal_case(name);
+
+    write_module_file(
+        &src.join("entities"),
+        name,
+        &format!("//! The `{type_name}` entity.\n\n#[derive(Debug, Clone)]\npub struct {type_name} {{\n    pub id: i64,\n}}\n"),
+    )
+}
+
+fn generate_migration(args: &GenerateNameArgs) -> Result<(), CliError> {
+    let migrations = args.project.join("migrations");
+    fs::create_dir_all(&migrations).map_err(|source| CliError::Write { path: migrations.clone(), source })?;
+
+    let next = next_migration_number(&migrations)?;
+    let path = migrations.join(format!("{next:04}_{}.sql", args.name));
+    if path.exists() {
+        return Err(CliError::GeneratedFileAlreadyExists { path });
+    }
+    fs::write(&path, format!("-- migration: {}\n", args.name)).map_err(|source| CliError::Write { path, source })
+}
+
+fn generate_middleware(args: &GenerateNameArgs) -> Result<(), CliError> {
+    let src = args.project.join("src");
+    let name = &args.name;
+
+    write_module_file(
+        &src.join("middleware"),
+        name,
+        &format!(
+            "//! The `{name}` middleware.\n\npub fn {name}<F>(next: F) -> impl Fn() + Clone\nwhere\n    F: Fn() + Clone,\n{{\n    move || next()\n}}\n"
+        ),
+    )?;
+    register_in(
+        &src.join("middleware.rs"),
+        MIDDLEWARE_MARKER,
+        "//! Middleware registered by `rustboot generate middleware`.\n\nuse crate::middleware;\n\n",
+        &format!("app.middleware(middleware::{name});\n"),
+    )
+}
+
+/// Creates `dir/{name}.rs` with `contents`, and ensures `dir/mod.rs`
+/// declares it. Fails if `dir/{name}.rs` already exists.
+fn write_module_file(dir: &Path, name: &str, contents: &str) -> Result<(), CliError> {
+    fs::create_dir_all(dir).map_err(|source| CliError::Write { path: dir.to_path_buf(), source })?;
+
+    let file = dir.join(format!("{name}.rs"));
+    if file.exists() {
+        return Err(CliError::GeneratedFileAlreadyExists { path: file });
+    }
+    fs::write(&file, contents).map_err(|source| CliError::Write { path: file, source })?;
+
+    let mod_rs = dir.join("mod.rs");
+    let declaration = format!("pub mod {name};\n");
+    let existing = fs::read_to_string(&mod_rs).unwrap_or_default();
+    if !existing.contains(&declaration) {
+        let updated = format!("{existing}{declaration}");
+        fs::write(&mod_rs, updated).map_err(|source| CliError::Write { path: mod_rs, source })?;
+    }
+    Ok(())
+}
+
+/// Inserts `registration` just above `marker` in `path`, creating the
+/// file as `header` followed by `marker`'s line if it doesn't exist yet.
+fn register_in(path: &Path, marker: &str, header: &str, registration: &str) -> Result<(), CliError> {
+    let existing = if path.exists() {
+        fs::read_to_string(path).map_err(|source| CliError::Read { path: path.to_path_buf(), source })?
+    } else {
+        format!("{header}{marker}\n")
+    };
+
+    let Some(marker_pos) = existing.find(marker) else {
+        return Err(CliError::MissingRegistrationMarker { path: path.to_path_buf(), marker: marker.to_string() });
+    };
+
+    let mut updated = String::with_capacity(existing.len() + registration.len());
+    updated.push_str(&existing[..marker_pos]);
+    updated.push_str(registration);
+    updated.push_str(&existing[marker_pos..]);
+
+    fs::write(path, updated).map_err(|source| CliError::Write { path: path.to_path_buf(), source })
+}
+
+/// The next unused 4-digit migration number in `migrations`, based on
+/// the highest `NNNN_*.sql` prefix already present.
+fn next_migration_number(migrations: &Path) -> Result<u32, CliError> {
+    let entries =
+        fs::read_dir(migrations).map_err(|source| CliError::Read { path: migrations.to_path_buf(), source })?;
+
+    let mut highest = 0;
+    for entry in entries {
+        let entry = entry.map_err(|source| CliError::Read { path: migrations.to_path_buf(), source })?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if let Some(number) = name.split('_').next().and_then(|prefix| prefix.parse::<u32>().ok()) {
+            highest = highest.max(number);
+        }
+    }
+    Ok(highest + 1)
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_fileio::TempDir;
+
+    fn name_args(project: &Path, name: &str) -> GenerateNameArgs {
+        GenerateNameArgs { name: name.to_string(), project: project.to_path_buf() }
+    }
+
+    #[test]
+    fn generate_handler_writes_file_mod_and_route() {
+        let dir = TempDir::new().unwrap();
+        generate_handler(&name_args(dir.path(), "health")).unwrap();
+
+        let handler = fs::read_to_string(dir.path().join("src/handlers/health.rs")).unwrap();
+        assert!(handler.contains("pub async fn health"));
+
+        let mod_rs = fs::read_to_string(dir.path().join("src/handlers/mod.rs")).unwrap();
+        assert_eq!(mod_rs, "pub mod health;\n");
+
+        let routes = fs::read_to_string(dir.path().join("src/routes.rs")).unwrap();
+        assert!(routes.contains("router.route(\"/health\", handlers::health);"));
+        assert!(routes.find("router.route").unwrap() < routes.find(ROUTES_MARKER).unwrap());
+    }
+
+    #[test]
+    fn generate_handler_appends_a_second_route_above_the_marker() {
+        let dir = TempDir::new().unwrap();
+        generate_handler(&name_args(dir.path(), "health")).unwrap();
+        generate_handler(&name_args(dir.path(), "status")).unwrap();
+
+        let routes = fs::read_to_string(dir.path().join("src/routes.rs")).unwrap();
+        assert!(routes.contains("handlers::health"));
+        assert!(routes.contains("handlers::status"));
+
+        let mod_rs = fs::read_to_string(dir.path().join("src/handlers/mod.rs")).unwrap();
+        assert!(mod_rs.contains("pub mod health;"));
+        assert!(mod_rs.contains("pub mod status;"));
+    }
+
+    #[test]
+    fn generate_handler_rejects_a_duplicate_name() {
+        let dir = TempDir::new().unwrap();
+        generate_handler(&name_args(dir.path(), "health")).unwrap();
+        let err = generate_handler(&name_args(dir.path(), "health")).unwrap_err();
+        assert!(matches!(err, CliError::GeneratedFileAlreadyExists { .. }));
+    }
+
+    #[test]
+    fn generate_entity_writes_a_pascal_case_struct() {
+        let dir = TempDir::new().unwrap();
+        generate_entity(&name_args(dir.path(), "blog_post")).unwrap();
+
+        let entity = fs::read_to_string(dir.path().join("src/entities/blog_post.rs")).unwrap();
+        assert!(entity.contains("pub struct BlogPost"));
+    }
+
+    #[test]
+    fn generate_migration_numbers_sequentially() {
+        let dir = TempDir::new().unwrap();
+        generate_migration(&name_args(dir.path(), "create_users")).unwrap();
+        generate_migration(&name_args(dir.path(), "add_users_email_index")).unwrap();
+
+        assert!(dir.path().join("migrations/0001_create_users.sql").exists());
+        assert!(dir.path().join("migrations/0002_add_users_email_index.sql").exists());
+    }
+
+    #[test]
+    fn generate_middleware_writes_file_mod_and_registration() {
+        let dir = TempDir::new().unwrap();
+        generate_middleware(&name_args(dir.path(), "request_id")).unwrap();
+
+        let middleware = fs::read_to_string(dir.path().join("src/middleware/request_id.rs")).unwrap();
+        assert!(middleware.contains("pub fn request_id"));
+
+        let registry = fs::read_to_string(dir.path().join("src/middleware.rs")).unwrap();
+        assert!(registry.contains("app.middleware(middleware::request_id);"));
+    }
+
+    #[test]
+    fn register_in_rejects_a_registry_file_missing_its_marker() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/routes.rs"), "// no marker here\n").unwrap();
+
+        let err = generate_handler(&name_args(dir.path(), "health")).unwrap_err();
+        assert!(matches!(err, CliError::MissingRegistrationMarker { .. }));
+    }
+}
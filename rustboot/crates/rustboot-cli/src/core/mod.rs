@@ -0,0 +1,9 @@
+//! Implementation details for the `rustboot` CLI's subcommands.
+
+pub mod convert;
+pub mod dev;
+pub mod doctor;
+pub mod generate;
+pub mod new;
+pub mod openapi;
+pub mod scaffold;
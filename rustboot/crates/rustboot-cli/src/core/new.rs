@@ -0,0 +1,85 @@
+//! Implementation of `rustboot new`: resolves a [`ScaffoldConfig`] from
+//! flags and interactive prompts, then hands it to
+//! [`crate::core::scaffold::generate`].
+//!
+//! Prompting is kept out of [`crate::core::scaffold`] so that module's
+//! file-generation logic stays testable without a terminal, the same
+//! split [`crate::core::convert`] draws between its stdin/file I/O and
+//! its pure `convert_bytes`.
+
+use dialoguer::{Confirm, Select};
+
+use crate::api::{CliError, Database, NewArgs, ProjectTemplate, ScaffoldConfig};
+use crate::core::scaffold;
+
+/// Runs `rustboot new` with the given arguments.
+pub fn run(args: &NewArgs) -> Result<(), CliError> {
+    if args.path.exists() {
+        return Err(CliError::ProjectAlreadyExists { path: args.path.clone() });
+    }
+
+    let config = resolve_config(args)?;
+    scaffold::generate(&args.path, &config)
+}
+
+fn resolve_config(args: &NewArgs) -> Result<ScaffoldConfig, CliError> {
+    if args.non_interactive {
+        let template = args.template.ok_or(CliError::MissingTemplateForNonInteractive)?;
+        let mut config = ScaffoldConfig::defaults_for(template);
+        if let Some(database) = args.database {
+            config.database = database;
+        }
+        if let Some(auth) = args.auth {
+            config.auth = auth;
+        }
+        if let Some(docker) = args.docker {
+            config.docker = docker;
+        }
+        return Ok(config);
+    }
+
+    let template = match args.template {
+        Some(template) => template,
+        None => prompt_template()?,
+    };
+
+    let database = if !template.wants_database() {
+        Database::None
+    } else if let Some(database) = args.database {
+        database
+    } else {
+        prompt_database()?
+    };
+
+    let auth = if !template.wants_auth() {
+        false
+    } else if let Some(auth) = args.auth {
+        auth
+    } else {
+        Confirm::new().with_prompt("Generate an authentication module?").default(false).interact()?
+    };
+
+    let docker = match args.docker {
+        Some(docker) => docker,
+        None => Confirm::new().with_prompt("Generate Dockerfile and docker-compose.yml?").default(false).interact()?,
+    };
+
+    Ok(ScaffoldConfig { template, database, auth, docker })
+}
+
+fn prompt_template() -> Result<ProjectTemplate, CliError> {
+    const TEMPLATES: [ProjectTemplate; 4] =
+        [ProjectTemplate::RestApi, ProjectTemplate::Worker, ProjectTemplate::CliTool, ProjectTemplate::FullStack];
+    let labels = ["rest-api", "worker", "cli-tool", "full-stack"];
+
+    let selection = Select::new().with_prompt("Select a project template").items(labels).default(0).interact()?;
+    Ok(TEMPLATES[selection])
+}
+
+fn prompt_database() -> Result<Database, CliError> {
+    const DATABASES: [Database; 4] = [Database::Postgres, Database::Sqlite, Database::Mysql, Database::None];
+    let labels = ["postgres", "sqlite", "mysql", "none"];
+
+    let selection = Select::new().with_prompt("Select a database").items(labels).default(1).interact()?;
+    Ok(DATABASES[selection])
+}
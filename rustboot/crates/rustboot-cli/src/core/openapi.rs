@@ -0,0 +1,188 @@
+//! Implementation of `rustboot openapi`: builds a project and exports
+//! the OpenAPI spec its binary produces at a well-known entry point,
+//! with an optional diff against a previously committed spec so
+//! breaking changes surface in review instead of at release.
+//!
+//! The entry point convention: a project generated with `rustboot new`
+//! and a `rustboot-openapi`-derived API wires up `--print-openapi-spec`
+//! on its binary to print the spec as JSON to stdout and exit.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::api::{CliError, OpenapiExportArgs, OpenapiFormat};
+
+const PRINT_SPEC_FLAG: &str = "--print-openapi-spec";
+
+/// Runs `rustboot openapi export`: builds the project, captures its
+/// spec, diffs it against `args.diff` if given, and writes it to
+/// `args.out`.
+pub fn run(args: &OpenapiExportArgs) -> Result<(), CliError> {
+    let spec = build_and_capture_spec(&args.project)?;
+
+    if let Some(diff_against) = &args.diff {
+        let committed_text =
+            fs::read_to_string(diff_against).map_err(|source| CliError::Read { path: diff_against.clone(), source })?;
+        let committed_format = OpenapiFormat::from_extension(diff_against.extension().and_then(|ext| ext.to_str()));
+        let committed = parse_spec(&committed_text, committed_format, diff_against)?;
+
+        let breaking = diff_specs(&committed, &spec);
+        for change in &breaking {
+            println!("breaking change: {change}");
+        }
+        if !breaking.is_empty() {
+            write_spec(&spec, &args.out)?;
+            return Err(CliError::OpenapiBreakingChanges(breaking.len()));
+        }
+    }
+
+    write_spec(&spec, &args.out)
+}
+
+/// Runs `cargo build` for `project`, then runs its binary with
+/// [`PRINT_SPEC_FLAG`] and parses stdout as the spec.
+fn build_and_capture_spec(project: &Path) -> Result<serde_json::Value, CliError> {
+    let manifest_path = project.join("Cargo.toml");
+    let status = Command::new("cargo")
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(&manifest_path)
+        .status()
+        .map_err(|source| CliError::Spawn { command: "cargo build".to_string(), source })?;
+    if !status.success() {
+        return Err(CliError::BuildFailed { project: project.to_path_buf() });
+    }
+
+    let binary = binary_path(project)?;
+    let output = Command::new(&binary)
+        .arg(PRINT_SPEC_FLAG)
+        .output()
+        .map_err(|source| CliError::Spawn { command: binary.display().to_string(), source })?;
+
+    serde_json::from_slice(&output.stdout)
+        .map_err(|err| CliError::NoOpenapiSpec { binary, message: err.to_string() })
+}
+
+/// The path `cargo build` places a project's debug binary at, named
+/// after its `Cargo.toml` package name.
+fn binary_path(project: &Path) -> Result<PathBuf, CliError> {
+    let manifest_path = project.join("Cargo.toml");
+    let manifest_text =
+        fs::read_to_string(&manifest_path).map_err(|source| CliError::Read { path: manifest_path.clone(), source })?;
+    let manifest: toml::Table = manifest_text
+        .parse()
+        .map_err(|err: toml::de::Error| CliError::Malformed { path: manifest_path.clone(), message: err.to_string() })?;
+    let name = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .ok_or_else(|| CliError::Malformed {
+            path: manifest_path.clone(),
+            message: "missing [package].name".to_string(),
+        })?;
+    Ok(project.join("target/debug").join(name))
+}
+
+fn parse_spec(text: &str, format: OpenapiFormat, path: &Path) -> Result<serde_json::Value, CliError> {
+    match format {
+        OpenapiFormat::Json => {
+            serde_json::from_str(text).map_err(|err| CliError::Malformed { path: path.to_path_buf(), message: err.to_string() })
+        }
+        OpenapiFormat::Yaml => rustboot_serialization::from_yaml(text).map_err(Into::into),
+    }
+}
+
+fn write_spec(spec: &serde_json::Value, out: &Path) -> Result<(), CliError> {
+    let format = OpenapiFormat::from_extension(out.extension().and_then(|ext| ext.to_str()));
+    let contents = match format {
+        OpenapiFormat::Json => serde_json::to_vec_pretty(spec).map_err(rustboot_serialization::SerializationError::from)?,
+        OpenapiFormat::Yaml => serde_yaml::to_string(spec)
+            .map_err(rustboot_serialization::SerializationError::from)?
+            .into_bytes(),
+    };
+    fs::write(out, contents).map_err(|source| CliError::Write { path: out.to_path_buf(), source })
+}
+
+/// Reports the OpenAPI-meaningful ways `new` might have broken `old`'s
+/// contract: a path removed entirely, or an operation (HTTP method)
+/// removed from a path that's still there. Request/response fields and
+/// schemas are not compared.
+fn diff_specs(old: &serde_json::Value, new: &serde_json::Value) -> Vec<String> {
+    let mut breaking = Vec::new();
+
+    let old_paths = old.get("paths").and_then(|paths| paths.as_object());
+    let new_paths = new.get("paths").and_then(|paths| paths.as_object());
+    let (Some(old_paths), Some(new_paths)) = (old_paths, new_paths) else { return breaking };
+
+    for (path, old_operations) in old_paths {
+        let Some(new_operations) = new_paths.get(path).and_then(|ops| ops.as_object()) else {
+            breaking.push(format!("removed path '{path}'"));
+            continue;
+        };
+        let Some(old_operations) = old_operations.as_object() else { continue };
+        for method in old_operations.keys() {
+            if !new_operations.contains_key(method) {
+                breaking.push(format!("removed operation '{}' on '{path}'", method.to_uppercase()));
+            }
+        }
+    }
+
+    breaking
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_fileio::TempDir;
+    use serde_json::json;
+
+    #[test]
+    fn diff_specs_flags_a_removed_path() {
+        let old = json!({"paths": {"/users": {"get": {}}, "/orders": {"get": {}}}});
+        let new = json!({"paths": {"/users": {"get": {}}}});
+        let breaking = diff_specs(&old, &new);
+        assert_eq!(breaking, vec!["removed path '/orders'".to_string()]);
+    }
+
+    #[test]
+    fn diff_specs_flags_a_removed_operation_on_a_surviving_path() {
+        let old = json!({"paths": {"/users": {"get": {}, "delete": {}}}});
+        let new = json!({"paths": {"/users": {"get": {}}}});
+        let breaking = diff_specs(&old, &new);
+        assert_eq!(breaking, vec!["removed operation 'DELETE' on '/users'".to_string()]);
+    }
+
+    #[test]
+    fn diff_specs_finds_nothing_for_additive_changes() {
+        let old = json!({"paths": {"/users": {"get": {}}}});
+        let new = json!({"paths": {"/users": {"get": {}, "post": {}}, "/orders": {"get": {}}}});
+        assert!(diff_specs(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn write_spec_writes_pretty_json_by_default() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path().join("openapi.json");
+        write_spec(&json!({"openapi": "3.0.0"}), &out).unwrap();
+        let contents = fs::read_to_string(&out).unwrap();
+        assert_eq!(contents, "{\n  \"openapi\": \"3.0.0\"\n}");
+    }
+
+    #[test]
+    fn write_spec_writes_yaml_for_a_yaml_extension() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path().join("openapi.yaml");
+        write_spec(&json!({"openapi": "3.0.0"}), &out).unwrap();
+        let contents = fs::read_to_string(&out).unwrap();
+        assert_eq!(contents, "openapi: 3.0.0\n");
+    }
+
+    #[test]
+    fn binary_path_reads_the_package_name_from_cargo_toml() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"my-app\"\nversion = \"0.1.0\"\n").unwrap();
+        let path = binary_path(dir.path()).unwrap();
+        assert_eq!(path, dir.path().join("target/debug/my-app"));
+    }
+}
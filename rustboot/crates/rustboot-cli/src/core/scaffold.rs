@@ -0,0 +1,170 @@
+//! Project file generation for `rustboot new`, given an already-resolved
+//! [`ScaffoldConfig`]. Kept free of prompting and other I/O beyond
+//! writing the generated files, so it can be exercised without a
+//! terminal — see [`crate::core::new`].
+
+use std::fs;
+use std::path::Path;
+
+use crate::api::{CliError, Database, ProjectTemplate, ScaffoldConfig};
+
+/// Generates a new project at `path` per `config`: a `Cargo.toml` with
+/// the template's `rustboot` dependencies and feature flags wired to
+/// `config`'s choices, a starter `src/main.rs`, a `.gitignore`, and
+/// (with `config.docker`) a `Dockerfile` and `docker-compose.yml`.
+pub fn generate(path: &Path, config: &ScaffoldConfig) -> Result<(), CliError> {
+    let name = project_name(path);
+
+    fs::create_dir_all(path.join("src")).map_err(|source| CliError::Write { path: path.join("src"), source })?;
+    write(&path.join("Cargo.toml"), &cargo_toml(&name, config))?;
+    write(&path.join("src/main.rs"), &main_rs(config))?;
+    write(&path.join(".gitignore"), "/target\n")?;
+
+    if config.docker {
+        write(&path.join("Dockerfile"), &dockerfile())?;
+        write(&path.join("docker-compose.yml"), &docker_compose(config))?;
+    }
+
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), CliError> {
+    fs::write(path, contents).map_err(|source| CliError::Write { path: path.to_path_buf(), source })
+}
+
+fn project_name(path: &Path) -> String {
+    path.file_name().and_then(|name| name.to_str()).unwrap_or("rustboot-app").to_string()
+}
+
+fn cargo_toml(name: &str, config: &ScaffoldConfig) -> String {
+    let mut dependencies = String::new();
+    match config.template {
+        ProjectTemplate::RestApi | ProjectTemplate::FullStack => {
+            dependencies.push_str("rustboot-web = \"0.1\"\n");
+        }
+        ProjectTemplate::Worker => {
+            dependencies.push_str("rustboot-scheduler = \"0.1\"\n");
+        }
+        ProjectTemplate::CliTool => {
+            dependencies.push_str("rustboot-cli = \"0.1\"\n");
+        }
+    }
+    if config.template == ProjectTemplate::FullStack {
+        dependencies.push_str("rustboot-parsing = \"0.1\"\n");
+    }
+
+    let mut features = String::new();
+    if config.auth {
+        features.push_str("auth = []\n");
+    }
+    match config.database {
+        Database::Postgres => features.push_str("postgres = []\n"),
+        Database::Sqlite => features.push_str("sqlite = []\n"),
+        Database::Mysql => features.push_str("mysql = []\n"),
+        Database::None => {}
+    }
+    let features_section = if features.is_empty() { String::new() } else { format!("\n[features]\n{features}") };
+
+    format!(
+        "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n{dependencies}{features_section}"
+    )
+}
+
+fn main_rs(config: &ScaffoldConfig) -> String {
+    let body = match config.template {
+        ProjectTemplate::RestApi => "// A rustboot-web REST API. Register routes here.\nfn main() {\n    println!(\"listening\");\n}\n",
+        ProjectTemplate::Worker => "// A rustboot-scheduler worker. Register jobs here.\nfn main() {\n    println!(\"working\");\n}\n",
+        ProjectTemplate::CliTool => "// A rustboot-cli command-line tool.\nfn main() {\n    println!(\"hello\");\n}\n",
+        ProjectTemplate::FullStack => {
+            "// A rustboot-web REST API paired with a server-rendered frontend.\nfn main() {\n    println!(\"listening\");\n}\n"
+        }
+    };
+
+    let mut header = String::new();
+    if config.auth {
+        header.push_str("// Auth module enabled: see the `auth` feature in Cargo.toml.\n");
+    }
+    if !matches!(config.database, Database::None) {
+        header.push_str("// Database wired up: see the database feature in Cargo.toml.\n");
+    }
+
+    format!("{header}{body}")
+}
+
+fn dockerfile() -> String {
+    "FROM rust:1-slim AS build\nWORKDIR /app\nCOPY . .\nRUN cargo build --release\n\nFROM debian:stable-slim\nCOPY --from=build /app/target/release/app /usr/local/bin/app\nCMD [\"app\"]\n".to_string()
+}
+
+fn docker_compose(config: &ScaffoldConfig) -> String {
+    let mut services = String::from("services:\n  app:\n    build: .\n");
+    match config.database {
+        Database::Postgres => {
+            services.push_str("  db:\n    image: postgres:16\n    environment:\n      POSTGRES_PASSWORD: postgres\n")
+        }
+        Database::Mysql => {
+            services.push_str("  db:\n    image: mysql:8\n    environment:\n      MYSQL_ROOT_PASSWORD: mysql\n")
+        }
+        Database::Sqlite | Database::None => {}
+    }
+    services
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_fileio::TempDir;
+
+    fn config(template: ProjectTemplate) -> ScaffoldConfig {
+        ScaffoldConfig::defaults_for(template)
+    }
+
+    #[test]
+    fn generate_writes_a_cargo_toml_and_main_rs() {
+        let dir = TempDir::new().unwrap();
+        let project = dir.path().join("my-api");
+        generate(&project, &config(ProjectTemplate::RestApi)).unwrap();
+
+        let cargo_toml = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("name = \"my-api\""));
+        assert!(cargo_toml.contains("rustboot-web"));
+        assert!(project.join("src/main.rs").exists());
+        assert!(project.join(".gitignore").exists());
+    }
+
+    #[test]
+    fn generate_skips_database_deps_for_cli_tool_template() {
+        let dir = TempDir::new().unwrap();
+        let project = dir.path().join("my-tool");
+        generate(&project, &config(ProjectTemplate::CliTool)).unwrap();
+
+        let cargo_toml = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+        assert!(!cargo_toml.contains("[features]"));
+    }
+
+    #[test]
+    fn generate_wires_auth_and_database_features() {
+        let dir = TempDir::new().unwrap();
+        let project = dir.path().join("my-api");
+        let config = ScaffoldConfig { auth: true, database: Database::Postgres, ..config(ProjectTemplate::RestApi) };
+        generate(&project, &config).unwrap();
+
+        let cargo_toml = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("auth = []"));
+        assert!(cargo_toml.contains("postgres = []"));
+
+        let main_rs = fs::read_to_string(project.join("src/main.rs")).unwrap();
+        assert!(main_rs.contains("Auth module enabled"));
+    }
+
+    #[test]
+    fn generate_writes_docker_files_when_requested() {
+        let dir = TempDir::new().unwrap();
+        let project = dir.path().join("my-api");
+        let config = ScaffoldConfig { docker: true, database: Database::Postgres, ..config(ProjectTemplate::RestApi) };
+        generate(&project, &config).unwrap();
+
+        assert!(project.join("Dockerfile").exists());
+        let compose = fs::read_to_string(project.join("docker-compose.yml")).unwrap();
+        assert!(compose.contains("postgres"));
+    }
+}
@@ -0,0 +1,67 @@
+//! Command-line tools for developing with the rustboot framework.
+//!
+//! - [`Cli`]/[`Command`]: the `rustboot` binary's argument grammar,
+//!   parsed with `clap`.
+//! - [`Command::Convert`]/[`core::convert::run`]: `rustboot convert`,
+//!   converting a data file between JSON, YAML, TOML, and MessagePack
+//!   via [`rustboot_serialization::transcode`], with stdin/stdout and
+//!   glob batch-mode support.
+//! - [`Command::New`]/[`core::new::run`]: `rustboot new`, scaffolding a
+//!   project from a selectable template ([`ProjectTemplate`]) with
+//!   interactive prompts for database, auth, and Docker files.
+//! - [`Command::Generate`]/[`core::generate::run`]: `rustboot generate`,
+//!   adding a handler, entity, migration, or middleware to an existing
+//!   project, registering handlers and middleware in the project's
+//!   routing/middleware registry as it goes.
+//! - [`Command::Doctor`]/[`core::doctor::run`]: `rustboot doctor`,
+//!   checking a project for mismatched database feature flags, missing
+//!   migrations, unreachable config keys, and debug settings left on
+//!   in the release profile.
+//! - [`Command::Dev`]/[`core::dev::run`]: `rustboot dev`, running a
+//!   project with `cargo run` and restarting it on source changes,
+//!   optionally proxying a front-end dev server alongside it.
+//! - [`Command::Openapi`]/[`core::openapi::run`]: `rustboot openapi
+//!   export`, building a project and writing the OpenAPI spec its
+//!   binary prints at a well-known entry point, with `--diff` support
+//!   for catching breaking changes against a committed spec.
+//!
+//! `src/main.rs` is a thin wrapper that parses [`Cli`] and dispatches
+//! to the matching `core` module; the logic itself lives here so it can
+//! be exercised without spawning a process.
+
+pub mod api;
+pub mod core;
+
+pub use api::{
+    Cli, CliError, Command, ConvertArgs, DataFormat, Database, DevArgs, Diagnostic, DoctorArgs, DoctorCheck,
+    GenerateArgs, GenerateNameArgs, GenerateTarget, NewArgs, OpenapiAction, OpenapiArgs, OpenapiExportArgs,
+    OpenapiFormat, ProjectTemplate, ScaffoldConfig,
+};
+
+/// Runs the parsed CLI invocation, dispatching to the matching
+/// subcommand's implementation.
+pub async fn run(cli: &Cli) -> Result<(), CliError> {
+    match &cli.command {
+        Command::Convert(args) => core::convert::run(args),
+        Command::New(args) => core::new::run(args),
+        Command::Generate(args) => core::generate::run(args),
+        Command::Doctor(args) => run_doctor(args),
+        Command::Dev(args) => core::dev::run(args).await,
+        Command::Openapi(args) => match &args.action {
+            OpenapiAction::Export(export_args) => core::openapi::run(export_args),
+        },
+    }
+}
+
+fn run_doctor(args: &api::DoctorArgs) -> Result<(), CliError> {
+    let diagnostics = core::doctor::run(args)?;
+    for diagnostic in &diagnostics {
+        println!("[{:?}] {}\n  fix: {}", diagnostic.check, diagnostic.message, diagnostic.fix);
+    }
+    if diagnostics.is_empty() {
+        println!("no issues found");
+        Ok(())
+    } else {
+        Err(CliError::DoctorFoundIssues(diagnostics.len()))
+    }
+}
@@ -0,0 +1,11 @@
+use clap::Parser;
+use rustboot_cli::Cli;
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    if let Err(err) = rustboot_cli::run(&cli).await {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
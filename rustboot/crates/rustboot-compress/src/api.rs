@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+/// Errors from compressing or decompressing data.
+#[derive(Debug, thiserror::Error)]
+pub enum CompressError {
+    /// The underlying codec or I/O layer failed.
+    #[error("compression I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The zip codec failed on something other than a plain I/O error
+    /// (a malformed archive, an unsupported compression method, ...).
+    #[error("zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    /// An archive entry's path would have extracted outside the
+    /// destination directory (a "zip slip" — `../../etc/passwd`-style
+    /// traversal, or an absolute path).
+    #[error("archive entry '{0}' would extract outside the destination directory")]
+    PathTraversal(PathBuf),
+    /// Extracting the archive would exceed the caller's total size
+    /// limit, checked as each entry is written so a malicious or
+    /// corrupt archive can't exhaust disk space before this is caught.
+    #[error("archive exceeds the size limit of {limit_bytes} bytes")]
+    SizeLimitExceeded {
+        /// The limit that was exceeded.
+        limit_bytes: u64,
+    },
+    /// An LZ4 frame failed to decompress (truncated input, or a size
+    /// prefix that doesn't match what follows).
+    #[cfg(feature = "lz4")]
+    #[error("lz4 decompress error: {0}")]
+    Lz4(#[from] lz4_flex::block::DecompressError),
+}
+
+/// A compression algorithm selectable at runtime, so protocol
+/// negotiation (an HTTP `Accept-Encoding` header, a messaging
+/// interceptor's codec handshake) can pick a codec without the caller
+/// hard-coding which one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// General-purpose, good compression ratio and speed; the default
+    /// for archives and at-rest data in this crate.
+    Zstd,
+    /// Favored by browsers and HTTP caches; best for compressing
+    /// responses that will be decompressed by a client rustboot doesn't
+    /// control.
+    #[cfg(feature = "brotli")]
+    Brotli,
+    /// Optimized for throughput over ratio; best for compressing
+    /// payloads passed between rustboot's own processes, where
+    /// compression time matters more than size.
+    #[cfg(feature = "lz4")]
+    Lz4,
+}
+
+/// A zstd dictionary trained on sample payloads, for compressing many
+/// small, structurally similar blobs (cached JSON, for example) better
+/// than compressing each in isolation.
+#[derive(Debug, Clone)]
+pub struct Dictionary(pub(crate) Vec<u8>);
+
+impl Dictionary {
+    /// The raw dictionary bytes, suitable for persisting alongside
+    /// compressed data so a later process can load the same dictionary.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Wraps previously trained dictionary bytes (e.g. loaded from
+    /// disk), without retraining.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
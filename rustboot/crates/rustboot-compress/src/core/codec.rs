@@ -0,0 +1,78 @@
+//! A codec-agnostic `compress`/`decompress` pair over [`Codec`], so
+//! callers that negotiate an algorithm (an HTTP `Accept-Encoding`
+//! header, a messaging interceptor's handshake) don't have to match on
+//! the codec themselves.
+
+use crate::api::{Codec, CompressError};
+use crate::core::zstd;
+
+/// Compresses `data` with `codec`, at each codec's default level.
+pub fn compress(codec: Codec, data: &[u8]) -> Result<Vec<u8>, CompressError> {
+    match codec {
+        Codec::Zstd => zstd::compress(data, 3),
+        #[cfg(feature = "brotli")]
+        Codec::Brotli => brotli_compress(data),
+        #[cfg(feature = "lz4")]
+        Codec::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+    }
+}
+
+/// Decompresses a buffer produced by [`compress`] with the same `codec`.
+pub fn decompress(codec: Codec, data: &[u8]) -> Result<Vec<u8>, CompressError> {
+    match codec {
+        Codec::Zstd => zstd::decompress(data),
+        #[cfg(feature = "brotli")]
+        Codec::Brotli => brotli_decompress(data),
+        #[cfg(feature = "lz4")]
+        Codec::Lz4 => Ok(lz4_flex::decompress_size_prepended(data)?),
+    }
+}
+
+#[cfg(feature = "brotli")]
+fn brotli_compress(data: &[u8]) -> Result<Vec<u8>, CompressError> {
+    let mut output = Vec::new();
+    let params = brotli::enc::BrotliEncoderParams::default();
+    brotli::BrotliCompress(&mut std::io::Cursor::new(data), &mut output, &params)?;
+    Ok(output)
+}
+
+#[cfg(feature = "brotli")]
+fn brotli_decompress(data: &[u8]) -> Result<Vec<u8>, CompressError> {
+    let mut output = Vec::new();
+    brotli::BrotliDecompress(&mut std::io::Cursor::new(data), &mut output)?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zstd_round_trips_through_the_codec_api() {
+        let data = b"hello world, hello world, hello world";
+        let compressed = compress(Codec::Zstd, data).unwrap();
+        assert_eq!(decompress(Codec::Zstd, &compressed).unwrap(), data);
+    }
+
+    #[cfg(feature = "brotli")]
+    #[test]
+    fn brotli_round_trips_through_the_codec_api() {
+        let data = b"hello world, hello world, hello world";
+        let compressed = compress(Codec::Brotli, data).unwrap();
+        assert_eq!(decompress(Codec::Brotli, &compressed).unwrap(), data);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn lz4_round_trips_through_the_codec_api() {
+        let data = b"hello world, hello world, hello world";
+        let compressed = compress(Codec::Lz4, data).unwrap();
+        assert_eq!(decompress(Codec::Lz4, &compressed).unwrap(), data);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn lz4_decompress_rejects_garbage_input() {
+        assert!(decompress(Codec::Lz4, b"not an lz4 frame").is_err());
+    }
+}
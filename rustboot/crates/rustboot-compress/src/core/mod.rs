@@ -0,0 +1,6 @@
+//! Implementation details for the compress module.
+
+pub mod codec;
+pub mod tar;
+pub mod zip;
+pub mod zstd;
@@ -0,0 +1,184 @@
+//! `.tar.gz` archive creation and extraction, with zip-slip protection
+//! and a total size limit enforced while unpacking.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::api::CompressError;
+
+const UNPACK_CHUNK_BYTES: usize = 64 * 1024;
+
+fn safe_join(dest: &Path, entry_path: &Path) -> Result<PathBuf, CompressError> {
+    if entry_path.is_absolute() || entry_path.components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+        return Err(CompressError::PathTraversal(entry_path.to_path_buf()));
+    }
+    Ok(dest.join(entry_path))
+}
+
+/// Copies from `reader` to `writer` in chunks, tracking actual bytes
+/// read in `bytes_unpacked` and failing as soon as that exceeds
+/// `max_total_bytes`. Unlike checking a declared size up front, this
+/// can't be fooled by an entry whose header understates how much its
+/// compressed stream actually inflates to.
+fn copy_bounded(
+    mut reader: impl Read,
+    mut writer: impl Write,
+    bytes_unpacked: &mut u64,
+    max_total_bytes: u64,
+) -> Result<(), CompressError> {
+    let mut chunk = [0u8; UNPACK_CHUNK_BYTES];
+    loop {
+        let read = reader.read(&mut chunk)?;
+        if read == 0 {
+            break;
+        }
+        *bytes_unpacked += read as u64;
+        if *bytes_unpacked > max_total_bytes {
+            return Err(CompressError::SizeLimitExceeded { limit_bytes: max_total_bytes });
+        }
+        writer.write_all(&chunk[..read])?;
+    }
+    Ok(())
+}
+
+/// Packs every file under `source_dir` into a `.tar.gz` archive at
+/// `archive_path`, calling `on_progress` with the running total of
+/// bytes packed after each file.
+pub fn tar_gz_pack(
+    source_dir: impl AsRef<Path>,
+    archive_path: impl AsRef<Path>,
+    mut on_progress: impl FnMut(u64),
+) -> Result<(), CompressError> {
+    let source_dir = source_dir.as_ref();
+    let file = fs::File::create(archive_path.as_ref())?;
+    let encoder = GzEncoder::new(file, Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+
+    let mut bytes_packed = 0u64;
+    append_dir(&mut builder, source_dir, source_dir, &mut bytes_packed, &mut on_progress)?;
+
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+fn append_dir<W: Write>(
+    builder: &mut tar::Builder<W>,
+    base: &Path,
+    dir: &Path,
+    bytes_packed: &mut u64,
+    on_progress: &mut impl FnMut(u64),
+) -> Result<(), CompressError> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let relative = path.strip_prefix(base).expect("walked entries are always under base");
+
+        if entry.file_type()?.is_dir() {
+            append_dir(builder, base, &path, bytes_packed, on_progress)?;
+        } else {
+            builder.append_path_with_name(&path, relative)?;
+            *bytes_packed += entry.metadata()?.len();
+            on_progress(*bytes_packed);
+        }
+    }
+    Ok(())
+}
+
+/// Extracts a `.tar.gz` archive at `archive_path` into `dest_dir`
+/// (created if it doesn't exist), rejecting any entry whose path would
+/// extract outside `dest_dir` ([`CompressError::PathTraversal`]) or
+/// whose cumulative size would exceed `max_total_bytes`
+/// ([`CompressError::SizeLimitExceeded`]), calling `on_progress` with
+/// the running total of bytes unpacked after each entry.
+pub fn tar_gz_unpack(
+    archive_path: impl AsRef<Path>,
+    dest_dir: impl AsRef<Path>,
+    max_total_bytes: u64,
+    mut on_progress: impl FnMut(u64),
+) -> Result<(), CompressError> {
+    let dest_dir = dest_dir.as_ref();
+    fs::create_dir_all(dest_dir)?;
+
+    let file = fs::File::open(archive_path.as_ref())?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+    let mut bytes_unpacked = 0u64;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.into_owned();
+        let target = safe_join(dest_dir, &entry_path)?;
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        if entry.header().entry_type().is_file() {
+            let mut out = fs::File::create(&target)?;
+            copy_bounded(&mut entry, &mut out, &mut bytes_unpacked, max_total_bytes)?;
+        } else {
+            entry.unpack(&target)?;
+        }
+        on_progress(bytes_unpacked);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rustboot_fileio::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn packs_and_unpacks_a_directory_tree() {
+        let source = TempDir::new().unwrap();
+        fs::write(source.path().join("top.txt"), b"top").unwrap();
+        fs::create_dir(source.path().join("nested")).unwrap();
+        fs::write(source.path().join("nested/inner.txt"), b"inner").unwrap();
+
+        let archive_dir = TempDir::new().unwrap();
+        let archive_path = archive_dir.path().join("archive.tar.gz");
+        let mut packed_progress = Vec::new();
+        tar_gz_pack(source.path(), &archive_path, |bytes| packed_progress.push(bytes)).unwrap();
+        assert!(!packed_progress.is_empty());
+
+        let dest = TempDir::new().unwrap();
+        let mut unpacked_progress = Vec::new();
+        tar_gz_unpack(&archive_path, dest.path(), u64::MAX, |bytes| unpacked_progress.push(bytes)).unwrap();
+
+        assert_eq!(fs::read(dest.path().join("top.txt")).unwrap(), b"top");
+        assert_eq!(fs::read(dest.path().join("nested/inner.txt")).unwrap(), b"inner");
+        assert!(!unpacked_progress.is_empty());
+    }
+
+    #[test]
+    fn unpack_rejects_an_archive_exceeding_the_size_limit() {
+        let source = TempDir::new().unwrap();
+        fs::write(source.path().join("big.txt"), vec![b'x'; 1024]).unwrap();
+
+        let archive_dir = TempDir::new().unwrap();
+        let archive_path = archive_dir.path().join("archive.tar.gz");
+        tar_gz_pack(source.path(), &archive_path, |_| {}).unwrap();
+
+        let dest = TempDir::new().unwrap();
+        let err = tar_gz_unpack(&archive_path, dest.path(), 10, |_| {}).unwrap_err();
+        assert!(matches!(err, CompressError::SizeLimitExceeded { limit_bytes: 10 }));
+    }
+
+    #[test]
+    fn safe_join_rejects_parent_dir_traversal() {
+        let err = safe_join(Path::new("/dest"), Path::new("../../etc/passwd")).unwrap_err();
+        assert!(matches!(err, CompressError::PathTraversal(_)));
+    }
+
+    #[test]
+    fn safe_join_rejects_absolute_paths() {
+        let err = safe_join(Path::new("/dest"), Path::new("/etc/passwd")).unwrap_err();
+        assert!(matches!(err, CompressError::PathTraversal(_)));
+    }
+}
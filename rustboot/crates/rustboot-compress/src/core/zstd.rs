@@ -0,0 +1,186 @@
+//! zstd compression: one-shot buffers, streaming `Read`/`Write` and
+//! async adapters, and dictionary training for compressing many small,
+//! structurally similar payloads.
+
+use std::io::{Read, Write};
+
+use crate::api::{CompressError, Dictionary};
+
+/// Compresses `data` at `level` (1 = fastest, 22 = smallest; zstd's
+/// default is 3).
+pub fn compress(data: &[u8], level: i32) -> Result<Vec<u8>, CompressError> {
+    ::zstd::stream::encode_all(data, level).map_err(CompressError::from)
+}
+
+/// Decompresses a buffer produced by [`compress`] or
+/// [`compress_with_dictionary`] (the latter only if its dictionary is
+/// also passed to [`decompress_with_dictionary`]).
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, CompressError> {
+    ::zstd::stream::decode_all(data).map_err(CompressError::from)
+}
+
+/// Trains a [`Dictionary`] on `samples`, capped at `max_size` bytes.
+/// Most effective with many (dozens or more) small samples that share
+/// structure, such as cached JSON blobs of the same shape.
+pub fn train_dictionary(samples: &[Vec<u8>], max_size: usize) -> Result<Dictionary, CompressError> {
+    ::zstd::dict::from_samples(samples, max_size).map(Dictionary).map_err(CompressError::from)
+}
+
+/// Compresses `data` at `level` using a dictionary trained by
+/// [`train_dictionary`], instead of building up context from `data`
+/// alone — the win for payloads too small to carry their own
+/// compression context.
+pub fn compress_with_dictionary(data: &[u8], dictionary: &Dictionary, level: i32) -> Result<Vec<u8>, CompressError> {
+    let mut compressor = ::zstd::bulk::Compressor::with_dictionary(level, dictionary.as_bytes())?;
+    compressor.compress(data).map_err(CompressError::from)
+}
+
+/// Decompresses a buffer produced by [`compress_with_dictionary`] with
+/// the same `dictionary`. `capacity` must be at least the decompressed
+/// size.
+pub fn decompress_with_dictionary(
+    data: &[u8],
+    dictionary: &Dictionary,
+    capacity: usize,
+) -> Result<Vec<u8>, CompressError> {
+    let mut decompressor = ::zstd::bulk::Decompressor::with_dictionary(dictionary.as_bytes())?;
+    decompressor.decompress(data, capacity).map_err(CompressError::from)
+}
+
+/// A streaming zstd compressor: bytes written through it are compressed
+/// and forwarded to the inner writer a chunk at a time, so a large
+/// payload never has to be buffered in full before compression starts.
+/// Call [`finish`](ZstdWriter::finish) to flush the final frame and
+/// recover the inner writer.
+pub struct ZstdWriter<'a, W: Write>(::zstd::stream::write::Encoder<'a, W>);
+
+impl<'a, W: Write> ZstdWriter<'a, W> {
+    /// Wraps `writer`, compressing everything written through this at
+    /// `level`.
+    pub fn new(writer: W, level: i32) -> Result<Self, CompressError> {
+        Ok(Self(::zstd::stream::write::Encoder::new(writer, level)?))
+    }
+
+    /// Flushes the final zstd frame and returns the inner writer.
+    pub fn finish(self) -> Result<W, CompressError> {
+        self.0.finish().map_err(CompressError::from)
+    }
+}
+
+impl<'a, W: Write> Write for ZstdWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// A streaming zstd decompressor: reading from it yields decompressed
+/// bytes pulled from the inner reader a chunk at a time.
+pub struct ZstdReader<'a, R: Read>(::zstd::stream::read::Decoder<'a, std::io::BufReader<R>>);
+
+impl<'a, R: Read> ZstdReader<'a, R> {
+    /// Wraps `reader`, decompressing everything read through this.
+    pub fn new(reader: R) -> Result<Self, CompressError> {
+        Ok(Self(::zstd::stream::read::Decoder::new(reader)?))
+    }
+}
+
+impl<'a, R: Read> Read for ZstdReader<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+/// An async zstd compressor (`tokio::io::AsyncWrite`), for compressing
+/// data as it's streamed out rather than buffered in full first.
+pub type AsyncZstdWriter<W> = async_compression::tokio::write::ZstdEncoder<W>;
+
+/// An async zstd decompressor (`tokio::io::AsyncRead`), decompressing a
+/// byte stream as it's read.
+pub type AsyncZstdReader<R> = async_compression::tokio::bufread::ZstdDecoder<R>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_payloads() -> Vec<Vec<u8>> {
+        (0..64)
+            .map(|i| format!(r#"{{"id":{i},"kind":"event","payload":"same shape every time"}}"#).into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn compress_and_decompress_round_trip() {
+        let data = b"hello world, hello world, hello world";
+        let compressed = compress(data, 3).unwrap();
+        assert_eq!(decompress(&compressed).unwrap(), data);
+    }
+
+    #[test]
+    fn compress_actually_shrinks_repetitive_data() {
+        let data = vec![b'a'; 4096];
+        let compressed = compress(&data, 3).unwrap();
+        assert!(compressed.len() < data.len());
+    }
+
+    #[test]
+    fn decompress_rejects_garbage_input() {
+        assert!(decompress(b"not a zstd frame").is_err());
+    }
+
+    #[test]
+    fn dictionary_compression_round_trips() {
+        let samples = sample_payloads();
+        let dictionary = train_dictionary(&samples, 4096).unwrap();
+
+        let payload = br#"{"id":999,"kind":"event","payload":"same shape every time"}"#;
+        let compressed = compress_with_dictionary(payload, &dictionary, 3).unwrap();
+        let decompressed = decompress_with_dictionary(&compressed, &dictionary, payload.len()).unwrap();
+
+        assert_eq!(decompressed, payload);
+    }
+
+    #[test]
+    fn dictionary_compression_beats_standalone_compression_for_small_similar_payloads() {
+        let samples = sample_payloads();
+        let dictionary = train_dictionary(&samples, 4096).unwrap();
+        let payload = br#"{"id":999,"kind":"event","payload":"same shape every time"}"#;
+
+        let with_dictionary = compress_with_dictionary(payload, &dictionary, 3).unwrap();
+        let standalone = compress(payload, 3).unwrap();
+
+        assert!(with_dictionary.len() < standalone.len());
+    }
+
+    #[test]
+    fn streaming_writer_and_reader_round_trip() {
+        let mut writer = ZstdWriter::new(Vec::new(), 3).unwrap();
+        writer.write_all(b"streamed in chunks").unwrap();
+        let compressed = writer.finish().unwrap();
+
+        let mut reader = ZstdReader::new(compressed.as_slice()).unwrap();
+        let mut decompressed = Vec::new();
+        reader.read_to_end(&mut decompressed).unwrap();
+
+        assert_eq!(decompressed, b"streamed in chunks");
+    }
+
+    #[tokio::test]
+    async fn async_writer_and_reader_round_trip() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut writer = AsyncZstdWriter::new(Vec::new());
+        writer.write_all(b"streamed asynchronously").await.unwrap();
+        writer.shutdown().await.unwrap();
+        let compressed = writer.into_inner();
+
+        let mut reader = AsyncZstdReader::new(compressed.as_slice());
+        let mut decompressed = Vec::new();
+        reader.read_to_end(&mut decompressed).await.unwrap();
+
+        assert_eq!(decompressed, b"streamed asynchronously");
+    }
+}
@@ -0,0 +1,35 @@
+//! Compression codecs and archive helpers for the rustboot framework.
+//!
+//! - [`compress`]/[`decompress`]: one-shot zstd compression of an
+//!   in-memory buffer.
+//! - [`train_dictionary`]/[`compress_with_dictionary`]/
+//!   [`decompress_with_dictionary`]: zstd dictionary support for
+//!   compressing many small, structurally similar payloads (cached JSON
+//!   blobs, for example) better than compressing each in isolation.
+//! - [`ZstdWriter`]/[`ZstdReader`]: streaming `Read`/`Write` adapters so
+//!   a large payload doesn't have to be buffered in full before
+//!   compression starts.
+//! - [`AsyncZstdWriter`]/[`AsyncZstdReader`]: the same, for
+//!   `tokio::io::AsyncRead`/`AsyncWrite` pipelines.
+//! - [`tar_gz_pack`]/[`tar_gz_unpack`] and [`zip_pack`]/[`zip_unpack`]:
+//!   archive a directory tree or extract one back out, with zip-slip
+//!   path-traversal protection, a total size limit on extraction, and a
+//!   progress callback — what the CLI scaffolding and backup tooling
+//!   both need from an archive format.
+//! - [`Codec`] and [`compress_with_codec`]/[`decompress_with_codec`]:
+//!   pick a codec (zstd, brotli, or lz4, the latter two behind their
+//!   own feature flags) at runtime instead of at the call site, for
+//!   middleware and messaging interceptors that negotiate the
+//!   algorithm with the other end.
+
+pub mod api;
+pub mod core;
+
+pub use api::{Codec, CompressError, Dictionary};
+pub use core::codec::{compress as compress_with_codec, decompress as decompress_with_codec};
+pub use core::tar::{tar_gz_pack, tar_gz_unpack};
+pub use core::zip::{zip_pack, zip_unpack};
+pub use core::zstd::{
+    compress, compress_with_dictionary, decompress, decompress_with_dictionary, train_dictionary, AsyncZstdReader,
+    AsyncZstdWriter, ZstdReader, ZstdWriter,
+};
@@ -0,0 +1,180 @@
+//! Public types for the config module.
+
+use std::collections::HashMap;
+
+/// A single configuration value.
+///
+/// Values form a tree: [`ConfigValue::Table`] nodes are addressed with
+/// dotted paths (`"server.port"`), and leaves are scalars or lists.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    /// A string value.
+    String(String),
+    /// An integer value.
+    Integer(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A boolean value.
+    Bool(bool),
+    /// An ordered list of values.
+    List(Vec<ConfigValue>),
+    /// A nested table of values, keyed by field name.
+    Table(HashMap<String, ConfigValue>),
+}
+
+/// How a list value is combined with the corresponding list already
+/// present in the base configuration during a merge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// The overlay list replaces the base list entirely (the default).
+    Replace,
+    /// The overlay list's entries are appended after the base list's.
+    Append,
+    /// Entries are tables matched by the value of `key_field`: a base
+    /// entry and an overlay entry with equal `key_field` values are
+    /// merged recursively (tables deep-merge, scalars/lists overwrite
+    /// per this same policy); overlay entries with no matching base
+    /// entry are appended.
+    MergeByKey {
+        /// The field used to match entries across the two lists.
+        key_field: String,
+    },
+}
+
+/// Selects a [`MergeStrategy`] per dotted config path, falling back to a
+/// default for paths with no explicit rule.
+///
+/// ```
+/// use rustboot_config::{MergePolicy, MergeStrategy};
+///
+/// let policy = MergePolicy::new()
+///     .with_rule("server.middleware", MergeStrategy::Append)
+///     .with_rule("server.routes", MergeStrategy::MergeByKey { key_field: "path".into() });
+/// assert_eq!(policy.strategy_for("server.middleware"), &MergeStrategy::Append);
+/// assert_eq!(policy.strategy_for("server.unrelated"), &MergeStrategy::Replace);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergePolicy {
+    rules: HashMap<String, MergeStrategy>,
+    default: MergeStrategy,
+}
+
+impl Default for MergePolicy {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::new(),
+            default: MergeStrategy::Replace,
+        }
+    }
+}
+
+impl MergePolicy {
+    /// Creates a policy with no per-path rules, defaulting every list to
+    /// [`MergeStrategy::Replace`] (matching `Config::merge`'s prior
+    /// behavior).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the strategy used when no rule matches a path.
+    pub fn with_default(mut self, strategy: MergeStrategy) -> Self {
+        self.default = strategy;
+        self
+    }
+
+    /// Adds a rule selecting `strategy` for lists at the dotted `path`.
+    pub fn with_rule(mut self, path: impl Into<String>, strategy: MergeStrategy) -> Self {
+        self.rules.insert(path.into(), strategy);
+        self
+    }
+
+    /// Returns the strategy that applies to `path`.
+    pub fn strategy_for(&self, path: &str) -> &MergeStrategy {
+        self.rules.get(path).unwrap_or(&self.default)
+    }
+}
+
+/// Errors produced while reading or merging configuration.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ConfigError {
+    /// No value was found at the requested path.
+    #[error("no config value at path '{0}'")]
+    NotFound(String),
+    /// A value was found but could not be converted to the requested type.
+    #[error("config value at path '{path}' has the wrong type: expected {expected}")]
+    WrongType {
+        /// The path that was looked up.
+        path: String,
+        /// The type the caller requested.
+        expected: &'static str,
+    },
+}
+
+/// Converts a [`ConfigValue`] into a concrete Rust type.
+///
+/// Implemented for the primitive scalar types; implement it for your own
+/// types to support `Config::get::<T>`.
+pub trait FromConfigValue: Sized {
+    /// Attempts to convert `value`, reporting `path` in any error.
+    fn from_config_value(value: &ConfigValue, path: &str) -> Result<Self, ConfigError>;
+}
+
+macro_rules! impl_from_config_value_int {
+    ($($ty:ty),+) => {
+        $(
+            impl FromConfigValue for $ty {
+                fn from_config_value(value: &ConfigValue, path: &str) -> Result<Self, ConfigError> {
+                    match value {
+                        ConfigValue::Integer(i) => <$ty>::try_from(*i).map_err(|_| ConfigError::WrongType {
+                            path: path.to_string(),
+                            expected: stringify!($ty),
+                        }),
+                        _ => Err(ConfigError::WrongType {
+                            path: path.to_string(),
+                            expected: stringify!($ty),
+                        }),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_config_value_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl FromConfigValue for String {
+    fn from_config_value(value: &ConfigValue, path: &str) -> Result<Self, ConfigError> {
+        match value {
+            ConfigValue::String(s) => Ok(s.clone()),
+            _ => Err(ConfigError::WrongType {
+                path: path.to_string(),
+                expected: "String",
+            }),
+        }
+    }
+}
+
+impl FromConfigValue for bool {
+    fn from_config_value(value: &ConfigValue, path: &str) -> Result<Self, ConfigError> {
+        match value {
+            ConfigValue::Bool(b) => Ok(*b),
+            _ => Err(ConfigError::WrongType {
+                path: path.to_string(),
+                expected: "bool",
+            }),
+        }
+    }
+}
+
+impl FromConfigValue for f64 {
+    fn from_config_value(value: &ConfigValue, path: &str) -> Result<Self, ConfigError> {
+        match value {
+            ConfigValue::Float(f) => Ok(*f),
+            ConfigValue::Integer(i) => Ok(*i as f64),
+            _ => Err(ConfigError::WrongType {
+                path: path.to_string(),
+                expected: "f64",
+            }),
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Implementation details for the config module.
+
+use std::collections::HashMap;
+
+use crate::api::{ConfigError, ConfigValue, FromConfigValue, MergePolicy, MergeStrategy};
+
+/// A hierarchical configuration tree addressed by dotted paths.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    root: HashMap<String, ConfigValue>,
+}
+
+impl Config {
+    /// Creates an empty configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a configuration from a pre-built table.
+    pub fn from_table(root: HashMap<String, ConfigValue>) -> Self {
+        Self { root }
+    }
+
+    /// Looks up `path` (e.g. `"server.port"`) and converts it to `T`.
+    pub fn get<T: FromConfigValue>(&self, path: &str) -> Result<T, ConfigError> {
+        let value = self.get_raw(path)?;
+        T::from_config_value(value, path)
+    }
+
+    /// Looks up `path` without converting it.
+    pub fn get_raw(&self, path: &str) -> Result<&ConfigValue, ConfigError> {
+        let mut segments = path.split('.');
+        let Some(first) = segments.next() else {
+            return Err(ConfigError::NotFound(path.to_string()));
+        };
+
+        let mut current = self
+            .root
+            .get(first)
+            .ok_or_else(|| ConfigError::NotFound(path.to_string()))?;
+
+        for segment in segments {
+            current = match current {
+                ConfigValue::Table(table) => table
+                    .get(segment)
+                    .ok_or_else(|| ConfigError::NotFound(path.to_string()))?,
+                _ => return Err(ConfigError::NotFound(path.to_string())),
+            };
+        }
+
+        Ok(current)
+    }
+
+    /// Merges `other` on top of `self`: scalars override `self`, lists
+    /// override `self` (use [`Config::merge_with`] for other list
+    /// strategies), and tables are merged key-by-key, recursively.
+    pub fn merge(&mut self, other: Config) {
+        self.merge_with(other, &MergePolicy::default());
+    }
+
+    /// Merges `other` on top of `self` like [`Config::merge`], but
+    /// resolves how each list is combined from `policy`, keyed by the
+    /// list's dotted path (e.g. `"server.middleware"`).
+    pub fn merge_with(&mut self, other: Config, policy: &MergePolicy) {
+        merge_tables(&mut self.root, other.root, policy, "");
+    }
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+fn merge_tables(
+    base: &mut HashMap<String, ConfigValue>,
+    overlay: HashMap<String, ConfigValue>,
+    policy: &MergePolicy,
+    prefix: &str,
+) {
+    for (key, overlay_value) in overlay {
+        let path = join_path(prefix, &key);
+        match (base.get_mut(&key), overlay_value) {
+            (Some(ConfigValue::Table(base_table)), ConfigValue::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table, policy, &path);
+            }
+            (Some(ConfigValue::List(base_list)), ConfigValue::List(overlay_list)) => {
+                let merged = merge_lists(base_list.clone(), overlay_list, policy, &path);
+                base.insert(key, ConfigValue::List(merged));
+            }
+            (_, overlay_value) => {
+                base.insert(key, overlay_value);
+            }
+        }
+    }
+}
+
+fn merge_lists(
+    base: Vec<ConfigValue>,
+    overlay: Vec<ConfigValue>,
+    policy: &MergePolicy,
+    path: &str,
+) -> Vec<ConfigValue> {
+    match policy.strategy_for(path) {
+        MergeStrategy::Replace => overlay,
+        MergeStrategy::Append => base.into_iter().chain(overlay).collect(),
+        MergeStrategy::MergeByKey { key_field } => {
+            merge_lists_by_key(base, overlay, key_field, policy, path)
+        }
+    }
+}
+
+fn merge_lists_by_key(
+    base: Vec<ConfigValue>,
+    overlay: Vec<ConfigValue>,
+    key_field: &str,
+    policy: &MergePolicy,
+    path: &str,
+) -> Vec<ConfigValue> {
+    let mut merged = base;
+    for overlay_entry in overlay {
+        let overlay_key = entry_key(&overlay_entry, key_field);
+        let existing = overlay_key.and_then(|overlay_key| {
+            merged
+                .iter()
+                .position(|base_entry| entry_key(base_entry, key_field) == Some(overlay_key))
+        });
+
+        match (existing, overlay_entry) {
+            (Some(index), ConfigValue::Table(overlay_table)) => {
+                if let ConfigValue::Table(base_table) = &mut merged[index] {
+                    merge_tables(base_table, overlay_table, policy, path);
+                } else {
+                    merged[index] = ConfigValue::Table(overlay_table);
+                }
+            }
+            (Some(index), overlay_value) => merged[index] = overlay_value,
+            (None, overlay_value) => merged.push(overlay_value),
+        }
+    }
+    merged
+}
+
+fn entry_key<'a>(entry: &'a ConfigValue, key_field: &str) -> Option<&'a str> {
+    match entry {
+        ConfigValue::Table(table) => match table.get(key_field) {
+            Some(ConfigValue::String(s)) => Some(s.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Config {
+        let mut server = HashMap::new();
+        server.insert("port".to_string(), ConfigValue::Integer(8080));
+        server.insert("host".to_string(), ConfigValue::String("0.0.0.0".into()));
+
+        let mut root = HashMap::new();
+        root.insert("server".to_string(), ConfigValue::Table(server));
+        Config::from_table(root)
+    }
+
+    #[test]
+    fn gets_nested_scalar_by_dotted_path() {
+        let config = sample();
+        assert_eq!(config.get::<u16>("server.port").unwrap(), 8080);
+        assert_eq!(config.get::<String>("server.host").unwrap(), "0.0.0.0");
+    }
+
+    #[test]
+    fn missing_path_is_not_found() {
+        let config = sample();
+        assert_eq!(
+            config.get::<u16>("server.timeout"),
+            Err(ConfigError::NotFound("server.timeout".to_string()))
+        );
+    }
+
+    #[test]
+    fn merge_overrides_scalars_and_merges_nested_tables() {
+        let mut base = sample();
+        let mut overlay_server = HashMap::new();
+        overlay_server.insert("port".to_string(), ConfigValue::Integer(9090));
+        let mut overlay_root = HashMap::new();
+        overlay_root.insert("server".to_string(), ConfigValue::Table(overlay_server));
+
+        base.merge(Config::from_table(overlay_root));
+
+        assert_eq!(base.get::<u16>("server.port").unwrap(), 9090);
+        assert_eq!(base.get::<String>("server.host").unwrap(), "0.0.0.0");
+    }
+
+    fn config_with_list(path: &str, items: Vec<ConfigValue>) -> Config {
+        let mut root = HashMap::new();
+        root.insert(path.to_string(), ConfigValue::List(items));
+        Config::from_table(root)
+    }
+
+    #[test]
+    fn default_policy_replaces_lists_like_merge() {
+        let mut base = config_with_list("middleware", vec![ConfigValue::String("auth".into())]);
+        let overlay = config_with_list("middleware", vec![ConfigValue::String("logging".into())]);
+
+        base.merge_with(overlay, &MergePolicy::default());
+
+        assert_eq!(
+            base.get_raw("middleware").unwrap(),
+            &ConfigValue::List(vec![ConfigValue::String("logging".into())])
+        );
+    }
+
+    #[test]
+    fn append_strategy_concatenates_lists() {
+        let mut base = config_with_list("middleware", vec![ConfigValue::String("auth".into())]);
+        let overlay = config_with_list("middleware", vec![ConfigValue::String("logging".into())]);
+        let policy = MergePolicy::new().with_rule("middleware", MergeStrategy::Append);
+
+        base.merge_with(overlay, &policy);
+
+        assert_eq!(
+            base.get_raw("middleware").unwrap(),
+            &ConfigValue::List(vec![
+                ConfigValue::String("auth".into()),
+                ConfigValue::String("logging".into())
+            ])
+        );
+    }
+
+    #[test]
+    fn merge_by_key_updates_matching_entries_and_appends_new_ones() {
+        fn route(path: &str, timeout: i64) -> ConfigValue {
+            let mut table = HashMap::new();
+            table.insert("path".to_string(), ConfigValue::String(path.to_string()));
+            table.insert("timeout".to_string(), ConfigValue::Integer(timeout));
+            ConfigValue::Table(table)
+        }
+
+        let mut base = config_with_list("routes", vec![route("/a", 10), route("/b", 20)]);
+        let overlay = config_with_list("routes", vec![route("/b", 99), route("/c", 30)]);
+        let policy = MergePolicy::new().with_rule(
+            "routes",
+            MergeStrategy::MergeByKey {
+                key_field: "path".to_string(),
+            },
+        );
+
+        base.merge_with(overlay, &policy);
+
+        assert_eq!(
+            base.get_raw("routes").unwrap(),
+            &ConfigValue::List(vec![route("/a", 10), route("/b", 99), route("/c", 30)])
+        );
+    }
+
+    #[test]
+    fn rules_apply_per_nested_path_with_an_unmatched_default() {
+        let mut base_server = HashMap::new();
+        base_server.insert(
+            "middleware".to_string(),
+            ConfigValue::List(vec![ConfigValue::String("auth".into())]),
+        );
+        base_server.insert(
+            "tags".to_string(),
+            ConfigValue::List(vec![ConfigValue::String("stable".into())]),
+        );
+        let mut base_root = HashMap::new();
+        base_root.insert("server".to_string(), ConfigValue::Table(base_server));
+        let mut base = Config::from_table(base_root);
+
+        let mut overlay_server = HashMap::new();
+        overlay_server.insert(
+            "middleware".to_string(),
+            ConfigValue::List(vec![ConfigValue::String("logging".into())]),
+        );
+        overlay_server.insert(
+            "tags".to_string(),
+            ConfigValue::List(vec![ConfigValue::String("canary".into())]),
+        );
+        let mut overlay_root = HashMap::new();
+        overlay_root.insert("server".to_string(), ConfigValue::Table(overlay_server));
+        let overlay = Config::from_table(overlay_root);
+
+        let policy = MergePolicy::new().with_rule("server.middleware", MergeStrategy::Append);
+        base.merge_with(overlay, &policy);
+
+        assert_eq!(
+            base.get_raw("server.middleware").unwrap(),
+            &ConfigValue::List(vec![
+                ConfigValue::String("auth".into()),
+                ConfigValue::String("logging".into())
+            ])
+        );
+        assert_eq!(
+            base.get_raw("server.tags").unwrap(),
+            &ConfigValue::List(vec![ConfigValue::String("canary".into())])
+        );
+    }
+}
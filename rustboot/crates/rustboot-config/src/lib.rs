@@ -0,0 +1,7 @@
+//! Hierarchical application configuration for the rustboot framework.
+
+pub mod api;
+pub mod core;
+
+pub use api::{ConfigError, ConfigValue, FromConfigValue, MergePolicy, MergeStrategy};
+pub use core::Config;
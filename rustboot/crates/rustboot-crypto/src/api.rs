@@ -0,0 +1,335 @@
+//! Public types for the crypto module.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A SHA-256 digest, stored as raw bytes rather than its hex string
+/// form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sha256Digest([u8; 32]);
+
+impl Sha256Digest {
+    /// Wraps a raw 32-byte SHA-256 digest.
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw digest bytes.
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Sha256Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Sha256Digest {
+    type Err = CryptoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 64 {
+            return Err(CryptoError::InvalidDigestLength(s.to_string()));
+        }
+
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let pair = &s[i * 2..i * 2 + 2];
+            *byte =
+                u8::from_str_radix(pair, 16).map_err(|_| CryptoError::InvalidDigestCharacter(s.to_string()))?;
+        }
+        Ok(Sha256Digest(bytes))
+    }
+}
+
+/// Errors produced while parsing a [`Sha256Digest`] or using the
+/// [`crate::core::aead`] functions.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CryptoError {
+    /// The input did not have 64 hex digits.
+    #[error("invalid SHA-256 digest length: '{0}'")]
+    InvalidDigestLength(String),
+    /// The input contained a non-hex-digit character.
+    #[error("invalid SHA-256 digest character: '{0}'")]
+    InvalidDigestCharacter(String),
+    /// AEAD encryption failed. The underlying libraries intentionally
+    /// don't expose why, to avoid leaking information useful for a
+    /// chosen-ciphertext attack.
+    #[error("AEAD encryption failed")]
+    EncryptionFailed,
+    /// AEAD decryption failed, most often because the ciphertext, AAD,
+    /// nonce, or key didn't match what it was sealed with.
+    #[error("AEAD decryption failed")]
+    DecryptionFailed,
+    /// An unwrapped key did not have the expected length for the key
+    /// type it was unwrapped into.
+    #[error("invalid key length: expected 32 bytes, got {0}")]
+    InvalidKeyLength(usize),
+    /// A key derivation function failed, most often because the
+    /// requested output length or salt didn't meet the algorithm's
+    /// constraints.
+    #[error("key derivation failed")]
+    KeyDerivationFailed,
+    /// A [`crate::core::key_ring::KeyRing`] operation referenced a key
+    /// version that isn't (or is no longer) in the ring.
+    #[error("unknown key version: {0}")]
+    UnknownKeyVersion(u32),
+    /// A PEM or DER-encoded key failed to parse, or didn't match the
+    /// [`SignatureAlgorithm`] it was loaded as.
+    #[error("invalid key encoding: {0}")]
+    InvalidKeyEncoding(String),
+    /// A signature failed to verify.
+    #[error("signature verification failed")]
+    SignatureVerificationFailed,
+}
+
+/// A digital signature algorithm, named after its JWS `alg` header
+/// value (RFC 7518) so a signature produced here can be dropped
+/// straight into a JWS without a translation step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignatureAlgorithm {
+    /// EdDSA over Curve25519 (RFC 8032). JWS `alg` value `"EdDSA"`.
+    Ed25519,
+    /// ECDSA over the NIST P-256 curve with SHA-256. JWS `alg` value
+    /// `"ES256"`.
+    Es256,
+}
+
+impl SignatureAlgorithm {
+    /// The JWS `alg` header value for this algorithm.
+    pub const fn jws_alg(self) -> &'static str {
+        match self {
+            SignatureAlgorithm::Ed25519 => "EdDSA",
+            SignatureAlgorithm::Es256 => "ES256",
+        }
+    }
+}
+
+/// A private key for either [`SignatureAlgorithm`].
+///
+/// `Debug` deliberately doesn't print the key material, so a key
+/// accidentally logged via `{:?}` doesn't leak into log output.
+pub enum SigningKey {
+    /// An Ed25519 private key.
+    Ed25519(ed25519_dalek::SigningKey),
+    /// A P-256 ECDSA private key.
+    Es256(p256::ecdsa::SigningKey),
+}
+
+impl SigningKey {
+    /// The algorithm this key signs with.
+    pub const fn algorithm(&self) -> SignatureAlgorithm {
+        match self {
+            SigningKey::Ed25519(_) => SignatureAlgorithm::Ed25519,
+            SigningKey::Es256(_) => SignatureAlgorithm::Es256,
+        }
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SigningKey").field(&self.algorithm()).field(&"<redacted>").finish()
+    }
+}
+
+impl Clone for SigningKey {
+    fn clone(&self) -> Self {
+        match self {
+            SigningKey::Ed25519(key) => SigningKey::Ed25519(key.clone()),
+            SigningKey::Es256(key) => SigningKey::Es256(key.clone()),
+        }
+    }
+}
+
+/// A public key for either [`SignatureAlgorithm`], used to verify
+/// signatures produced by the matching [`SigningKey`].
+#[derive(Debug, Clone)]
+pub enum VerifyingKey {
+    /// An Ed25519 public key.
+    Ed25519(ed25519_dalek::VerifyingKey),
+    /// A P-256 ECDSA public key.
+    Es256(p256::ecdsa::VerifyingKey),
+}
+
+impl VerifyingKey {
+    /// The algorithm this key verifies signatures for.
+    pub const fn algorithm(&self) -> SignatureAlgorithm {
+        match self {
+            VerifyingKey::Ed25519(_) => SignatureAlgorithm::Ed25519,
+            VerifyingKey::Es256(_) => SignatureAlgorithm::Es256,
+        }
+    }
+}
+
+/// A byte buffer holding secret material (a password, a raw token)
+/// that must not linger in memory after it's dropped, and must never be
+/// compared to another buffer with a length- or content-dependent
+/// short-circuit — both properties `Vec<u8>` and `String` lack.
+///
+/// `Debug` deliberately doesn't print the contents, and `PartialEq`
+/// compares in constant time via [`constant_time_eq`].
+pub struct SecretBytes(zeroize::Zeroizing<Vec<u8>>);
+
+impl SecretBytes {
+    /// Takes ownership of `bytes`, zeroizing them when this is dropped.
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        Self(zeroize::Zeroizing::new(bytes))
+    }
+
+    /// The secret's raw bytes. Avoid copying them into a
+    /// longer-lived, non-zeroizing buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecretBytes").field(&"<redacted>").finish()
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        crate::core::secret::constant_time_eq(self.as_bytes(), other.as_bytes())
+    }
+}
+
+impl Eq for SecretBytes {}
+
+/// A signature produced by [`crate::core::signature::sign`], stored as
+/// the raw bytes a JWS would carry (base64url-encoded, with no ASN.1
+/// wrapping): 64 bytes for Ed25519, or the 64-byte `r || s` compact form
+/// for ES256.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature(pub(crate) Vec<u8>);
+
+impl Signature {
+    /// Wraps raw signature bytes, as produced by signing or as read off
+    /// a JWS.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// The raw signature bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// An authenticated encryption with associated data (AEAD) algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AeadAlgorithm {
+    /// AES-256 in Galois/Counter Mode. Hardware-accelerated on most
+    /// server CPUs; the default choice when in doubt.
+    Aes256Gcm,
+    /// ChaCha20-Poly1305. Faster than AES-GCM without AES-NI, and a
+    /// common choice for mobile or embedded peers.
+    ChaCha20Poly1305,
+}
+
+/// A 256-bit symmetric key for use with any [`AeadAlgorithm`].
+///
+/// `Debug` deliberately doesn't print the key bytes, so a key accidentally
+/// logged via `{:?}` doesn't leak into log output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AeadKey([u8; 32]);
+
+impl AeadKey {
+    /// Wraps raw key bytes.
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw key bytes.
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for AeadKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AeadKey").field(&"<redacted>").finish()
+    }
+}
+
+/// A 96-bit AEAD nonce. Must never be reused with the same key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nonce([u8; 12]);
+
+impl Nonce {
+    /// Wraps raw nonce bytes.
+    pub const fn from_bytes(bytes: [u8; 12]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw nonce bytes.
+    pub const fn as_bytes(&self) -> &[u8; 12] {
+        &self.0
+    }
+}
+
+/// The output of sealing a message with [`crate::core::aead::encrypt`]:
+/// the nonce used, bundled with the ciphertext it produced, since both
+/// are needed to decrypt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ciphertext {
+    pub(crate) nonce: Nonce,
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl Ciphertext {
+    /// The nonce the ciphertext was sealed with.
+    pub const fn nonce(&self) -> Nonce {
+        self.nonce
+    }
+
+    /// The raw ciphertext bytes (includes the authentication tag).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// A key encrypted under another ("key-encrypting") key, produced by
+/// [`crate::core::aead::wrap_key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedKey {
+    pub(crate) algorithm: AeadAlgorithm,
+    pub(crate) ciphertext: Ciphertext,
+}
+
+impl WrappedKey {
+    /// The algorithm the key was wrapped with, needed to unwrap it.
+    pub const fn algorithm(&self) -> AeadAlgorithm {
+        self.algorithm
+    }
+
+    /// The wrapped key's ciphertext.
+    pub const fn ciphertext(&self) -> &Ciphertext {
+        &self.ciphertext
+    }
+}
+
+/// A ciphertext produced by [`crate::core::key_ring::KeyRing::encrypt`],
+/// tagged with the version of the key it was sealed under so
+/// [`KeyRing::decrypt`](crate::core::key_ring::KeyRing::decrypt) can
+/// pick the matching key out of the ring instead of trying every key it
+/// holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedCiphertext {
+    pub(crate) key_version: u32,
+    pub(crate) algorithm: AeadAlgorithm,
+    pub(crate) ciphertext: Ciphertext,
+}
+
+impl VersionedCiphertext {
+    /// The version of the key this was sealed under.
+    pub const fn key_version(&self) -> u32 {
+        self.key_version
+    }
+}
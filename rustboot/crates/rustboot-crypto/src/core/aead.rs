@@ -0,0 +1,156 @@
+//! Authenticated encryption with associated data (AES-256-GCM and
+//! ChaCha20-Poly1305), and key wrapping built on top of it.
+
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::{Aead as _, KeyInit, Payload};
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::ChaCha20Poly1305;
+use rand::RngCore;
+
+use crate::api::{AeadAlgorithm, AeadKey, Ciphertext, CryptoError, Nonce, WrappedKey};
+
+impl AeadKey {
+    /// Generates a random 256-bit key suitable for either
+    /// [`AeadAlgorithm`].
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        Self::from_bytes(bytes)
+    }
+}
+
+impl Nonce {
+    /// Generates a random 96-bit nonce. Safe to call once per message
+    /// for a given key: at random 96-bit nonces, a collision becomes
+    /// likely only after billions of messages under the same key, far
+    /// beyond the point a key should have been rotated anyway.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Encrypts `plaintext` under `key` with a freshly generated nonce,
+/// authenticating `aad` alongside it without encrypting it.
+pub fn encrypt(algorithm: AeadAlgorithm, key: &AeadKey, aad: &[u8], plaintext: &[u8]) -> Result<Ciphertext, CryptoError> {
+    let nonce = Nonce::generate();
+    let payload = Payload { msg: plaintext, aad };
+    let bytes = seal(algorithm, key, &nonce, payload)?;
+    Ok(Ciphertext { nonce, bytes })
+}
+
+/// Decrypts a [`Ciphertext`] produced by [`encrypt`] with the same
+/// `algorithm`, `key`, and `aad`.
+pub fn decrypt(algorithm: AeadAlgorithm, key: &AeadKey, aad: &[u8], ciphertext: &Ciphertext) -> Result<Vec<u8>, CryptoError> {
+    let payload = Payload { msg: ciphertext.as_bytes(), aad };
+    open(algorithm, key, &ciphertext.nonce, payload)
+}
+
+fn seal(algorithm: AeadAlgorithm, key: &AeadKey, nonce: &Nonce, payload: Payload<'_, '_>) -> Result<Vec<u8>, CryptoError> {
+    let nonce = GenericArray::from_slice(nonce.as_bytes());
+    match algorithm {
+        AeadAlgorithm::Aes256Gcm => Aes256Gcm::new(GenericArray::from_slice(key.as_bytes())).encrypt(nonce, payload),
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            ChaCha20Poly1305::new(GenericArray::from_slice(key.as_bytes())).encrypt(nonce, payload)
+        }
+    }
+    .map_err(|_| CryptoError::EncryptionFailed)
+}
+
+fn open(algorithm: AeadAlgorithm, key: &AeadKey, nonce: &Nonce, payload: Payload<'_, '_>) -> Result<Vec<u8>, CryptoError> {
+    let nonce = GenericArray::from_slice(nonce.as_bytes());
+    match algorithm {
+        AeadAlgorithm::Aes256Gcm => Aes256Gcm::new(GenericArray::from_slice(key.as_bytes())).decrypt(nonce, payload),
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            ChaCha20Poly1305::new(GenericArray::from_slice(key.as_bytes())).decrypt(nonce, payload)
+        }
+    }
+    .map_err(|_| CryptoError::DecryptionFailed)
+}
+
+/// Wraps `key_to_wrap` under `kek` ("key-encrypting key"): encrypting
+/// one key under another so a key-rotation event only has to rewrap the
+/// (small, fixed-size) wrapped key rather than re-encrypting everything
+/// `key_to_wrap` protects.
+pub fn wrap_key(algorithm: AeadAlgorithm, kek: &AeadKey, key_to_wrap: &AeadKey) -> Result<WrappedKey, CryptoError> {
+    let ciphertext = encrypt(algorithm, kek, b"", key_to_wrap.as_bytes())?;
+    Ok(WrappedKey { algorithm, ciphertext })
+}
+
+/// Unwraps a [`WrappedKey`] produced by [`wrap_key`] with the same
+/// `kek`.
+pub fn unwrap_key(kek: &AeadKey, wrapped: &WrappedKey) -> Result<AeadKey, CryptoError> {
+    let bytes = decrypt(wrapped.algorithm, kek, b"", &wrapped.ciphertext)?;
+    let bytes: [u8; 32] = bytes.try_into().map_err(|bytes: Vec<u8>| CryptoError::InvalidKeyLength(bytes.len()))?;
+    Ok(AeadKey::from_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aes_gcm_round_trips_with_matching_aad() {
+        let key = AeadKey::generate();
+        let ciphertext = encrypt(AeadAlgorithm::Aes256Gcm, &key, b"context", b"top secret").unwrap();
+        let plaintext = decrypt(AeadAlgorithm::Aes256Gcm, &key, b"context", &ciphertext).unwrap();
+        assert_eq!(plaintext, b"top secret");
+    }
+
+    #[test]
+    fn chacha20poly1305_round_trips_with_matching_aad() {
+        let key = AeadKey::generate();
+        let ciphertext = encrypt(AeadAlgorithm::ChaCha20Poly1305, &key, b"context", b"top secret").unwrap();
+        let plaintext = decrypt(AeadAlgorithm::ChaCha20Poly1305, &key, b"context", &ciphertext).unwrap();
+        assert_eq!(plaintext, b"top secret");
+    }
+
+    #[test]
+    fn decrypt_rejects_mismatched_aad() {
+        let key = AeadKey::generate();
+        let ciphertext = encrypt(AeadAlgorithm::Aes256Gcm, &key, b"context", b"top secret").unwrap();
+        assert!(decrypt(AeadAlgorithm::Aes256Gcm, &key, b"other context", &ciphertext).is_err());
+    }
+
+    #[test]
+    fn decrypt_rejects_the_wrong_key() {
+        let key = AeadKey::generate();
+        let other_key = AeadKey::generate();
+        let ciphertext = encrypt(AeadAlgorithm::Aes256Gcm, &key, b"", b"top secret").unwrap();
+        assert!(decrypt(AeadAlgorithm::Aes256Gcm, &other_key, b"", &ciphertext).is_err());
+    }
+
+    #[test]
+    fn decrypt_rejects_a_tampered_ciphertext() {
+        let key = AeadKey::generate();
+        let mut ciphertext = encrypt(AeadAlgorithm::Aes256Gcm, &key, b"", b"top secret").unwrap();
+        ciphertext.bytes[0] ^= 0xff;
+        assert!(decrypt(AeadAlgorithm::Aes256Gcm, &key, b"", &ciphertext).is_err());
+    }
+
+    #[test]
+    fn each_encryption_uses_a_fresh_nonce() {
+        let key = AeadKey::generate();
+        let first = encrypt(AeadAlgorithm::Aes256Gcm, &key, b"", b"top secret").unwrap();
+        let second = encrypt(AeadAlgorithm::Aes256Gcm, &key, b"", b"top secret").unwrap();
+        assert_ne!(first.nonce(), second.nonce());
+    }
+
+    #[test]
+    fn wrap_and_unwrap_key_round_trips() {
+        let kek = AeadKey::generate();
+        let key_to_wrap = AeadKey::generate();
+        let wrapped = wrap_key(AeadAlgorithm::ChaCha20Poly1305, &kek, &key_to_wrap).unwrap();
+        let unwrapped = unwrap_key(&kek, &wrapped).unwrap();
+        assert_eq!(unwrapped, key_to_wrap);
+    }
+
+    #[test]
+    fn unwrap_key_rejects_the_wrong_kek() {
+        let kek = AeadKey::generate();
+        let other_kek = AeadKey::generate();
+        let wrapped = wrap_key(AeadAlgorithm::Aes256Gcm, &kek, &AeadKey::generate()).unwrap();
+        assert!(unwrap_key(&other_kek, &wrapped).is_err());
+    }
+}
@@ -0,0 +1,49 @@
+//! SHA-256 hashing.
+
+use sha2::{Digest, Sha256};
+
+use crate::api::Sha256Digest;
+
+impl Sha256Digest {
+    /// Hashes `data` with SHA-256.
+    pub fn of(data: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        Sha256Digest::from_bytes(hasher.finalize().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn of_matches_a_known_sha256_vector() {
+        let digest = Sha256Digest::of(b"abc");
+        assert_eq!(digest.to_string(), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn display_and_from_str_round_trip() {
+        let digest = Sha256Digest::of(b"hello world");
+        let parsed: Sha256Digest = digest.to_string().parse().unwrap();
+        assert_eq!(digest, parsed);
+    }
+
+    #[test]
+    fn from_str_rejects_the_wrong_length() {
+        assert!("not-a-digest".parse::<Sha256Digest>().is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_non_hex_characters() {
+        let bad = "z".repeat(64);
+        assert!(bad.parse::<Sha256Digest>().is_err());
+    }
+
+    #[test]
+    fn of_is_deterministic_and_sensitive_to_input() {
+        assert_eq!(Sha256Digest::of(b"same"), Sha256Digest::of(b"same"));
+        assert_ne!(Sha256Digest::of(b"left"), Sha256Digest::of(b"right"));
+    }
+}
@@ -0,0 +1,123 @@
+//! Key rotation: a ring of versioned AEAD keys, so rotating in a new
+//! primary key doesn't invalidate data encrypted under an older one.
+
+use std::collections::HashMap;
+
+use crate::api::{AeadAlgorithm, AeadKey, CryptoError, VersionedCiphertext};
+use crate::core::aead;
+
+/// A set of versioned [`AeadKey`]s: one designated primary, used to
+/// encrypt new data, and the rest kept around purely to decrypt data
+/// encrypted under them before they were rotated out.
+#[derive(Debug)]
+pub struct KeyRing {
+    primary: u32,
+    keys: HashMap<u32, AeadKey>,
+}
+
+impl KeyRing {
+    /// Starts a ring with `key` at `version`, as the primary.
+    pub fn new(version: u32, key: AeadKey) -> Self {
+        let mut keys = HashMap::new();
+        keys.insert(version, key);
+        Self { primary: version, keys }
+    }
+
+    /// Adds or replaces the key at `version`, without changing the
+    /// primary. Call [`promote`](KeyRing::promote) separately to make
+    /// it the one used for new encryptions.
+    pub fn insert(&mut self, version: u32, key: AeadKey) {
+        self.keys.insert(version, key);
+    }
+
+    /// Makes `version` the primary. Returns
+    /// [`CryptoError::UnknownKeyVersion`] if `version` isn't in the
+    /// ring — insert it first.
+    pub fn promote(&mut self, version: u32) -> Result<(), CryptoError> {
+        if !self.keys.contains_key(&version) {
+            return Err(CryptoError::UnknownKeyVersion(version));
+        }
+        self.primary = version;
+        Ok(())
+    }
+
+    /// Drops the key at `version`. Data encrypted under it can no
+    /// longer be decrypted through this ring afterward.
+    pub fn remove(&mut self, version: u32) {
+        self.keys.remove(&version);
+    }
+
+    /// The primary key's version.
+    pub fn primary_version(&self) -> u32 {
+        self.primary
+    }
+
+    /// Encrypts `plaintext` under the primary key.
+    pub fn encrypt(
+        &self,
+        algorithm: AeadAlgorithm,
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<VersionedCiphertext, CryptoError> {
+        let key = self.keys.get(&self.primary).ok_or(CryptoError::UnknownKeyVersion(self.primary))?;
+        let ciphertext = aead::encrypt(algorithm, key, aad, plaintext)?;
+        Ok(VersionedCiphertext { key_version: self.primary, algorithm, ciphertext })
+    }
+
+    /// Decrypts a [`VersionedCiphertext`] using whichever key in the
+    /// ring matches the version it was sealed under — not necessarily
+    /// the primary, so a key rotated out of primary use can still
+    /// decrypt what it previously encrypted, as long as it hasn't also
+    /// been [`remove`](KeyRing::remove)d.
+    pub fn decrypt(&self, versioned: &VersionedCiphertext, aad: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let key = self.keys.get(&versioned.key_version).ok_or(CryptoError::UnknownKeyVersion(versioned.key_version))?;
+        aead::decrypt(versioned.algorithm, key, aad, &versioned.ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_and_decrypt_round_trip_through_the_primary() {
+        let ring = KeyRing::new(1, AeadKey::generate());
+        let versioned = ring.encrypt(AeadAlgorithm::Aes256Gcm, b"", b"top secret").unwrap();
+        assert_eq!(versioned.key_version(), 1);
+        assert_eq!(ring.decrypt(&versioned, b"").unwrap(), b"top secret");
+    }
+
+    #[test]
+    fn rotating_the_primary_still_decrypts_data_from_the_old_key() {
+        let mut ring = KeyRing::new(1, AeadKey::generate());
+        let old = ring.encrypt(AeadAlgorithm::Aes256Gcm, b"", b"encrypted under v1").unwrap();
+
+        ring.insert(2, AeadKey::generate());
+        ring.promote(2).unwrap();
+        assert_eq!(ring.primary_version(), 2);
+
+        let new = ring.encrypt(AeadAlgorithm::Aes256Gcm, b"", b"encrypted under v2").unwrap();
+        assert_eq!(new.key_version(), 2);
+
+        assert_eq!(ring.decrypt(&old, b"").unwrap(), b"encrypted under v1");
+        assert_eq!(ring.decrypt(&new, b"").unwrap(), b"encrypted under v2");
+    }
+
+    #[test]
+    fn removing_a_version_makes_its_data_undecryptable() {
+        let mut ring = KeyRing::new(1, AeadKey::generate());
+        let versioned = ring.encrypt(AeadAlgorithm::Aes256Gcm, b"", b"top secret").unwrap();
+
+        ring.insert(2, AeadKey::generate());
+        ring.promote(2).unwrap();
+        ring.remove(1);
+
+        assert!(matches!(ring.decrypt(&versioned, b""), Err(CryptoError::UnknownKeyVersion(1))));
+    }
+
+    #[test]
+    fn promote_rejects_an_unknown_version() {
+        let mut ring = KeyRing::new(1, AeadKey::generate());
+        assert!(matches!(ring.promote(99), Err(CryptoError::UnknownKeyVersion(99))));
+    }
+}
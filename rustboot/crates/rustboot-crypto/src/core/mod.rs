@@ -0,0 +1,9 @@
+//! Implementation details for the crypto module.
+
+pub mod aead;
+pub mod hash;
+pub mod kdf;
+pub mod key_ring;
+pub mod mac;
+pub mod secret;
+pub mod signature;
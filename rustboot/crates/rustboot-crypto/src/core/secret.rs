@@ -0,0 +1,101 @@
+//! Constant-time comparison, zeroizing secret storage, and random token
+//! generation — the memory- and timing-hygiene utilities every other
+//! module in this crate leans on.
+
+use rand::RngCore;
+use subtle::ConstantTimeEq;
+
+use crate::api::SecretBytes;
+
+/// Compares `a` and `b` in constant time with respect to their
+/// contents, so comparing an attacker-supplied value (an API key, a
+/// CSRF token) against the expected one doesn't leak timing
+/// information about where they first differ. A length mismatch still
+/// short-circuits, since it isn't secret-dependent.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && bool::from(a.ct_eq(b))
+}
+
+/// Generates a random, URL-safe token of `len` bytes of entropy,
+/// base64url-encoded without padding — suitable for session IDs, CSRF
+/// tokens, or API keys.
+pub fn random_token(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    base64url_encode(&bytes)
+}
+
+impl SecretBytes {
+    /// Generates `len` random bytes as a [`SecretBytes`].
+    pub fn generate(len: usize) -> Self {
+        let mut bytes = vec![0u8; len];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        SecretBytes::from_vec(bytes)
+    }
+}
+
+fn base64url_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        if let Some(b1) = b1 {
+            out.push(ALPHABET[((b1 & 0x0f) << 2 | b2.unwrap_or(0) >> 6) as usize] as char);
+        }
+        if let Some(b2) = b2 {
+            out.push(ALPHABET[(b2 & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_time_eq_matches_equal_slices() {
+        assert!(constant_time_eq(b"same bytes", b"same bytes"));
+    }
+
+    #[test]
+    fn constant_time_eq_rejects_different_slices() {
+        assert!(!constant_time_eq(b"same bytes", b"different"));
+    }
+
+    #[test]
+    fn constant_time_eq_rejects_mismatched_lengths() {
+        assert!(!constant_time_eq(b"short", b"a longer slice"));
+    }
+
+    #[test]
+    fn random_token_has_no_padding_or_unsafe_characters() {
+        let token = random_token(32);
+        assert!(!token.contains('+'));
+        assert!(!token.contains('/'));
+        assert!(!token.contains('='));
+    }
+
+    #[test]
+    fn random_token_calls_produce_distinct_output() {
+        assert_ne!(random_token(32), random_token(32));
+    }
+
+    #[test]
+    fn secret_bytes_eq_uses_constant_time_comparison() {
+        assert_eq!(SecretBytes::from_vec(b"secret".to_vec()), SecretBytes::from_vec(b"secret".to_vec()));
+        assert_ne!(SecretBytes::from_vec(b"secret".to_vec()), SecretBytes::from_vec(b"different".to_vec()));
+    }
+
+    #[test]
+    fn secret_bytes_debug_does_not_print_contents() {
+        let secret = SecretBytes::from_vec(b"do not leak me".to_vec());
+        assert!(!format!("{secret:?}").contains("do not leak me"));
+    }
+}
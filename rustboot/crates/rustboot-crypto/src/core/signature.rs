@@ -0,0 +1,156 @@
+//! Digital signatures: Ed25519 and ECDSA P-256 (ES256), with PEM/DER key
+//! loading and JWS-compatible (raw, non-ASN.1) signature output.
+
+use ed25519_dalek::{Signer as _, Verifier as _};
+use pkcs8::{DecodePrivateKey, DecodePublicKey};
+
+use crate::api::{CryptoError, Signature, SignatureAlgorithm, SigningKey, VerifyingKey};
+
+impl SigningKey {
+    /// Generates a random private key for `algorithm`.
+    pub fn generate(algorithm: SignatureAlgorithm) -> Self {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => SigningKey::Ed25519(ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng)),
+            SignatureAlgorithm::Es256 => SigningKey::Es256(p256::ecdsa::SigningKey::random(&mut rand::rngs::OsRng)),
+        }
+    }
+
+    /// Loads a PKCS#8 PEM-encoded private key as `algorithm`.
+    pub fn from_pkcs8_pem(algorithm: SignatureAlgorithm, pem: &str) -> Result<Self, CryptoError> {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => ed25519_dalek::SigningKey::from_pkcs8_pem(pem)
+                .map(SigningKey::Ed25519)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+            SignatureAlgorithm::Es256 => p256::ecdsa::SigningKey::from_pkcs8_pem(pem)
+                .map(SigningKey::Es256)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+        }
+    }
+
+    /// Loads a PKCS#8 DER-encoded private key as `algorithm`.
+    pub fn from_pkcs8_der(algorithm: SignatureAlgorithm, der: &[u8]) -> Result<Self, CryptoError> {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => ed25519_dalek::SigningKey::from_pkcs8_der(der)
+                .map(SigningKey::Ed25519)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+            SignatureAlgorithm::Es256 => p256::ecdsa::SigningKey::from_pkcs8_der(der)
+                .map(SigningKey::Es256)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+        }
+    }
+
+    /// Derives the public key that verifies signatures made with this
+    /// key.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        match self {
+            SigningKey::Ed25519(key) => VerifyingKey::Ed25519(key.verifying_key()),
+            SigningKey::Es256(key) => VerifyingKey::Es256(*key.verifying_key()),
+        }
+    }
+}
+
+impl VerifyingKey {
+    /// Loads an SPKI PEM-encoded public key as `algorithm`.
+    pub fn from_public_key_pem(algorithm: SignatureAlgorithm, pem: &str) -> Result<Self, CryptoError> {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => ed25519_dalek::VerifyingKey::from_public_key_pem(pem)
+                .map(VerifyingKey::Ed25519)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+            SignatureAlgorithm::Es256 => p256::ecdsa::VerifyingKey::from_public_key_pem(pem)
+                .map(VerifyingKey::Es256)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+        }
+    }
+
+    /// Loads an SPKI DER-encoded public key as `algorithm`.
+    pub fn from_public_key_der(algorithm: SignatureAlgorithm, der: &[u8]) -> Result<Self, CryptoError> {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => ed25519_dalek::VerifyingKey::from_public_key_der(der)
+                .map(VerifyingKey::Ed25519)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+            SignatureAlgorithm::Es256 => p256::ecdsa::VerifyingKey::from_public_key_der(der)
+                .map(VerifyingKey::Es256)
+                .map_err(|e| CryptoError::InvalidKeyEncoding(e.to_string())),
+        }
+    }
+}
+
+/// Signs `message` with `key`, producing JWS-compatible raw signature
+/// bytes (no ASN.1 wrapping).
+pub fn sign(key: &SigningKey, message: &[u8]) -> Signature {
+    let bytes = match key {
+        SigningKey::Ed25519(key) => key.sign(message).to_bytes().to_vec(),
+        SigningKey::Es256(key) => {
+            let signature: p256::ecdsa::Signature = key.sign(message);
+            signature.to_bytes().to_vec()
+        }
+    };
+    Signature(bytes)
+}
+
+/// Verifies `signature` over `message` against `key`.
+pub fn verify(key: &VerifyingKey, message: &[u8], signature: &Signature) -> Result<(), CryptoError> {
+    match key {
+        VerifyingKey::Ed25519(key) => {
+            let signature = ed25519_dalek::Signature::from_slice(signature.as_bytes())
+                .map_err(|_| CryptoError::SignatureVerificationFailed)?;
+            key.verify(message, &signature).map_err(|_| CryptoError::SignatureVerificationFailed)
+        }
+        VerifyingKey::Es256(key) => {
+            let signature = p256::ecdsa::Signature::from_slice(signature.as_bytes())
+                .map_err(|_| CryptoError::SignatureVerificationFailed)?;
+            key.verify(message, &signature).map_err(|_| CryptoError::SignatureVerificationFailed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ed25519_round_trips() {
+        let key = SigningKey::generate(SignatureAlgorithm::Ed25519);
+        let signature = sign(&key, b"webhook payload");
+        assert!(verify(&key.verifying_key(), b"webhook payload", &signature).is_ok());
+    }
+
+    #[test]
+    fn es256_round_trips() {
+        let key = SigningKey::generate(SignatureAlgorithm::Es256);
+        let signature = sign(&key, b"webhook payload");
+        assert!(verify(&key.verifying_key(), b"webhook payload", &signature).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_message() {
+        let key = SigningKey::generate(SignatureAlgorithm::Ed25519);
+        let signature = sign(&key, b"webhook payload");
+        assert!(verify(&key.verifying_key(), b"tampered payload", &signature).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_a_signature_from_a_different_key() {
+        let key = SigningKey::generate(SignatureAlgorithm::Es256);
+        let other_key = SigningKey::generate(SignatureAlgorithm::Es256);
+        let signature = sign(&key, b"webhook payload");
+        assert!(verify(&other_key.verifying_key(), b"webhook payload", &signature).is_err());
+    }
+
+    #[test]
+    fn pkcs8_pem_round_trip_for_ed25519() {
+        let key = SigningKey::generate(SignatureAlgorithm::Ed25519);
+        let SigningKey::Ed25519(inner) = &key else { unreachable!() };
+        let pem = pkcs8::EncodePrivateKey::to_pkcs8_pem(inner, pkcs8::LineEnding::LF).unwrap();
+
+        let loaded = SigningKey::from_pkcs8_pem(SignatureAlgorithm::Ed25519, &pem).unwrap();
+        let signature = sign(&loaded, b"message");
+        assert!(verify(&key.verifying_key(), b"message", &signature).is_ok());
+    }
+
+    #[test]
+    fn jws_alg_names_match_rfc_7518() {
+        assert_eq!(SignatureAlgorithm::Ed25519.jws_alg(), "EdDSA");
+        assert_eq!(SignatureAlgorithm::Es256.jws_alg(), "ES256");
+    }
+}
@@ -0,0 +1,46 @@
+//! Hashing and encryption helpers for the rustboot framework.
+//!
+//! - [`Sha256Digest`] computes and (de)serializes-to-hex SHA-256
+//!   digests, so checksum sidecars and content-addressed caches in
+//!   other crates share one digest type instead of each formatting raw
+//!   hash bytes differently.
+//! - [`encrypt`]/[`decrypt`]: AES-256-GCM and ChaCha20-Poly1305
+//!   authenticated encryption, so rustboot-security's secrets and
+//!   session-cookie encryption don't need a separate crypto dependency.
+//! - [`wrap_key`]/[`unwrap_key`]: encrypt one key under another, for
+//!   rotating a key-encrypting key without re-encrypting everything it
+//!   protects.
+//! - [`hkdf`], [`pbkdf2`], [`argon2id`]: derive key material from a
+//!   high-entropy secret or a low-entropy password.
+//! - [`hmac_sha256`]/[`hmac_sha256_verify`]: HMAC-SHA256 for symmetric
+//!   integrity checks, including the HS256 JWT algorithm in
+//!   rustboot-security.
+//! - [`KeyRing`]: a set of versioned keys — primary for new
+//!   encryptions, all of them for decryption — so tokens, cookies, and
+//!   encrypted secrets can be rotated without invalidating everything
+//!   encrypted under the previous key.
+//! - [`sign`]/[`verify`]: Ed25519 and ECDSA P-256 (ES256) digital
+//!   signatures, with PEM/DER key loading and JWS-compatible raw
+//!   signature output, for webhook signature verification and the
+//!   ES256 JWT algorithm in rustboot-security. RSA signatures are not
+//!   provided.
+//! - [`constant_time_eq`] and [`SecretBytes`]: timing-safe comparison
+//!   and zeroizing storage for secrets, so comparing an API key or CSRF
+//!   token doesn't leak timing information and a dropped secret doesn't
+//!   linger in memory.
+//! - [`random_token`]: a random, URL-safe token for session IDs, CSRF
+//!   tokens, or API keys.
+
+pub mod api;
+pub mod core;
+
+pub use api::{
+    AeadAlgorithm, AeadKey, Ciphertext, CryptoError, Nonce, SecretBytes, Sha256Digest, Signature, SignatureAlgorithm,
+    SigningKey, VerifyingKey, VersionedCiphertext, WrappedKey,
+};
+pub use core::aead::{decrypt, encrypt, unwrap_key, wrap_key};
+pub use core::kdf::{argon2id, hkdf, pbkdf2};
+pub use core::key_ring::KeyRing;
+pub use core::mac::{hmac_sha256, hmac_sha256_verify};
+pub use core::secret::{constant_time_eq, random_token};
+pub use core::signature::{sign, verify};
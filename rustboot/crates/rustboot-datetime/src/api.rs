@@ -0,0 +1,82 @@
+//! Public types for the datetime module.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A point in time, stored as a UTC instant.
+///
+/// Serializes to and from an RFC 3339 string (delegating to
+/// [`chrono::DateTime<Utc>`]'s own `serde` support), so it round-trips
+/// through JSON the same way a timestamp column or API field typically
+/// does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Timestamp(pub(crate) chrono::DateTime<chrono::Utc>);
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.to_rfc3339())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(|dt| Timestamp(dt.with_timezone(&chrono::Utc)))
+            .map_err(|_| DateTimeError::ParseFailed(s.to_string()))
+    }
+}
+
+/// An IANA timezone identifier (e.g. `"America/New_York"`), used to
+/// convert a [`Timestamp`] to local wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeZoneId(pub(crate) chrono_tz::Tz);
+
+impl TimeZoneId {
+    /// The UTC timezone.
+    pub const UTC: TimeZoneId = TimeZoneId(chrono_tz::UTC);
+
+    /// Looks up a timezone by its IANA name (e.g. `"Europe/Berlin"`).
+    pub fn from_iana(name: &str) -> Result<Self, DateTimeError> {
+        name.parse::<chrono_tz::Tz>().map(TimeZoneId).map_err(|_| DateTimeError::UnknownTimeZone(name.to_string()))
+    }
+}
+
+impl fmt::Display for TimeZoneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.name())
+    }
+}
+
+/// Commonly used `strftime`-style patterns for
+/// [`Timestamp::format_in`](crate::core::timezone), following chrono's
+/// format syntax.
+pub mod patterns {
+    /// `2024-01-01`
+    pub const ISO_DATE: &str = "%Y-%m-%d";
+    /// `2024-01-01 10:00:00`
+    pub const ISO_DATETIME: &str = "%Y-%m-%d %H:%M:%S";
+    /// `Mon, 01 Jan 2024 10:00:00 +0000`
+    pub const RFC1123: &str = "%a, %d %b %Y %H:%M:%S %z";
+}
+
+/// Errors produced while parsing or converting [`Timestamp`]s and
+/// [`TimeZoneId`]s.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DateTimeError {
+    /// The input didn't match any of the formats `parse_flexible`
+    /// understands, or didn't match the specific format a stricter
+    /// parser (e.g. `FromStr` for `Timestamp`) requires.
+    #[error("could not parse '{0}' as a timestamp")]
+    ParseFailed(String),
+    /// The input wasn't a recognized IANA timezone identifier.
+    #[error("unknown timezone: '{0}'")]
+    UnknownTimeZone(String),
+    /// The input didn't match `parse_duration`'s compact duration
+    /// syntax (e.g. `"1h30m"`, `"500ms"`).
+    #[error("invalid duration: '{0}'")]
+    InvalidDuration(String),
+}
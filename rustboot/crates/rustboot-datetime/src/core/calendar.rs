@@ -0,0 +1,315 @@
+//! Business-day arithmetic, date ranges, and cron-like next-occurrence
+//! calculation.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{Datelike, NaiveDate, Timelike};
+
+use crate::api::{DateTimeError, Timestamp};
+use crate::spi::HolidaySet;
+
+/// A fixed, finite set of holiday dates.
+#[derive(Debug, Clone, Default)]
+pub struct FixedHolidays(HashSet<NaiveDate>);
+
+impl FixedHolidays {
+    /// Builds a holiday set from a list of dates.
+    pub fn new(dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self(dates.into_iter().collect())
+    }
+}
+
+impl HolidaySet for FixedHolidays {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.0.contains(&date)
+    }
+}
+
+/// A calendar that treats Saturdays and Sundays, plus whatever a
+/// pluggable [`HolidaySet`] reports, as non-business days.
+#[derive(Clone)]
+pub struct Calendar {
+    holidays: Arc<dyn HolidaySet>,
+}
+
+impl Calendar {
+    /// Builds a calendar backed by `holidays`.
+    pub fn new(holidays: Arc<dyn HolidaySet>) -> Self {
+        Self { holidays }
+    }
+
+    /// A calendar with no holidays — only weekends are non-business
+    /// days.
+    pub fn weekends_only() -> Self {
+        Self::new(Arc::new(FixedHolidays::default()))
+    }
+
+    /// Returns whether `date` falls on a weekend.
+    pub fn is_weekend(date: NaiveDate) -> bool {
+        matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun)
+    }
+
+    /// Returns whether `date` is one of this calendar's holidays.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.is_holiday(date)
+    }
+
+    /// Returns whether `date` is neither a weekend nor a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !Self::is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Adds `n` business days to `date`, skipping weekends and
+    /// holidays. `n` may be negative to go backwards; `n == 0` returns
+    /// `date` unchanged, even if it isn't itself a business day.
+    pub fn add_business_days(&self, date: NaiveDate, n: i64) -> NaiveDate {
+        let step = if n >= 0 { 1 } else { -1 };
+        let mut remaining = n.unsigned_abs();
+        let mut current = date;
+        while remaining > 0 {
+            current += chrono::Duration::days(step);
+            if self.is_business_day(current) {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    /// Iterates the business days in `[start, end)`.
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        DateRange::new(start, end).filter(|date| self.is_business_day(*date))
+    }
+}
+
+/// An iterator over the dates in `[start, end)`.
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    next: NaiveDate,
+    end: NaiveDate,
+}
+
+impl DateRange {
+    /// Builds a range over the dates from `start` (inclusive) to `end`
+    /// (exclusive).
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Self {
+        Self { next: start, end }
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        let current = self.next;
+        self.next += chrono::Duration::days(1);
+        Some(current)
+    }
+}
+
+/// Upper bound on how many days ahead [`next_occurrence`] will search
+/// before giving up on an expression that can never match (e.g.
+/// February 30th).
+const SEARCH_HORIZON_DAYS: i64 = 4 * 366;
+
+/// Computes the next time, strictly after `after`, that matches the
+/// standard 5-field cron expression `minute hour day-of-month month
+/// day-of-week` (evaluated in UTC).
+///
+/// This is a lightweight, self-contained alternative to
+/// `rustboot_scheduler::Schedule::cron` for callers that already depend
+/// on `rustboot-datetime` and only need a one-off "when does this cron
+/// expression next fire" calculation, not a running scheduler.
+pub fn next_occurrence(expression: &str, after: Timestamp) -> Result<Timestamp, DateTimeError> {
+    CronLike::parse(expression)?.next_after(after)
+}
+
+struct CronLike {
+    minutes: HashSet<u32>,
+    hours: HashSet<u32>,
+    days_of_month: HashSet<u32>,
+    months: HashSet<u32>,
+    days_of_week: HashSet<u32>,
+    dom_restricted: bool,
+    dow_restricted: bool,
+    expression: String,
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<HashSet<u32>, DateTimeError> {
+    let invalid = || DateTimeError::ParseFailed(field.to_string());
+
+    let mut values = HashSet::new();
+    for part in field.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((range_part, step)) => (range_part, step.parse::<u32>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(invalid());
+        }
+
+        let (start, end) = if range_part == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range_part.split_once('-') {
+            (start.parse::<u32>().map_err(|_| invalid())?, end.parse::<u32>().map_err(|_| invalid())?)
+        } else {
+            let value = range_part.parse::<u32>().map_err(|_| invalid())?;
+            (value, value)
+        };
+
+        if start < min || end > max || start > end {
+            return Err(invalid());
+        }
+
+        let mut value = start;
+        while value <= end {
+            values.insert(value);
+            value += step;
+        }
+    }
+    Ok(values)
+}
+
+impl CronLike {
+    fn parse(expression: &str) -> Result<Self, DateTimeError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(DateTimeError::ParseFailed(expression.to_string()));
+        };
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days_of_month: parse_field(day_of_month, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            days_of_week: parse_field(day_of_week, 0, 6)?,
+            dom_restricted: day_of_month != "*",
+            dow_restricted: day_of_week != "*",
+            expression: expression.to_string(),
+        })
+    }
+
+    fn matches(&self, date: NaiveDate, hour: u32, minute: u32) -> bool {
+        if !self.minutes.contains(&minute) || !self.hours.contains(&hour) {
+            return false;
+        }
+        if !self.months.contains(&date.month()) {
+            return false;
+        }
+
+        let weekday = date.weekday().num_days_from_sunday();
+        match (self.dom_restricted, self.dow_restricted) {
+            (false, false) => true,
+            (true, false) => self.days_of_month.contains(&date.day()),
+            (false, true) => self.days_of_week.contains(&weekday),
+            (true, true) => self.days_of_month.contains(&date.day()) || self.days_of_week.contains(&weekday),
+        }
+    }
+
+    /// Returns the first whole minute, after `after`, at which this
+    /// expression matches.
+    fn next_after(&self, after: Timestamp) -> Result<Timestamp, DateTimeError> {
+        let naive = after.0.naive_utc();
+        let mut candidate =
+            naive.date().and_hms_opt(naive.hour(), naive.minute(), 0).expect("valid time") + chrono::Duration::minutes(1);
+        let horizon = naive + chrono::Duration::days(SEARCH_HORIZON_DAYS);
+
+        while candidate <= horizon {
+            if self.matches(candidate.date(), candidate.hour(), candidate.minute()) {
+                return Ok(Timestamp(candidate.and_utc()));
+            }
+            candidate += chrono::Duration::minutes(1);
+        }
+
+        Err(DateTimeError::ParseFailed(self.expression.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn is_weekend_identifies_saturday_and_sunday() {
+        assert!(Calendar::is_weekend(date(2024, 1, 6))); // Saturday
+        assert!(Calendar::is_weekend(date(2024, 1, 7))); // Sunday
+        assert!(!Calendar::is_weekend(date(2024, 1, 8))); // Monday
+    }
+
+    #[test]
+    fn is_business_day_excludes_weekends_and_holidays() {
+        let holidays = FixedHolidays::new([date(2024, 1, 1)]);
+        let calendar = Calendar::new(Arc::new(holidays));
+        assert!(!calendar.is_business_day(date(2024, 1, 1))); // holiday
+        assert!(!calendar.is_business_day(date(2024, 1, 6))); // Saturday
+        assert!(calendar.is_business_day(date(2024, 1, 2)));
+    }
+
+    #[test]
+    fn add_business_days_skips_weekend() {
+        let calendar = Calendar::weekends_only();
+        // Friday 2024-01-05 + 1 business day -> Monday 2024-01-08.
+        assert_eq!(calendar.add_business_days(date(2024, 1, 5), 1), date(2024, 1, 8));
+    }
+
+    #[test]
+    fn add_business_days_handles_zero() {
+        let calendar = Calendar::weekends_only();
+        assert_eq!(calendar.add_business_days(date(2024, 1, 6), 0), date(2024, 1, 6));
+    }
+
+    #[test]
+    fn add_business_days_goes_backwards() {
+        let calendar = Calendar::weekends_only();
+        // Monday 2024-01-08 - 1 business day -> Friday 2024-01-05.
+        assert_eq!(calendar.add_business_days(date(2024, 1, 8), -1), date(2024, 1, 5));
+    }
+
+    #[test]
+    fn date_range_is_half_open() {
+        let range: Vec<NaiveDate> = DateRange::new(date(2024, 1, 1), date(2024, 1, 4)).collect();
+        assert_eq!(range, vec![date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 3)]);
+    }
+
+    #[test]
+    fn business_days_between_skips_weekend() {
+        let calendar = Calendar::weekends_only();
+        let days: Vec<NaiveDate> = calendar.business_days_between(date(2024, 1, 5), date(2024, 1, 9)).collect();
+        assert_eq!(days, vec![date(2024, 1, 5), date(2024, 1, 8)]);
+    }
+
+    #[test]
+    fn next_occurrence_finds_next_matching_minute_same_day() {
+        let after =
+            Timestamp::from_unix_seconds(date(2024, 1, 1).and_hms_opt(8, 0, 0).unwrap().and_utc().timestamp()).unwrap();
+        let next = next_occurrence("30 9 * * *", after).unwrap();
+        assert_eq!(next.to_unix_seconds(), date(2024, 1, 1).and_hms_opt(9, 30, 0).unwrap().and_utc().timestamp());
+    }
+
+    #[test]
+    fn next_occurrence_rolls_over_to_next_day() {
+        let after =
+            Timestamp::from_unix_seconds(date(2024, 1, 1).and_hms_opt(23, 0, 0).unwrap().and_utc().timestamp()).unwrap();
+        let next = next_occurrence("0 0 * * *", after).unwrap();
+        assert_eq!(next.to_unix_seconds(), date(2024, 1, 2).and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp());
+    }
+
+    #[test]
+    fn next_occurrence_errors_when_expression_never_matches() {
+        let after = Timestamp::from_unix_seconds(0).unwrap();
+        assert!(next_occurrence("0 0 30 2 *", after).is_err());
+    }
+
+    #[test]
+    fn next_occurrence_rejects_malformed_expression() {
+        let after = Timestamp::from_unix_seconds(0).unwrap();
+        assert!(next_occurrence("* * * *", after).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+//! Parsing and formatting human-friendly durations, and relative-time
+//! strings ("3 minutes ago") for [`Timestamp`].
+
+use std::time::Duration;
+
+use crate::api::{DateTimeError, Timestamp};
+
+const MS_PER_UNIT: &[(&str, u128)] = &[("d", 86_400_000), ("h", 3_600_000), ("m", 60_000), ("s", 1_000), ("ms", 1)];
+
+/// Parses a compact duration string such as `"1h30m"`, `"30s"`, or
+/// `"500ms"` into a [`Duration`]. Recognized units are `d`, `h`, `m`,
+/// `s`, and `ms`; components can be combined (`"1d12h"`) and may use
+/// fractional values (`"1.5h"`).
+pub fn parse_duration(input: &str) -> Result<Duration, DateTimeError> {
+    let trimmed = input.trim();
+    let invalid = || DateTimeError::InvalidDuration(input.to_string());
+
+    let mut total = Duration::ZERO;
+    let mut chars = trimmed.chars().peekable();
+    let mut saw_component = false;
+
+    while chars.peek().is_some() {
+        let digits: String = std::iter::from_fn(|| chars.by_ref().next_if(|c| c.is_ascii_digit() || *c == '.')).collect();
+        if digits.is_empty() {
+            return Err(invalid());
+        }
+        let unit: String = std::iter::from_fn(|| chars.by_ref().next_if(char::is_ascii_alphabetic)).collect();
+
+        let value: f64 = digits.parse().map_err(|_| invalid())?;
+        let ms_per_unit = MS_PER_UNIT.iter().find(|(name, _)| *name == unit).map(|(_, ms)| *ms).ok_or_else(invalid)?;
+        total += Duration::from_secs_f64(value * (ms_per_unit as f64 / 1_000.0));
+        saw_component = true;
+    }
+
+    if !saw_component {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+/// Formats a [`Duration`] as a compact string in the same format
+/// [`parse_duration`] accepts (e.g. `"1h30m"`), dropping any unit whose
+/// component is zero.
+pub fn humanize_duration(duration: Duration) -> String {
+    let mut remaining = duration.as_millis();
+    if remaining == 0 {
+        return "0s".to_string();
+    }
+
+    let mut formatted = String::new();
+    for (unit, ms_per_unit) in MS_PER_UNIT {
+        let count = remaining / ms_per_unit;
+        if count > 0 {
+            formatted.push_str(&count.to_string());
+            formatted.push_str(unit);
+            remaining %= ms_per_unit;
+        }
+    }
+    formatted
+}
+
+/// Describes `ts` relative to now, e.g. `"3 minutes ago"` or `"in 2
+/// hours"`. Anything within 5 seconds of now is `"just now"`.
+pub fn relative_time(ts: Timestamp) -> String {
+    relative_to(ts, Timestamp::now())
+}
+
+fn relative_to(ts: Timestamp, now: Timestamp) -> String {
+    let delta = now.to_unix_seconds() - ts.to_unix_seconds();
+    if delta.abs() < 5 {
+        return "just now".to_string();
+    }
+
+    let (value, unit) = largest_unit(delta.unsigned_abs());
+    let plural = if value == 1 { "" } else { "s" };
+    if delta > 0 {
+        format!("{value} {unit}{plural} ago")
+    } else {
+        format!("in {value} {unit}{plural}")
+    }
+}
+
+fn largest_unit(seconds: u64) -> (u64, &'static str) {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const MONTH: u64 = 30 * DAY;
+    const YEAR: u64 = 365 * DAY;
+
+    if seconds >= YEAR {
+        (seconds / YEAR, "year")
+    } else if seconds >= MONTH {
+        (seconds / MONTH, "month")
+    } else if seconds >= DAY {
+        (seconds / DAY, "day")
+    } else if seconds >= HOUR {
+        (seconds / HOUR, "hour")
+    } else if seconds >= MINUTE {
+        (seconds / MINUTE, "minute")
+    } else {
+        (seconds, "second")
+    }
+}
+
+/// A `#[serde(with = "rustboot_datetime::duration_serde")]` helper that
+/// (de)serializes a [`Duration`] as a compact string (e.g. `"30s"`),
+/// for config fields like `ttl: 30s` that would otherwise need a raw
+/// number of seconds or milliseconds.
+pub mod duration_serde {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{humanize_duration, parse_duration};
+
+    /// Serializes `duration` as its [`humanize_duration`] string.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&humanize_duration(*duration))
+    }
+
+    /// Deserializes a duration string via [`parse_duration`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        parse_duration(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_compound_duration() {
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5_400));
+    }
+
+    #[test]
+    fn parses_milliseconds_before_minutes_are_disambiguated() {
+        assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn parses_fractional_component() {
+        assert_eq!(parse_duration("1.5h").unwrap(), Duration::from_secs(5_400));
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        assert!(parse_duration("1x").is_err());
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(parse_duration("").is_err());
+    }
+
+    #[test]
+    fn humanize_round_trips_through_parse() {
+        let duration = Duration::from_secs(5_400);
+        assert_eq!(humanize_duration(duration), "1h30m");
+        assert_eq!(parse_duration(&humanize_duration(duration)).unwrap(), duration);
+    }
+
+    #[test]
+    fn humanize_zero_duration() {
+        assert_eq!(humanize_duration(Duration::ZERO), "0s");
+    }
+
+    #[test]
+    fn relative_time_reports_just_now() {
+        let now = Timestamp::now();
+        assert_eq!(relative_to(now, now), "just now");
+    }
+
+    #[test]
+    fn relative_time_reports_past_minutes() {
+        let now = Timestamp::from_unix_seconds(10_000).unwrap();
+        let past = Timestamp::from_unix_seconds(10_000 - 180).unwrap();
+        assert_eq!(relative_to(past, now), "3 minutes ago");
+    }
+
+    #[test]
+    fn relative_time_reports_future_hours() {
+        let now = Timestamp::from_unix_seconds(10_000).unwrap();
+        let future = Timestamp::from_unix_seconds(10_000 + 7_200).unwrap();
+        assert_eq!(relative_to(future, now), "in 2 hours");
+    }
+
+    #[test]
+    fn relative_time_uses_singular_unit() {
+        let now = Timestamp::from_unix_seconds(10_000).unwrap();
+        let past = Timestamp::from_unix_seconds(10_000 - 60).unwrap();
+        assert_eq!(relative_to(past, now), "1 minute ago");
+    }
+}
@@ -0,0 +1,7 @@
+//! Implementation details for the datetime module.
+
+pub mod calendar;
+pub mod duration;
+pub mod parse;
+pub mod timestamp;
+pub mod timezone;
@@ -0,0 +1,106 @@
+//! Lenient parsing across the timestamp formats rustboot sees in
+//! practice: RFC 3339, RFC 2822, unix epoch, and bare "date time"
+//! strings with no explicit offset (assumed UTC).
+
+use crate::api::{DateTimeError, Timestamp};
+
+/// A unix-seconds timestamp for any date up to the year 5138 fits under
+/// this many seconds; a bare integer larger than it is almost certainly
+/// milliseconds rather than seconds.
+const MAX_PLAUSIBLE_UNIX_SECONDS: i64 = 100_000_000_000;
+
+const NAIVE_DATETIME_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"];
+
+/// Parses `input` as a [`Timestamp`], trying several formats in turn:
+///
+/// - RFC 3339 (`2024-01-01T10:00:00Z`)
+/// - RFC 2822 (`Mon, 1 Jan 2024 10:00:00 +0000`)
+/// - A unix epoch integer, in seconds or milliseconds (disambiguated by
+///   magnitude)
+/// - A bare date/time with no offset (`2024-01-01 10:00` or
+///   `2024-01-01 10:00:00`), assumed to be UTC
+/// - A bare date (`2024-01-01`), assumed to be midnight UTC
+pub fn parse_flexible(input: &str) -> Result<Timestamp, DateTimeError> {
+    let input = input.trim();
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(input) {
+        return Ok(Timestamp(dt.with_timezone(&chrono::Utc)));
+    }
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc2822(input) {
+        return Ok(Timestamp(dt.with_timezone(&chrono::Utc)));
+    }
+
+    if let Ok(epoch) = input.parse::<i64>() {
+        let timestamp = if epoch.abs() > MAX_PLAUSIBLE_UNIX_SECONDS {
+            Timestamp::from_unix_millis(epoch)
+        } else {
+            Timestamp::from_unix_seconds(epoch)
+        };
+        return timestamp.ok_or_else(|| DateTimeError::ParseFailed(input.to_string()));
+    }
+
+    for format in NAIVE_DATETIME_FORMATS {
+        if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(input, format) {
+            return Ok(Timestamp(naive.and_utc()));
+        }
+    }
+
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Ok(Timestamp(date.and_hms_opt(0, 0, 0).expect("midnight is always valid").and_utc()));
+    }
+
+    Err(DateTimeError::ParseFailed(input.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rfc3339() {
+        let ts = parse_flexible("2024-01-01T10:00:00Z").unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_704_103_200);
+    }
+
+    #[test]
+    fn parses_rfc2822() {
+        let ts = parse_flexible("Mon, 1 Jan 2024 10:00:00 +0000").unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_704_103_200);
+    }
+
+    #[test]
+    fn parses_unix_seconds() {
+        let ts = parse_flexible("1704103200").unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_704_103_200);
+    }
+
+    #[test]
+    fn parses_unix_millis() {
+        let ts = parse_flexible("1704103200123").unwrap();
+        assert_eq!(ts.to_unix_millis(), 1_704_103_200_123);
+    }
+
+    #[test]
+    fn parses_naive_datetime_with_seconds() {
+        let ts = parse_flexible("2024-01-01 10:00:00").unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_704_103_200);
+    }
+
+    #[test]
+    fn parses_naive_datetime_without_seconds() {
+        let ts = parse_flexible("2024-01-01 10:00").unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_704_103_200);
+    }
+
+    #[test]
+    fn parses_bare_date() {
+        let ts = parse_flexible("2024-01-01").unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_704_067_200);
+    }
+
+    #[test]
+    fn rejects_unparseable_input() {
+        assert!(parse_flexible("not a date").is_err());
+    }
+}
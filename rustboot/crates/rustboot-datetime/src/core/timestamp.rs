@@ -0,0 +1,71 @@
+//! Construction and unix-epoch conversions for [`Timestamp`].
+
+use crate::api::Timestamp;
+
+impl Timestamp {
+    /// The current instant.
+    pub fn now() -> Self {
+        Timestamp(chrono::Utc::now())
+    }
+
+    /// Builds a [`Timestamp`] from a unix epoch offset in whole seconds.
+    /// Returns `None` if `seconds` is out of chrono's representable
+    /// range.
+    pub fn from_unix_seconds(seconds: i64) -> Option<Self> {
+        chrono::DateTime::from_timestamp(seconds, 0).map(Timestamp)
+    }
+
+    /// Builds a [`Timestamp`] from a unix epoch offset in milliseconds.
+    /// Returns `None` if `millis` is out of chrono's representable
+    /// range.
+    pub fn from_unix_millis(millis: i64) -> Option<Self> {
+        chrono::DateTime::from_timestamp_millis(millis).map(Timestamp)
+    }
+
+    /// The unix epoch offset in whole seconds, truncating any
+    /// sub-second component.
+    pub const fn to_unix_seconds(&self) -> i64 {
+        self.0.timestamp()
+    }
+
+    /// The unix epoch offset in milliseconds.
+    pub const fn to_unix_millis(&self) -> i64 {
+        self.0.timestamp_millis()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_seconds_round_trips() {
+        let ts = Timestamp::from_unix_seconds(1_700_000_000).unwrap();
+        assert_eq!(ts.to_unix_seconds(), 1_700_000_000);
+    }
+
+    #[test]
+    fn unix_millis_round_trips() {
+        let ts = Timestamp::from_unix_millis(1_700_000_000_123).unwrap();
+        assert_eq!(ts.to_unix_millis(), 1_700_000_000_123);
+    }
+
+    #[test]
+    fn display_and_from_str_round_trip() {
+        let ts = Timestamp::from_unix_seconds(1_700_000_000).unwrap();
+        let formatted = ts.to_string();
+        assert_eq!(formatted.parse::<Timestamp>().unwrap(), ts);
+    }
+
+    #[test]
+    fn from_str_rejects_garbage() {
+        assert!("not a timestamp".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn ordering_follows_time() {
+        let earlier = Timestamp::from_unix_seconds(1_000).unwrap();
+        let later = Timestamp::from_unix_seconds(2_000).unwrap();
+        assert!(earlier < later);
+    }
+}
@@ -0,0 +1,48 @@
+//! Timezone conversion and localized formatting for [`Timestamp`].
+
+use crate::api::{TimeZoneId, Timestamp};
+
+impl Timestamp {
+    /// Converts this instant to wall-clock date/time in `tz`.
+    pub fn in_timezone(&self, tz: TimeZoneId) -> chrono::DateTime<chrono_tz::Tz> {
+        self.0.with_timezone(&tz.0)
+    }
+
+    /// Formats this instant's wall-clock time in `tz` using a
+    /// `strftime`-style `pattern` (see [`crate::api::patterns`] for
+    /// common ones).
+    pub fn format_in(&self, tz: TimeZoneId, pattern: &str) -> String {
+        self.in_timezone(tz).format(pattern).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::patterns;
+
+    #[test]
+    fn converts_utc_to_local_wall_clock() {
+        // 2024-01-01T00:00:00Z is 2023-12-31T19:00:00 in New York (UTC-5).
+        let ts = Timestamp::from_unix_seconds(1_704_067_200).unwrap();
+        let ny = TimeZoneId::from_iana("America/New_York").unwrap();
+        assert_eq!(ts.format_in(ny, patterns::ISO_DATETIME), "2023-12-31 19:00:00");
+    }
+
+    #[test]
+    fn utc_timezone_is_a_no_op() {
+        let ts = Timestamp::from_unix_seconds(1_704_067_200).unwrap();
+        assert_eq!(ts.format_in(TimeZoneId::UTC, patterns::ISO_DATETIME), "2024-01-01 00:00:00");
+    }
+
+    #[test]
+    fn unknown_timezone_name_is_rejected() {
+        assert!(TimeZoneId::from_iana("Not/A_Zone").is_err());
+    }
+
+    #[test]
+    fn timezone_display_prints_iana_name() {
+        let tz = TimeZoneId::from_iana("Europe/Berlin").unwrap();
+        assert_eq!(tz.to_string(), "Europe/Berlin");
+    }
+}
@@ -0,0 +1,39 @@
+//! UTC timestamps, timezone conversion, and flexible parsing for the
+//! rustboot framework.
+//!
+//! - [`Timestamp`] is a UTC instant that serializes to and parses from
+//!   RFC 3339, so a timestamp field round-trips through JSON the same
+//!   way across every rustboot crate that carries one.
+//! - [`Timestamp::in_timezone`]/[`Timestamp::format_in`] convert to
+//!   local wall-clock time and format it, using the IANA tz database
+//!   (via `chrono-tz`) so DST transitions and historical offset changes
+//!   are handled correctly rather than approximated with a fixed UTC
+//!   offset.
+//! - [`TimeZoneId`] looks up an IANA timezone by name (e.g.
+//!   `"America/New_York"`).
+//! - [`parse_flexible`] accepts RFC 3339, RFC 2822, unix epoch
+//!   (seconds or milliseconds), and bare "date time" strings with no
+//!   offset, for ingesting timestamps from sources that don't agree on
+//!   a single format.
+//! - [`parse_duration`]/[`humanize_duration`] convert between
+//!   [`std::time::Duration`] and a compact string like `"1h30m"`; the
+//!   [`duration_serde`] module plugs the same conversion into
+//!   `#[serde(with = "...")]` for config fields like `ttl: 30s`.
+//! - [`relative_time`] describes a [`Timestamp`] relative to now, e.g.
+//!   `"3 minutes ago"`.
+//! - [`Calendar`]: business-day arithmetic and holiday checks, with a
+//!   pluggable [`spi::HolidaySet`] so the holiday list can come from a
+//!   fixed set or some other source.
+//! - [`DateRange`]: an iterator over the dates in a range.
+//! - [`next_occurrence`]: the next time a standard 5-field cron
+//!   expression fires, for one-off calculations that don't need a full
+//!   `rustboot_scheduler::Scheduler`.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{patterns, DateTimeError, TimeZoneId, Timestamp};
+pub use core::calendar::{next_occurrence, Calendar, DateRange, FixedHolidays};
+pub use core::duration::{duration_serde, humanize_duration, parse_duration, relative_time};
+pub use core::parse::parse_flexible;
@@ -0,0 +1,16 @@
+//! Service provider interfaces for the datetime module.
+
+use chrono::NaiveDate;
+
+/// A set of holiday dates, checked by [`crate::core::calendar::Calendar`]
+/// for business-day arithmetic.
+///
+/// Implement this to plug in a locale- or jurisdiction-specific holiday
+/// calendar (a fixed list, a rule-based generator for floating holidays
+/// like "fourth Thursday in November", or one fetched from an external
+/// service); [`crate::core::calendar::FixedHolidays`] covers the common
+/// case of a known, finite list of dates.
+pub trait HolidaySet: Send + Sync {
+    /// Returns whether `date` is a holiday.
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+}
@@ -0,0 +1,173 @@
+//! Public types shared across rustboot-debug's features.
+
+use std::path::PathBuf;
+
+/// Errors from rustboot-debug's debugging and diagnostics tooling.
+#[derive(Debug, thiserror::Error)]
+pub enum DebugError {
+    /// A cassette file couldn't be read or written.
+    #[error("failed to access cassette '{path}': {source}")]
+    Cassette {
+        /// The cassette file.
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A cassette file's contents weren't valid JSON, or a recorded
+    /// response body wasn't valid base64.
+    #[error("cassette '{path}' is malformed: {message}")]
+    MalformedCassette {
+        /// The cassette file.
+        path: PathBuf,
+        /// What went wrong.
+        message: String,
+    },
+    /// An [`HttpRecorder`](crate::core::http::HttpRecorder) in replay
+    /// mode was asked for a request that doesn't match the next
+    /// recorded interaction (the cassette is exhausted, or the caller
+    /// made requests in a different order than when it was recorded).
+    #[error("no recorded interaction for {method} {url}")]
+    NoRecordedInteraction {
+        /// The method of the unmatched request.
+        method: String,
+        /// The URL of the unmatched request.
+        url: String,
+    },
+}
+
+/// A point-in-time snapshot of a running instance's internal state, for
+/// [`crate::core::diagnostics::render_diagnostics`] to serve as JSON from
+/// a protected "actuator"-style endpoint.
+///
+/// Every field is filled in by the embedding application from whatever
+/// components it has wired up (a DI container, caches, connection pools,
+/// circuit breakers, a slow query log, ...); rustboot-debug only defines
+/// the shape and renders it, so it stays decoupled from every crate that
+/// might back a section. An application that doesn't have a given section
+/// leaves it empty.
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct DiagnosticsSnapshot {
+    /// Services registered with the application's DI container.
+    pub di_registrations: Vec<DiRegistration>,
+    /// The active configuration, with secrets redacted.
+    pub config: serde_json::Value,
+    /// Per-cache hit/miss/size counters.
+    pub caches: Vec<CacheStats>,
+    /// Per-pool (database, HTTP, ...) utilization.
+    pub pools: Vec<PoolStatus>,
+    /// Per-breaker circuit state.
+    pub circuit_breakers: Vec<CircuitBreakerStatus>,
+    /// The most recent queries that exceeded the application's slow
+    /// query threshold, newest first.
+    pub slow_queries: Vec<SlowQuery>,
+}
+
+/// One entry in [`DiagnosticsSnapshot::di_registrations`].
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DiRegistration {
+    /// The registered type's name.
+    pub type_name: String,
+    /// The registered scope (e.g. `"Transient"`, `"Singleton"`).
+    pub scope: String,
+    /// Whether a singleton instance has already been created.
+    pub instantiated: bool,
+}
+
+/// One entry in [`DiagnosticsSnapshot::caches`].
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheStats {
+    /// The cache's name, e.g. `"sessions"`.
+    pub name: String,
+    /// The number of entries currently stored.
+    pub entries: u64,
+    /// The number of `get` calls that found a live entry.
+    pub hits: u64,
+    /// The number of `get` calls that found no live entry.
+    pub misses: u64,
+}
+
+/// One entry in [`DiagnosticsSnapshot::pools`].
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PoolStatus {
+    /// The pool's name, e.g. `"postgres"`.
+    pub name: String,
+    /// Connections currently checked out.
+    pub in_use: u32,
+    /// Connections open and available.
+    pub idle: u32,
+    /// The pool's configured maximum size.
+    pub max_size: u32,
+}
+
+/// One entry in [`DiagnosticsSnapshot::circuit_breakers`].
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CircuitBreakerStatus {
+    /// The breaker's name, e.g. the downstream service it protects.
+    pub name: String,
+    /// The breaker's current state (e.g. `"Closed"`, `"Open"`, `"HalfOpen"`).
+    pub state: String,
+}
+
+/// One entry in [`DiagnosticsSnapshot::slow_queries`].
+#[cfg(feature = "diagnostics")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SlowQuery {
+    /// The sanitized statement (bind values stripped, not literal
+    /// parameters).
+    pub statement: String,
+    /// How long the query took to execute.
+    pub duration_ms: u64,
+    /// Milliseconds since the Unix epoch when the query completed.
+    pub recorded_at_unix_ms: u64,
+}
+
+/// A point-in-time description of one task tracked by a
+/// [`rustboot_async::TaskRegistry`], rendered by
+/// [`crate::core::tasks::render_tasks`].
+#[cfg(feature = "tasks")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TaskInfo {
+    /// The task's registry-assigned id.
+    pub id: u64,
+    /// The name it was spawned with.
+    pub name: String,
+    /// Its current status (`"running"` or `"completed"`).
+    pub status: String,
+    /// The `file:line` where it was spawned.
+    pub spawn_location: String,
+    /// Milliseconds elapsed since it was spawned; for a completed task
+    /// this keeps counting past the moment it finished.
+    pub running_for_ms: u64,
+}
+
+/// A point-in-time depth reading for a [`rustboot_streams::EventSender`],
+/// rendered by [`crate::core::tasks::render_tasks`].
+#[cfg(feature = "tasks")]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct QueueDepth {
+    /// The channel's name.
+    pub name: String,
+    /// The number of items currently buffered.
+    pub depth: usize,
+}
+
+/// The bytes/call counts a [`crate::core::alloc::CountingAllocator`]
+/// recorded, either as a running total or as the delta over an
+/// [`crate::core::alloc::TimingScope`].
+#[cfg(feature = "alloc-stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct AllocDelta {
+    /// Bytes requested via `alloc`/`realloc` growth.
+    pub bytes_allocated: u64,
+    /// Bytes released via `dealloc`/`realloc` shrinkage.
+    pub bytes_freed: u64,
+    /// The number of `alloc` calls.
+    pub allocations: u64,
+    /// The number of `dealloc` calls.
+    pub deallocations: u64,
+}
@@ -0,0 +1,155 @@
+//! A counting [`GlobalAlloc`] wrapper and scoped delta measurement, for
+//! hunting allocation hot spots (e.g. in serialization) without
+//! attaching a full profiler.
+//!
+//! ```
+//! use rustboot_debug::{TimingScope, CountingAllocator};
+//! use std::alloc::System;
+//! use std::hint::black_box;
+//!
+//! #[global_allocator]
+//! static ALLOC: CountingAllocator<System> = CountingAllocator::new(System);
+//!
+//! let scope = TimingScope::start(&ALLOC);
+//! let v = black_box(vec![0u8; 1024]);
+//! let delta = scope.finish();
+//! assert!(delta.bytes_allocated >= 1024);
+//! drop(v);
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::api::AllocDelta;
+
+/// A [`GlobalAlloc`] wrapper around `A` that counts bytes and calls
+/// allocated/freed process-wide, for [`TimingScope`] to diff. Install it
+/// as the process's global allocator to enable measurement:
+///
+/// ```ignore
+/// use rustboot_debug::CountingAllocator;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static ALLOC: CountingAllocator<System> = CountingAllocator::new(System);
+/// ```
+pub struct CountingAllocator<A> {
+    inner: A,
+    bytes_allocated: AtomicU64,
+    bytes_freed: AtomicU64,
+    allocations: AtomicU64,
+    deallocations: AtomicU64,
+}
+
+impl<A> CountingAllocator<A> {
+    /// Wraps `inner` (typically [`std::alloc::System`]) with counters,
+    /// all starting at zero.
+    pub const fn new(inner: A) -> Self {
+        Self {
+            inner,
+            bytes_allocated: AtomicU64::new(0),
+            bytes_freed: AtomicU64::new(0),
+            allocations: AtomicU64::new(0),
+            deallocations: AtomicU64::new(0),
+        }
+    }
+
+    /// The running totals recorded since the process started.
+    pub fn totals(&self) -> AllocDelta {
+        AllocDelta {
+            bytes_allocated: self.bytes_allocated.load(Ordering::Relaxed),
+            bytes_freed: self.bytes_freed.load(Ordering::Relaxed),
+            allocations: self.allocations.load(Ordering::Relaxed),
+            deallocations: self.deallocations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+// Safety: every method forwards to `inner`'s implementation after
+// updating plain atomic counters, so this upholds `GlobalAlloc`'s
+// contract exactly as `inner` does.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.bytes_allocated.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        unsafe { self.inner.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.bytes_freed.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        self.deallocations.fetch_add(1, Ordering::Relaxed);
+        unsafe { self.inner.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            self.bytes_allocated.fetch_add((new_size - layout.size()) as u64, Ordering::Relaxed);
+        } else {
+            self.bytes_freed.fetch_add((layout.size() - new_size) as u64, Ordering::Relaxed);
+        }
+        unsafe { self.inner.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Measures the bytes allocated/freed between [`TimingScope::start`] and
+/// [`TimingScope::finish`], via a [`CountingAllocator`] installed as the
+/// global allocator.
+///
+/// Process-wide, not thread-scoped: concurrent allocations on other
+/// threads during the scope are included in the delta.
+pub struct TimingScope<'a, A> {
+    allocator: &'a CountingAllocator<A>,
+    start: AllocDelta,
+}
+
+impl<'a, A> TimingScope<'a, A> {
+    /// Starts a scope, snapshotting `allocator`'s current totals.
+    pub fn start(allocator: &'a CountingAllocator<A>) -> Self {
+        Self { allocator, start: allocator.totals() }
+    }
+
+    /// Ends the scope, returning how much `allocator`'s totals changed
+    /// since [`TimingScope::start`].
+    pub fn finish(self) -> AllocDelta {
+        let end = self.allocator.totals();
+        AllocDelta {
+            bytes_allocated: end.bytes_allocated - self.start.bytes_allocated,
+            bytes_freed: end.bytes_freed - self.start.bytes_freed,
+            allocations: end.allocations - self.start.allocations,
+            deallocations: end.deallocations - self.start.deallocations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::System;
+
+    #[global_allocator]
+    static ALLOC: CountingAllocator<System> = CountingAllocator::new(System);
+
+    #[test]
+    fn scope_reports_bytes_allocated_and_freed_during_it() {
+        let scope = TimingScope::start(&ALLOC);
+        let v = vec![0u8; 4096];
+        std::hint::black_box(&v);
+        drop(v);
+        let delta = scope.finish();
+
+        assert!(delta.bytes_allocated >= 4096, "expected at least 4096 bytes allocated, got {}", delta.bytes_allocated);
+        assert!(delta.bytes_freed >= 4096, "expected at least 4096 bytes freed, got {}", delta.bytes_freed);
+        assert!(delta.allocations >= 1);
+        assert!(delta.deallocations >= 1);
+    }
+
+    #[test]
+    fn totals_accumulate_across_scopes() {
+        let before = ALLOC.totals();
+        let v = vec![0u8; 128];
+        std::hint::black_box(&v);
+        let after = ALLOC.totals();
+
+        assert!(after.bytes_allocated > before.bytes_allocated);
+    }
+}
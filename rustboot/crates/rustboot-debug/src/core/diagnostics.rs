@@ -0,0 +1,52 @@
+//! A ready-made diagnostics handler serving a [`DiagnosticsSnapshot`] as
+//! JSON, in the style of rustboot-web's `render_metrics`: a pure,
+//! framework-agnostic function the embedding app mounts behind its own
+//! auth middleware (this crate has no opinion on how the route is
+//! protected), typically at `GET /actuator/diagnostics` in dev/staging.
+
+use http::{header, Response};
+
+use crate::api::DiagnosticsSnapshot;
+
+/// Renders `snapshot` as a complete `application/json` response.
+pub fn render_diagnostics(snapshot: &DiagnosticsSnapshot) -> Response<Vec<u8>> {
+    let body = serde_json::to_vec_pretty(snapshot).unwrap_or_default();
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .unwrap_or_else(|_| Response::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{CircuitBreakerStatus, DiRegistration};
+
+    #[test]
+    fn renders_the_snapshot_as_json() {
+        let snapshot = DiagnosticsSnapshot {
+            di_registrations: vec![DiRegistration {
+                type_name: "myapp::UserRepository".to_string(),
+                scope: "Singleton".to_string(),
+                instantiated: true,
+            }],
+            circuit_breakers: vec![CircuitBreakerStatus { name: "billing-api".to_string(), state: "Open".to_string() }],
+            ..Default::default()
+        };
+
+        let response = render_diagnostics(&snapshot);
+
+        assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "application/json");
+        let body = String::from_utf8(response.body().clone()).unwrap();
+        assert!(body.contains("myapp::UserRepository"));
+        assert!(body.contains("billing-api"));
+    }
+
+    #[test]
+    fn renders_an_empty_snapshot() {
+        let response = render_diagnostics(&DiagnosticsSnapshot::default());
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(body["di_registrations"], serde_json::json!([]));
+    }
+}
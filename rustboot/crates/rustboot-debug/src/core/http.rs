@@ -0,0 +1,213 @@
+//! Records outbound HTTP requests/responses to a cassette file so a
+//! test suite can later replay them deterministically instead of
+//! hitting the real upstream, in the style of Ruby's VCR.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rustboot_http_client::{HttpClient, HttpClientError, HttpRequest, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+use crate::api::DebugError;
+
+/// Header names whose values are replaced with a placeholder before a
+/// cassette is written, so recorded fixtures are safe to commit.
+const SENSITIVE_HEADERS: [&str; 3] = ["authorization", "cookie", "set-cookie"];
+const REDACTED: &str = "***redacted***";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    method: String,
+    url: String,
+    request_headers: HashMap<String, String>,
+    status: u16,
+    response_headers: HashMap<String, String>,
+    response_body: String,
+}
+
+impl Interaction {
+    fn capture(request: &HttpRequest, response: &HttpResponse) -> Self {
+        Self {
+            method: request.method.to_string(),
+            url: request.url.clone(),
+            request_headers: redact(&request.headers),
+            status: response.status,
+            response_headers: redact(&response.headers),
+            response_body: BASE64.encode(&response.body),
+        }
+    }
+
+    fn matches(&self, request: &HttpRequest) -> bool {
+        self.method == request.method.as_str() && self.url == request.url
+    }
+
+    fn into_response(self, cassette_path: &Path) -> Result<HttpResponse, DebugError> {
+        let body = BASE64.decode(&self.response_body).map_err(|err| DebugError::MalformedCassette {
+            path: cassette_path.to_path_buf(),
+            message: format!("response body for {} {} is not valid base64: {err}", self.method, self.url),
+        })?;
+        Ok(HttpResponse { status: self.status, headers: self.response_headers, body })
+    }
+}
+
+fn redact(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            if SENSITIVE_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                (name.clone(), REDACTED.to_string())
+            } else {
+                (name.clone(), value.clone())
+            }
+        })
+        .collect()
+}
+
+enum Backing {
+    Record { inner: Arc<dyn HttpClient>, cassette_path: PathBuf, recorded: Mutex<Vec<Interaction>> },
+    Replay { cassette_path: PathBuf, queue: Mutex<VecDeque<Interaction>> },
+}
+
+/// An [`HttpClient`] that wraps another client to either record every
+/// request/response into a cassette file, or replay a previously
+/// recorded cassette without making any real requests.
+///
+/// Auth headers ([`SENSITIVE_HEADERS`]) are redacted before a cassette
+/// is written, so a recorded fixture is safe to commit alongside the
+/// test that uses it.
+pub struct HttpRecorder {
+    backing: Backing,
+}
+
+impl HttpRecorder {
+    /// Wraps `inner`, appending every request/response it handles to
+    /// `cassette_path` as it happens.
+    pub fn record(inner: Arc<dyn HttpClient>, cassette_path: impl Into<PathBuf>) -> Self {
+        Self {
+            backing: Backing::Record { inner, cassette_path: cassette_path.into(), recorded: Mutex::new(Vec::new()) },
+        }
+    }
+
+    /// Loads `cassette_path` and replays its interactions in order,
+    /// without making any real requests. Fails at call time if a
+    /// request doesn't match the next recorded interaction.
+    pub fn replay(cassette_path: impl AsRef<Path>) -> Result<Self, DebugError> {
+        let path = cassette_path.as_ref();
+        let text =
+            fs::read_to_string(path).map_err(|source| DebugError::Cassette { path: path.to_path_buf(), source })?;
+        let interactions: Vec<Interaction> = serde_json::from_str(&text)
+            .map_err(|err| DebugError::MalformedCassette { path: path.to_path_buf(), message: err.to_string() })?;
+        Ok(Self {
+            backing: Backing::Replay {
+                cassette_path: path.to_path_buf(),
+                queue: Mutex::new(interactions.into_iter().collect()),
+            },
+        })
+    }
+}
+
+#[async_trait]
+impl HttpClient for HttpRecorder {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpClientError> {
+        match &self.backing {
+            Backing::Record { inner, cassette_path, recorded } => {
+                let response = inner.execute(request.clone()).await?;
+                let mut recorded = recorded.lock().unwrap();
+                recorded.push(Interaction::capture(&request, &response));
+                write_cassette(cassette_path, &recorded).map_err(|err| HttpClientError::Transport(err.to_string()))?;
+                Ok(response)
+            }
+            Backing::Replay { cassette_path, queue } => {
+                let unmatched = || {
+                    HttpClientError::Transport(
+                        DebugError::NoRecordedInteraction {
+                            method: request.method.to_string(),
+                            url: request.url.clone(),
+                        }
+                        .to_string(),
+                    )
+                };
+                let mut queue = queue.lock().unwrap();
+                let next = queue.front().filter(|interaction| interaction.matches(&request)).ok_or_else(unmatched)?;
+                let response =
+                    next.clone().into_response(cassette_path).map_err(|err| HttpClientError::Transport(err.to_string()))?;
+                queue.pop_front();
+                Ok(response)
+            }
+        }
+    }
+}
+
+fn write_cassette(path: &Path, recorded: &[Interaction]) -> Result<(), DebugError> {
+    let json = serde_json::to_vec_pretty(recorded)
+        .map_err(|err| DebugError::MalformedCassette { path: path.to_path_buf(), message: err.to_string() })?;
+    fs::write(path, json).map_err(|source| DebugError::Cassette { path: path.to_path_buf(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_fileio::TempDir;
+    use rustboot_http_client::Method;
+
+    struct FixedClient(HttpResponse);
+
+    #[async_trait]
+    impl HttpClient for FixedClient {
+        async fn execute(&self, _request: HttpRequest) -> Result<HttpResponse, HttpClientError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn response(status: u16, body: &[u8]) -> HttpResponse {
+        HttpResponse { status, headers: HashMap::new(), body: body.to_vec() }
+    }
+
+    #[tokio::test]
+    async fn record_writes_a_cassette_and_redacts_the_auth_header() {
+        let dir = TempDir::new().unwrap();
+        let cassette = dir.path().join("cassette.json");
+        let inner = Arc::new(FixedClient(response(200, b"pong")));
+        let recorder = HttpRecorder::record(inner, &cassette);
+
+        let request = HttpRequest::new(Method::GET, "https://api.example.com/ping").with_header("Authorization", "Bearer secret");
+        let response = recorder.execute(request).await.unwrap();
+        assert_eq!(response.body, b"pong");
+
+        let written = fs::read_to_string(&cassette).unwrap();
+        assert!(written.contains("***redacted***"));
+        assert!(!written.contains("Bearer secret"));
+    }
+
+    #[tokio::test]
+    async fn replay_returns_the_recorded_response_without_calling_the_inner_client() {
+        let dir = TempDir::new().unwrap();
+        let cassette = dir.path().join("cassette.json");
+        let inner = Arc::new(FixedClient(response(200, b"pong")));
+        let recorder = HttpRecorder::record(inner, &cassette);
+        recorder.execute(HttpRequest::new(Method::GET, "https://api.example.com/ping")).await.unwrap();
+
+        let replayed = HttpRecorder::replay(&cassette).unwrap();
+        let response = replayed.execute(HttpRequest::new(Method::GET, "https://api.example.com/ping")).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"pong");
+    }
+
+    #[tokio::test]
+    async fn replay_fails_a_request_that_does_not_match_the_next_interaction() {
+        let dir = TempDir::new().unwrap();
+        let cassette = dir.path().join("cassette.json");
+        let inner = Arc::new(FixedClient(response(200, b"pong")));
+        let recorder = HttpRecorder::record(inner, &cassette);
+        recorder.execute(HttpRequest::new(Method::GET, "https://api.example.com/ping")).await.unwrap();
+
+        let replayed = HttpRecorder::replay(&cassette).unwrap();
+        let err = replayed.execute(HttpRequest::new(Method::GET, "https://api.example.com/other")).await.unwrap_err();
+        assert!(matches!(err, HttpClientError::Transport(_)));
+    }
+}
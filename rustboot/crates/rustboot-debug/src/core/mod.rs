@@ -0,0 +1,10 @@
+//! Implementation details for rustboot-debug's features.
+
+#[cfg(feature = "alloc-stats")]
+pub mod alloc;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "tasks")]
+pub mod tasks;
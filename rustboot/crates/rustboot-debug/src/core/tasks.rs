@@ -0,0 +1,83 @@
+//! Renders a [`rustboot_async::TaskRegistry`] snapshot, plus queue-depth
+//! readings from named [`rustboot_streams::EventSender`]s, as JSON — for
+//! diagnosing stuck background workers at runtime.
+
+use http::{header, Response};
+use rustboot_async::{TaskRegistry, TaskStatus};
+use rustboot_streams::EventSender;
+
+use crate::api::{QueueDepth, TaskInfo};
+
+/// Reads `registry`'s tracked tasks into JSON-serializable snapshots.
+pub fn task_snapshot(registry: &TaskRegistry) -> Vec<TaskInfo> {
+    registry
+        .snapshot()
+        .into_iter()
+        .map(|task| TaskInfo {
+            id: task.id,
+            name: task.name,
+            status: status_label(task.status).to_string(),
+            spawn_location: task.spawn_location,
+            running_for_ms: task.running_for.as_millis() as u64,
+        })
+        .collect()
+}
+
+fn status_label(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Running => "running",
+        TaskStatus::Completed => "completed",
+    }
+}
+
+/// Reads `sender`'s current buffered item count, labelling it `fallback`
+/// if the channel wasn't created with [`rustboot_streams::named_channel`].
+pub fn queue_depth<T>(fallback: &str, sender: &EventSender<T>) -> QueueDepth {
+    QueueDepth { name: sender.name().unwrap_or(fallback).to_string(), depth: sender.queue_depth() }
+}
+
+/// Renders `tasks` and `queues` as a combined `application/json` response.
+pub fn render_tasks(tasks: &[TaskInfo], queues: &[QueueDepth]) -> Response<Vec<u8>> {
+    let body = serde_json::json!({ "tasks": tasks, "queues": queues });
+    let body = serde_json::to_vec_pretty(&body).unwrap_or_default();
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .unwrap_or_else(|_| Response::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_streams::{channel, named_channel, OverflowStrategy};
+
+    #[tokio::test]
+    async fn task_snapshot_reports_a_running_task() {
+        let registry = TaskRegistry::new();
+        registry.spawn("worker", std::future::pending::<()>());
+
+        let tasks = task_snapshot(&registry);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "worker");
+        assert_eq!(tasks[0].status, "running");
+    }
+
+    #[test]
+    fn queue_depth_uses_the_channel_name_when_present() {
+        let (unnamed, _) = channel::<i32>(4);
+        let (named, _) = named_channel::<i32>("orders", 4, OverflowStrategy::Block);
+
+        assert_eq!(queue_depth("fallback", &unnamed).name, "fallback");
+        assert_eq!(queue_depth("fallback", &named).name, "orders");
+    }
+
+    #[test]
+    fn render_tasks_produces_a_json_object_with_both_sections() {
+        let response = render_tasks(&[], &[]);
+        assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "application/json");
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(body["tasks"], serde_json::json!([]));
+        assert_eq!(body["queues"], serde_json::json!([]));
+    }
+}
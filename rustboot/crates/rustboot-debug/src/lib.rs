@@ -0,0 +1,38 @@
+//! Debugging and diagnostics tooling for the rustboot framework.
+//!
+//! - [`core::http::HttpRecorder`] (`http` feature): an [`rustboot_http_client::HttpClient`]
+//!   wrapper that records outbound requests/responses to a cassette
+//!   file, or replays a previously recorded cassette, for deterministic
+//!   tests against third-party APIs without hitting them.
+//! - [`api::DiagnosticsSnapshot`]/[`core::diagnostics::render_diagnostics`]
+//!   (`diagnostics` feature): a JSON "actuator"-style endpoint handler
+//!   dumping DI registrations, redacted config, cache/pool/circuit
+//!   breaker status, and recent slow queries, for a protected dev/staging
+//!   route.
+//! - [`core::tasks`] (`tasks` feature): renders a
+//!   [`rustboot_async::TaskRegistry`] snapshot and named
+//!   [`rustboot_streams::EventSender`] queue depths as JSON, to diagnose
+//!   stuck background workers at runtime.
+//! - [`core::alloc::CountingAllocator`]/[`core::alloc::TimingScope`]
+//!   (`alloc-stats` feature): a counting global allocator and a scoped
+//!   delta reader, for hunting allocation hot spots (e.g. in
+//!   serialization) without attaching a full profiler.
+
+pub mod api;
+pub mod core;
+
+pub use api::DebugError;
+#[cfg(feature = "alloc-stats")]
+pub use api::AllocDelta;
+#[cfg(feature = "alloc-stats")]
+pub use core::alloc::{CountingAllocator, TimingScope};
+#[cfg(feature = "diagnostics")]
+pub use api::{CacheStats, CircuitBreakerStatus, DiRegistration, DiagnosticsSnapshot, PoolStatus, SlowQuery};
+#[cfg(feature = "diagnostics")]
+pub use core::diagnostics::render_diagnostics;
+#[cfg(feature = "http")]
+pub use core::http::HttpRecorder;
+#[cfg(feature = "tasks")]
+pub use api::{QueueDepth, TaskInfo};
+#[cfg(feature = "tasks")]
+pub use core::tasks::{queue_depth, render_tasks, task_snapshot};
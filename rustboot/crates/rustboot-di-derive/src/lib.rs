@@ -0,0 +1,93 @@
+//! Derive macro for `rustboot_di::Injectable`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derives `Injectable` for a struct, resolving each field either from a
+/// container registration or, via `#[inject(config = "some.path")]`, from
+/// `rustboot-config`.
+///
+/// ```ignore
+/// #[derive(Injectable)]
+/// struct Server {
+///     #[inject(config = "server.port")]
+///     port: u16,
+///     repository: Arc<UserRepository>,
+/// }
+/// ```
+#[proc_macro_derive(Injectable, attributes(inject))]
+pub fn derive_injectable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input, "Injectable can only be derived for structs")
+            .to_compile_error()
+            .into();
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return syn::Error::new_spanned(
+            &input,
+            "Injectable requires a struct with named fields",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let mut field_inits = Vec::new();
+    for field in &fields.named {
+        let field_name = field.ident.as_ref().expect("named field");
+        let field_ty = &field.ty;
+
+        let config_path = match config_path_for(field) {
+            Ok(path) => path,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+        field_inits.push(match config_path {
+            Some(path) => quote! {
+                #field_name: ::rustboot_di::Container::config_value::<#field_ty>(container, #path)?
+            },
+            None => quote! {
+                #field_name: ::rustboot_di::Container::get::<#field_ty>(container)?
+            },
+        });
+    }
+
+    let expanded = quote! {
+        impl ::rustboot_di::Injectable for #name {
+            fn inject(container: &::rustboot_di::Container) -> ::std::result::Result<Self, ::rustboot_di::DiError> {
+                ::std::result::Result::Ok(Self {
+                    #(#field_inits),*
+                })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Extracts the `config = "..."` path from a field's `#[inject(...)]`
+/// attribute, if present.
+fn config_path_for(field: &syn::Field) -> syn::Result<Option<String>> {
+    let mut config_path = None;
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("inject") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("config") {
+                let value = meta.value()?;
+                let lit: syn::LitStr = value.parse()?;
+                config_path = Some(lit.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `inject` attribute; expected `config = \"...\"`"))
+            }
+        })?;
+    }
+
+    Ok(config_path)
+}
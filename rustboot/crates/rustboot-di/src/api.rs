@@ -0,0 +1,45 @@
+//! Public types for the dependency injection module.
+
+use rustboot_config::ConfigError;
+
+/// The lifecycle of a registered dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// A new instance is created on every resolution.
+    Transient,
+    /// The first resolved instance is cached and reused for the lifetime
+    /// of the container.
+    Singleton,
+}
+
+/// A point-in-time description of one registered type, returned by
+/// [`crate::Container::registrations`] for diagnostics/introspection
+/// tooling rather than everyday DI use.
+#[derive(Debug, Clone)]
+pub struct RegistrationInfo {
+    /// The registered type's name, as reported by [`std::any::type_name`].
+    pub type_name: &'static str,
+    /// The scope it was registered with.
+    pub scope: Scope,
+    /// Whether a singleton instance has already been created. Always
+    /// `false` for [`Scope::Transient`] registrations.
+    pub instantiated: bool,
+}
+
+/// Errors produced while registering or resolving dependencies.
+#[derive(Debug, thiserror::Error)]
+pub enum DiError {
+    /// No factory was registered for the requested type.
+    #[error("no registration found for type: {0}")]
+    NotRegistered(&'static str),
+    /// A registered factory returned a value of an unexpected type.
+    #[error("registration for type {0} returned a mismatched type")]
+    TypeMismatch(&'static str),
+    /// A field used `#[inject(config = ...)]` but the container has no
+    /// `rustboot_config::Config` attached.
+    #[error("field requires config binding but the container has no Config attached")]
+    NoConfig,
+    /// Resolving a value from config failed.
+    #[error("config binding failed: {0}")]
+    Config(#[from] ConfigError),
+}
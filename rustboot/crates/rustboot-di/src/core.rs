@@ -0,0 +1,232 @@
+//! Implementation details for the dependency injection module.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use rustboot_config::{Config, FromConfigValue};
+
+use crate::api::{DiError, RegistrationInfo, Scope};
+
+type BoxedFactory = dyn Fn(&Container) -> Result<Box<dyn Any + Send + Sync>, DiError> + Send + Sync;
+
+struct Registration {
+    type_name: &'static str,
+    scope: Scope,
+    factory: Arc<BoxedFactory>,
+}
+
+/// A resolved type that can be built entirely from a [`Container`],
+/// including primitive fields bound from configuration.
+///
+/// Normally implemented via `#[derive(Injectable)]` rather than by hand.
+pub trait Injectable: Sized {
+    /// Builds an instance by resolving each field from `container`.
+    fn inject(container: &Container) -> Result<Self, DiError>;
+}
+
+/// A type-safe dependency injection container.
+///
+/// Services are registered by type with a [`Scope`] and resolved via
+/// [`Container::get`]; structs deriving `Injectable` are resolved via
+/// [`Container::resolve`] and may additionally bind primitive fields
+/// straight from an attached [`Config`].
+#[derive(Default)]
+pub struct Container {
+    factories: Mutex<HashMap<TypeId, Registration>>,
+    singletons: Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+    config: Option<Config>,
+}
+
+impl Container {
+    /// Creates an empty container with no attached configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty container with an attached [`Config`], enabling
+    /// `#[inject(config = "...")]` field resolution.
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            config: Some(config),
+            ..Self::default()
+        }
+    }
+
+    /// Registers a factory for `T` with the given scope.
+    pub fn register<T, F>(&self, scope: Scope, factory: F)
+    where
+        T: Any + Send + Sync,
+        F: Fn(&Container) -> Result<T, DiError> + Send + Sync + 'static,
+    {
+        let boxed: Arc<BoxedFactory> =
+            Arc::new(move |c: &Container| factory(c).map(|v| Box::new(v) as Box<dyn Any + Send + Sync>));
+        self.factories.lock().unwrap().insert(
+            TypeId::of::<T>(),
+            Registration { type_name: std::any::type_name::<T>(), scope, factory: boxed },
+        );
+    }
+
+    /// Registers a pre-built singleton instance for `T`.
+    pub fn register_instance<T: Any + Send + Sync + Clone>(&self, instance: T) {
+        self.register(Scope::Singleton, move |_| Ok(instance.clone()));
+    }
+
+    /// Resolves a registered service of type `T`.
+    pub fn get<T: Any + Send + Sync + Clone>(&self) -> Result<T, DiError> {
+        let type_id = TypeId::of::<T>();
+
+        let registration = {
+            let factories = self.factories.lock().unwrap();
+            factories
+                .get(&type_id)
+                .map(|r| (r.scope, r.factory.clone()))
+                .ok_or(DiError::NotRegistered(std::any::type_name::<T>()))?
+        };
+        let (scope, factory) = registration;
+
+        if scope == Scope::Singleton {
+            if let Some(existing) = self.singletons.lock().unwrap().get(&type_id) {
+                return existing
+                    .downcast_ref::<T>()
+                    .cloned()
+                    .ok_or(DiError::TypeMismatch(std::any::type_name::<T>()));
+            }
+        }
+
+        let boxed = factory(self)?;
+        let instance = *boxed
+            .downcast::<T>()
+            .map_err(|_| DiError::TypeMismatch(std::any::type_name::<T>()))?;
+
+        if scope == Scope::Singleton {
+            self.singletons
+                .lock()
+                .unwrap()
+                .insert(type_id, Arc::new(instance.clone()));
+        }
+
+        Ok(instance)
+    }
+
+    /// Resolves `path` from the attached [`Config`], converting it to `T`.
+    ///
+    /// Returns [`DiError::NoConfig`] if the container was built without
+    /// one via [`Container::with_config`].
+    pub fn config_value<T: FromConfigValue>(&self, path: &str) -> Result<T, DiError> {
+        let config = self.config.as_ref().ok_or(DiError::NoConfig)?;
+        Ok(config.get::<T>(path)?)
+    }
+
+    /// Resolves an [`Injectable`] type, typically one using
+    /// `#[derive(Injectable)]`.
+    pub fn resolve<T: Injectable>(&self) -> Result<T, DiError> {
+        T::inject(self)
+    }
+
+    /// Lists every registered type, its scope, and whether a singleton
+    /// instance has already been created.
+    ///
+    /// Intended for diagnostics/introspection tooling (e.g. an
+    /// "actuator"-style endpoint), not everyday DI use.
+    pub fn registrations(&self) -> Vec<RegistrationInfo> {
+        let factories = self.factories.lock().unwrap();
+        let singletons = self.singletons.lock().unwrap();
+        factories
+            .iter()
+            .map(|(type_id, registration)| RegistrationInfo {
+                type_name: registration.type_name,
+                scope: registration.scope,
+                instantiated: singletons.contains_key(type_id),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap as StdHashMap;
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Greeting(String);
+
+    #[test]
+    fn transient_registration_creates_new_instance_each_time() {
+        let container = Container::new();
+        let counter = Arc::new(Mutex::new(0));
+        let counter_clone = counter.clone();
+        container.register(Scope::Transient, move |_| {
+            *counter_clone.lock().unwrap() += 1;
+            Ok(*counter_clone.lock().unwrap())
+        });
+
+        assert_eq!(container.get::<i32>().unwrap(), 1);
+        assert_eq!(container.get::<i32>().unwrap(), 2);
+    }
+
+    #[test]
+    fn singleton_registration_reuses_instance() {
+        let container = Container::new();
+        container.register_instance(Greeting("hello".into()));
+
+        assert_eq!(container.get::<Greeting>().unwrap(), Greeting("hello".into()));
+        assert_eq!(container.get::<Greeting>().unwrap(), Greeting("hello".into()));
+    }
+
+    #[test]
+    fn unregistered_type_is_an_error() {
+        let container = Container::new();
+        assert!(matches!(
+            container.get::<Greeting>(),
+            Err(DiError::NotRegistered(_))
+        ));
+    }
+
+    #[test]
+    fn config_value_binds_from_attached_config() {
+        use rustboot_config::ConfigValue;
+
+        let mut server = StdHashMap::new();
+        server.insert("port".to_string(), ConfigValue::Integer(8080));
+        let mut root = StdHashMap::new();
+        root.insert("server".to_string(), ConfigValue::Table(server));
+
+        let container = Container::with_config(Config::from_table(root));
+        assert_eq!(container.config_value::<u16>("server.port").unwrap(), 8080);
+    }
+
+    #[test]
+    fn registrations_reports_scope_and_singleton_instantiation() {
+        let container = Container::new();
+        container.register(Scope::Transient, |_| Ok(1i32));
+        container.register_instance(Greeting("hello".into()));
+
+        let before = container.registrations();
+        assert_eq!(before.len(), 2);
+        let greeting_before = before.iter().find(|r| r.type_name.contains("Greeting")).unwrap();
+        assert_eq!(greeting_before.scope, Scope::Singleton);
+        assert!(!greeting_before.instantiated, "registering a singleton doesn't build it until first resolved");
+
+        let transient_before = before.iter().find(|r| r.type_name.contains("i32")).unwrap();
+        assert_eq!(transient_before.scope, Scope::Transient);
+        assert!(!transient_before.instantiated);
+
+        container.get::<Greeting>().unwrap();
+        container.get::<i32>().unwrap();
+        let after = container.registrations();
+        let greeting_after = after.iter().find(|r| r.type_name.contains("Greeting")).unwrap();
+        assert!(greeting_after.instantiated, "resolving a singleton caches it");
+        let transient_after = after.iter().find(|r| r.type_name.contains("i32")).unwrap();
+        assert!(!transient_after.instantiated, "transient registrations are never cached as singletons");
+    }
+
+    #[test]
+    fn config_value_without_attached_config_errors() {
+        let container = Container::new();
+        assert!(matches!(
+            container.config_value::<u16>("server.port"),
+            Err(DiError::NoConfig)
+        ));
+    }
+}
@@ -0,0 +1,36 @@
+//! Dependency injection container for the rustboot framework.
+//!
+//! - [`Container`]: register services by type and [`Scope`], resolve them
+//!   with [`Container::get`].
+//! - [`Injectable`] / `#[derive(Injectable)]`: build a struct entirely
+//!   from a container, including primitive fields bound straight from
+//!   `rustboot-config` via `#[inject(config = "server.port")]`.
+//!
+//! # Example
+//!
+//! ```
+//! use rustboot_di::{Container, Injectable, Scope};
+//!
+//! #[derive(Injectable)]
+//! struct Greeter {
+//!     #[inject(config = "greeting.name")]
+//!     name: String,
+//! }
+//!
+//! let mut table = std::collections::HashMap::new();
+//! let mut greeting = std::collections::HashMap::new();
+//! greeting.insert("name".to_string(), rustboot_config::ConfigValue::String("world".into()));
+//! table.insert("greeting".to_string(), rustboot_config::ConfigValue::Table(greeting));
+//!
+//! let container = Container::with_config(rustboot_config::Config::from_table(table));
+//! let greeter: Greeter = container.resolve().unwrap();
+//! assert_eq!(greeter.name, "world");
+//! # let _ = Scope::Transient;
+//! ```
+
+pub mod api;
+pub mod core;
+
+pub use api::{DiError, RegistrationInfo, Scope};
+pub use core::{Container, Injectable};
+pub use rustboot_di_derive::Injectable;
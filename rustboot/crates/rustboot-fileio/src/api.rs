@@ -0,0 +1,66 @@
+//! Public types for the fileio module.
+
+use std::path::PathBuf;
+
+/// A change observed on a watched path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileEvent {
+    /// A file or directory was created.
+    Created(PathBuf),
+    /// A file's contents or metadata changed.
+    Modified(PathBuf),
+    /// A file or directory was removed.
+    Removed(PathBuf),
+}
+
+impl FileEvent {
+    /// The path this event is about.
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            FileEvent::Created(path) => path,
+            FileEvent::Modified(path) => path,
+            FileEvent::Removed(path) => path,
+        }
+    }
+}
+
+/// A snapshot of progress through a [`crate::copy_with_progress`] copy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressEvent {
+    /// Bytes copied so far.
+    pub bytes_copied: u64,
+    /// The total size being copied, if known in advance.
+    pub total_bytes: Option<u64>,
+}
+
+/// Errors from filesystem operations in rustboot-fileio.
+#[derive(Debug, thiserror::Error)]
+pub enum FileIoError {
+    /// The underlying OS file-watching backend failed to start or
+    /// deliver an event.
+    #[error("failed to watch '{path}': {source}")]
+    Watch {
+        /// The path that was being watched.
+        path: PathBuf,
+        /// The error returned by the watching backend.
+        source: notify::Error,
+    },
+    /// A plain I/O error on `path`.
+    #[error("I/O error on '{path}': {source}")]
+    Io {
+        /// The path the operation was performed on.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+    /// A glob pattern passed to [`crate::list_glob`] was malformed.
+    #[error("invalid glob pattern: {0}")]
+    InvalidGlobPattern(#[from] glob::PatternError),
+    /// [`crate::read_verified`] found that `path`'s contents don't match
+    /// the digest recorded in its checksum sidecar.
+    #[error("checksum mismatch for '{path}'")]
+    ChecksumMismatch {
+        /// The path whose contents failed verification.
+        path: PathBuf,
+    },
+}
@@ -0,0 +1,110 @@
+//! Checksum-verified atomic file writes and reads, backed by
+//! `rustboot_crypto::Sha256Digest`.
+
+use std::path::{Path, PathBuf};
+
+use rustboot_crypto::Sha256Digest;
+use tokio::fs;
+
+use crate::api::FileIoError;
+
+fn io_err(path: impl Into<PathBuf>, source: std::io::Error) -> FileIoError {
+    FileIoError::Io { path: path.into(), source }
+}
+
+fn sidecar_path(path: &Path) -> PathBuf {
+    let mut sidecar = path.as_os_str().to_owned();
+    sidecar.push(".sha256");
+    PathBuf::from(sidecar)
+}
+
+/// Writes `contents` to `path` without ever leaving a partially-written
+/// file behind — `contents` is written to a sibling temp file, which is
+/// then renamed over `path` — and records a `{path}.sha256` sidecar
+/// with the hex digest, so a later [`read_verified`] call can detect
+/// corruption that the atomic write itself doesn't protect against
+/// (e.g. bit rot, or the file being edited out from under its checksum).
+pub async fn write_atomic_with_checksum(path: impl AsRef<Path>, contents: &[u8]) -> Result<Sha256Digest, FileIoError> {
+    let path = path.as_ref();
+    let digest = Sha256Digest::of(contents);
+
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let tmp_path = parent.join(format!(".{file_name}.tmp-{:016x}", rand::random::<u64>()));
+
+    fs::write(&tmp_path, contents).await.map_err(|source| io_err(&tmp_path, source))?;
+    fs::rename(&tmp_path, path).await.map_err(|source| io_err(path, source))?;
+
+    let sidecar = sidecar_path(path);
+    fs::write(&sidecar, digest.to_string()).await.map_err(|source| io_err(&sidecar, source))?;
+
+    Ok(digest)
+}
+
+/// Reads `path` and verifies its contents against the digest recorded
+/// in the `{path}.sha256` sidecar written by
+/// [`write_atomic_with_checksum`], returning
+/// [`FileIoError::ChecksumMismatch`] if they disagree or the sidecar is
+/// unreadable as a digest.
+pub async fn read_verified(path: impl AsRef<Path>) -> Result<Vec<u8>, FileIoError> {
+    let path = path.as_ref();
+    let contents = fs::read(path).await.map_err(|source| io_err(path, source))?;
+
+    let sidecar = sidecar_path(path);
+    let expected_hex = fs::read_to_string(&sidecar).await.map_err(|source| io_err(&sidecar, source))?;
+    let expected: Sha256Digest =
+        expected_hex.trim().parse().map_err(|_| FileIoError::ChecksumMismatch { path: path.to_path_buf() })?;
+
+    if Sha256Digest::of(&contents) != expected {
+        return Err(FileIoError::ChecksumMismatch { path: path.to_path_buf() });
+    }
+
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dir::TempDir;
+    use super::*;
+
+    #[tokio::test]
+    async fn read_verified_round_trips_contents_written_with_a_checksum() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.bin");
+
+        write_atomic_with_checksum(&path, b"hello world").await.unwrap();
+        let contents = read_verified(&path).await.unwrap();
+
+        assert_eq!(contents, b"hello world");
+    }
+
+    #[tokio::test]
+    async fn read_verified_rejects_contents_that_no_longer_match_the_sidecar() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.bin");
+
+        write_atomic_with_checksum(&path, b"original").await.unwrap();
+        fs::write(&path, b"tampered").await.unwrap();
+
+        let err = read_verified(&path).await.unwrap_err();
+        assert!(matches!(err, FileIoError::ChecksumMismatch { .. }));
+    }
+
+    #[tokio::test]
+    async fn read_verified_fails_without_a_sidecar() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.bin");
+        fs::write(&path, b"no sidecar").await.unwrap();
+
+        assert!(read_verified(&path).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn write_atomic_with_checksum_returns_the_digest_of_the_contents() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.bin");
+
+        let digest = write_atomic_with_checksum(&path, b"hello world").await.unwrap();
+        assert_eq!(digest, Sha256Digest::of(b"hello world"));
+    }
+}
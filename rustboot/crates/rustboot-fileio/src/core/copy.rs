@@ -0,0 +1,123 @@
+//! Chunked copying with progress events, cancellable mid-way.
+
+use rustboot_async::{run_until_cancelled, CancellationToken};
+use rustboot_streams::{channel, EventReceiver};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::api::ProgressEvent;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+const DEFAULT_CAPACITY: usize = 16;
+
+/// Copies every byte from `reader` to `writer` in [`CHUNK_SIZE`] chunks,
+/// emitting a [`ProgressEvent`] after each chunk is written, so a caller
+/// can drive a progress bar on a long download or file copy instead of
+/// only finding out when it's done.
+///
+/// `total_bytes`, if known, is carried on every event so a consumer can
+/// compute a percentage. Copying stops as soon as `cancellation` is
+/// cancelled; the returned receiver then simply closes (the stream never
+/// observes [`rustboot_streams::StreamItem::Complete`]), the same as it
+/// would for a read or write error — both mean "stopped before
+/// finishing", for the same reason a consumer only cares about in order
+/// to stop waiting.
+pub fn copy_with_progress<R, W>(
+    mut reader: R,
+    mut writer: W,
+    total_bytes: Option<u64>,
+    cancellation: CancellationToken,
+) -> EventReceiver<ProgressEvent>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    let (tx, rx) = channel(DEFAULT_CAPACITY);
+
+    tokio::spawn(async move {
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut bytes_copied: u64 = 0;
+
+        loop {
+            let read = match run_until_cancelled(reader.read(&mut buffer), &cancellation).await {
+                Some(Ok(0)) => break,
+                Some(Ok(read)) => read,
+                Some(Err(_)) | None => return,
+            };
+
+            if run_until_cancelled(writer.write_all(&buffer[..read]), &cancellation).await.is_none_or(|r| r.is_err())
+            {
+                return;
+            }
+
+            bytes_copied += read as u64;
+            if tx.send(ProgressEvent { bytes_copied, total_bytes }).await.is_err() {
+                return;
+            }
+        }
+
+        if writer.flush().await.is_err() {
+            return;
+        }
+        let _ = tx.complete(Some(ProgressEvent { bytes_copied, total_bytes })).await;
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rustboot_streams::StreamItem;
+
+    use super::super::dir::TempDir;
+    use super::*;
+
+    #[tokio::test]
+    async fn copies_all_bytes_and_completes_with_the_final_progress() {
+        let dir = TempDir::new().unwrap();
+        let src_path = dir.path().join("src.bin");
+        let dst_path = dir.path().join("dst.bin");
+        let data = vec![7u8; CHUNK_SIZE * 3 + 100];
+        tokio::fs::write(&src_path, &data).await.unwrap();
+
+        let reader = tokio::fs::File::open(&src_path).await.unwrap();
+        let writer = tokio::fs::File::create(&dst_path).await.unwrap();
+        let mut events = copy_with_progress(reader, writer, Some(data.len() as u64), CancellationToken::new());
+
+        let mut last = None;
+        while let Some(item) = events.recv().await {
+            match item {
+                StreamItem::Item(event) => last = Some(event),
+                StreamItem::Complete(terminal) => {
+                    last = terminal;
+                    break;
+                }
+            }
+        }
+
+        assert_eq!(last, Some(ProgressEvent { bytes_copied: data.len() as u64, total_bytes: Some(data.len() as u64) }));
+        assert_eq!(tokio::fs::read(&dst_path).await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn stops_and_closes_without_completing_once_cancelled() {
+        let dir = TempDir::new().unwrap();
+        let dst_path = dir.path().join("dst.bin");
+        let (_reader_tx, reader_rx) = tokio::io::duplex(CHUNK_SIZE);
+        let writer = tokio::fs::File::create(&dst_path).await.unwrap();
+        let cancellation = CancellationToken::new();
+        let mut events = copy_with_progress(reader_rx, writer, None, cancellation.clone());
+
+        cancellation.cancel();
+
+        let mut saw_complete = false;
+        while let Some(item) = tokio::time::timeout(Duration::from_secs(5), events.recv()).await.unwrap() {
+            if matches!(item, StreamItem::Complete(_)) {
+                saw_complete = true;
+            }
+        }
+
+        assert!(!saw_complete, "a cancelled copy should not reach StreamItem::Complete");
+    }
+}
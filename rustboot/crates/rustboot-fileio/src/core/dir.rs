@@ -0,0 +1,203 @@
+//! Recursive directory copies, atomic directory replacement, temporary
+//! directories, and glob-based listing.
+//!
+//! These operations are synchronous (`std::fs`, not `tokio::fs`): each
+//! walks or renames a whole directory tree, which gains nothing from
+//! being async and would need unbounded recursion through a boxed
+//! future to express with `tokio::fs`. Call them via
+//! `tokio::task::spawn_blocking` from an async context that can't
+//! afford to block its executor thread on a large tree.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::api::FileIoError;
+
+fn io_err(path: impl Into<PathBuf>, source: io::Error) -> FileIoError {
+    FileIoError::Io { path: path.into(), source }
+}
+
+/// Recursively copies every file and subdirectory under `src` into
+/// `dst`, creating `dst` (and any missing parents) if needed.
+pub fn copy_dir_recursive(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> Result<(), FileIoError> {
+    let src = src.as_ref();
+    let dst = dst.as_ref();
+    fs::create_dir_all(dst).map_err(|source| io_err(dst, source))?;
+
+    for entry in fs::read_dir(src).map_err(|source| io_err(src, source))? {
+        let entry = entry.map_err(|source| io_err(src, source))?;
+        let entry_path = entry.path();
+        let file_type = entry.file_type().map_err(|source| io_err(&entry_path, source))?;
+        let dst_path = dst.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir_recursive(&entry_path, &dst_path)?;
+        } else {
+            fs::copy(&entry_path, &dst_path).map_err(|source| io_err(&entry_path, source))?;
+        }
+    }
+    Ok(())
+}
+
+/// Replaces `target` with a freshly built directory tree without ever
+/// leaving `target` in a partially-written state: `build` populates a
+/// temporary directory, which is then renamed over `target` — a single
+/// atomic rename as long as both are on the same filesystem.
+pub fn replace_dir_atomic(
+    target: impl AsRef<Path>,
+    build: impl FnOnce(&Path) -> Result<(), FileIoError>,
+) -> Result<(), FileIoError> {
+    let target = target.as_ref();
+    let parent = target.parent().unwrap_or_else(|| Path::new("."));
+    let staging = TempDir::new_in(parent)?;
+
+    build(staging.path())?;
+
+    // `rename` can only replace an empty directory, so a non-empty
+    // `target` has to be cleared first. That leaves a brief window where
+    // neither the old nor the new tree is at `target`, but the swap into
+    // the now-empty (or absent) `target` is still a single atomic
+    // rename, so a reader never observes a partially-written tree.
+    if target.is_dir() {
+        fs::remove_dir_all(target).map_err(|source| io_err(target, source))?;
+    }
+    fs::rename(staging.path(), target).map_err(|source| io_err(target, source))
+}
+
+/// An RAII temporary directory: created fresh, removed (recursively)
+/// when dropped.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates a temporary directory under the system temp directory.
+    pub fn new() -> Result<Self, FileIoError> {
+        Self::new_in(std::env::temp_dir())
+    }
+
+    /// Creates a temporary directory under `base`, which must already
+    /// exist.
+    pub fn new_in(base: impl AsRef<Path>) -> Result<Self, FileIoError> {
+        let base = base.as_ref();
+        for _ in 0..100 {
+            let candidate = base.join(format!("rustboot-{:016x}", rand::random::<u64>()));
+            match fs::create_dir(&candidate) {
+                Ok(()) => return Ok(Self { path: candidate }),
+                Err(source) if source.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(source) => return Err(io_err(candidate, source)),
+            }
+        }
+        Err(io_err(
+            base,
+            io::Error::new(io::ErrorKind::AlreadyExists, "could not allocate a unique temp directory name"),
+        ))
+    }
+
+    /// The directory's path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Lists paths matching a glob `pattern` (e.g. `"src/**/*.rs"`).
+pub fn list_glob(pattern: &str) -> Result<Vec<PathBuf>, FileIoError> {
+    glob::glob(pattern)?
+        .map(|entry| entry.map_err(|error| io_err(error.path().to_path_buf(), error_into_io(error))))
+        .collect()
+}
+
+fn error_into_io(error: glob::GlobError) -> io::Error {
+    // `GlobError` wraps an `io::Error` per failed entry but only exposes
+    // it through `Display`/`Error`, not by value, so it's rebuilt here
+    // to keep `FileIoError::Io` as the one place path+`io::Error` pairs
+    // are reported from this crate.
+    io::Error::other(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs as stdfs;
+
+    #[test]
+    fn copy_dir_recursive_copies_nested_files() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+
+        stdfs::write(src.path().join("top.txt"), b"top").unwrap();
+        stdfs::create_dir(src.path().join("nested")).unwrap();
+        stdfs::write(src.path().join("nested/inner.txt"), b"inner").unwrap();
+
+        copy_dir_recursive(src.path(), dst.path()).unwrap();
+
+        assert_eq!(stdfs::read(dst.path().join("top.txt")).unwrap(), b"top");
+        assert_eq!(stdfs::read(dst.path().join("nested/inner.txt")).unwrap(), b"inner");
+    }
+
+    #[test]
+    fn replace_dir_atomic_swaps_in_the_built_tree() {
+        let root = TempDir::new().unwrap();
+        let target = root.path().join("live");
+        stdfs::create_dir(&target).unwrap();
+        stdfs::write(target.join("old.txt"), b"old").unwrap();
+
+        replace_dir_atomic(&target, |staging| {
+            stdfs::write(staging.join("new.txt"), b"new").unwrap();
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(!target.join("old.txt").exists());
+        assert_eq!(stdfs::read(target.join("new.txt")).unwrap(), b"new");
+    }
+
+    #[test]
+    fn replace_dir_atomic_leaves_the_target_untouched_if_build_fails() {
+        let root = TempDir::new().unwrap();
+        let target = root.path().join("live");
+        stdfs::create_dir(&target).unwrap();
+        stdfs::write(target.join("old.txt"), b"old").unwrap();
+
+        let err = replace_dir_atomic(&target, |_staging| {
+            Err(io_err(root.path(), io::Error::other("boom")))
+        });
+
+        assert!(err.is_err());
+        assert_eq!(stdfs::read(target.join("old.txt")).unwrap(), b"old");
+    }
+
+    #[test]
+    fn temp_dir_is_removed_on_drop() {
+        let path = {
+            let dir = TempDir::new().unwrap();
+            dir.path().to_path_buf()
+        };
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn list_glob_matches_files_by_extension() {
+        let dir = TempDir::new().unwrap();
+        stdfs::write(dir.path().join("a.rs"), b"").unwrap();
+        stdfs::write(dir.path().join("b.rs"), b"").unwrap();
+        stdfs::write(dir.path().join("c.txt"), b"").unwrap();
+
+        let mut matches = list_glob(&format!("{}/*.rs", dir.path().display())).unwrap();
+        matches.sort();
+
+        assert_eq!(matches, vec![dir.path().join("a.rs"), dir.path().join("b.rs")]);
+    }
+
+    #[test]
+    fn list_glob_rejects_a_malformed_pattern() {
+        let err = list_glob("[").unwrap_err();
+        assert!(matches!(err, FileIoError::InvalidGlobPattern(_)));
+    }
+}
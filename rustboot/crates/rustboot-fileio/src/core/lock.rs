@@ -0,0 +1,128 @@
+//! Advisory, cross-process file locking built on exclusive file
+//! creation (`O_EXCL`), so cooperating processes or tasks can coordinate
+//! around a shared file on disk without a database or a shared-memory
+//! primitive.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tokio::fs;
+
+use crate::api::FileIoError;
+
+fn io_err(path: impl Into<PathBuf>, source: std::io::Error) -> FileIoError {
+    FileIoError::Io { path: path.into(), source }
+}
+
+const LOCK_RETRY_DELAY: Duration = Duration::from_millis(20);
+
+fn lock_path_for(path: &Path) -> PathBuf {
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    PathBuf::from(lock_path)
+}
+
+/// Runs `f` while holding an advisory lock on `path`, blocking until any
+/// other `with_file_lock` call on the same `path` (in this process or
+/// another) has released it.
+///
+/// The lock is a `{path}.lock` file created with `O_EXCL`: acquisition
+/// retries on a short delay until creation succeeds, and the file is
+/// removed once `f` completes (including on panic). This only
+/// coordinates cooperating callers of `with_file_lock` — it does not
+/// stop an unrelated writer from touching `path` directly.
+pub async fn with_file_lock<F, Fut, T>(path: impl AsRef<Path>, f: F) -> Result<T, FileIoError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = T>,
+{
+    let _guard = FileLockGuard::acquire(lock_path_for(path.as_ref())).await?;
+    Ok(f().await)
+}
+
+struct FileLockGuard {
+    path: PathBuf,
+}
+
+impl FileLockGuard {
+    async fn acquire(path: PathBuf) -> Result<Self, FileIoError> {
+        loop {
+            match fs::OpenOptions::new().create_new(true).write(true).open(&path).await {
+                Ok(_) => return Ok(Self { path }),
+                Err(source) if source.kind() == std::io::ErrorKind::AlreadyExists => {
+                    tokio::time::sleep(LOCK_RETRY_DELAY).await;
+                }
+                Err(source) => return Err(io_err(path, source)),
+            }
+        }
+    }
+}
+
+impl Drop for FileLockGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::super::dir::TempDir;
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_the_closure_and_returns_its_result() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("shared.txt");
+
+        let result = with_file_lock(&path, || async { 42 }).await.unwrap();
+
+        assert_eq!(result, 42);
+    }
+
+    #[tokio::test]
+    async fn releases_the_lock_so_a_later_call_can_acquire_it() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("shared.txt");
+
+        with_file_lock(&path, || async {}).await.unwrap();
+        let second = with_file_lock(&path, || async { "second" }).await;
+
+        assert_eq!(second.unwrap(), "second");
+        assert!(!lock_path_for(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn serializes_concurrent_holders_of_the_same_lock() {
+        let dir = TempDir::new().unwrap();
+        let path = Arc::new(dir.path().join("shared.txt"));
+        let concurrent = Arc::new(AtomicUsize::new(0));
+        let max_concurrent = Arc::new(AtomicUsize::new(0));
+
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let path = path.clone();
+            let concurrent = concurrent.clone();
+            let max_concurrent = max_concurrent.clone();
+            handles.push(tokio::spawn(async move {
+                with_file_lock(path.as_path(), || async {
+                    let now = concurrent.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_concurrent.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    concurrent.fetch_sub(1, Ordering::SeqCst);
+                })
+                .await
+                .unwrap();
+            }));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(max_concurrent.load(Ordering::SeqCst), 1);
+    }
+}
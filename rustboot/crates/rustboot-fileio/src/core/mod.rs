@@ -0,0 +1,7 @@
+//! Implementation details for the fileio module.
+
+pub mod checksum;
+pub mod copy;
+pub mod dir;
+pub mod lock;
+pub mod watch;
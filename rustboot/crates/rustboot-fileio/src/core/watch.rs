@@ -0,0 +1,111 @@
+//! Watching a path for filesystem changes, built on `notify`.
+
+use std::path::Path;
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use rustboot_streams::{channel, EventReceiver, EventStreamExt};
+
+use crate::api::{FileEvent, FileIoError};
+
+/// Output channel capacity for the raw, pre-debounce event stream.
+const DEFAULT_CAPACITY: usize = 64;
+
+fn to_file_events(event: notify::Event) -> Vec<FileEvent> {
+    let wrap = match event.kind {
+        EventKind::Create(_) => FileEvent::Created,
+        EventKind::Modify(_) => FileEvent::Modified,
+        EventKind::Remove(_) => FileEvent::Removed,
+        _ => return Vec::new(),
+    };
+    event.paths.into_iter().map(wrap).collect()
+}
+
+/// Watches `path` (recursively, if it's a directory) for creates,
+/// modifications, and removals, coalescing bursts of events within
+/// `delay` of each other (the same editor save can fire several raw
+/// events for one file) into the latest one per [`EventStreamExt::debounce`].
+///
+/// The returned receiver closes once its sender is dropped, which
+/// happens when the underlying OS watch is torn down (e.g. `path` is
+/// removed and the watching backend gives up) or this process shuts
+/// down.
+pub fn watch_path(path: impl AsRef<Path>, delay: Duration) -> Result<EventReceiver<FileEvent>, FileIoError> {
+    let path = path.as_ref().to_path_buf();
+    let (internal_tx, mut internal_rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        if let Ok(event) = result {
+            for file_event in to_file_events(event) {
+                let _ = internal_tx.send(file_event);
+            }
+        }
+    })
+    .map_err(|source| FileIoError::Watch { path: path.clone(), source })?;
+
+    watcher
+        .watch(&path, RecursiveMode::Recursive)
+        .map_err(|source| FileIoError::Watch { path: path.clone(), source })?;
+
+    let (tx, rx) = channel(DEFAULT_CAPACITY);
+    tokio::spawn(async move {
+        let _watcher = watcher;
+        while let Some(file_event) = internal_rx.recv().await {
+            if tx.send(file_event).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    Ok(rx.debounce(delay))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dir::TempDir;
+    use super::*;
+    use rustboot_streams::StreamItem;
+    use std::fs;
+
+    #[tokio::test]
+    async fn reports_a_created_file_under_a_watched_directory() {
+        let dir = TempDir::new().unwrap();
+        let mut events = watch_path(dir.path(), Duration::from_millis(20)).unwrap();
+
+        fs::write(dir.path().join("new.txt"), b"hello").unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .expect("a file event")
+            .expect("the stream stayed open");
+        match event {
+            StreamItem::Item(FileEvent::Created(path)) | StreamItem::Item(FileEvent::Modified(path)) => {
+                assert_eq!(path.file_name().unwrap(), "new.txt");
+            }
+            other => panic!("expected a Created or Modified event, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn coalesces_rapid_writes_to_the_same_file_within_the_delay() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hot.txt");
+        fs::write(&path, b"0").unwrap();
+
+        let mut events = watch_path(dir.path(), Duration::from_millis(100)).unwrap();
+
+        for i in 1..=5 {
+            fs::write(&path, i.to_string()).unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let first = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .expect("a debounced event")
+            .expect("the stream stayed open");
+        assert!(matches!(first, StreamItem::Item(FileEvent::Modified(_)) | StreamItem::Item(FileEvent::Created(_))));
+
+        let none_yet = tokio::time::timeout(Duration::from_millis(50), events.recv()).await;
+        assert!(none_yet.is_err(), "the burst of writes should have coalesced into one event");
+    }
+}
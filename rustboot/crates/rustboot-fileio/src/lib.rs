@@ -0,0 +1,29 @@
+//! Filesystem watching and file I/O helpers for the rustboot framework.
+//!
+//! - [`watch_path`]: watches a path (recursively, if it's a directory)
+//!   for creates, modifications, and removals, debounced into a
+//!   `rustboot_streams::EventReceiver<FileEvent>` so config hot-reload
+//!   and asset pipelines share one file-watching primitive instead of
+//!   each wrapping `notify` themselves.
+//! - [`copy_dir_recursive`], [`replace_dir_atomic`], [`TempDir`], and
+//!   [`list_glob`]: the directory-tree helpers that keep getting
+//!   reimplemented around build pipelines and config staging —
+//!   recursive copy, atomic swap-in, scoped temp dirs, and glob listing.
+//! - [`write_atomic_with_checksum`] and [`read_verified`]: atomic writes
+//!   paired with a SHA-256 sidecar (via `rustboot_crypto`), so a reader
+//!   can detect corruption a plain atomic write doesn't protect against.
+//! - [`with_file_lock`]: an advisory, `O_EXCL`-backed lock for
+//!   coordinating processes or tasks around a shared file.
+//! - [`copy_with_progress`]: chunked, cancellable copying between any
+//!   `AsyncRead`/`AsyncWrite` pair, reporting [`ProgressEvent`]s so a
+//!   long download or file copy can drive a progress bar.
+
+pub mod api;
+pub mod core;
+
+pub use api::{FileEvent, FileIoError, ProgressEvent};
+pub use core::checksum::{read_verified, write_atomic_with_checksum};
+pub use core::copy::copy_with_progress;
+pub use core::dir::{copy_dir_recursive, list_glob, replace_dir_atomic, TempDir};
+pub use core::lock::with_file_lock;
+pub use core::watch::watch_path;
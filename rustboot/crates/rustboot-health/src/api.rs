@@ -0,0 +1,123 @@
+//! Public types for the health module.
+//!
+//! The JSON shape these types serialize to follows the IETF
+//! "Health Check Response Format for HTTP APIs" draft
+//! (<https://datatracker.ietf.org/doc/html/draft-inadarei-api-health-check>),
+//! with one documented extension (`durationMs`) for per-check timing.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+/// The outcome of a single health check, per the draft's `status` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// The component is healthy.
+    #[default]
+    Pass,
+    /// The component is reachable but degraded.
+    Warn,
+    /// The component is unhealthy.
+    Fail,
+}
+
+impl HealthStatus {
+    /// Whether this status represents a fully healthy component.
+    pub fn is_healthy(self) -> bool {
+        self == HealthStatus::Pass
+    }
+
+    /// The more severe of `self` and `other` (`Fail` > `Warn` > `Pass`),
+    /// used to roll individual checks up into an overall report status.
+    pub fn worst(self, other: HealthStatus) -> HealthStatus {
+        use HealthStatus::*;
+        match (self, other) {
+            (Fail, _) | (_, Fail) => Fail,
+            (Warn, _) | (_, Warn) => Warn,
+            (Pass, Pass) => Pass,
+        }
+    }
+}
+
+/// The kind of component a check observes, per the draft's
+/// `componentType` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentType {
+    /// A generic software component.
+    Component,
+    /// A datastore (database, cache, queue, ...).
+    Datastore,
+    /// A system-level resource (disk, memory, ...).
+    System,
+}
+
+/// A single observation contributing to a health check entry.
+///
+/// The draft allows multiple observations per check key (e.g. one
+/// `uptime` measurement and one `responseTime` measurement for the
+/// same component); rustboot-health models that as `Vec<CheckObservation>`
+/// per key in [`HealthReport::checks`].
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckObservation {
+    /// An identifier for the component being checked (e.g. `"postgres-primary"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_id: Option<String>,
+    /// The kind of component being checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<ComponentType>,
+    /// The measured value (e.g. free disk bytes, queue depth).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed_value: Option<serde_json::Value>,
+    /// The unit `observed_value` is measured in (e.g. `"bytes"`, `"ms"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed_unit: Option<String>,
+    /// The outcome of this observation.
+    pub status: HealthStatus,
+    /// How long the check took to run, in milliseconds.
+    ///
+    /// Not part of the draft; a documented extension field so
+    /// monitoring tooling can distinguish a slow-but-passing dependency
+    /// from a consistently fast one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// A human-readable explanation, typically set on failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Related URIs (e.g. a runbook or dashboard), keyed by link name.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub links: HashMap<String, String>,
+}
+
+/// A versioned, schema-stable health report, compatible with the IETF
+/// health-check draft's top-level JSON shape.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    /// The overall status: the worst status across all checks, or
+    /// [`HealthStatus::Pass`] if there are none.
+    pub status: HealthStatus,
+    /// The report schema version. Bump this if the shape of
+    /// [`HealthReport`] or [`CheckObservation`] changes incompatibly.
+    pub version: String,
+    /// The deployed release identifier of the service, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_id: Option<String>,
+    /// Free-form notes about the report as a whole.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    /// A human-readable summary, typically set when `status` is not `pass`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Observations, keyed by `"<componentId>:<measurementName>"` per
+    /// the draft's `checks` object.
+    pub checks: HashMap<String, Vec<CheckObservation>>,
+    /// Related URIs for the report as a whole.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub links: HashMap<String, String>,
+}
+
+/// The schema version reported in [`HealthReport::version`].
+pub const SCHEMA_VERSION: &str = "1";
@@ -0,0 +1,180 @@
+//! Implementation details for the health module.
+
+use std::time::Duration;
+
+use crate::api::{
+    CheckObservation, ComponentType, HealthReport, HealthStatus, SCHEMA_VERSION,
+};
+
+impl CheckObservation {
+    /// Creates an observation with only a status set.
+    pub fn new(status: HealthStatus) -> Self {
+        Self {
+            status,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the component ID.
+    pub fn with_component_id(mut self, component_id: impl Into<String>) -> Self {
+        self.component_id = Some(component_id.into());
+        self
+    }
+
+    /// Sets the component type.
+    pub fn with_component_type(mut self, component_type: ComponentType) -> Self {
+        self.component_type = Some(component_type);
+        self
+    }
+
+    /// Sets the observed value and the unit it's measured in.
+    pub fn with_observed_value(
+        mut self,
+        value: impl Into<serde_json::Value>,
+        unit: impl Into<String>,
+    ) -> Self {
+        self.observed_value = Some(value.into());
+        self.observed_unit = Some(unit.into());
+        self
+    }
+
+    /// Sets how long the check took to run.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration_ms = Some(duration.as_millis() as u64);
+        self
+    }
+
+    /// Sets a human-readable explanation.
+    pub fn with_output(mut self, output: impl Into<String>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+
+    /// Adds a related link.
+    pub fn with_link(mut self, name: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.links.insert(name.into(), uri.into());
+        self
+    }
+}
+
+impl Default for HealthReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthReport {
+    /// Creates an empty report with [`HealthStatus::Pass`].
+    pub fn new() -> Self {
+        Self {
+            status: HealthStatus::Pass,
+            version: SCHEMA_VERSION.to_string(),
+            release_id: None,
+            notes: Vec::new(),
+            output: None,
+            checks: std::collections::HashMap::new(),
+            links: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Sets the deployed release identifier.
+    pub fn with_release_id(mut self, release_id: impl Into<String>) -> Self {
+        self.release_id = Some(release_id.into());
+        self
+    }
+
+    /// Records `observation` under `key` (conventionally
+    /// `"<componentId>:<measurementName>"`, e.g. `"postgres:responseTime"`)
+    /// and rolls its status into the report's overall [`HealthReport::status`].
+    pub fn add_check(&mut self, key: impl Into<String>, observation: CheckObservation) {
+        self.status = self.status.worst(observation.status);
+        self.checks.entry(key.into()).or_default().push(observation);
+    }
+
+    /// Whether the overall status is [`HealthStatus::Pass`].
+    pub fn is_healthy(&self) -> bool {
+        self.status.is_healthy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn new_report_is_healthy_with_no_checks() {
+        let report = HealthReport::new();
+        assert!(report.is_healthy());
+        assert_eq!(report.status, HealthStatus::Pass);
+    }
+
+    #[test]
+    fn overall_status_is_the_worst_of_all_checks() {
+        let mut report = HealthReport::new();
+        report.add_check("cache:ping", CheckObservation::new(HealthStatus::Pass));
+        report.add_check(
+            "postgres:responseTime",
+            CheckObservation::new(HealthStatus::Warn),
+        );
+        assert_eq!(report.status, HealthStatus::Warn);
+
+        report.add_check("disk:freeSpace", CheckObservation::new(HealthStatus::Fail));
+        assert_eq!(report.status, HealthStatus::Fail);
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn multiple_observations_accumulate_under_the_same_key() {
+        let mut report = HealthReport::new();
+        report.add_check("postgres:ping", CheckObservation::new(HealthStatus::Pass));
+        report.add_check("postgres:ping", CheckObservation::new(HealthStatus::Pass));
+
+        assert_eq!(report.checks["postgres:ping"].len(), 2);
+    }
+
+    #[test]
+    fn serializes_to_the_documented_camel_case_shape() {
+        let mut report = HealthReport::new().with_release_id("1.4.2");
+        report.add_check(
+            "disk:freeSpace",
+            CheckObservation::new(HealthStatus::Warn)
+                .with_component_id("root-volume")
+                .with_component_type(ComponentType::System)
+                .with_observed_value(json!(512_000_000u64), "bytes")
+                .with_duration(Duration::from_millis(12))
+                .with_output("below 1GB threshold")
+                .with_link("runbook", "https://runbooks.example/disk-space"),
+        );
+
+        let value = serde_json::to_value(&report).unwrap();
+        assert_eq!(value["status"], "warn");
+        assert_eq!(value["version"], "1");
+        assert_eq!(value["releaseId"], "1.4.2");
+
+        let observation = &value["checks"]["disk:freeSpace"][0];
+        assert_eq!(observation["componentId"], "root-volume");
+        assert_eq!(observation["componentType"], "system");
+        assert_eq!(observation["observedValue"], 512_000_000u64);
+        assert_eq!(observation["observedUnit"], "bytes");
+        assert_eq!(observation["durationMs"], 12);
+        assert_eq!(observation["output"], "below 1GB threshold");
+        assert_eq!(
+            observation["links"]["runbook"],
+            "https://runbooks.example/disk-space"
+        );
+    }
+
+    #[test]
+    fn omits_unset_optional_fields() {
+        let mut report = HealthReport::new();
+        report.add_check("cache:ping", CheckObservation::new(HealthStatus::Pass));
+
+        let value = serde_json::to_value(&report).unwrap();
+        assert!(value.get("releaseId").is_none());
+        assert!(value.get("output").is_none());
+        let observation = &value["checks"]["cache:ping"][0];
+        assert!(observation.get("componentId").is_none());
+        assert!(observation.get("durationMs").is_none());
+    }
+}
@@ -0,0 +1,10 @@
+//! Health check aggregation for the rustboot framework.
+//!
+//! [`HealthReport`] serializes to the versioned, schema-stable JSON
+//! shape documented in [`api`], compatible with the IETF health-check
+//! draft format.
+
+pub mod api;
+pub mod core;
+
+pub use api::{CheckObservation, ComponentType, HealthReport, HealthStatus, SCHEMA_VERSION};
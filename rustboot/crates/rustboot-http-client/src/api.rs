@@ -0,0 +1,168 @@
+//! Public types for the HTTP client module.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use http::Method;
+
+/// An outbound HTTP request.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// HTTP method.
+    pub method: Method,
+    /// Absolute URL, or a path appended to [`HttpClientConfig::base_url`]
+    /// when it isn't already absolute.
+    pub url: String,
+    /// Headers to send with the request, in addition to
+    /// [`HttpClientConfig::default_headers`]. These take precedence on
+    /// conflict.
+    pub headers: HashMap<String, String>,
+    /// Request body, if any.
+    pub body: Option<Vec<u8>>,
+    /// Overrides [`HttpClientConfig::default_timeout`] for this request
+    /// only.
+    pub timeout: Option<Duration>,
+}
+
+impl HttpRequest {
+    /// Creates a request with no headers, body, or timeout override.
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: HashMap::new(),
+            body: None,
+            timeout: None,
+        }
+    }
+
+    /// Sets a header, overwriting any existing value for the same name.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the request body.
+    pub fn with_body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Overrides the default timeout for this request only.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// The result of a successfully executed [`HttpRequest`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers. Multi-valued headers are collapsed to their
+    /// last value.
+    pub headers: HashMap<String, String>,
+    /// Response body.
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Whether the status code is in the `2xx` range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Whether the status code is in the `4xx` range.
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.status)
+    }
+
+    /// Whether the status code is in the `5xx` range.
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.status)
+    }
+}
+
+/// Proxy settings for an [`HttpClientConfig`].
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    /// Proxy URL (e.g. `http://proxy.internal:3128`), applied to every
+    /// scheme.
+    pub url: String,
+    /// Basic auth credentials for the proxy, if required.
+    pub credentials: Option<(String, String)>,
+}
+
+impl ProxyConfig {
+    /// Creates a proxy config with no credentials.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            credentials: None,
+        }
+    }
+
+    /// Sets basic auth credentials for the proxy.
+    pub fn with_credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+}
+
+/// Configures a [`crate::core::reqwest_client::ReqwestClientBuilder`].
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    /// Base URL prepended to a request's `url` when it isn't already
+    /// absolute.
+    pub base_url: Option<String>,
+    /// Headers sent with every request, overridden per-request by
+    /// [`HttpRequest::headers`] on conflict.
+    pub default_headers: HashMap<String, String>,
+    /// Default per-request timeout, overridable via
+    /// [`HttpRequest::timeout`].
+    pub default_timeout: Duration,
+    /// Timeout for establishing the TCP/TLS connection, separate from
+    /// the overall request timeout.
+    pub connect_timeout: Duration,
+    /// Maximum number of idle pooled connections kept open per host.
+    pub max_connections_per_host: usize,
+    /// Proxy applied to every request, if set.
+    pub proxy: Option<ProxyConfig>,
+    /// Additional root CA certificates (PEM-encoded), trusted alongside
+    /// the platform's default trust store.
+    pub root_ca_certs: Vec<Vec<u8>>,
+    /// `User-Agent` header value sent with every request.
+    pub user_agent: String,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: None,
+            default_headers: HashMap::new(),
+            default_timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            max_connections_per_host: 32,
+            proxy: None,
+            root_ca_certs: Vec::new(),
+            user_agent: "rustboot-http-client".to_string(),
+        }
+    }
+}
+
+/// Errors from building or executing requests with an [`HttpClientConfig`].
+#[derive(Debug, thiserror::Error)]
+pub enum HttpClientError {
+    /// The configuration (a proxy URL, a root CA certificate, ...) was
+    /// rejected while building the client.
+    #[error("invalid HTTP client configuration: {0}")]
+    InvalidConfig(String),
+    /// The request timed out.
+    #[error("request timed out")]
+    Timeout,
+    /// The request failed at the transport level (DNS, connection,
+    /// TLS, ...).
+    #[error("HTTP transport error: {0}")]
+    Transport(String),
+}
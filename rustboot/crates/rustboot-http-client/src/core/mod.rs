@@ -0,0 +1,3 @@
+//! Implementation details for the HTTP client module.
+
+pub mod reqwest_client;
@@ -0,0 +1,142 @@
+//! `reqwest`-backed implementation of [`HttpClient`].
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::{HttpClientConfig, HttpClientError, HttpRequest, HttpResponse, ProxyConfig};
+use crate::spi::HttpClient;
+
+/// Builds a [`ReqwestHttpClient`] from an [`HttpClientConfig`].
+pub struct ReqwestClientBuilder {
+    config: HttpClientConfig,
+}
+
+impl ReqwestClientBuilder {
+    /// Starts a builder with the given configuration.
+    pub fn new(config: HttpClientConfig) -> Self {
+        Self { config }
+    }
+
+    /// Builds the client, applying timeouts, pooling, proxy, and TLS
+    /// settings from the configuration to the underlying `reqwest`
+    /// client.
+    pub fn build(self) -> Result<ReqwestHttpClient, HttpClientError> {
+        let config = self.config;
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.default_timeout)
+            .connect_timeout(config.connect_timeout)
+            .pool_max_idle_per_host(config.max_connections_per_host)
+            .user_agent(config.user_agent.clone());
+
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(build_proxy(proxy)?);
+        }
+
+        for pem in &config.root_ca_certs {
+            let cert = reqwest::Certificate::from_pem(pem)
+                .map_err(|err| HttpClientError::InvalidConfig(err.to_string()))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let default_headers = build_header_map(&config.default_headers)?;
+        builder = builder.default_headers(default_headers);
+
+        let inner = builder
+            .build()
+            .map_err(|err| HttpClientError::InvalidConfig(err.to_string()))?;
+
+        Ok(ReqwestHttpClient {
+            inner,
+            base_url: config.base_url,
+        })
+    }
+}
+
+fn build_proxy(config: &ProxyConfig) -> Result<reqwest::Proxy, HttpClientError> {
+    let mut proxy = reqwest::Proxy::all(&config.url)
+        .map_err(|err| HttpClientError::InvalidConfig(err.to_string()))?;
+    if let Some((username, password)) = &config.credentials {
+        proxy = proxy.basic_auth(username, password);
+    }
+    Ok(proxy)
+}
+
+fn build_header_map(headers: &HashMap<String, String>) -> Result<HeaderMap, HttpClientError> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = HeaderName::try_from(name.as_str())
+            .map_err(|err| HttpClientError::InvalidConfig(err.to_string()))?;
+        let value = HeaderValue::try_from(value.as_str())
+            .map_err(|err| HttpClientError::InvalidConfig(err.to_string()))?;
+        map.insert(name, value);
+    }
+    Ok(map)
+}
+
+fn is_absolute_url(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+/// An [`HttpClient`] backed by a pooled `reqwest::Client`.
+pub struct ReqwestHttpClient {
+    inner: reqwest::Client,
+    base_url: Option<String>,
+}
+
+impl ReqwestHttpClient {
+    fn resolve_url(&self, url: &str) -> String {
+        if is_absolute_url(url) {
+            return url.to_string();
+        }
+        match &self.base_url {
+            Some(base) => format!("{}{}", base.trim_end_matches('/'), url),
+            None => url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl HttpClient for ReqwestHttpClient {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpClientError> {
+        let url = self.resolve_url(&request.url);
+        let mut builder = self.inner.request(request.method, url);
+        builder = builder.headers(build_header_map(&request.headers)?);
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await.map_err(map_transport_error)?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    value.to_str().unwrap_or_default().to_string(),
+                )
+            })
+            .collect();
+        let body = response
+            .bytes()
+            .await
+            .map_err(map_transport_error)?
+            .to_vec();
+
+        Ok(HttpResponse { status, headers, body })
+    }
+}
+
+fn map_transport_error(err: reqwest::Error) -> HttpClientError {
+    if err.is_timeout() {
+        HttpClientError::Timeout
+    } else {
+        HttpClientError::Transport(err.to_string())
+    }
+}
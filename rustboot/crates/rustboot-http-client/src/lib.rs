@@ -0,0 +1,31 @@
+//! Outbound HTTP client building blocks for the rustboot framework.
+//!
+//! - [`HttpClient`]: the pluggable executor trait, implemented for
+//!   production use by [`core::reqwest_client::ReqwestHttpClient`].
+//! - [`core::reqwest_client::ReqwestClientBuilder`]: builds a pooled,
+//!   `reqwest`-backed [`HttpClient`] from an [`HttpClientConfig`],
+//!   applying per-request and global timeouts, connect timeout, max
+//!   connections per host, proxy settings, custom root CAs, and a
+//!   `User-Agent`.
+//! - [`BearerTokenProvider`]: supplies the bearer token attached by
+//!   `#[http_api]`-generated clients.
+//! - [`http_api`]: trait-level macro that turns a trait of
+//!   `#[get]`/`#[post]`/`#[put]`/`#[delete]`-annotated endpoint methods
+//!   into a `<Trait>Client` wired to one shared base URL, default
+//!   headers, and bearer-token provider.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{HttpClientConfig, HttpClientError, HttpRequest, HttpResponse, ProxyConfig};
+pub use core::reqwest_client::{ReqwestClientBuilder, ReqwestHttpClient};
+pub use rustboot_http_client_derive::http_api;
+pub use spi::{BearerTokenProvider, HttpClient};
+
+/// Re-exported so `#[http_api]`-generated code (see
+/// `rustboot-http-client-derive`) can reference `::rustboot_http_client::async_trait`
+/// and `::rustboot_http_client::Method` without requiring `async-trait`
+/// or `http` as direct dependencies of the crate that uses the macro.
+pub use async_trait::async_trait;
+pub use http::Method;
@@ -0,0 +1,49 @@
+//! Pluggable executor for the HTTP client module.
+
+use async_trait::async_trait;
+use http::Method;
+
+use crate::api::{HttpClientError, HttpRequest, HttpResponse};
+
+/// Executes outbound HTTP requests.
+///
+/// Implement this against a real backend (see
+/// [`crate::core::reqwest_client::ReqwestHttpClient`]) or a test double,
+/// so callers depend on the trait rather than a specific HTTP stack.
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    /// Executes `request` and returns its response.
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpClientError>;
+
+    /// Convenience for a `GET` request with no body.
+    async fn get(&self, url: &str) -> Result<HttpResponse, HttpClientError> {
+        self.execute(HttpRequest::new(Method::GET, url)).await
+    }
+
+    /// Convenience for a `POST` request.
+    async fn post(&self, url: &str, body: Vec<u8>) -> Result<HttpResponse, HttpClientError> {
+        self.execute(HttpRequest::new(Method::POST, url).with_body(body)).await
+    }
+
+    /// Convenience for a `PUT` request.
+    async fn put(&self, url: &str, body: Vec<u8>) -> Result<HttpResponse, HttpClientError> {
+        self.execute(HttpRequest::new(Method::PUT, url).with_body(body)).await
+    }
+
+    /// Convenience for a `DELETE` request with no body.
+    async fn delete(&self, url: &str) -> Result<HttpResponse, HttpClientError> {
+        self.execute(HttpRequest::new(Method::DELETE, url)).await
+    }
+}
+
+/// Supplies a bearer token for authenticated requests.
+///
+/// Used by `#[http_api]`-generated clients (see `rustboot-http-client-derive`)
+/// to attach an `Authorization: Bearer <token>` header without baking a
+/// specific token source (static config, an OAuth refresh flow, ...)
+/// into the generated code.
+#[async_trait]
+pub trait BearerTokenProvider: Send + Sync {
+    /// Returns the current bearer token.
+    async fn token(&self) -> Result<String, HttpClientError>;
+}
@@ -0,0 +1,212 @@
+//! Public types for the identifiers module.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A 128-bit UUID, stored as raw bytes rather than its hyphenated string
+/// form.
+///
+/// Serializes to and from the canonical hyphenated, lowercase form
+/// (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`), so it can be used directly
+/// as a `serde`-derived struct field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// Wraps raw 128-bit UUID bytes, as laid out on the wire (RFC 4122
+    /// network byte order), without checking the version or variant.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw 128-bit UUID bytes.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// The nil UUID, `00000000-0000-0000-0000-000000000000`.
+    pub const fn nil() -> Self {
+        Self([0; 16])
+    }
+
+    /// The RFC 4122 namespace for fully-qualified domain names, for use
+    /// with [`Uuid::new_v5`].
+    pub const NAMESPACE_DNS: Uuid = Uuid([
+        0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+    ]);
+
+    /// The RFC 4122 namespace for URLs, for use with [`Uuid::new_v5`].
+    pub const NAMESPACE_URL: Uuid = Uuid([
+        0x6b, 0xa7, 0xb8, 0x11, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+    ]);
+}
+
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = &self.0;
+        write!(
+            f,
+            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]
+        )
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = IdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex: String = s.chars().filter(|c| *c != '-').collect();
+        if hex.len() != 32 {
+            return Err(IdentifierError::InvalidLength(s.to_string()));
+        }
+
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let pair = &hex[i * 2..i * 2 + 2];
+            *byte = u8::from_str_radix(pair, 16)
+                .map_err(|_| IdentifierError::InvalidCharacter(s.to_string()))?;
+        }
+        Ok(Uuid(bytes))
+    }
+}
+
+impl Serialize for Uuid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Uuid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Errors produced while parsing a [`Uuid`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum IdentifierError {
+    /// The input did not have 32 hex digits once hyphens were stripped.
+    #[error("invalid UUID length: '{0}'")]
+    InvalidLength(String),
+    /// The input contained a non-hex-digit character.
+    #[error("invalid UUID character: '{0}'")]
+    InvalidCharacter(String),
+    /// The input contained a character outside the expected base62 or
+    /// base32 alphabet.
+    #[error("invalid encoded UUID character: '{0}'")]
+    InvalidEncodedCharacter(String),
+    /// A decoded base62 or base32 value didn't fit in 128 bits, or a
+    /// base32 string wasn't exactly 26 characters.
+    #[error("value out of range for a UUID: '{0}'")]
+    ValueOutOfRange(String),
+}
+
+/// A [`Uuid`] branded with a marker type `T`, so e.g. a `UserId` and an
+/// `OrderId` aren't interchangeable even though both wrap a `Uuid`.
+///
+/// `T` is never constructed — it only distinguishes one `TypedId` from
+/// another at compile time — so define it as an empty marker type:
+///
+/// ```
+/// use rustboot_identifiers::TypedId;
+///
+/// enum UserMarker {}
+/// type UserId = TypedId<UserMarker>;
+///
+/// let id = UserId::new_v4();
+/// assert_eq!(id, id.to_string().parse().unwrap());
+/// ```
+pub struct TypedId<T> {
+    id: Uuid,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedId<T> {
+    /// Wraps an existing [`Uuid`] as a `TypedId<T>`.
+    pub const fn from_uuid(id: Uuid) -> Self {
+        Self { id, marker: PhantomData }
+    }
+
+    /// The underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> Uuid {
+        self.id
+    }
+
+    /// Unwraps this into the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.id
+    }
+}
+
+impl<T> fmt::Debug for TypedId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TypedId({})", self.id)
+    }
+}
+
+impl<T> fmt::Display for TypedId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+impl<T> Clone for TypedId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedId<T> {}
+
+impl<T> PartialEq for TypedId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for TypedId<T> {}
+
+impl<T> PartialOrd for TypedId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for TypedId<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<T> Hash for TypedId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T> FromStr for TypedId<T> {
+    type Err = IdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::from_uuid)
+    }
+}
+
+impl<T> Serialize for TypedId<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.id.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for TypedId<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Uuid::deserialize(deserializer).map(Self::from_uuid)
+    }
+}
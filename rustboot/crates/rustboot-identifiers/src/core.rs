@@ -0,0 +1,307 @@
+//! Implementation details for the identifiers module.
+
+use sha1::{Digest, Sha1};
+
+use crate::api::{IdentifierError, TypedId, Uuid};
+
+const BASE62_ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Crockford's base32 alphabet (excludes `I`, `L`, `O`, `U` to avoid
+/// confusion with `1`, `1`, `0`, and profanity), as used by ULID.
+const BASE32_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl Uuid {
+    /// Generates a random (version 4, variant 1) UUID, per RFC 4122.
+    pub fn new_v4() -> Self {
+        let mut bytes: [u8; 16] = rand::random();
+        bytes[6] = (bytes[6] & 0x0f) | 0x40; // version 4
+        bytes[8] = (bytes[8] & 0x3f) | 0x80; // variant 1 (RFC 4122)
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Generates a time-ordered (version 7, variant 1) UUID, per RFC
+    /// 9562: a 48-bit millisecond Unix timestamp followed by random
+    /// bits. Sorting by byte value also sorts by creation time, which
+    /// makes these a better fit than [`Uuid::new_v4`] for primary keys
+    /// and request/trace identifiers that end up in an index.
+    pub fn new_v7() -> Self {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let mut bytes = [0u8; 16];
+        bytes[0..6].copy_from_slice(&millis.to_be_bytes()[2..8]);
+
+        let rand: [u8; 10] = rand::random();
+        bytes[6] = (rand[0] & 0x0f) | 0x70; // version 7
+        bytes[7] = rand[1];
+        bytes[8] = (rand[2] & 0x3f) | 0x80; // variant 1 (RFC 4122)
+        bytes[9..16].copy_from_slice(&rand[3..10]);
+
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Generates a deterministic (version 5, variant 1) UUID from a
+    /// namespace UUID and a name, per RFC 4122: the same namespace and
+    /// name always hash to the same UUID, which makes this useful for
+    /// deriving a stable ID from some other identifier (a URL, an email
+    /// address) without a lookup table.
+    pub fn new_v5(namespace: Uuid, name: &[u8]) -> Self {
+        let mut hasher = Sha1::new();
+        hasher.update(namespace.as_bytes());
+        hasher.update(name);
+        let digest = hasher.finalize();
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        bytes[6] = (bytes[6] & 0x0f) | 0x50; // version 5
+        bytes[8] = (bytes[8] & 0x3f) | 0x80; // variant 1 (RFC 4122)
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Encodes this UUID's 128 bits as a base62 string. Shorter than
+    /// the canonical hyphenated form (22 characters or fewer, versus
+    /// 36) and URL-safe without escaping, at the cost of no longer
+    /// being visually split into RFC 4122's version/variant fields.
+    pub fn to_base62(&self) -> String {
+        let mut value = u128::from_be_bytes(*self.as_bytes());
+        if value == 0 {
+            return "0".to_string();
+        }
+
+        let mut digits = Vec::new();
+        while value > 0 {
+            digits.push(BASE62_ALPHABET[(value % 62) as usize]);
+            value /= 62;
+        }
+        digits.reverse();
+        String::from_utf8(digits).expect("base62 alphabet is ASCII")
+    }
+
+    /// Decodes a [`Uuid::to_base62`]-encoded string.
+    pub fn from_base62(s: &str) -> Result<Self, IdentifierError> {
+        if s.is_empty() {
+            return Err(IdentifierError::ValueOutOfRange(s.to_string()));
+        }
+
+        let mut value: u128 = 0;
+        for c in s.chars() {
+            let digit = BASE62_ALPHABET
+                .iter()
+                .position(|&b| b as char == c)
+                .ok_or_else(|| IdentifierError::InvalidEncodedCharacter(s.to_string()))? as u128;
+            value = value
+                .checked_mul(62)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or_else(|| IdentifierError::ValueOutOfRange(s.to_string()))?;
+        }
+        Ok(Uuid::from_bytes(value.to_be_bytes()))
+    }
+
+    /// Encodes this UUID's 128 bits as a fixed-width, 26-character
+    /// string using Crockford's base32 alphabet — the same encoding
+    /// ULIDs use, so a [`Uuid::new_v7`] ID (which is already time-
+    /// ordered) round-trips through a ULID-shaped string.
+    pub fn to_base32(&self) -> String {
+        let value = u128::from_be_bytes(*self.as_bytes());
+        let mut out = String::with_capacity(26);
+        out.push(BASE32_ALPHABET[((value >> 125) & 0x7) as usize] as char);
+        for i in (0..25).rev() {
+            out.push(BASE32_ALPHABET[((value >> (i * 5)) & 0x1f) as usize] as char);
+        }
+        out
+    }
+
+    /// Decodes a [`Uuid::to_base32`]-encoded string.
+    pub fn from_base32(s: &str) -> Result<Self, IdentifierError> {
+        if s.len() != 26 {
+            return Err(IdentifierError::ValueOutOfRange(s.to_string()));
+        }
+
+        let mut value: u128 = 0;
+        for (i, c) in s.chars().enumerate() {
+            let digit = BASE32_ALPHABET
+                .iter()
+                .position(|&b| b as char == c.to_ascii_uppercase())
+                .ok_or_else(|| IdentifierError::InvalidEncodedCharacter(s.to_string()))? as u128;
+            if i == 0 {
+                if digit > 7 {
+                    return Err(IdentifierError::ValueOutOfRange(s.to_string()));
+                }
+                value |= digit << 125;
+            } else {
+                value |= digit << ((25 - i) * 5);
+            }
+        }
+        Ok(Uuid::from_bytes(value.to_be_bytes()))
+    }
+}
+
+impl<T> TypedId<T> {
+    /// Generates a [`TypedId`] wrapping a random (version 4) [`Uuid`].
+    pub fn new_v4() -> Self {
+        Self::from_uuid(Uuid::new_v4())
+    }
+
+    /// Generates a [`TypedId`] wrapping a time-ordered (version 7)
+    /// [`Uuid`].
+    pub fn new_v7() -> Self {
+        Self::from_uuid(Uuid::new_v7())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_v4_sets_version_and_variant_bits() {
+        let id = Uuid::new_v4();
+        let bytes = id.as_bytes();
+        assert_eq!(bytes[6] & 0xf0, 0x40);
+        assert_eq!(bytes[8] & 0xc0, 0x80);
+    }
+
+    #[test]
+    fn new_v4_generates_distinct_ids() {
+        assert_ne!(Uuid::new_v4(), Uuid::new_v4());
+    }
+
+    #[test]
+    fn new_v7_sets_version_and_variant_bits() {
+        let id = Uuid::new_v7();
+        let bytes = id.as_bytes();
+        assert_eq!(bytes[6] & 0xf0, 0x70);
+        assert_eq!(bytes[8] & 0xc0, 0x80);
+    }
+
+    #[test]
+    fn new_v7_generates_distinct_ids() {
+        assert_ne!(Uuid::new_v7(), Uuid::new_v7());
+    }
+
+    #[test]
+    fn new_v7_ids_sort_non_decreasing_by_creation_time() {
+        let first = Uuid::new_v7();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let second = Uuid::new_v7();
+        assert!(first <= second);
+    }
+
+    #[test]
+    fn display_and_from_str_round_trip() {
+        let id = Uuid::new_v4();
+        let parsed: Uuid = id.to_string().parse().unwrap();
+        assert_eq!(id, parsed);
+    }
+
+    #[test]
+    fn from_str_accepts_the_nil_uuid() {
+        let id: Uuid = "00000000-0000-0000-0000-000000000000".parse().unwrap();
+        assert_eq!(id, Uuid::nil());
+    }
+
+    #[test]
+    fn from_str_rejects_the_wrong_length() {
+        assert!("not-a-uuid".parse::<Uuid>().is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_non_hex_characters() {
+        assert!("zzzzzzzz-zzzz-zzzz-zzzz-zzzzzzzzzzzz".parse::<Uuid>().is_err());
+    }
+
+    #[test]
+    fn serializes_to_and_from_its_hyphenated_string_form() {
+        let id = Uuid::new_v4();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{id}\""));
+        assert_eq!(serde_json::from_str::<Uuid>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn new_v5_is_deterministic() {
+        let first = Uuid::new_v5(Uuid::NAMESPACE_DNS, b"example.com");
+        let second = Uuid::new_v5(Uuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn new_v5_sets_version_and_variant_bits() {
+        let id = Uuid::new_v5(Uuid::NAMESPACE_URL, b"https://example.com");
+        let bytes = id.as_bytes();
+        assert_eq!(bytes[6] & 0xf0, 0x50);
+        assert_eq!(bytes[8] & 0xc0, 0x80);
+    }
+
+    #[test]
+    fn new_v5_differs_by_namespace_or_name() {
+        let by_name = Uuid::new_v5(Uuid::NAMESPACE_DNS, b"example.com");
+        let different_name = Uuid::new_v5(Uuid::NAMESPACE_DNS, b"example.org");
+        let different_namespace = Uuid::new_v5(Uuid::NAMESPACE_URL, b"example.com");
+        assert_ne!(by_name, different_name);
+        assert_ne!(by_name, different_namespace);
+    }
+
+    #[test]
+    fn base62_round_trips() {
+        let id = Uuid::new_v4();
+        assert_eq!(Uuid::from_base62(&id.to_base62()).unwrap(), id);
+    }
+
+    #[test]
+    fn base62_encodes_the_nil_uuid_as_zero() {
+        assert_eq!(Uuid::nil().to_base62(), "0");
+    }
+
+    #[test]
+    fn base62_rejects_an_out_of_alphabet_character() {
+        assert!(Uuid::from_base62("!!!").is_err());
+    }
+
+    #[test]
+    fn base62_rejects_empty_input() {
+        assert!(Uuid::from_base62("").is_err());
+    }
+
+    #[test]
+    fn base32_round_trips() {
+        let id = Uuid::new_v7();
+        let encoded = id.to_base32();
+        assert_eq!(encoded.len(), 26);
+        assert_eq!(Uuid::from_base32(&encoded).unwrap(), id);
+    }
+
+    #[test]
+    fn base32_rejects_the_wrong_length() {
+        assert!(Uuid::from_base32("TOOSHORT").is_err());
+    }
+
+    #[test]
+    fn base32_rejects_an_out_of_alphabet_character() {
+        assert!(Uuid::from_base32("IIIIIIIIIIIIIIIIIIIIIIIIII").is_err());
+    }
+
+    #[test]
+    fn typed_id_new_v4_wraps_a_version_4_uuid() {
+        enum UserMarker {}
+        let id = TypedId::<UserMarker>::new_v4();
+        assert_eq!(id.as_uuid().as_bytes()[6] & 0xf0, 0x40);
+    }
+
+    #[test]
+    fn typed_id_display_and_from_str_round_trip() {
+        enum OrderMarker {}
+        let id = TypedId::<OrderMarker>::new_v7();
+        let parsed: TypedId<OrderMarker> = id.to_string().parse().unwrap();
+        assert_eq!(id, parsed);
+    }
+
+    #[test]
+    fn typed_id_serializes_like_the_underlying_uuid() {
+        enum UserMarker {}
+        let id = TypedId::<UserMarker>::new_v4();
+        assert_eq!(serde_json::to_string(&id).unwrap(), serde_json::to_string(&id.as_uuid()).unwrap());
+    }
+}
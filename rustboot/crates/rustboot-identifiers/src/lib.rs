@@ -0,0 +1,26 @@
+//! UUID identifiers for the rustboot framework.
+//!
+//! [`Uuid`] generates and parses RFC 4122 version-4 (random), version-5
+//! (namespace + name), and RFC 9562 version-7 (time-ordered) UUIDs, and
+//! (de)serializes to their canonical hyphenated string form, so it can
+//! be dropped into any `serde`-derived type as an ID field.
+//! [`Uuid::to_base62`]/[`Uuid::to_base32`] offer shorter encodings of
+//! the same 128 bits for contexts (URLs, log lines) where the
+//! hyphenated form is needlessly long.
+//!
+//! [`TypedId<T>`] brands a `Uuid` with a marker type, so a `UserId` and
+//! an `OrderId` can't be accidentally swapped even though both wrap a
+//! `Uuid`.
+//!
+//! Conversions to `rustboot_database::Value` and a path-param extractor
+//! for `rustboot-web` are out of scope for now: neither a database
+//! value abstraction nor a request-routing/extractor layer exists yet
+//! in this workspace. Once those crates land, add `From`/`TryFrom`
+//! impls here (or in the crate that defines the trait being converted
+//! to/from) rather than introducing a dependency from this crate on
+//! either of them.
+
+pub mod api;
+pub mod core;
+
+pub use api::{IdentifierError, TypedId, Uuid};
@@ -0,0 +1,97 @@
+//! Derive macro for `rustboot_messaging::spi::Event`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, LitStr};
+
+/// Derives `Event` from a struct-level `#[event(topic = "...", version = N)]`
+/// attribute:
+///
+/// ```ignore
+/// #[derive(Event, Serialize)]
+/// #[event(topic = "orders", version = 2)]
+/// struct OrderCreated {
+///     #[event(key)]
+///     order_id: String,
+///     total_cents: u64,
+/// }
+/// ```
+///
+/// `topic()` returns the literal topic string, and `schema_version()`
+/// the literal version (defaulting to `1` if omitted). `routing_key()`
+/// returns the `Display` of the field marked `#[event(key)]`, or the
+/// topic itself if no field is marked.
+#[proc_macro_derive(Event, attributes(event))]
+pub fn derive_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let mut topic: Option<LitStr> = None;
+    let mut version: Option<LitInt> = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("event") {
+            continue;
+        }
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("topic") {
+                topic = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("version") {
+                version = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `#[event(...)]` attribute, expected `topic` or `version`"))
+            }
+        });
+        if let Err(err) = result {
+            return err.to_compile_error().into();
+        }
+    }
+
+    let Some(topic) = topic else {
+        return syn::Error::new_spanned(&input, "Event requires `#[event(topic = \"...\")]`")
+            .to_compile_error()
+            .into();
+    };
+    let version = version.unwrap_or_else(|| LitInt::new("1", proc_macro2::Span::call_site()));
+
+    let key_field = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().find(|field| {
+                field.attrs.iter().any(|attr| {
+                    attr.path().is_ident("event")
+                        && attr
+                            .parse_nested_meta(|meta| if meta.path.is_ident("key") { Ok(()) } else { Err(meta.error("")) })
+                            .is_ok()
+                })
+            }),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let routing_key_body = match key_field {
+        Some(field) => {
+            let ident = field.ident.as_ref().expect("named field");
+            quote! { ::std::string::ToString::to_string(&self.#ident) }
+        }
+        None => quote! { ::std::string::ToString::to_string(#topic) },
+    };
+
+    let expanded = quote! {
+        impl ::rustboot_messaging::spi::Event for #name {
+            fn topic(&self) -> &'static str {
+                #topic
+            }
+
+            fn routing_key(&self) -> ::std::string::String {
+                #routing_key_body
+            }
+
+            fn schema_version(&self) -> u32 {
+                #version
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,88 @@
+//! Public types for the messaging module.
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// A message published to or consumed from a broker.
+#[derive(Debug, Clone)]
+pub struct Message {
+    /// Destination or origin topic.
+    pub topic: String,
+    /// Optional partitioning/routing key.
+    pub key: Option<String>,
+    /// Raw message payload.
+    pub payload: Vec<u8>,
+    /// Arbitrary metadata headers.
+    pub headers: HashMap<String, String>,
+    /// Time the message was created.
+    pub timestamp: SystemTime,
+    /// MIME content type of `payload`.
+    pub content_type: Option<String>,
+}
+
+impl Message {
+    /// Creates a new message for `topic` with the given `payload`.
+    pub fn new(topic: impl Into<String>, payload: impl Into<Vec<u8>>) -> Self {
+        Self {
+            topic: topic.into(),
+            key: None,
+            payload: payload.into(),
+            headers: HashMap::new(),
+            timestamp: SystemTime::now(),
+            content_type: None,
+        }
+    }
+
+    /// Sets the routing key.
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Sets a single header.
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the content type.
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+/// Errors shared across broker implementations.
+#[derive(Debug, thiserror::Error)]
+pub enum MessagingError {
+    /// The broker connection could not be established or was lost.
+    #[error("broker connection error: {0}")]
+    Connection(String),
+    /// A publish or consume operation failed.
+    #[error("broker operation failed: {0}")]
+    Operation(String),
+}
+
+/// Consumer group configuration shared across broker implementations.
+#[derive(Debug, Clone)]
+pub struct ConsumerConfig {
+    /// Consumer group identifier.
+    pub group_id: String,
+    /// Topics to subscribe to.
+    pub topics: Vec<String>,
+}
+
+/// Queue configuration shared across broker implementations.
+#[derive(Debug, Clone)]
+pub struct QueueConfig {
+    /// Queue name.
+    pub name: String,
+    /// Whether the queue survives broker restarts.
+    pub durable: bool,
+    /// Whether the queue is deleted once unused.
+    pub auto_delete: bool,
+    /// Maximum redelivery attempts before dead-lettering.
+    pub max_retries: u32,
+    /// Delay between redelivery attempts.
+    pub retry_delay: Duration,
+}
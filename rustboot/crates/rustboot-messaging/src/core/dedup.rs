@@ -0,0 +1,127 @@
+//! An idempotent-consumer helper for at-least-once brokers, which may
+//! redeliver a message a consumer already handled successfully.
+//!
+//! [`DedupSubscriber`] tracks recently-seen message IDs in a
+//! [`rustboot_cache::spi::Cache`]-backed set with a configurable
+//! retention window, so a consumer built as poll → [`DedupSubscriber::filter_new`]
+//! → handle only sees each ID once per window, regardless of which
+//! broker redelivered it or how many consumer instances share the
+//! cache.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use rustboot_cache::spi::Cache;
+
+use crate::api::{Message, MessagingError};
+
+/// Deduplicates [`Message`]s by an identifying header, backed by any
+/// [`Cache`] implementation (share one across consumer instances for
+/// dedup that holds across process restarts and horizontal scaling).
+pub struct DedupSubscriber<C> {
+    cache: C,
+    id_header: String,
+    window: Duration,
+}
+
+impl<C: Cache> DedupSubscriber<C> {
+    /// Creates a dedup filter keyed on the `id_header` header of each
+    /// message (falling back to a hash of the topic and payload if the
+    /// header isn't set), remembering an ID for `window` before a
+    /// repeat of it is let through again.
+    pub fn new(cache: C, id_header: impl Into<String>, window: Duration) -> Self {
+        Self {
+            cache,
+            id_header: id_header.into(),
+            window,
+        }
+    }
+
+    /// Returns `true` the first time `message`'s ID is seen within the
+    /// configured window, `false` if it's a redelivered duplicate.
+    pub async fn accept(&self, message: &Message) -> Result<bool, MessagingError> {
+        let key = format!("dedup:{}", self.message_id(message));
+        self.cache
+            .set_if_absent(&key, serde_json::Value::Bool(true), Some(self.window))
+            .await
+            .map_err(|err| MessagingError::Operation(err.to_string()))
+    }
+
+    /// Filters `messages` down to the ones not already seen, preserving
+    /// order.
+    pub async fn filter_new(&self, messages: Vec<Message>) -> Result<Vec<Message>, MessagingError> {
+        let mut kept = Vec::with_capacity(messages.len());
+        for message in messages {
+            if self.accept(&message).await? {
+                kept.push(message);
+            }
+        }
+        Ok(kept)
+    }
+
+    fn message_id(&self, message: &Message) -> String {
+        match message.headers.get(&self.id_header) {
+            Some(id) => id.clone(),
+            None => payload_fingerprint(message),
+        }
+    }
+}
+
+fn payload_fingerprint(message: &Message) -> String {
+    let mut hasher = DefaultHasher::new();
+    message.topic.hash(&mut hasher);
+    message.payload.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustboot_cache::core::memory::InMemoryCache;
+
+    fn message_with_id(id: &str) -> Message {
+        Message::new("orders.created", "payload").with_header("message-id", id)
+    }
+
+    #[tokio::test]
+    async fn a_message_is_accepted_the_first_time_and_rejected_on_redelivery() {
+        let dedup = DedupSubscriber::new(InMemoryCache::new(), "message-id", Duration::from_secs(60));
+        let message = message_with_id("abc-123");
+
+        assert!(dedup.accept(&message).await.unwrap());
+        assert!(!dedup.accept(&message).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn messages_without_the_id_header_are_deduplicated_by_payload() {
+        let dedup = DedupSubscriber::new(InMemoryCache::new(), "message-id", Duration::from_secs(60));
+        let first = Message::new("orders.created", "same payload");
+        let second = Message::new("orders.created", "same payload");
+
+        assert!(dedup.accept(&first).await.unwrap());
+        assert!(!dedup.accept(&second).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn filter_new_drops_duplicates_while_preserving_order() {
+        let dedup = DedupSubscriber::new(InMemoryCache::new(), "message-id", Duration::from_secs(60));
+        let messages = vec![
+            message_with_id("a"),
+            message_with_id("b"),
+            message_with_id("a"),
+            message_with_id("c"),
+        ];
+
+        let kept = dedup.filter_new(messages).await.unwrap();
+        let ids: Vec<&str> = kept.iter().map(|m| m.headers["message-id"].as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn distinct_ids_are_all_accepted() {
+        let dedup = DedupSubscriber::new(InMemoryCache::new(), "message-id", Duration::from_secs(60));
+        assert!(dedup.accept(&message_with_id("a")).await.unwrap());
+        assert!(dedup.accept(&message_with_id("b")).await.unwrap());
+    }
+}
@@ -0,0 +1,407 @@
+//! An in-process [`Broker`] with hierarchical-topic pub/sub, for tests
+//! and single-process deployments that don't need a real transport.
+//!
+//! Topics are dot-separated (`orders.created`), and a subscription can
+//! match more than one exact topic using the same wildcards RabbitMQ's
+//! topic exchange supports: `*` matches exactly one segment, `#`
+//! matches zero or more segments (anywhere in the pattern, not just at
+//! the end).
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+use crate::api::{Message, MessagingError, QueueConfig};
+use crate::spi::Broker;
+
+/// Whether `topic` matches `pattern` under RabbitMQ topic-exchange
+/// wildcard rules (`*` = exactly one dot-separated segment, `#` = zero
+/// or more segments).
+pub fn topic_matches(pattern: &str, topic: &str) -> bool {
+    let pattern_segments: Vec<&str> = pattern.split('.').collect();
+    let topic_segments: Vec<&str> = topic.split('.').collect();
+    matches_segments(&pattern_segments, &topic_segments)
+}
+
+fn matches_segments(pattern: &[&str], topic: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => topic.is_empty(),
+        Some((&"#", rest)) => {
+            (0..=topic.len()).any(|skip| matches_segments(rest, &topic[skip..]))
+        }
+        Some((&"*", rest)) => !topic.is_empty() && matches_segments(rest, &topic[1..]),
+        Some((segment, rest)) => topic.first() == Some(segment) && matches_segments(rest, &topic[1..]),
+    }
+}
+
+struct Registration {
+    pattern: String,
+    queue: Arc<Mutex<VecDeque<Message>>>,
+}
+
+/// A message parked until `deliver_at`, ordered by that deadline so the
+/// heap below can always pop the next-due entry first.
+///
+/// The deadline is tracked as a [`tokio::time::Instant`] rather than a
+/// [`SystemTime`] even though [`Broker::publish_at`] takes wall-clock
+/// time: `Instant` is what `tokio::time::sleep` schedules against, and
+/// under `#[tokio::test(start_paused = true)]` it's the only clock that
+/// advances with `tokio::time::advance` — `SystemTime` keeps ticking in
+/// real time regardless, which would desync the two.
+struct DelayedEntry {
+    deliver_at: Instant,
+    message: Message,
+}
+
+impl PartialEq for DelayedEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.deliver_at == other.deliver_at
+    }
+}
+
+impl Eq for DelayedEntry {}
+
+impl PartialOrd for DelayedEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DelayedEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.deliver_at.cmp(&other.deliver_at)
+    }
+}
+
+#[derive(Default)]
+struct Inner {
+    subscriptions: Mutex<Vec<Registration>>,
+    // A min-heap (via `Reverse`) of not-yet-due messages. At this scale
+    // this is functionally equivalent to a timer wheel; it's just a
+    // heap because rustboot-messaging doesn't need a literal wheel's
+    // O(1) insert to stay fast enough for an in-process bus.
+    delayed: Mutex<BinaryHeap<Reverse<DelayedEntry>>>,
+    connected: AtomicBool,
+    // Wakes the background timer loop early when a message with an
+    // earlier deadline than the one it's currently sleeping for is
+    // published.
+    wake: Notify,
+}
+
+fn deliver(inner: &Inner, message: &Message) {
+    for registration in inner.subscriptions.lock().unwrap().iter() {
+        if topic_matches(&registration.pattern, &message.topic) {
+            registration.queue.lock().unwrap().push_back(message.clone());
+        }
+    }
+}
+
+/// Pops and delivers every entry already due, returning the duration
+/// until the next one (if any are left waiting).
+fn deliver_due(inner: &Inner) -> Option<std::time::Duration> {
+    loop {
+        // Only the delayed-queue lock is held while deciding what's due;
+        // it's released before `deliver` takes the subscriptions lock,
+        // since the two are never needed at once.
+        let due = {
+            let mut delayed = inner.delayed.lock().unwrap();
+            match delayed.peek() {
+                Some(Reverse(entry)) => {
+                    let now = Instant::now();
+                    if entry.deliver_at > now {
+                        return Some(entry.deliver_at - now);
+                    }
+                    Some(delayed.pop().unwrap().0)
+                }
+                None => None,
+            }
+        };
+        match due {
+            Some(entry) => deliver(inner, &entry.message),
+            None => return None,
+        }
+    }
+}
+
+/// An in-process, single-node [`Broker`]. [`InMemoryBus::subscribe`]
+/// registers a topic pattern and returns a [`Subscription`] to pull
+/// delivered messages from, mirroring the pull-based consumption model
+/// [`crate::core::kafka::KafkaBroker::poll`] uses for a real broker.
+///
+/// [`Broker::publish_at`]/[`Broker::publish_delayed`] are overridden
+/// here with a native delay queue instead of the default's
+/// blocking-sleep fallback: the first delayed publish lazily spawns a
+/// background task (holding a [`Weak`] reference to the bus's shared
+/// state between wake-ups, and woken when the bus is dropped, so it
+/// exits then) that wakes for each deadline in turn and delivers
+/// messages as they come due.
+#[derive(Default)]
+pub struct InMemoryBus {
+    inner: Arc<Inner>,
+    timer_started: AtomicBool,
+}
+
+impl InMemoryBus {
+    /// Creates a bus with no subscriptions, not connected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribes to every topic matching `pattern` (an exact topic
+    /// name, or one containing `*`/`#` wildcards). [`Broker::publish`]
+    /// delivers a copy of each matching message to every subscription
+    /// registered at the time it's published — subscribing later
+    /// doesn't retroactively see earlier messages.
+    pub fn subscribe(&self, pattern: impl Into<String>) -> Subscription {
+        let queue = Arc::new(Mutex::new(VecDeque::new()));
+        self.inner.subscriptions.lock().unwrap().push(Registration {
+            pattern: pattern.into(),
+            queue: queue.clone(),
+        });
+        Subscription { queue }
+    }
+
+    fn ensure_timer_started(&self) {
+        if self.timer_started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let weak = Arc::downgrade(&self.inner);
+        tokio::spawn(run_timer(weak));
+    }
+
+    /// Shared by the [`Broker::publish_at`]/[`Broker::publish_delayed`]
+    /// overrides below: parks `message` on the delay queue and makes
+    /// sure the background timer is running and awake to notice it.
+    ///
+    /// `at` (wall-clock) is converted to a delay from now and re-anchored
+    /// to [`Instant::now`] immediately, so the entry's ordering is
+    /// unaffected by wall-clock skew after this call returns.
+    fn schedule(&self, message: Message, at: SystemTime) -> Result<(), MessagingError> {
+        let delay = at.duration_since(SystemTime::now()).unwrap_or_default();
+        let deliver_at = Instant::now() + delay;
+        self.inner
+            .delayed
+            .lock()
+            .unwrap()
+            .push(Reverse(DelayedEntry { deliver_at, message }));
+        self.ensure_timer_started();
+        self.inner.wake.notify_one();
+        Ok(())
+    }
+}
+
+impl Drop for InMemoryBus {
+    fn drop(&mut self) {
+        // `run_timer` holds a strong reference while it waits; waking it
+        // lets it release that reference and find the bus gone.
+        self.inner.wake.notify_one();
+    }
+}
+
+async fn run_timer(inner: Weak<Inner>) {
+    loop {
+        let Some(inner) = inner.upgrade() else {
+            return;
+        };
+        match deliver_due(&inner) {
+            Some(remaining) => {
+                tokio::select! {
+                    _ = tokio::time::sleep(remaining) => {}
+                    _ = inner.wake.notified() => {}
+                }
+            }
+            None => {
+                inner.wake.notified().await;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Broker for InMemoryBus {
+    async fn connect(&self) -> Result<(), MessagingError> {
+        self.inner.connected.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), MessagingError> {
+        self.inner.connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn publish(&self, message: Message) -> Result<(), MessagingError> {
+        deliver(&self.inner, &message);
+        Ok(())
+    }
+
+    async fn publish_at(&self, message: Message, at: SystemTime) -> Result<(), MessagingError> {
+        self.schedule(message, at)
+    }
+
+    async fn publish_delayed(&self, message: Message, delay: std::time::Duration) -> Result<(), MessagingError> {
+        self.schedule(message, SystemTime::now() + delay)
+    }
+
+    async fn declare_queue(&self, _config: QueueConfig) -> Result<(), MessagingError> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.connected.load(Ordering::Relaxed)
+    }
+}
+
+/// A pull handle for messages delivered to the pattern it was created
+/// with via [`InMemoryBus::subscribe`].
+pub struct Subscription {
+    queue: Arc<Mutex<VecDeque<Message>>>,
+}
+
+impl Subscription {
+    /// Drains every message delivered to this subscription since the
+    /// last poll, oldest first.
+    pub fn poll(&self) -> Vec<Message> {
+        self.queue.lock().unwrap().drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn single_star_matches_exactly_one_segment() {
+        assert!(topic_matches("orders.*", "orders.created"));
+        assert!(!topic_matches("orders.*", "orders.created.eu"));
+        assert!(!topic_matches("orders.*", "orders"));
+    }
+
+    #[test]
+    fn hash_matches_zero_or_more_trailing_segments() {
+        assert!(topic_matches("orders.#", "orders"));
+        assert!(topic_matches("orders.#", "orders.created"));
+        assert!(topic_matches("orders.#", "orders.created.eu"));
+        assert!(!topic_matches("orders.#", "shipments.created"));
+    }
+
+    #[test]
+    fn exact_pattern_matches_only_the_same_topic() {
+        assert!(topic_matches("orders.created", "orders.created"));
+        assert!(!topic_matches("orders.created", "orders.cancelled"));
+    }
+
+    #[tokio::test]
+    async fn a_subscriber_receives_only_messages_matching_its_pattern() {
+        let bus = InMemoryBus::new();
+        let orders = bus.subscribe("orders.*");
+
+        bus.publish(Message::new("orders.created", "a")).await.unwrap();
+        bus.publish(Message::new("shipments.created", "b")).await.unwrap();
+        bus.publish(Message::new("orders.created.eu", "c")).await.unwrap();
+
+        let received = orders.poll();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].payload, b"a");
+    }
+
+    #[tokio::test]
+    async fn every_matching_subscriber_gets_its_own_copy() {
+        let bus = InMemoryBus::new();
+        let all_orders = bus.subscribe("orders.#");
+        let created_only = bus.subscribe("orders.created");
+
+        bus.publish(Message::new("orders.created", "a")).await.unwrap();
+        bus.publish(Message::new("orders.cancelled", "b")).await.unwrap();
+
+        assert_eq!(all_orders.poll().len(), 2);
+        assert_eq!(created_only.poll().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn poll_drains_and_does_not_redeliver() {
+        let bus = InMemoryBus::new();
+        let sub = bus.subscribe("orders.created");
+        bus.publish(Message::new("orders.created", "a")).await.unwrap();
+
+        assert_eq!(sub.poll().len(), 1);
+        assert!(sub.poll().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tracks_connection_state() {
+        let bus = InMemoryBus::new();
+        assert!(!bus.is_connected());
+        bus.connect().await.unwrap();
+        assert!(bus.is_connected());
+        bus.disconnect().await.unwrap();
+        assert!(!bus.is_connected());
+    }
+
+    // These use `sleep` rather than `tokio::time::advance` to let paused
+    // time auto-advance to the next pending timer: `advance` jumps the
+    // clock without waiting for the background timer task to actually
+    // observe it, so a `sleep` due at or before the jump isn't
+    // guaranteed to have fired yet when `advance` returns.
+
+    #[tokio::test(start_paused = true)]
+    async fn a_delayed_message_is_not_delivered_until_its_delay_elapses() {
+        let bus = InMemoryBus::new();
+        let sub = bus.subscribe("orders.created");
+
+        bus.publish_delayed(Message::new("orders.created", "a"), Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert!(sub.poll().is_empty());
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert!(sub.poll().is_empty());
+
+        tokio::time::sleep(Duration::from_secs(6)).await;
+        assert_eq!(sub.poll().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn publish_at_with_a_past_timestamp_delivers_immediately() {
+        let bus = InMemoryBus::new();
+        let sub = bus.subscribe("orders.created");
+
+        let already_past = SystemTime::now() - Duration::from_secs(60);
+        bus.publish_at(Message::new("orders.created", "a"), already_past)
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert_eq!(sub.poll().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_messages_are_delivered_in_deadline_order() {
+        let bus = InMemoryBus::new();
+        let sub = bus.subscribe("orders.created");
+
+        bus.publish_delayed(Message::new("orders.created", "later"), Duration::from_secs(20))
+            .await
+            .unwrap();
+        bus.publish_delayed(Message::new("orders.created", "sooner"), Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_secs(6)).await;
+        let received = sub.poll();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].payload, b"sooner");
+
+        tokio::time::sleep(Duration::from_secs(15)).await;
+        let received = sub.poll();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].payload, b"later");
+    }
+}
@@ -0,0 +1,449 @@
+//! Kafka consumer support with manual/auto offset commit, seeking,
+//! rebalance callbacks, and consumer lag reporting.
+//!
+//! [`KafkaTransport`] abstracts over the underlying wire client so that
+//! `KafkaBroker` stays testable without linking a real Kafka client.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use rustboot_async::{run_until_cancelled, CancellationToken};
+
+use crate::api::MessagingError;
+
+/// A topic/partition pair, the unit of assignment and offset tracking in
+/// Kafka.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopicPartition {
+    /// Topic name.
+    pub topic: String,
+    /// Partition number within the topic.
+    pub partition: i32,
+}
+
+impl TopicPartition {
+    /// Creates a new topic/partition pair.
+    pub fn new(topic: impl Into<String>, partition: i32) -> Self {
+        Self {
+            topic: topic.into(),
+            partition,
+        }
+    }
+}
+
+/// A position to seek a partition to.
+#[derive(Debug, Clone, Copy)]
+pub enum Offset {
+    /// The earliest retained offset.
+    Earliest,
+    /// The next offset to be produced.
+    Latest,
+    /// A specific offset.
+    Exact(i64),
+    /// The earliest offset at or after this Unix timestamp (milliseconds).
+    Timestamp(i64),
+}
+
+/// A single record fetched from a partition.
+#[derive(Debug, Clone)]
+pub struct KafkaRecord {
+    /// Partition the record was read from.
+    pub partition: TopicPartition,
+    /// Offset of the record within its partition.
+    pub offset: i64,
+    /// Optional record key.
+    pub key: Option<Vec<u8>>,
+    /// Record payload.
+    pub payload: Vec<u8>,
+}
+
+/// Whether offsets are committed automatically after each poll, or only
+/// when [`KafkaBroker::commit`] is called explicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitMode {
+    /// Commit consumed offsets automatically after every successful poll.
+    Auto,
+    /// Offsets are only committed when the caller invokes
+    /// [`KafkaBroker::commit`].
+    Manual,
+}
+
+/// Configuration for a [`KafkaBroker`] consumer.
+#[derive(Debug, Clone)]
+pub struct KafkaConsumerConfig {
+    /// Consumer group id.
+    pub group_id: String,
+    /// Topics to consume from.
+    pub topics: Vec<String>,
+    /// Whether offsets commit automatically or manually.
+    pub commit_mode: CommitMode,
+}
+
+/// Low-level operations a concrete Kafka client must provide.
+///
+/// Real integrations implement this over a wire protocol client; tests and
+/// examples can implement it directly for full control over fetched
+/// records and watermarks.
+#[async_trait]
+pub trait KafkaTransport: Send + Sync {
+    /// Fetches newly available records for the given assignment.
+    async fn poll(&self, assignment: &[TopicPartition]) -> Result<Vec<KafkaRecord>, MessagingError>;
+
+    /// Commits the given per-partition offsets.
+    async fn commit_offsets(
+        &self,
+        offsets: &HashMap<TopicPartition, i64>,
+    ) -> Result<(), MessagingError>;
+
+    /// Moves the consumer position for `partition` to `offset`.
+    async fn seek(&self, partition: &TopicPartition, offset: Offset) -> Result<(), MessagingError>;
+
+    /// Returns the high watermark (next offset to be produced) for `partition`.
+    async fn high_watermark(&self, partition: &TopicPartition) -> Result<i64, MessagingError>;
+
+    /// Returns the partitions currently assigned to this consumer.
+    async fn assignment(&self) -> Result<Vec<TopicPartition>, MessagingError>;
+}
+
+/// Notified when the consumer group rebalances.
+pub trait RebalanceListener: Send + Sync {
+    /// Called after partitions are assigned to this consumer.
+    fn on_partitions_assigned(&self, partitions: &[TopicPartition]) {
+        let _ = partitions;
+    }
+
+    /// Called before partitions are revoked from this consumer.
+    fn on_partitions_revoked(&self, partitions: &[TopicPartition]) {
+        let _ = partitions;
+    }
+}
+
+/// A Kafka consumer with manual/auto commit, seeking, rebalance
+/// callbacks, and lag reporting, built on top of a pluggable
+/// [`KafkaTransport`].
+pub struct KafkaBroker<T: KafkaTransport> {
+    transport: T,
+    config: KafkaConsumerConfig,
+    listeners: Vec<Box<dyn RebalanceListener>>,
+    assignment: Mutex<Vec<TopicPartition>>,
+    positions: Mutex<HashMap<TopicPartition, i64>>,
+}
+
+impl<T: KafkaTransport> KafkaBroker<T> {
+    /// Creates a new `KafkaBroker` over `transport`.
+    pub fn new(transport: T, config: KafkaConsumerConfig) -> Self {
+        Self {
+            transport,
+            config,
+            listeners: Vec::new(),
+            assignment: Mutex::new(Vec::new()),
+            positions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers a listener invoked on every consumer group rebalance.
+    pub fn add_rebalance_listener(&mut self, listener: impl RebalanceListener + 'static) {
+        self.listeners.push(Box::new(listener));
+    }
+
+    /// Refreshes the assignment from the transport, firing rebalance
+    /// callbacks for any partitions gained or lost.
+    pub async fn sync_assignment(&self) -> Result<(), MessagingError> {
+        let new_assignment = self.transport.assignment().await?;
+        let old_assignment = {
+            let mut guard = self.assignment.lock().unwrap();
+            std::mem::replace(&mut *guard, new_assignment.clone())
+        };
+
+        let revoked: Vec<_> = old_assignment
+            .iter()
+            .filter(|tp| !new_assignment.contains(tp))
+            .cloned()
+            .collect();
+        let assigned: Vec<_> = new_assignment
+            .iter()
+            .filter(|tp| !old_assignment.contains(tp))
+            .cloned()
+            .collect();
+
+        if !revoked.is_empty() {
+            for listener in &self.listeners {
+                listener.on_partitions_revoked(&revoked);
+            }
+            let mut positions = self.positions.lock().unwrap();
+            for tp in &revoked {
+                positions.remove(tp);
+            }
+        }
+        if !assigned.is_empty() {
+            for listener in &self.listeners {
+                listener.on_partitions_assigned(&assigned);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Polls the current assignment for new records, advancing local
+    /// offset positions and auto-committing if [`CommitMode::Auto`] is
+    /// configured.
+    pub async fn poll(&self) -> Result<Vec<KafkaRecord>, MessagingError> {
+        let assignment = self.assignment.lock().unwrap().clone();
+        let records = self.transport.poll(&assignment).await?;
+
+        {
+            let mut positions = self.positions.lock().unwrap();
+            for record in &records {
+                positions.insert(record.partition.clone(), record.offset + 1);
+            }
+        }
+
+        if self.config.commit_mode == CommitMode::Auto && !records.is_empty() {
+            self.commit().await?;
+        }
+
+        Ok(records)
+    }
+
+    /// Commits the current local offset positions to the broker.
+    ///
+    /// Required after every [`poll`](Self::poll) when running in
+    /// [`CommitMode::Manual`]; a no-op there would lose progress on
+    /// restart.
+    pub async fn commit(&self) -> Result<(), MessagingError> {
+        let positions = self.positions.lock().unwrap().clone();
+        if positions.is_empty() {
+            return Ok(());
+        }
+        self.transport.commit_offsets(&positions).await
+    }
+
+    /// Seeks `partition` to an explicit offset.
+    pub async fn seek_to_offset(
+        &self,
+        partition: &TopicPartition,
+        offset: i64,
+    ) -> Result<(), MessagingError> {
+        self.transport.seek(partition, Offset::Exact(offset)).await?;
+        self.positions
+            .lock()
+            .unwrap()
+            .insert(partition.clone(), offset);
+        Ok(())
+    }
+
+    /// Seeks `partition` to the earliest offset at or after `timestamp_ms`.
+    pub async fn seek_to_timestamp(
+        &self,
+        partition: &TopicPartition,
+        timestamp_ms: i64,
+    ) -> Result<(), MessagingError> {
+        self.transport
+            .seek(partition, Offset::Timestamp(timestamp_ms))
+            .await
+    }
+
+    /// Returns the consumer lag (high watermark minus current position)
+    /// for every assigned partition.
+    pub async fn lag(&self) -> Result<HashMap<TopicPartition, i64>, MessagingError> {
+        let assignment = self.assignment.lock().unwrap().clone();
+        let positions = self.positions.lock().unwrap().clone();
+
+        let mut lag = HashMap::with_capacity(assignment.len());
+        for tp in &assignment {
+            let watermark = self.transport.high_watermark(tp).await?;
+            let position = positions.get(tp).copied().unwrap_or(0);
+            lag.insert(tp.clone(), (watermark - position).max(0));
+        }
+        Ok(lag)
+    }
+
+    /// Polls in a loop, passing each non-empty batch to `handler` and
+    /// committing under [`CommitMode::Manual`] once it returns `Ok`,
+    /// until `token` is cancelled.
+    ///
+    /// A poll already in flight when `token` is cancelled is abandoned
+    /// rather than awaited to completion, so shutdown isn't held up
+    /// waiting on a broker that's slow or unreachable.
+    pub async fn run_until_cancelled<F, Fut>(
+        &self,
+        token: &CancellationToken,
+        mut handler: F,
+    ) -> Result<(), MessagingError>
+    where
+        F: FnMut(Vec<KafkaRecord>) -> Fut,
+        Fut: Future<Output = Result<(), MessagingError>>,
+    {
+        while !token.is_cancelled() {
+            let records = match run_until_cancelled(self.poll(), token).await {
+                Some(records) => records?,
+                None => break,
+            };
+            if records.is_empty() {
+                continue;
+            }
+
+            handler(records).await?;
+            if self.config.commit_mode == CommitMode::Manual {
+                self.commit().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the configured consumer group id.
+    pub fn group_id(&self) -> &str {
+        &self.config.group_id
+    }
+
+    /// Returns the configured topic subscriptions.
+    pub fn topics(&self) -> &[String] {
+        &self.config.topics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct FakeTransport {
+        watermark: AtomicI64,
+    }
+
+    #[async_trait]
+    impl KafkaTransport for FakeTransport {
+        async fn poll(&self, assignment: &[TopicPartition]) -> Result<Vec<KafkaRecord>, MessagingError> {
+            let tp = assignment.first().cloned().unwrap();
+            Ok(vec![KafkaRecord {
+                partition: tp,
+                offset: 0,
+                key: None,
+                payload: b"hi".to_vec(),
+            }])
+        }
+
+        async fn commit_offsets(
+            &self,
+            _offsets: &HashMap<TopicPartition, i64>,
+        ) -> Result<(), MessagingError> {
+            Ok(())
+        }
+
+        async fn seek(&self, _partition: &TopicPartition, _offset: Offset) -> Result<(), MessagingError> {
+            Ok(())
+        }
+
+        async fn high_watermark(&self, _partition: &TopicPartition) -> Result<i64, MessagingError> {
+            Ok(self.watermark.load(Ordering::SeqCst))
+        }
+
+        async fn assignment(&self) -> Result<Vec<TopicPartition>, MessagingError> {
+            Ok(vec![TopicPartition::new("orders", 0)])
+        }
+    }
+
+    fn broker() -> KafkaBroker<FakeTransport> {
+        KafkaBroker::new(
+            FakeTransport {
+                watermark: AtomicI64::new(5),
+            },
+            KafkaConsumerConfig {
+                group_id: "test-group".into(),
+                topics: vec!["orders".into()],
+                commit_mode: CommitMode::Manual,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn tracks_lag_after_poll() {
+        let broker = broker();
+        broker.sync_assignment().await.unwrap();
+        broker.poll().await.unwrap();
+
+        let lag = broker.lag().await.unwrap();
+        assert_eq!(lag[&TopicPartition::new("orders", 0)], 4);
+    }
+
+    #[tokio::test]
+    async fn rebalance_listener_sees_assignment() {
+        struct Recorder {
+            assigned: Mutex<Vec<TopicPartition>>,
+        }
+
+        impl RebalanceListener for Recorder {
+            fn on_partitions_assigned(&self, partitions: &[TopicPartition]) {
+                self.assigned.lock().unwrap().extend_from_slice(partitions);
+            }
+        }
+
+        let mut broker = broker();
+        let recorder = std::sync::Arc::new(Recorder {
+            assigned: Mutex::new(Vec::new()),
+        });
+
+        struct Forwarder(std::sync::Arc<Recorder>);
+        impl RebalanceListener for Forwarder {
+            fn on_partitions_assigned(&self, partitions: &[TopicPartition]) {
+                self.0.on_partitions_assigned(partitions);
+            }
+        }
+
+        broker.add_rebalance_listener(Forwarder(recorder.clone()));
+        broker.sync_assignment().await.unwrap();
+
+        assert_eq!(recorder.assigned.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn seek_to_offset_updates_position() {
+        let broker = broker();
+        broker.sync_assignment().await.unwrap();
+        let tp = TopicPartition::new("orders", 0);
+
+        broker.seek_to_offset(&tp, 3).await.unwrap();
+        let lag = broker.lag().await.unwrap();
+        assert_eq!(lag[&tp], 2);
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled_stops_once_the_token_is_cancelled() {
+        let broker = broker();
+        broker.sync_assignment().await.unwrap();
+
+        let token = CancellationToken::new();
+        let token_for_handler = token.clone();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_for_handler = calls.clone();
+
+        broker
+            .run_until_cancelled(&token, move |records| {
+                assert_eq!(records.len(), 1);
+                calls_for_handler.fetch_add(1, Ordering::SeqCst);
+                token_for_handler.cancel();
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled_returns_immediately_for_an_already_cancelled_token() {
+        let broker = broker();
+        broker.sync_assignment().await.unwrap();
+
+        let token = CancellationToken::new();
+        token.cancel();
+
+        broker
+            .run_until_cancelled(&token, |_records| async { Ok(()) })
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,7 @@
+//! Implementation details for the messaging module.
+
+pub mod dedup;
+pub mod in_memory;
+pub mod kafka;
+pub mod rabbitmq;
+pub mod typed;
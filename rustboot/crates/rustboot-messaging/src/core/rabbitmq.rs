@@ -0,0 +1,277 @@
+//! RabbitMQ broker support with publisher confirms, mandatory-return
+//! handling, quorum queue declaration, and channel pooling.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::oneshot;
+
+use crate::api::{Message, MessagingError};
+
+/// Options for declaring a queue, including RabbitMQ's quorum queue and
+/// dead-letter/TTL/length-limit policy arguments.
+#[derive(Debug, Clone)]
+pub struct QueueDeclareOptions {
+    /// Queue name.
+    pub name: String,
+    /// Declares a quorum queue instead of a classic mirrored queue.
+    pub quorum: bool,
+    /// Per-message time-to-live.
+    pub ttl: Option<Duration>,
+    /// Maximum number of messages the queue retains.
+    pub max_length: Option<u32>,
+    /// Dead-letter exchange for expired/rejected/overflowed messages.
+    pub dead_letter_exchange: Option<String>,
+}
+
+impl QueueDeclareOptions {
+    /// Creates options for a durable quorum queue with no limits.
+    pub fn quorum(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            quorum: true,
+            ttl: None,
+            max_length: None,
+            dead_letter_exchange: None,
+        }
+    }
+}
+
+/// Low-level operations a concrete RabbitMQ client must provide.
+#[async_trait]
+pub trait RabbitMqTransport: Send + Sync {
+    /// Number of channels available for pooling.
+    fn channel_count(&self) -> usize;
+
+    /// Publishes `message` on `channel`, returning its publisher-confirm
+    /// delivery tag.
+    async fn publish(&self, channel: usize, message: &Message) -> Result<u64, MessagingError>;
+
+    /// Declares a queue on `channel` with the given options.
+    async fn declare_queue(
+        &self,
+        channel: usize,
+        options: &QueueDeclareOptions,
+    ) -> Result<(), MessagingError>;
+}
+
+/// Tracks in-flight publisher confirms by delivery tag.
+#[derive(Default)]
+struct ConfirmTracker {
+    pending: Mutex<HashMap<u64, oneshot::Sender<bool>>>,
+}
+
+impl ConfirmTracker {
+    fn register(&self, tag: u64) -> oneshot::Receiver<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(tag, tx);
+        rx
+    }
+
+    fn resolve(&self, tag: u64, acked: bool) {
+        if let Some(tx) = self.pending.lock().unwrap().remove(&tag) {
+            let _ = tx.send(acked);
+        }
+    }
+}
+
+/// Round-robins publishes and queue operations across a fixed set of
+/// channels, avoiding the overhead of opening a channel per operation.
+struct ChannelPool {
+    size: usize,
+    next: AtomicUsize,
+}
+
+impl ChannelPool {
+    fn new(size: usize) -> Self {
+        Self {
+            size: size.max(1),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    fn acquire(&self) -> usize {
+        self.next.fetch_add(1, Ordering::Relaxed) % self.size
+    }
+}
+
+/// A callback invoked with a mandatory-flagged message the broker could
+/// not route to any queue.
+type ReturnHandler = Box<dyn Fn(Message) + Send + Sync>;
+
+/// A RabbitMQ broker with publisher confirms, mandatory-return handling,
+/// quorum queue support, and channel pooling, built on top of a pluggable
+/// [`RabbitMqTransport`].
+pub struct RabbitMqBroker<T: RabbitMqTransport> {
+    transport: T,
+    confirms: Arc<ConfirmTracker>,
+    channels: ChannelPool,
+    return_handler: Mutex<Option<ReturnHandler>>,
+}
+
+impl<T: RabbitMqTransport> RabbitMqBroker<T> {
+    /// Creates a new `RabbitMqBroker` over `transport`.
+    pub fn new(transport: T) -> Self {
+        let channels = ChannelPool::new(transport.channel_count());
+        Self {
+            transport,
+            confirms: Arc::new(ConfirmTracker::default()),
+            channels,
+            return_handler: Mutex::new(None),
+        }
+    }
+
+    /// Registers a handler invoked whenever a mandatory-flagged publish is
+    /// returned to the publisher as unroutable.
+    pub fn on_mandatory_return(&self, handler: impl Fn(Message) + Send + Sync + 'static) {
+        *self.return_handler.lock().unwrap() = Some(Box::new(handler));
+    }
+
+    /// Publishes `message` and awaits its publisher confirm, failing with
+    /// a timeout error if the broker does not ack or nack within
+    /// `timeout`.
+    ///
+    /// This is the only way to claim at-least-once publishing: without a
+    /// confirm, a message can be silently dropped on a connection failure
+    /// between the client write and the broker's persistence of it.
+    pub async fn publish_confirmed(
+        &self,
+        message: Message,
+        timeout: Duration,
+    ) -> Result<(), MessagingError> {
+        let channel = self.channels.acquire();
+        let tag = self.transport.publish(channel, &message).await?;
+        let confirm = self.confirms.register(tag);
+
+        match tokio::time::timeout(timeout, confirm).await {
+            Ok(Ok(true)) => Ok(()),
+            Ok(Ok(false)) => Err(MessagingError::Operation(format!(
+                "publish nacked by broker (delivery tag {tag})"
+            ))),
+            Ok(Err(_)) => Err(MessagingError::Operation(
+                "confirm channel dropped before resolution".into(),
+            )),
+            Err(_) => Err(MessagingError::Operation(format!(
+                "publisher confirm timed out after {timeout:?} (delivery tag {tag})"
+            ))),
+        }
+    }
+
+    /// Resolves a pending publisher confirm as acked. Called by the
+    /// transport's event loop when the broker acks a delivery tag.
+    pub fn handle_ack(&self, delivery_tag: u64) {
+        self.confirms.resolve(delivery_tag, true);
+    }
+
+    /// Resolves a pending publisher confirm as nacked. Called by the
+    /// transport's event loop when the broker nacks a delivery tag.
+    pub fn handle_nack(&self, delivery_tag: u64) {
+        self.confirms.resolve(delivery_tag, false);
+    }
+
+    /// Invokes the mandatory-return handler, if one is registered, for a
+    /// message the broker could not route to any queue.
+    pub fn handle_return(&self, message: Message) {
+        if let Some(handler) = self.return_handler.lock().unwrap().as_ref() {
+            handler(message);
+        }
+    }
+
+    /// Declares a queue, including quorum/TTL/length-limit/DLX options.
+    pub async fn declare_queue(&self, options: QueueDeclareOptions) -> Result<(), MessagingError> {
+        let channel = self.channels.acquire();
+        self.transport.declare_queue(channel, &options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU64;
+
+    struct FakeTransport {
+        channels: usize,
+        next_tag: AtomicU64,
+    }
+
+    #[async_trait]
+    impl RabbitMqTransport for FakeTransport {
+        fn channel_count(&self) -> usize {
+            self.channels
+        }
+
+        async fn publish(&self, _channel: usize, _message: &Message) -> Result<u64, MessagingError> {
+            Ok(self.next_tag.fetch_add(1, Ordering::SeqCst))
+        }
+
+        async fn declare_queue(
+            &self,
+            _channel: usize,
+            _options: &QueueDeclareOptions,
+        ) -> Result<(), MessagingError> {
+            Ok(())
+        }
+    }
+
+    fn broker(channels: usize) -> RabbitMqBroker<FakeTransport> {
+        RabbitMqBroker::new(FakeTransport {
+            channels,
+            next_tag: AtomicU64::new(1),
+        })
+    }
+
+    #[tokio::test]
+    async fn publish_confirmed_resolves_on_ack() {
+        let broker = Arc::new(broker(2));
+        let b = broker.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            b.handle_ack(1);
+        });
+
+        let result = broker
+            .publish_confirmed(Message::new("orders", b"hi".to_vec()), Duration::from_secs(1))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn publish_confirmed_fails_on_nack() {
+        let broker = Arc::new(broker(2));
+        let b = broker.clone();
+        tokio::spawn(async move {
+            b.handle_nack(1);
+        });
+
+        let result = broker
+            .publish_confirmed(Message::new("orders", b"hi".to_vec()), Duration::from_secs(1))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn publish_confirmed_times_out_without_resolution() {
+        let broker = broker(2);
+        let result = broker
+            .publish_confirmed(Message::new("orders", b"hi".to_vec()), Duration::from_millis(10))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn mandatory_return_handler_is_invoked() {
+        let broker = broker(1);
+        let seen = Arc::new(Mutex::new(None));
+        let seen_clone = seen.clone();
+        broker.on_mandatory_return(move |msg| {
+            *seen_clone.lock().unwrap() = Some(msg.topic);
+        });
+
+        broker.handle_return(Message::new("unroutable", b"x".to_vec()));
+
+        assert_eq!(seen.lock().unwrap().as_deref(), Some("unroutable"));
+    }
+}
@@ -0,0 +1,93 @@
+//! Publishes [`Event`]s (typically `#[derive(rustboot_messaging_derive::Event)]`
+//! structs) to a [`Broker`] without the caller naming a topic string at
+//! every call site.
+
+use serde::Serialize;
+
+use crate::api::{Message, MessagingError};
+use crate::spi::{Broker, Event};
+
+/// The header [`TypedPublisher::publish`] carries an event's
+/// [`Event::schema_version`] in.
+pub const SCHEMA_VERSION_HEADER: &str = "schema-version";
+
+/// Wraps a [`Broker`] to publish [`Event`]s by their own topic, routing
+/// key, and schema version instead of a hand-built [`Message`].
+pub struct TypedPublisher<B> {
+    broker: B,
+}
+
+impl<B: Broker> TypedPublisher<B> {
+    /// Wraps `broker` for typed publishing.
+    pub fn new(broker: B) -> Self {
+        Self { broker }
+    }
+
+    /// Serializes `event` as its JSON payload and publishes it to
+    /// [`Event::topic`], keyed by [`Event::routing_key`], with its
+    /// [`Event::schema_version`] carried in the [`SCHEMA_VERSION_HEADER`]
+    /// header.
+    pub async fn publish<E: Event + Serialize>(&self, event: &E) -> Result<(), MessagingError> {
+        let payload = serde_json::to_vec(event)
+            .map_err(|err| MessagingError::Operation(format!("failed to serialize event: {err}")))?;
+        let message = Message::new(event.topic(), payload)
+            .with_key(event.routing_key())
+            .with_header(SCHEMA_VERSION_HEADER, event.schema_version().to_string())
+            .with_content_type("application/json");
+        self.broker.publish(message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+    use crate::core::in_memory::InMemoryBus;
+
+    #[derive(Serialize)]
+    struct OrderCreated {
+        order_id: String,
+        total_cents: u64,
+    }
+
+    impl Event for OrderCreated {
+        fn topic(&self) -> &'static str {
+            "orders.created"
+        }
+
+        fn routing_key(&self) -> String {
+            self.order_id.clone()
+        }
+
+        fn schema_version(&self) -> u32 {
+            2
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_sends_json_payload_keyed_and_versioned() {
+        let bus = InMemoryBus::new();
+        let sub = bus.subscribe("orders.created");
+        let publisher = TypedPublisher::new(bus);
+
+        publisher
+            .publish(&OrderCreated {
+                order_id: "ord-1".to_string(),
+                total_cents: 1999,
+            })
+            .await
+            .unwrap();
+
+        let received = sub.poll();
+        assert_eq!(received.len(), 1);
+        let message = &received[0];
+        assert_eq!(message.topic, "orders.created");
+        assert_eq!(message.key.as_deref(), Some("ord-1"));
+        assert_eq!(message.headers.get(SCHEMA_VERSION_HEADER).map(String::as_str), Some("2"));
+
+        let decoded: serde_json::Value = serde_json::from_slice(&message.payload).unwrap();
+        assert_eq!(decoded["order_id"], "ord-1");
+        assert_eq!(decoded["total_cents"], 1999);
+    }
+}
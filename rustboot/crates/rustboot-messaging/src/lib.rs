@@ -0,0 +1,42 @@
+//! Message broker abstractions for the rustboot framework.
+//!
+//! - [`spi::Broker`]: the service provider interface concrete transports
+//!   (Kafka, RabbitMQ, an in-memory bus, ...) implement.
+//! - [`core::kafka`]: a Kafka consumer with manual/auto offset commit,
+//!   seeking, rebalance callbacks, and lag reporting.
+//!   [`KafkaBroker::run_until_cancelled`] drives the poll loop until a
+//!   `rustboot_async::CancellationToken` shared with the rest of the app
+//!   is cancelled.
+//! - [`core::dedup::DedupSubscriber`]: an idempotent-consumer helper
+//!   that skips a message whose ID (an identifying header, or a hash of
+//!   its payload) was already seen within a configurable window, backed
+//!   by any [`rustboot_cache::spi::Cache`] — for at-least-once brokers
+//!   that redeliver.
+//! - [`core::in_memory::InMemoryBus`]: an in-process [`Broker`] with
+//!   hierarchical-topic pub/sub, so event routing doesn't require
+//!   subscribing to every concrete topic name (`orders.*`, `orders.#`
+//!   wildcards) — for tests and single-process deployments. It also
+//!   overrides [`Broker::publish_at`]/[`Broker::publish_delayed`] with a
+//!   native delay queue instead of the default trait methods' blocking
+//!   sleep.
+//! - [`spi::Event`] / `#[derive(Event)]`: gives a struct a fixed topic,
+//!   routing key, and schema version from a struct-level
+//!   `#[event(topic = "...", version = N)]` attribute, so
+//!   [`core::typed::TypedPublisher`] can publish it without the topic
+//!   name being repeated as a bare string at every call site.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{ConsumerConfig, Message, MessagingError, QueueConfig};
+pub use core::dedup::DedupSubscriber;
+pub use core::in_memory::{topic_matches, InMemoryBus, Subscription};
+pub use core::kafka::{
+    CommitMode, KafkaBroker, KafkaConsumerConfig, KafkaRecord, KafkaTransport, Offset,
+    RebalanceListener, TopicPartition,
+};
+pub use core::rabbitmq::{QueueDeclareOptions, RabbitMqBroker, RabbitMqTransport};
+pub use core::typed::{TypedPublisher, SCHEMA_VERSION_HEADER};
+pub use rustboot_messaging_derive::Event;
+pub use spi::{Broker, Event};
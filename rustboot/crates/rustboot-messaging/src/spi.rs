@@ -0,0 +1,92 @@
+//! Service provider interfaces for the messaging module.
+//!
+//! Implement [`Broker`] to integrate a concrete transport (Kafka, RabbitMQ,
+//! an in-memory bus, ...) with the rest of rustboot-messaging.
+
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+
+use crate::api::{Message, MessagingError, QueueConfig};
+
+/// A message broker connection.
+///
+/// Implement this for Kafka, RabbitMQ, etc.
+#[async_trait]
+pub trait Broker: Send + Sync {
+    /// Connects to the broker.
+    async fn connect(&self) -> Result<(), MessagingError>;
+
+    /// Disconnects from the broker.
+    async fn disconnect(&self) -> Result<(), MessagingError>;
+
+    /// Publishes a single message.
+    async fn publish(&self, message: Message) -> Result<(), MessagingError>;
+
+    /// Publishes `message` at `at`, a point in time. The default
+    /// implementation delegates to [`Broker::publish_delayed`] with the
+    /// duration remaining until `at` (zero if `at` has already passed);
+    /// override this directly for a broker with native scheduled-delivery
+    /// support (e.g. [`crate::core::in_memory::InMemoryBus`]'s internal
+    /// timer, or a Redis sorted-set-backed transport).
+    async fn publish_at(&self, message: Message, at: SystemTime) -> Result<(), MessagingError> {
+        let delay = at.duration_since(SystemTime::now()).unwrap_or_default();
+        self.publish_delayed(message, delay).await
+    }
+
+    /// Publishes `message` after `delay` elapses. The default
+    /// implementation blocks the caller for `delay` then calls
+    /// [`Broker::publish`]; override this (or [`Broker::publish_at`]) for
+    /// a broker that can schedule delivery without holding the caller's
+    /// task open for the whole delay (RabbitMQ's delayed-message
+    /// exchange, Redis sorted sets, ...).
+    async fn publish_delayed(&self, message: Message, delay: Duration) -> Result<(), MessagingError> {
+        tokio::time::sleep(delay).await;
+        self.publish(message).await
+    }
+
+    /// Declares a queue.
+    async fn declare_queue(&self, config: QueueConfig) -> Result<(), MessagingError>;
+
+    /// Returns whether the broker connection is currently live.
+    fn is_connected(&self) -> bool;
+}
+
+/// A domain event with a fixed topic, a routing key, and a schema
+/// version, so [`crate::core::typed::TypedPublisher`] can publish it
+/// without the caller naming its topic as a bare string at every call
+/// site.
+///
+/// `#[derive(rustboot_messaging::Event)]` implements this from a
+/// struct-level `#[event(topic = "...", version = N)]` attribute (and
+/// an optional `#[event(key)]` on one field for the routing key):
+///
+/// ```
+/// use rustboot_messaging::Event;
+/// use serde::Serialize;
+///
+/// #[derive(Event, Serialize)]
+/// #[event(topic = "orders.created", version = 2)]
+/// struct OrderCreated {
+///     #[event(key)]
+///     order_id: String,
+///     total_cents: u64,
+/// }
+///
+/// let event = OrderCreated { order_id: "ord-1".to_string(), total_cents: 1999 };
+/// assert_eq!(event.topic(), "orders.created");
+/// assert_eq!(event.routing_key(), "ord-1");
+/// assert_eq!(event.schema_version(), 2);
+/// ```
+pub trait Event {
+    /// The topic this event is always published to.
+    fn topic(&self) -> &'static str;
+
+    /// The routing/partition key for this event instance.
+    fn routing_key(&self) -> String;
+
+    /// The schema version this event was produced under, carried as a
+    /// message header so a consumer can branch on it during a
+    /// migration between event shapes.
+    fn schema_version(&self) -> u32;
+}
@@ -0,0 +1,238 @@
+//! Attribute macros for `rustboot_observability::core::recorder` and
+//! `rustboot_observability::core::metrics_registry`.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, Ident, ItemFn, LitStr, Pat};
+
+/// Wraps an async function so every call emits a
+/// [`SpanRecord`](../rustboot_observability/api/struct.SpanRecord.html)
+/// to the globally installed
+/// [`Recorder`](../rustboot_observability/spi/trait.Recorder.html) (see
+/// `rustboot_observability::core::recorder::install_global_recorder`),
+/// carrying the function name, module, call duration, and
+/// [`SpanOutcome::Success`](../rustboot_observability/api/enum.SpanOutcome.html)/`Failure`.
+///
+/// Applies only to an `async fn` returning `Result<T, E>`:
+///
+/// ```ignore
+/// #[timed]
+/// async fn fetch_order(id: &str) -> Result<Order, OrderError> {
+///     // ...
+/// }
+/// ```
+///
+/// For the same instrumentation plus opt-in argument capture, use
+/// [`macro@traced`] instead.
+#[proc_macro_attribute]
+pub fn timed(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(proc_macro2::Span::call_site(), "#[timed] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let func = parse_macro_input!(item as ItemFn);
+    build_span_fn(func, false).into()
+}
+
+/// Identical to [`macro@timed`], with optional argument capture: add
+/// `#[traced(args)]` to also `Debug`-format every call argument into
+/// [`SpanRecord::args`](../rustboot_observability/api/struct.SpanRecord.html#structfield.args)
+/// as `name=value` pairs. Plain `#[traced]` behaves exactly like
+/// `#[timed]`.
+///
+/// Every argument type must implement `Debug` when `args` is used.
+#[proc_macro_attribute]
+pub fn traced(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let capture_args = if attr.is_empty() {
+        false
+    } else {
+        match syn::parse::<Ident>(attr) {
+            Ok(ident) if ident == "args" => true,
+            Ok(ident) => {
+                return syn::Error::new_spanned(ident, "#[traced] only accepts `args`")
+                    .to_compile_error()
+                    .into();
+            }
+            Err(err) => return err.to_compile_error().into(),
+        }
+    };
+    let func = parse_macro_input!(item as ItemFn);
+    build_span_fn(func, capture_args).into()
+}
+
+/// Wraps an async function so every call records its wall-clock duration,
+/// in seconds, as an observation on a named histogram via
+/// [`rustboot_observability::core::metrics_registry::observe_histogram`]
+/// (see `rustboot_observability::core::metrics_registry::install_global_metrics`).
+///
+/// The metric name defaults to the function's name, or can be set
+/// explicitly: `#[metrics_histogram("order_checkout_seconds")]`.
+///
+/// ```ignore
+/// #[metrics_histogram]
+/// async fn checkout(order_id: &str) -> Result<Receipt, CheckoutError> {
+///     // ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn metrics_histogram(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let metric_name = if attr.is_empty() {
+        None
+    } else {
+        match syn::parse::<LitStr>(attr) {
+            Ok(lit) => Some(lit),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    };
+    let func = parse_macro_input!(item as ItemFn);
+    build_histogram_fn(func, metric_name).into()
+}
+
+fn build_histogram_fn(func: ItemFn, metric_name: Option<LitStr>) -> proc_macro2::TokenStream {
+    let vis = &func.vis;
+    let sig = &func.sig;
+    let block = &func.block;
+    let attrs = &func.attrs;
+    let fn_name = &sig.ident;
+
+    if sig.asyncness.is_none() {
+        return syn::Error::new_spanned(
+            sig,
+            "#[metrics_histogram] can only be applied to an `async fn`",
+        )
+        .to_compile_error();
+    }
+
+    let inner_name = format_ident!("__{}_histogram", fn_name);
+    let mut inner_sig = sig.clone();
+    inner_sig.ident = inner_name.clone();
+
+    let mut call_args = Vec::new();
+    for arg in &sig.inputs {
+        match arg {
+            FnArg::Receiver(_) => call_args.push(quote! { self }),
+            FnArg::Typed(pat_type) => match &*pat_type.pat {
+                Pat::Ident(pat_ident) => {
+                    let ident = &pat_ident.ident;
+                    call_args.push(quote! { #ident });
+                }
+                other => {
+                    return syn::Error::new_spanned(
+                        other,
+                        "#[metrics_histogram] requires simple argument names",
+                    )
+                    .to_compile_error();
+                }
+            },
+        }
+    }
+
+    let metric_name_expr = match metric_name {
+        Some(lit) => quote! { #lit },
+        None => quote! { stringify!(#fn_name) },
+    };
+
+    quote! {
+        #(#attrs)*
+        #vis #sig {
+            #[allow(non_snake_case)]
+            async #inner_sig #block
+
+            let __histogram_start = ::std::time::Instant::now();
+            let __histogram_result = #inner_name(#(#call_args),*).await;
+            ::rustboot_observability::core::metrics_registry::observe_histogram(
+                #metric_name_expr,
+                &[],
+                __histogram_start.elapsed().as_secs_f64(),
+            );
+            __histogram_result
+        }
+    }
+}
+
+fn build_span_fn(func: ItemFn, capture_args: bool) -> proc_macro2::TokenStream {
+    let vis = &func.vis;
+    let sig = &func.sig;
+    let block = &func.block;
+    let attrs = &func.attrs;
+    let fn_name = &sig.ident;
+
+    if sig.asyncness.is_none() {
+        return syn::Error::new_spanned(
+            sig,
+            "#[timed]/#[traced] can only be applied to an `async fn` returning `Result<T, E>`",
+        )
+        .to_compile_error();
+    }
+
+    let inner_name = format_ident!("__{}_spanned", fn_name);
+    let mut inner_sig = sig.clone();
+    inner_sig.ident = inner_name.clone();
+
+    let mut call_args = Vec::new();
+    let mut arg_capture = Vec::new();
+    for arg in &sig.inputs {
+        match arg {
+            syn::FnArg::Receiver(_) => call_args.push(quote! { self }),
+            syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) => {
+                    let ident = &pat_ident.ident;
+                    call_args.push(quote! { #ident });
+                    if capture_args {
+                        arg_capture.push(quote! {
+                            __span_args.push(format!("{}={:?}", stringify!(#ident), #ident));
+                        });
+                    }
+                }
+                other => {
+                    return syn::Error::new_spanned(
+                        other,
+                        "#[timed]/#[traced] requires simple argument names",
+                    )
+                    .to_compile_error();
+                }
+            },
+        }
+    }
+
+    let args_expr = if capture_args {
+        quote! {
+            {
+                let mut __span_args: Vec<String> = Vec::new();
+                #(#arg_capture)*
+                Some(__span_args.join(", "))
+            }
+        }
+    } else {
+        quote! { None }
+    };
+
+    quote! {
+        #(#attrs)*
+        #vis #sig {
+            #[allow(non_snake_case)]
+            async #inner_sig #block
+
+            let __span_start = ::std::time::Instant::now();
+            let __span_args = #args_expr;
+            let __span_result = #inner_name(#(#call_args),*).await;
+            let __span_outcome = if __span_result.is_ok() {
+                ::rustboot_observability::api::SpanOutcome::Success
+            } else {
+                ::rustboot_observability::api::SpanOutcome::Failure
+            };
+
+            ::rustboot_observability::core::recorder::record(::rustboot_observability::api::SpanRecord {
+                function: stringify!(#fn_name),
+                module: module_path!(),
+                args: __span_args,
+                duration: __span_start.elapsed(),
+                outcome: __span_outcome,
+                fields: ::std::collections::BTreeMap::new(),
+            });
+
+            __span_result
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Public types for the observability module.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Estimated latency percentiles for a recorded metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percentiles {
+    /// Median latency.
+    pub p50: Duration,
+    /// 95th percentile latency.
+    pub p95: Duration,
+    /// 99th percentile latency.
+    pub p99: Duration,
+    /// Number of samples the estimate is based on.
+    pub count: u64,
+}
+
+/// The outcome of a single function call instrumented with
+/// `#[timed]`/`#[traced]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanOutcome {
+    /// The function returned `Ok`.
+    Success,
+    /// The function returned `Err`.
+    Failure,
+}
+
+/// One function call recorded by `#[timed]`/`#[traced]` and forwarded to
+/// the installed [`crate::spi::Recorder`].
+#[derive(Debug, Clone)]
+pub struct SpanRecord {
+    /// The instrumented function's name.
+    pub function: &'static str,
+    /// The function's module path, as `module_path!()` sees it at the
+    /// call site.
+    pub module: &'static str,
+    /// `Debug`-formatted call arguments as `name=value` pairs, captured
+    /// only when opted into via `#[traced(args)]`. Always `None` for
+    /// `#[timed]` and for `#[traced]` without `args`.
+    pub args: Option<String>,
+    /// Wall-clock time the function body took to return.
+    pub duration: Duration,
+    /// Whether the call succeeded.
+    pub outcome: SpanOutcome,
+    /// The [`crate::core::context_fields::ContextFields`] active for the
+    /// current task when the call finished (session id, user id,
+    /// request id, ...), merged in by
+    /// [`crate::core::recorder::record`] so callers never set this
+    /// themselves.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// A [W3C `traceparent`](https://www.w3.org/TR/trace-context/) header
+/// value: which distributed trace a request belongs to, which span
+/// produced it, and whether it's being sampled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceContext {
+    /// The trace this request belongs to, shared across every service
+    /// it passes through.
+    pub trace_id: [u8; 16],
+    /// The id of the span that produced this request (the caller's span
+    /// when propagating outward, or the span this service should treat
+    /// as its parent when propagating inward).
+    pub parent_id: [u8; 8],
+    /// Whether the trace is being sampled (the `01` flag bit).
+    pub sampled: bool,
+}
+
+/// Errors from parsing a `traceparent` header.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum TraceContextError {
+    /// The header isn't four `-`-separated fields of the expected
+    /// lengths, or a trace/parent id is all zeros.
+    #[error("malformed traceparent header: '{0}'")]
+    Malformed(String),
+    /// The header's version field isn't `00`, the only version this
+    /// module understands.
+    #[error("unsupported traceparent version: '{0}'")]
+    UnsupportedVersion(String),
+}
+
+/// A log record's severity, ordered from least to most severe so a
+/// [`crate::core::logging::SamplingConfig`] can compare against a
+/// threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Fine-grained diagnostic detail, off by default in production.
+    Trace,
+    /// Diagnostic detail useful while developing or debugging.
+    Debug,
+    /// Normal operational events.
+    Info,
+    /// Unexpected but recoverable conditions.
+    Warn,
+    /// Failures that need attention.
+    Error,
+}
+
+/// One structured log entry, as passed to every configured
+/// [`crate::spi::LogSink`].
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    /// The record's severity.
+    pub level: LogLevel,
+    /// The human-readable log message.
+    pub message: String,
+    /// Structured fields attached to the message, after redaction rules
+    /// have been applied.
+    pub fields: std::collections::BTreeMap<String, String>,
+    /// Wall-clock time the record was created.
+    pub timestamp: std::time::SystemTime,
+}
+
+/// Output shape for a [`crate::core::logging::Logger`]'s rendered lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per line
+    /// (`{"timestamp":1700000000.000000,"level":"info","message":"...",...}`).
+    Json,
+    /// A human-readable single-line format, the timestamp in Unix
+    /// seconds (`1700000000.000000 INFO message key=value`).
+    Pretty,
+}
+
+/// Controls how often records below `always_log_at` are kept.
+#[derive(Debug, Clone, Copy)]
+pub struct SamplingConfig {
+    /// Fraction of records to keep, in `0.0..=1.0`. `1.0` keeps every
+    /// record; `0.0` drops every sampled record.
+    pub rate: f64,
+    /// Records at this level or more severe always bypass sampling.
+    pub always_log_at: LogLevel,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            always_log_at: LogLevel::Warn,
+        }
+    }
+}
+
+/// Errors from building or configuring a [`crate::core::logging::Logger`].
+#[derive(Debug, thiserror::Error)]
+pub enum LoggingError {
+    /// A configured value was syntactically present but not usable
+    /// (e.g. an unrecognized `logging.format`).
+    #[error("invalid logging configuration: {0}")]
+    InvalidConfig(String),
+    /// Reading a value from `rustboot-config` failed.
+    #[error("logging config error: {0}")]
+    Config(#[from] rustboot_config::ConfigError),
+    /// A rolling file sink could not open or rotate its file.
+    #[error("log file I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line handed to [`crate::core::log_parsing::parse_line`] didn't
+    /// match the expected [`LogFormat`].
+    #[error("malformed log line: {0}")]
+    Malformed(String),
+}
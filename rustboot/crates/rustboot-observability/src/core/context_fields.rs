@@ -0,0 +1,97 @@
+//! Task-local key/value fields, so middleware (session, auth,
+//! request-id, ...) can attach correlation data once and have it show
+//! up on every log line and [`crate::api::SpanRecord`] for the rest of
+//! that request's scope, without threading the fields through every
+//! function signature — the same task-local propagation
+//! [`crate::api::TraceContext`] uses for distributed tracing.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+
+tokio::task_local! {
+    static CONTEXT_FIELDS: BTreeMap<String, String>;
+}
+
+/// Task-local correlation fields attached by middleware and picked up
+/// automatically by [`crate::core::logging::Logger`] and
+/// `#[timed]`/`#[traced]` spans.
+pub struct ContextFields;
+
+impl ContextFields {
+    /// Returns the fields active for the currently running task, or an
+    /// empty map outside of any [`ContextFields::scope`].
+    pub fn current() -> BTreeMap<String, String> {
+        CONTEXT_FIELDS.try_with(|fields| fields.clone()).unwrap_or_default()
+    }
+
+    /// Runs `fut` with `fields` merged on top of whatever
+    /// [`ContextFields`] are already active, for its entire lifetime
+    /// including any further `tokio::spawn`ed subtasks that inherit or
+    /// extend the scope in turn.
+    ///
+    /// Call this from middleware at the point a piece of correlation
+    /// data becomes known (a session is resolved, a request id is
+    /// assigned) — nesting scopes lets each middleware layer add its
+    /// own fields on top of the ones outer layers already attached,
+    /// rather than one layer having to know about all the others.
+    pub async fn scope<F: Future>(fields: impl IntoIterator<Item = (String, String)>, fut: F) -> F::Output {
+        let mut merged = Self::current();
+        merged.extend(fields);
+        CONTEXT_FIELDS.scope(merged, fut).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_is_empty_outside_of_a_scope() {
+        assert!(ContextFields::current().is_empty());
+    }
+
+    #[tokio::test]
+    async fn scope_makes_fields_available_as_current() {
+        ContextFields::scope([("request_id".to_string(), "r-1".to_string())], async {
+            assert_eq!(ContextFields::current().get("request_id"), Some(&"r-1".to_string()));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn nested_scopes_merge_with_the_outer_scope() {
+        ContextFields::scope([("request_id".to_string(), "r-1".to_string())], async {
+            ContextFields::scope([("user_id".to_string(), "u-9".to_string())], async {
+                let fields = ContextFields::current();
+                assert_eq!(fields.get("request_id"), Some(&"r-1".to_string()));
+                assert_eq!(fields.get("user_id"), Some(&"u-9".to_string()));
+            })
+            .await;
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn an_inner_scope_can_override_an_outer_field() {
+        ContextFields::scope([("user_id".to_string(), "u-9".to_string())], async {
+            ContextFields::scope([("user_id".to_string(), "u-10".to_string())], async {
+                assert_eq!(ContextFields::current().get("user_id"), Some(&"u-10".to_string()));
+            })
+            .await;
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn scope_propagates_into_a_spawned_subtask() {
+        ContextFields::scope([("request_id".to_string(), "r-1".to_string())], async {
+            let fields = ContextFields::current();
+            tokio::spawn(ContextFields::scope(fields, async {
+                assert_eq!(ContextFields::current().get("request_id"), Some(&"r-1".to_string()));
+            }))
+            .await
+            .unwrap();
+        })
+        .await;
+    }
+}
@@ -0,0 +1,150 @@
+//! A logarithmic-bucket latency histogram, in the spirit of HDR
+//! histogram: bounded memory, bounded relative error, and cheap
+//! percentile estimation without keeping every sample.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::api::Percentiles;
+
+/// A latency histogram with logarithmically-spaced buckets between `min`
+/// and `max`, giving roughly constant relative precision across the whole
+/// range instead of the fixed absolute precision of linear buckets.
+pub struct LatencyHistogram {
+    min_nanos: f64,
+    growth_ln: f64,
+    bucket_count: usize,
+    counts: Mutex<Vec<u64>>,
+    total: AtomicU64,
+}
+
+impl LatencyHistogram {
+    /// Creates a histogram covering `[min, max]` with `bucket_count`
+    /// logarithmically-spaced buckets.
+    ///
+    /// Panics if `min` is zero, `max` is not greater than `min`, or
+    /// `bucket_count` is zero — these are configuration mistakes, not
+    /// runtime conditions callers need to recover from.
+    pub fn new(min: Duration, max: Duration, bucket_count: usize) -> Self {
+        assert!(!min.is_zero(), "histogram min must be greater than zero");
+        assert!(max > min, "histogram max must be greater than min");
+        assert!(bucket_count > 0, "histogram must have at least one bucket");
+
+        let min_nanos = min.as_nanos() as f64;
+        let max_nanos = max.as_nanos() as f64;
+
+        Self {
+            min_nanos,
+            growth_ln: (max_nanos / min_nanos).ln() / bucket_count as f64,
+            bucket_count,
+            counts: Mutex::new(vec![0; bucket_count]),
+            total: AtomicU64::new(0),
+        }
+    }
+
+    /// Records one observation, clamped into `[min, max]` if it falls
+    /// outside the configured range.
+    pub fn record(&self, value: Duration) {
+        let index = self.bucket_index(value);
+        self.counts.lock().unwrap()[index] += 1;
+        self.total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn bucket_index(&self, value: Duration) -> usize {
+        let nanos = (value.as_nanos() as f64).max(self.min_nanos);
+        let raw = (nanos / self.min_nanos).ln() / self.growth_ln;
+        (raw as usize).min(self.bucket_count - 1)
+    }
+
+    fn bucket_upper_bound(&self, index: usize) -> Duration {
+        let nanos = self.min_nanos * ((index + 1) as f64 * self.growth_ln).exp();
+        Duration::from_nanos(nanos as u64)
+    }
+
+    /// Number of observations recorded so far.
+    pub fn count(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    /// Estimates the `p`th percentile (`0.0..=100.0`), returning `None` if
+    /// no observations have been recorded.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        let total = self.count();
+        if total == 0 {
+            return None;
+        }
+
+        let target = ((p / 100.0) * total as f64).ceil() as u64;
+        let counts = self.counts.lock().unwrap();
+        let mut cumulative = 0u64;
+        for (index, count) in counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target.max(1) {
+                return Some(self.bucket_upper_bound(index));
+            }
+        }
+        Some(self.bucket_upper_bound(self.bucket_count - 1))
+    }
+
+    /// Estimates p50, p95, and p99 in one pass, returning `None` if no
+    /// observations have been recorded.
+    pub fn percentiles(&self) -> Option<Percentiles> {
+        Some(Percentiles {
+            p50: self.percentile(50.0)?,
+            p95: self.percentile(95.0)?,
+            p99: self.percentile(99.0)?,
+            count: self.count(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn histogram() -> LatencyHistogram {
+        LatencyHistogram::new(Duration::from_micros(1), Duration::from_secs(10), 256)
+    }
+
+    #[test]
+    fn no_observations_returns_none() {
+        let histogram = histogram();
+        assert!(histogram.percentiles().is_none());
+    }
+
+    #[test]
+    fn single_observation_is_its_own_percentiles() {
+        let histogram = histogram();
+        histogram.record(Duration::from_millis(50));
+
+        let percentiles = histogram.percentiles().unwrap();
+        assert_eq!(percentiles.count, 1);
+        // Bucketed, so the estimate is close but not exact.
+        let diff = percentiles.p99.as_millis() as i64 - 50;
+        assert!(diff.abs() < 5, "p99 {:?} too far from 50ms", percentiles.p99);
+    }
+
+    #[test]
+    fn percentiles_reflect_distribution_shape() {
+        let histogram = histogram();
+        for _ in 0..900 {
+            histogram.record(Duration::from_millis(10));
+        }
+        for _ in 0..100 {
+            histogram.record(Duration::from_millis(500));
+        }
+
+        let percentiles = histogram.percentiles().unwrap();
+        assert!(percentiles.p50 < Duration::from_millis(20));
+        assert!(percentiles.p99 > Duration::from_millis(100));
+    }
+
+    #[test]
+    fn values_above_max_are_clamped_into_top_bucket() {
+        let histogram = histogram();
+        histogram.record(Duration::from_secs(1000));
+        let percentiles = histogram.percentiles().unwrap();
+        assert!(percentiles.p99 <= Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,143 @@
+//! An in-process metrics registry, for tests, debug endpoints, and
+//! adaptive concurrency controllers that need local percentile estimates
+//! without shipping to an external metrics backend.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+
+use crate::api::Percentiles;
+use crate::core::histogram::LatencyHistogram;
+
+/// Lower bound, upper bound, and bucket count used for every
+/// [`LatencyHistogram`] created by [`InMemoryMetrics`].
+#[derive(Debug, Clone, Copy)]
+pub struct HistogramConfig {
+    /// Smallest latency the histogram can distinguish.
+    pub min: Duration,
+    /// Largest latency the histogram can distinguish; larger values are
+    /// clamped into the top bucket.
+    pub max: Duration,
+    /// Number of logarithmically-spaced buckets.
+    pub bucket_count: usize,
+}
+
+impl Default for HistogramConfig {
+    fn default() -> Self {
+        Self {
+            min: Duration::from_micros(1),
+            max: Duration::from_secs(60),
+            bucket_count: 256,
+        }
+    }
+}
+
+/// An in-process registry of named counters and latency histograms.
+pub struct InMemoryMetrics {
+    histogram_config: HistogramConfig,
+    counters: RwLock<HashMap<String, AtomicU64>>,
+    histograms: Mutex<HashMap<String, LatencyHistogram>>,
+}
+
+impl InMemoryMetrics {
+    /// Creates a new, empty registry, sizing every histogram it creates
+    /// per `histogram_config`.
+    pub fn new(histogram_config: HistogramConfig) -> Self {
+        Self {
+            histogram_config,
+            counters: RwLock::new(HashMap::new()),
+            histograms: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Increments the named counter by one, creating it at zero first if
+    /// this is the first time `name` has been used.
+    pub fn increment(&self, name: &str) {
+        self.add(name, 1);
+    }
+
+    /// Increments the named counter by `delta`, creating it at zero first
+    /// if this is the first time `name` has been used.
+    pub fn add(&self, name: &str, delta: u64) {
+        if let Some(counter) = self.counters.read().unwrap().get(name) {
+            counter.fetch_add(delta, Ordering::Relaxed);
+            return;
+        }
+        self.counters
+            .write()
+            .unwrap()
+            .entry(name.to_string())
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(delta, Ordering::Relaxed);
+    }
+
+    /// Returns the current value of the named counter, or `0` if it has
+    /// never been incremented.
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters
+            .read()
+            .unwrap()
+            .get(name)
+            .map(|c| c.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
+    /// Records a latency observation for the named metric, creating its
+    /// histogram first if this is the first observation.
+    pub fn record_duration(&self, name: &str, value: Duration) {
+        let mut histograms = self.histograms.lock().unwrap();
+        let config = self.histogram_config;
+        histograms
+            .entry(name.to_string())
+            .or_insert_with(|| LatencyHistogram::new(config.min, config.max, config.bucket_count))
+            .record(value);
+    }
+
+    /// Returns p50/p95/p99 for the named metric, or `None` if it has no
+    /// recorded observations.
+    pub fn percentiles(&self, name: &str) -> Option<Percentiles> {
+        self.histograms.lock().unwrap().get(name)?.percentiles()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_starts_at_zero_and_accumulates() {
+        let metrics = InMemoryMetrics::new(HistogramConfig::default());
+        assert_eq!(metrics.counter("requests"), 0);
+
+        metrics.increment("requests");
+        metrics.add("requests", 4);
+
+        assert_eq!(metrics.counter("requests"), 5);
+    }
+
+    #[test]
+    fn counters_are_independent_per_name() {
+        let metrics = InMemoryMetrics::new(HistogramConfig::default());
+        metrics.increment("a");
+        assert_eq!(metrics.counter("b"), 0);
+    }
+
+    #[test]
+    fn missing_histogram_has_no_percentiles() {
+        let metrics = InMemoryMetrics::new(HistogramConfig::default());
+        assert!(metrics.percentiles("latency").is_none());
+    }
+
+    #[test]
+    fn records_and_estimates_percentiles() {
+        let metrics = InMemoryMetrics::new(HistogramConfig::default());
+        for _ in 0..100 {
+            metrics.record_duration("latency", Duration::from_millis(10));
+        }
+
+        let percentiles = metrics.percentiles("latency").unwrap();
+        assert_eq!(percentiles.count, 100);
+        assert!(percentiles.p50 < Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,239 @@
+//! Parses lines produced by [`crate::core::logging::Logger`] back into
+//! [`LogRecord`]s — the inverse of `format_json`/`format_pretty` in
+//! [`crate::core::logging`] — for tooling that tails or replays a log
+//! file (a CLI viewer, a test asserting on emitted lines, a debug
+//! endpoint that re-streams recent entries).
+//!
+//! Hand-rolled rather than pulled in via a JSON crate, matching how
+//! [`crate::core::logging`] hand-rolls its own JSON output: the shape
+//! is fixed and flat (a level, a message, and string-valued fields), so
+//! a general-purpose parser buys nothing here.
+
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use crate::api::{LogFormat, LogLevel, LogRecord, LoggingError};
+
+/// Parses a single line in the given `format` into a [`LogRecord`].
+///
+/// ```
+/// use rustboot_observability::{LogFormat};
+/// use rustboot_observability::core::log_parsing::parse_line;
+///
+/// let record = parse_line(r#"{"timestamp":1700000000.0,"level":"info","message":"hi"}"#, LogFormat::Json).unwrap();
+/// assert_eq!(record.message, "hi");
+/// ```
+pub fn parse_line(line: &str, format: LogFormat) -> Result<LogRecord, LoggingError> {
+    match format {
+        LogFormat::Json => parse_json_line(line),
+        LogFormat::Pretty => parse_pretty_line(line),
+    }
+}
+
+/// Parses every non-blank line of `text` in the given `format`,
+/// returning one result per line so a malformed line doesn't abort the
+/// rest of the file.
+pub fn parse_lines(text: &str, format: LogFormat) -> Vec<Result<LogRecord, LoggingError>> {
+    text.lines().filter(|line| !line.trim().is_empty()).map(|line| parse_line(line, format)).collect()
+}
+
+fn level_from_name(name: &str) -> Result<LogLevel, LoggingError> {
+    match name {
+        "trace" => Ok(LogLevel::Trace),
+        "debug" => Ok(LogLevel::Debug),
+        "info" => Ok(LogLevel::Info),
+        "warn" => Ok(LogLevel::Warn),
+        "error" => Ok(LogLevel::Error),
+        other => Err(LoggingError::Malformed(format!("unrecognized log level '{other}'"))),
+    }
+}
+
+fn timestamp_from_unix_secs(secs: f64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs_f64(secs.max(0.0))
+}
+
+fn unescape_json(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+/// Reads one `"key":"value"` or `"key":number` pair starting at `input`,
+/// returning it and the remainder of `input` just past it (past a
+/// trailing `,` if present).
+fn parse_field(input: &str) -> Result<((String, String), &str), LoggingError> {
+    let input = input.trim_start();
+    let malformed = || LoggingError::Malformed(format!("expected a \"key\":value pair in '{input}'"));
+
+    let rest = input.strip_prefix('"').ok_or_else(malformed)?;
+    let key_end = rest.find('"').ok_or_else(malformed)?;
+    let key = unescape_json(&rest[..key_end]);
+    let rest = rest[key_end + 1..].trim_start().strip_prefix(':').ok_or_else(malformed)?.trim_start();
+
+    let (value, rest) = if let Some(rest) = rest.strip_prefix('"') {
+        let mut end = None;
+        let mut escaped = false;
+        for (i, ch) in rest.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                end = Some(i);
+                break;
+            }
+        }
+        let end = end.ok_or_else(malformed)?;
+        (unescape_json(&rest[..end]), &rest[end + 1..])
+    } else {
+        let end = rest.find([',', '}']).ok_or_else(malformed)?;
+        (rest[..end].trim().to_string(), &rest[end..])
+    };
+
+    let rest = rest.trim_start().strip_prefix(',').unwrap_or(rest).trim_start();
+    Ok(((key, value), rest))
+}
+
+fn parse_json_line(line: &str) -> Result<LogRecord, LoggingError> {
+    let malformed = || LoggingError::Malformed(format!("not a JSON log line: '{line}'"));
+    let mut rest = line.trim().strip_prefix('{').ok_or_else(malformed)?.trim_start();
+    rest = rest.strip_suffix('}').ok_or_else(malformed)?;
+
+    let mut timestamp = None;
+    let mut level = None;
+    let mut message = None;
+    let mut fields = BTreeMap::new();
+
+    while !rest.trim().is_empty() {
+        let ((key, value), remainder) = parse_field(rest)?;
+        match key.as_str() {
+            "timestamp" => timestamp = Some(value.parse::<f64>().map_err(|_| malformed())?),
+            "level" => level = Some(level_from_name(&value)?),
+            "message" => message = Some(value),
+            _ => {
+                fields.insert(key, value);
+            }
+        }
+        rest = remainder;
+    }
+
+    Ok(LogRecord {
+        level: level.ok_or_else(malformed)?,
+        message: message.ok_or_else(malformed)?,
+        fields,
+        timestamp: timestamp_from_unix_secs(timestamp.ok_or_else(malformed)?),
+    })
+}
+
+fn looks_like_field(word: &str) -> bool {
+    match word.split_once('=') {
+        Some((key, _)) => !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        None => false,
+    }
+}
+
+fn parse_pretty_line(line: &str) -> Result<LogRecord, LoggingError> {
+    let malformed = || LoggingError::Malformed(format!("not a pretty log line: '{line}'"));
+
+    let mut parts = line.trim().splitn(3, ' ');
+    let timestamp = parts.next().ok_or_else(malformed)?.parse::<f64>().map_err(|_| malformed())?;
+    let level = level_from_name(&parts.next().ok_or_else(malformed)?.to_lowercase())?;
+    let rest = parts.next().ok_or_else(malformed)?;
+
+    let words: Vec<&str> = rest.split(' ').collect();
+    let mut field_start = words.len();
+    while field_start > 0 && looks_like_field(words[field_start - 1]) {
+        field_start -= 1;
+    }
+
+    let fields = words[field_start..]
+        .iter()
+        .map(|word| {
+            let (key, value) = word.split_once('=').expect("looks_like_field guarantees an '='");
+            (key.to_string(), value.to_string())
+        })
+        .collect();
+    let message = words[..field_start].join(" ");
+    if message.is_empty() {
+        return Err(malformed());
+    }
+
+    Ok(LogRecord {
+        level,
+        message,
+        fields,
+        timestamp: timestamp_from_unix_secs(timestamp),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_json_line_round_trips_a_record_with_fields() {
+        let line = r#"{"timestamp":1700000000.500000,"level":"info","message":"user logged in","user_id":"42"}"#;
+        let record = parse_json_line(line).unwrap();
+        assert_eq!(record.level, LogLevel::Info);
+        assert_eq!(record.message, "user logged in");
+        assert_eq!(record.fields.get("user_id"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn parse_json_line_unescapes_quotes_and_newlines() {
+        let line = r#"{"timestamp":0.0,"level":"error","message":"line one\nline \"two\""}"#;
+        let record = parse_json_line(line).unwrap();
+        assert_eq!(record.message, "line one\nline \"two\"");
+    }
+
+    #[test]
+    fn parse_json_line_rejects_an_unrecognized_level() {
+        let line = r#"{"timestamp":0.0,"level":"critical","message":"oops"}"#;
+        assert!(parse_json_line(line).is_err());
+    }
+
+    #[test]
+    fn parse_pretty_line_splits_message_from_trailing_fields() {
+        let record = parse_pretty_line("1700000000.500000 WARN retrying request order_id=7 attempt=2").unwrap();
+        assert_eq!(record.level, LogLevel::Warn);
+        assert_eq!(record.message, "retrying request");
+        assert_eq!(record.fields.get("order_id"), Some(&"7".to_string()));
+        assert_eq!(record.fields.get("attempt"), Some(&"2".to_string()));
+    }
+
+    #[test]
+    fn parse_pretty_line_handles_a_message_with_no_fields() {
+        let record = parse_pretty_line("0.000000 INFO ready").unwrap();
+        assert_eq!(record.message, "ready");
+        assert!(record.fields.is_empty());
+    }
+
+    #[test]
+    fn parse_lines_reports_each_lines_result_independently() {
+        let text = "0.0 INFO ready\nnot a log line\n1.0 WARN degraded\n";
+        let results = parse_lines(text, LogFormat::Pretty);
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn parse_line_dispatches_on_format() {
+        assert!(parse_line("0.0 INFO hi", LogFormat::Pretty).is_ok());
+        assert!(parse_line(r#"{"timestamp":0.0,"level":"info","message":"hi"}"#, LogFormat::Json).is_ok());
+    }
+}
@@ -0,0 +1,556 @@
+//! Structured logging: formatting, redaction, sampling, and pluggable
+//! sinks.
+//!
+//! This does not depend on rustboot-fileio: [`RollingFileSink`] talks to
+//! `std::fs` directly. Likewise, no concrete "remote" sink ships here;
+//! shipping logs to a remote aggregator is exactly what [`crate::spi::LogSink`]
+//! is for, following the same pluggable-backend convention used for Redis
+//! transports and audit sinks elsewhere in rustboot.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rustboot_config::Config;
+
+use crate::api::{LogFormat, LogLevel, LogRecord, LoggingError, SamplingConfig};
+use crate::core::context_fields::ContextFields;
+use crate::core::runtime_config::log_level_enabled;
+use crate::spi::LogSink;
+
+/// Writes every log line to stdout.
+#[derive(Debug, Default)]
+pub struct StdoutSink;
+
+impl LogSink for StdoutSink {
+    fn write_line(&self, line: &str) {
+        println!("{line}");
+    }
+}
+
+/// Writes every log line to a file, rotating to a fresh file once the
+/// current one has reached `max_bytes`.
+///
+/// Rotation renames the current file to `<path>.1`, overwriting any
+/// previous `.1`; this mirrors the simplest single-backup rotation scheme
+/// rather than numbering an unbounded history.
+pub struct RollingFileSink {
+    path: PathBuf,
+    max_bytes: u64,
+    state: Mutex<RollingState>,
+}
+
+struct RollingState {
+    file: File,
+    written_bytes: u64,
+}
+
+impl RollingFileSink {
+    /// Opens (creating if necessary) a rolling file sink at `path`,
+    /// rotating before the next write once the file has reached
+    /// `max_bytes` (the line that crosses the limit is still written to
+    /// the current file).
+    pub fn new(path: impl Into<PathBuf>, max_bytes: u64) -> Result<Self, LoggingError> {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written_bytes = file.metadata()?.len();
+        Ok(Self {
+            path,
+            max_bytes,
+            state: Mutex::new(RollingState {
+                file,
+                written_bytes,
+            }),
+        })
+    }
+
+    fn rotate(&self, state: &mut RollingState) -> Result<(), LoggingError> {
+        let mut backup_path = self.path.clone();
+        backup_path.set_extension(match self.path.extension() {
+            Some(ext) => format!("{}.1", ext.to_string_lossy()),
+            None => "1".to_string(),
+        });
+        std::fs::rename(&self.path, &backup_path)?;
+        state.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        state.written_bytes = 0;
+        Ok(())
+    }
+}
+
+impl LogSink for RollingFileSink {
+    fn write_line(&self, line: &str) {
+        let mut state = self.state.lock().unwrap();
+        if state.written_bytes >= self.max_bytes && self.rotate(&mut state).is_err() {
+            return;
+        }
+        if writeln!(state.file, "{line}").is_ok() {
+            state.written_bytes += line.len() as u64 + 1;
+        }
+    }
+}
+
+/// Builds a [`Logger`] with a chosen output format, field redaction
+/// rules, a sampling policy, and one or more sinks.
+pub struct LoggerBuilder {
+    format: LogFormat,
+    redacted_fields: Vec<String>,
+    sampling: SamplingConfig,
+    sinks: Vec<std::sync::Arc<dyn LogSink>>,
+}
+
+impl Default for LoggerBuilder {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::Json,
+            redacted_fields: Vec::new(),
+            sampling: SamplingConfig::default(),
+            sinks: Vec::new(),
+        }
+    }
+}
+
+impl LoggerBuilder {
+    /// Creates a builder with JSON output, no redaction, no sampling, and
+    /// no sinks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the output format.
+    pub fn format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Marks a field name so its value is replaced with `"[REDACTED]"`
+    /// wherever it appears among a record's structured fields.
+    pub fn redact_field(mut self, field: impl Into<String>) -> Self {
+        self.redacted_fields.push(field.into());
+        self
+    }
+
+    /// Sets the sampling policy for below-threshold records.
+    pub fn sampling(mut self, sampling: SamplingConfig) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Adds a sink every record is written to.
+    pub fn with_sink(mut self, sink: std::sync::Arc<dyn LogSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Builds a [`LoggerBuilder`] from `logging.*` configuration:
+    ///
+    /// - `logging.format`: `"json"` or `"pretty"` (defaults to `json`).
+    /// - `logging.redacted_fields`: a list of field names (defaults to
+    ///   none).
+    /// - `logging.sampling.rate`: a `0.0..=1.0` fraction (defaults to
+    ///   `1.0`).
+    ///
+    /// Sinks are never configured here — attach them with
+    /// [`LoggerBuilder::with_sink`] after loading the config, since a
+    /// sink may need resources (an open file handle, a network client)
+    /// that don't belong in a config value.
+    pub fn from_config(config: &Config) -> Result<Self, LoggingError> {
+        let mut builder = Self::new();
+
+        match config.get::<String>("logging.format") {
+            Ok(value) => {
+                builder.format = match value.as_str() {
+                    "json" => LogFormat::Json,
+                    "pretty" => LogFormat::Pretty,
+                    other => {
+                        return Err(LoggingError::InvalidConfig(format!(
+                            "unrecognized logging.format '{other}'"
+                        )))
+                    }
+                };
+            }
+            Err(rustboot_config::ConfigError::NotFound(_)) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        match config.get_raw("logging.redacted_fields") {
+            Ok(rustboot_config::ConfigValue::List(items)) => {
+                for item in items {
+                    match item {
+                        rustboot_config::ConfigValue::String(field) => {
+                            builder.redacted_fields.push(field.clone());
+                        }
+                        _ => {
+                            return Err(LoggingError::InvalidConfig(
+                                "logging.redacted_fields entries must be strings".to_string(),
+                            ))
+                        }
+                    }
+                }
+            }
+            Ok(_) => {
+                return Err(LoggingError::InvalidConfig(
+                    "logging.redacted_fields must be a list".to_string(),
+                ))
+            }
+            Err(rustboot_config::ConfigError::NotFound(_)) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        match config.get::<f64>("logging.sampling.rate") {
+            Ok(rate) => builder.sampling.rate = rate,
+            Err(rustboot_config::ConfigError::NotFound(_)) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        Ok(builder)
+    }
+
+    /// Finishes building the logger.
+    pub fn build(self) -> Logger {
+        Logger {
+            format: self.format,
+            redacted_fields: self.redacted_fields,
+            sampling: self.sampling,
+            sinks: self.sinks,
+        }
+    }
+}
+
+/// Formats and dispatches [`LogRecord`]s to every configured
+/// [`crate::spi::LogSink`], applying redaction and sampling first.
+pub struct Logger {
+    format: LogFormat,
+    redacted_fields: Vec<String>,
+    sampling: SamplingConfig,
+    sinks: Vec<std::sync::Arc<dyn LogSink>>,
+}
+
+impl Logger {
+    fn redact(&self, fields: BTreeMap<String, String>) -> BTreeMap<String, String> {
+        let mut fields = fields;
+        for field in &self.redacted_fields {
+            if fields.contains_key(field) {
+                fields.insert(field.clone(), "[REDACTED]".to_string());
+            }
+        }
+        fields
+    }
+
+    fn should_keep(&self, target: &str, level: LogLevel) -> bool {
+        if !log_level_enabled(target, level) {
+            return false;
+        }
+        level >= self.sampling.always_log_at || rand::random::<f64>() < self.sampling.rate
+    }
+
+    fn format_line(&self, record: &LogRecord) -> String {
+        match self.format {
+            LogFormat::Json => format_json(record),
+            LogFormat::Pretty => format_pretty(record),
+        }
+    }
+
+    /// Logs one record for `target` (a module path, e.g.
+    /// `module_path!()` at the call site): checks it against the
+    /// process-wide [`crate::core::runtime_config`] level filter first,
+    /// then merges in the current task's
+    /// [`crate::core::context_fields::ContextFields`] (`fields` wins on
+    /// conflict), redacts configured fields, applies the sampling
+    /// policy, and — if the record survives all three — writes the
+    /// formatted line to every configured sink.
+    pub fn log(&self, target: &str, level: LogLevel, message: impl Into<String>, fields: BTreeMap<String, String>) {
+        if !self.should_keep(target, level) {
+            return;
+        }
+        let mut merged_fields = ContextFields::current();
+        merged_fields.extend(fields);
+        let record = LogRecord {
+            level,
+            message: message.into(),
+            fields: self.redact(merged_fields),
+            timestamp: SystemTime::now(),
+        };
+        let line = self.format_line(&record);
+        for sink in &self.sinks {
+            sink.write_line(&line);
+        }
+    }
+
+    /// Logs at [`LogLevel::Trace`] with no structured fields.
+    pub fn trace(&self, target: &str, message: impl Into<String>) {
+        self.log(target, LogLevel::Trace, message, BTreeMap::new());
+    }
+
+    /// Logs at [`LogLevel::Debug`] with no structured fields.
+    pub fn debug(&self, target: &str, message: impl Into<String>) {
+        self.log(target, LogLevel::Debug, message, BTreeMap::new());
+    }
+
+    /// Logs at [`LogLevel::Info`] with no structured fields.
+    pub fn info(&self, target: &str, message: impl Into<String>) {
+        self.log(target, LogLevel::Info, message, BTreeMap::new());
+    }
+
+    /// Logs at [`LogLevel::Warn`] with no structured fields.
+    pub fn warn(&self, target: &str, message: impl Into<String>) {
+        self.log(target, LogLevel::Warn, message, BTreeMap::new());
+    }
+
+    /// Logs at [`LogLevel::Error`] with no structured fields.
+    pub fn error(&self, target: &str, message: impl Into<String>) {
+        self.log(target, LogLevel::Error, message, BTreeMap::new());
+    }
+}
+
+fn level_name(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Trace => "trace",
+        LogLevel::Debug => "debug",
+        LogLevel::Info => "info",
+        LogLevel::Warn => "warn",
+        LogLevel::Error => "error",
+    }
+}
+
+fn unix_timestamp_secs(timestamp: SystemTime) -> f64 {
+    timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn format_json(record: &LogRecord) -> String {
+    let mut line = format!(
+        "{{\"timestamp\":{:.6},\"level\":\"{}\",\"message\":\"{}\"",
+        unix_timestamp_secs(record.timestamp),
+        level_name(record.level),
+        escape_json(&record.message)
+    );
+    for (key, value) in &record.fields {
+        line.push_str(&format!(
+            ",\"{}\":\"{}\"",
+            escape_json(key),
+            escape_json(value)
+        ));
+    }
+    line.push('}');
+    line
+}
+
+fn format_pretty(record: &LogRecord) -> String {
+    let mut line = format!(
+        "{:.6} {} {}",
+        unix_timestamp_secs(record.timestamp),
+        level_name(record.level).to_uppercase(),
+        record.message
+    );
+    for (key, value) in &record.fields {
+        line.push_str(&format!(" {key}={value}"));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct CollectingSink {
+        lines: Mutex<Vec<String>>,
+    }
+
+    impl LogSink for CollectingSink {
+        fn write_line(&self, line: &str) {
+            self.lines.lock().unwrap().push(line.to_string());
+        }
+    }
+
+    #[test]
+    fn json_format_includes_level_message_and_fields() {
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new()
+            .format(LogFormat::Json)
+            .with_sink(sink.clone())
+            .build();
+
+        let mut fields = BTreeMap::new();
+        fields.insert("user_id".to_string(), "42".to_string());
+        logger.log("test", LogLevel::Info, "user logged in", fields);
+
+        let lines = sink.lines.lock().unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("\"level\":\"info\""));
+        assert!(lines[0].contains("\"message\":\"user logged in\""));
+        assert!(lines[0].contains("\"user_id\":\"42\""));
+    }
+
+    #[test]
+    fn pretty_format_is_single_line_with_key_value_fields() {
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new()
+            .format(LogFormat::Pretty)
+            .with_sink(sink.clone())
+            .build();
+
+        let mut fields = BTreeMap::new();
+        fields.insert("order_id".to_string(), "7".to_string());
+        logger.log("test", LogLevel::Warn, "retrying", fields);
+
+        let lines = sink.lines.lock().unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with(char::is_numeric));
+        assert!(lines[0].contains("WARN retrying"));
+        assert!(lines[0].contains("order_id=7"));
+    }
+
+    #[test]
+    fn redacted_fields_are_replaced_before_formatting() {
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new()
+            .redact_field("password")
+            .with_sink(sink.clone())
+            .build();
+
+        let mut fields = BTreeMap::new();
+        fields.insert("password".to_string(), "hunter2".to_string());
+        logger.log("test", LogLevel::Info, "login attempt", fields);
+
+        let lines = sink.lines.lock().unwrap();
+        assert!(lines[0].contains("\"password\":\"[REDACTED]\""));
+        assert!(!lines[0].contains("hunter2"));
+    }
+
+    #[test]
+    fn sampling_rate_zero_drops_records_below_the_always_log_threshold() {
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new()
+            .sampling(SamplingConfig {
+                rate: 0.0,
+                always_log_at: LogLevel::Error,
+            })
+            .with_sink(sink.clone())
+            .build();
+
+        logger.info("test", "dropped");
+        logger.error("test", "kept");
+
+        let lines = sink.lines.lock().unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("kept"));
+    }
+
+    #[test]
+    fn from_config_reads_format_redacted_fields_and_sampling_rate() {
+        let mut root = std::collections::HashMap::new();
+        let mut logging = std::collections::HashMap::new();
+        logging.insert(
+            "format".to_string(),
+            rustboot_config::ConfigValue::String("pretty".to_string()),
+        );
+        logging.insert(
+            "redacted_fields".to_string(),
+            rustboot_config::ConfigValue::List(vec![rustboot_config::ConfigValue::String(
+                "ssn".to_string(),
+            )]),
+        );
+        let mut sampling = std::collections::HashMap::new();
+        sampling.insert(
+            "rate".to_string(),
+            rustboot_config::ConfigValue::Float(0.5),
+        );
+        logging.insert(
+            "sampling".to_string(),
+            rustboot_config::ConfigValue::Table(sampling),
+        );
+        root.insert("logging".to_string(), rustboot_config::ConfigValue::Table(logging));
+        let config = Config::from_table(root);
+
+        let builder = LoggerBuilder::from_config(&config).unwrap();
+
+        assert_eq!(builder.format, LogFormat::Pretty);
+        assert_eq!(builder.redacted_fields, vec!["ssn".to_string()]);
+        assert_eq!(builder.sampling.rate, 0.5);
+    }
+
+    #[tokio::test]
+    async fn context_fields_are_merged_into_every_log_line() {
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new().with_sink(sink.clone()).build();
+
+        crate::core::context_fields::ContextFields::scope(
+            [("request_id".to_string(), "r-1".to_string())],
+            async { logger.info("test", "handled request") },
+        )
+        .await;
+
+        let lines = sink.lines.lock().unwrap();
+        assert!(lines[0].contains("\"request_id\":\"r-1\""));
+    }
+
+    #[tokio::test]
+    async fn explicit_fields_win_over_context_fields_on_conflict() {
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new().with_sink(sink.clone()).build();
+
+        crate::core::context_fields::ContextFields::scope(
+            [("request_id".to_string(), "from_context".to_string())],
+            async {
+                let mut fields = BTreeMap::new();
+                fields.insert("request_id".to_string(), "explicit".to_string());
+                logger.log("test", LogLevel::Info, "handled request", fields);
+            },
+        )
+        .await;
+
+        let lines = sink.lines.lock().unwrap();
+        assert!(lines[0].contains("\"request_id\":\"explicit\""));
+    }
+
+    #[test]
+    fn from_config_defaults_when_logging_section_is_absent() {
+        let config = Config::new();
+        let builder = LoggerBuilder::from_config(&config).unwrap();
+        assert_eq!(builder.format, LogFormat::Json);
+        assert!(builder.redacted_fields.is_empty());
+        assert_eq!(builder.sampling.rate, 1.0);
+    }
+
+    #[test]
+    fn a_target_raised_above_the_default_level_is_dropped_until_lowered_again() {
+        use crate::core::runtime_config::{clear_log_level, set_log_level};
+
+        // A target unique to this test, so it can't race with other
+        // tests touching the same process-wide level filter.
+        let target = "rustboot_observability::core::logging::tests::raised_target";
+        let sink = Arc::new(CollectingSink::default());
+        let logger = LoggerBuilder::new().with_sink(sink.clone()).build();
+
+        set_log_level(target, LogLevel::Error);
+        logger.info(target, "dropped by the raised level");
+        assert!(sink.lines.lock().unwrap().is_empty());
+
+        clear_log_level(target);
+        logger.info(target, "kept once the override is cleared");
+        assert_eq!(sink.lines.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,84 @@
+//! Global dispatch point for `#[metrics_histogram]`, mirroring
+//! [`crate::core::recorder`]'s installation pattern so the macro can
+//! record into a pluggable [`Metrics`] backend without threading a
+//! reference through every call site.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::spi::Metrics;
+
+static GLOBAL_METRICS: OnceLock<Arc<dyn Metrics>> = OnceLock::new();
+
+/// Installs the process-wide [`Metrics`] backend used by
+/// `#[metrics_histogram]`.
+///
+/// Only the first call takes effect; later calls are silently ignored.
+pub fn install_global_metrics(metrics: Arc<dyn Metrics>) {
+    let _ = GLOBAL_METRICS.set(metrics);
+}
+
+/// Increments the named counter against the installed backend, a no-op
+/// if none has been installed.
+pub fn increment_counter(name: &str, labels: &[(&str, &str)], delta: u64) {
+    if let Some(metrics) = GLOBAL_METRICS.get() {
+        metrics.counter(name, labels, delta);
+    }
+}
+
+/// Records one histogram observation against the installed backend, a
+/// no-op if none has been installed.
+pub fn observe_histogram(name: &str, labels: &[(&str, &str)], value: f64) {
+    if let Some(metrics) = GLOBAL_METRICS.get() {
+        metrics.histogram(name, labels, value);
+    }
+}
+
+/// Records one gauge reading against the installed backend, a no-op if
+/// none has been installed.
+pub fn record_gauge(name: &str, labels: &[(&str, &str)], value: f64) {
+    if let Some(metrics) = GLOBAL_METRICS.get() {
+        metrics.gauge(name, labels, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct CollectingMetrics {
+        counters: Mutex<Vec<(String, u64)>>,
+        histograms: Mutex<Vec<(String, f64)>>,
+        gauges: Mutex<Vec<(String, f64)>>,
+    }
+
+    impl Metrics for CollectingMetrics {
+        fn counter(&self, name: &str, _labels: &[(&str, &str)], delta: u64) {
+            self.counters.lock().unwrap().push((name.to_string(), delta));
+        }
+        fn gauge(&self, name: &str, _labels: &[(&str, &str)], value: f64) {
+            self.gauges.lock().unwrap().push((name.to_string(), value));
+        }
+        fn histogram(&self, name: &str, _labels: &[(&str, &str)], value: f64) {
+            self.histograms.lock().unwrap().push((name.to_string(), value));
+        }
+    }
+
+    #[test]
+    fn dispatch_functions_forward_to_the_installed_backend() {
+        let metrics = Arc::new(CollectingMetrics::default());
+        install_global_metrics(metrics.clone());
+
+        increment_counter("requests_total", &[], 1);
+        observe_histogram("request_duration_seconds", &[], 0.42);
+        record_gauge("queue_depth", &[], 7.0);
+
+        let counters = metrics.counters.lock().unwrap();
+        assert!(counters.contains(&("requests_total".to_string(), 1)));
+        let histograms = metrics.histograms.lock().unwrap();
+        assert!(histograms.contains(&("request_duration_seconds".to_string(), 0.42)));
+        let gauges = metrics.gauges.lock().unwrap();
+        assert!(gauges.contains(&("queue_depth".to_string(), 7.0)));
+    }
+}
@@ -0,0 +1,12 @@
+//! Implementation details for the observability module.
+
+pub mod context_fields;
+pub mod histogram;
+pub mod in_memory;
+pub mod log_parsing;
+pub mod logging;
+pub mod metrics_registry;
+pub mod prometheus;
+pub mod recorder;
+pub mod runtime_config;
+pub mod trace_context;
@@ -0,0 +1,309 @@
+//! A labeled counter/gauge/histogram registry that renders the
+//! [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/),
+//! so metrics collected in-process can be scraped by an external
+//! monitoring stack. [`crate::core::in_memory::InMemoryMetrics`] has no
+//! such export path and no label support; this module is the
+//! label-aware counterpart meant for that purpose.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+/// A metric's label set. Stored sorted so the same labels always
+/// serialize identically regardless of insertion order, keeping a
+/// metric's identity (name + labels) well-defined.
+pub type Labels = BTreeMap<String, String>;
+
+/// Default histogram bucket upper bounds, in seconds, covering
+/// 5-millisecond to 10-second latencies — the same shape as
+/// Prometheus client libraries ship by default.
+pub const DEFAULT_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+#[derive(Debug, Clone)]
+struct HistogramState {
+    bucket_counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl HistogramState {
+    fn new(bucket_count: usize) -> Self {
+        Self {
+            bucket_counts: vec![0; bucket_count],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, bounds: &[f64], value: f64) {
+        for (index, bound) in bounds.iter().enumerate() {
+            if value <= *bound {
+                self.bucket_counts[index] += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// A labeled metrics registry rendering the Prometheus text exposition
+/// format.
+pub struct PrometheusMetrics {
+    bucket_bounds: Vec<f64>,
+    counters: Mutex<HashMap<(String, Labels), u64>>,
+    gauges: Mutex<HashMap<(String, Labels), f64>>,
+    histograms: Mutex<HashMap<(String, Labels), HistogramState>>,
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new(DEFAULT_BUCKETS.to_vec())
+    }
+}
+
+impl PrometheusMetrics {
+    /// Creates an empty registry, using `bucket_bounds` (ascending,
+    /// excluding the implicit `+Inf` bucket) for every histogram it
+    /// creates.
+    pub fn new(bucket_bounds: Vec<f64>) -> Self {
+        Self {
+            bucket_bounds,
+            counters: Mutex::new(HashMap::new()),
+            gauges: Mutex::new(HashMap::new()),
+            histograms: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Increments the named counter, with the given labels, by one.
+    pub fn increment_counter(&self, name: &str, labels: Labels) {
+        self.add_counter(name, labels, 1);
+    }
+
+    /// Increments the named counter, with the given labels, by `delta`.
+    pub fn add_counter(&self, name: &str, labels: Labels, delta: u64) {
+        *self
+            .counters
+            .lock()
+            .unwrap()
+            .entry((name.to_string(), labels))
+            .or_insert(0) += delta;
+    }
+
+    /// Sets the named gauge, with the given labels, to `value`.
+    pub fn set_gauge(&self, name: &str, labels: Labels, value: f64) {
+        self.gauges
+            .lock()
+            .unwrap()
+            .insert((name.to_string(), labels), value);
+    }
+
+    /// Adds `delta` (negative to decrease) to the named gauge, with the
+    /// given labels, creating it at zero first if needed.
+    pub fn add_gauge(&self, name: &str, labels: Labels, delta: f64) {
+        *self
+            .gauges
+            .lock()
+            .unwrap()
+            .entry((name.to_string(), labels))
+            .or_insert(0.0) += delta;
+    }
+
+    /// Records one observation for the named histogram, with the given
+    /// labels.
+    pub fn observe_histogram(&self, name: &str, labels: Labels, value: f64) {
+        let bucket_count = self.bucket_bounds.len();
+        self.histograms
+            .lock()
+            .unwrap()
+            .entry((name.to_string(), labels))
+            .or_insert_with(|| HistogramState::new(bucket_count))
+            .observe(&self.bucket_bounds, value);
+    }
+
+    /// Renders every counter, gauge, and histogram as Prometheus text
+    /// exposition format.
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        render_counters(&self.counters.lock().unwrap(), &mut output);
+        render_gauges(&self.gauges.lock().unwrap(), &mut output);
+        render_histograms(&self.histograms.lock().unwrap(), &self.bucket_bounds, &mut output);
+        output
+    }
+}
+
+fn render_counters(counters: &HashMap<(String, Labels), u64>, output: &mut String) {
+    let mut entries: Vec<_> = counters.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut last_name: Option<&str> = None;
+    for ((name, labels), value) in entries {
+        if last_name != Some(name.as_str()) {
+            output.push_str(&format!("# TYPE {name} counter\n"));
+            last_name = Some(name);
+        }
+        output.push_str(&format!("{name}{} {value}\n", render_labels(labels, None)));
+    }
+}
+
+fn render_gauges(gauges: &HashMap<(String, Labels), f64>, output: &mut String) {
+    let mut entries: Vec<_> = gauges.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut last_name: Option<&str> = None;
+    for ((name, labels), value) in entries {
+        if last_name != Some(name.as_str()) {
+            output.push_str(&format!("# TYPE {name} gauge\n"));
+            last_name = Some(name);
+        }
+        output.push_str(&format!("{name}{} {value}\n", render_labels(labels, None)));
+    }
+}
+
+fn render_histograms(
+    histograms: &HashMap<(String, Labels), HistogramState>,
+    bucket_bounds: &[f64],
+    output: &mut String,
+) {
+    let mut entries: Vec<_> = histograms.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut last_name: Option<&str> = None;
+    for ((name, labels), state) in entries {
+        if last_name != Some(name.as_str()) {
+            output.push_str(&format!("# TYPE {name} histogram\n"));
+            last_name = Some(name);
+        }
+        for (index, bound) in bucket_bounds.iter().enumerate() {
+            let le = format!("{bound}");
+            output.push_str(&format!(
+                "{name}_bucket{} {}\n",
+                render_labels(labels, Some(&le)),
+                state.bucket_counts[index]
+            ));
+        }
+        output.push_str(&format!(
+            "{name}_bucket{} {}\n",
+            render_labels(labels, Some("+Inf")),
+            state.count
+        ));
+        output.push_str(&format!("{name}_sum{} {}\n", render_labels(labels, None), state.sum));
+        output.push_str(&format!("{name}_count{} {}\n", render_labels(labels, None), state.count));
+    }
+}
+
+impl crate::spi::Metrics for PrometheusMetrics {
+    fn counter(&self, name: &str, labels: &[(&str, &str)], delta: u64) {
+        self.add_counter(name, to_labels(labels), delta);
+    }
+
+    fn gauge(&self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.set_gauge(name, to_labels(labels), value);
+    }
+
+    fn histogram(&self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.observe_histogram(name, to_labels(labels), value);
+    }
+}
+
+fn to_labels(pairs: &[(&str, &str)]) -> Labels {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+fn render_labels(labels: &Labels, le: Option<&str>) -> String {
+    if labels.is_empty() && le.is_none() {
+        return String::new();
+    }
+
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(k, v)| format!("{k}=\"{}\"", escape_label_value(v)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{le}\""));
+    }
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(pairs: &[(&str, &str)]) -> Labels {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn counter_accumulates_per_label_set() {
+        let metrics = PrometheusMetrics::default();
+        metrics.increment_counter("requests_total", labels(&[("route", "/a")]));
+        metrics.add_counter("requests_total", labels(&[("route", "/a")]), 4);
+        metrics.increment_counter("requests_total", labels(&[("route", "/b")]));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("requests_total{route=\"/a\"} 5\n"));
+        assert!(rendered.contains("requests_total{route=\"/b\"} 1\n"));
+    }
+
+    #[test]
+    fn gauge_set_overwrites_and_add_accumulates() {
+        let metrics = PrometheusMetrics::default();
+        metrics.set_gauge("queue_depth", labels(&[]), 3.0);
+        metrics.add_gauge("queue_depth", labels(&[]), -1.0);
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("queue_depth 2\n"));
+    }
+
+    #[test]
+    fn histogram_buckets_are_cumulative_and_include_sum_and_count() {
+        let metrics = PrometheusMetrics::new(vec![0.1, 1.0]);
+        metrics.observe_histogram("latency_seconds", labels(&[]), 0.05);
+        metrics.observe_histogram("latency_seconds", labels(&[]), 0.5);
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("latency_seconds_bucket{le=\"0.1\"} 1\n"));
+        assert!(rendered.contains("latency_seconds_bucket{le=\"1\"} 2\n"));
+        assert!(rendered.contains("latency_seconds_bucket{le=\"+Inf\"} 2\n"));
+        assert!(rendered.contains("latency_seconds_sum 0.55\n"));
+        assert!(rendered.contains("latency_seconds_count 2\n"));
+    }
+
+    #[test]
+    fn emits_type_line_once_per_metric_name() {
+        let metrics = PrometheusMetrics::default();
+        metrics.increment_counter("hits", labels(&[("shard", "1")]));
+        metrics.increment_counter("hits", labels(&[("shard", "2")]));
+
+        let rendered = metrics.render();
+        assert_eq!(rendered.matches("# TYPE hits counter").count(), 1);
+    }
+
+    #[test]
+    fn metrics_trait_impl_delegates_to_the_inherent_methods() {
+        use crate::spi::Metrics;
+
+        let metrics = PrometheusMetrics::default();
+        Metrics::counter(&metrics, "http_requests", &[("route", "/users"), ("status", "200")], 1);
+        Metrics::gauge(&metrics, "queue_depth", &[], 2.0);
+        Metrics::histogram(&metrics, "latency_seconds", &[], 0.2);
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("http_requests{route=\"/users\",status=\"200\"} 1\n"));
+        assert!(rendered.contains("queue_depth 2\n"));
+        assert!(rendered.contains("latency_seconds_count 1\n"));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        let metrics = PrometheusMetrics::default();
+        metrics.increment_counter("errors", labels(&[("message", "bad \"input\"")]));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("message=\"bad \\\"input\\\"\""));
+    }
+}
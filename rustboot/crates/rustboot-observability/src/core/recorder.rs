@@ -0,0 +1,114 @@
+//! Global dispatch point for `#[timed]`/`#[traced]`, so the macros can
+//! emit a [`SpanRecord`] without a recorder reference threaded through
+//! every instrumented call site.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::api::SpanRecord;
+use crate::core::context_fields::ContextFields;
+use crate::spi::Recorder;
+
+static GLOBAL_RECORDER: OnceLock<Arc<dyn Recorder>> = OnceLock::new();
+
+/// Installs the process-wide [`Recorder`] used by `#[timed]`/`#[traced]`.
+///
+/// Only the first call takes effect; later calls are silently ignored,
+/// same as [`rustboot_security`](../../rustboot_security/index.html)'s
+/// audit sink installation.
+pub fn install_global_recorder(recorder: Arc<dyn Recorder>) {
+    let _ = GLOBAL_RECORDER.set(recorder);
+}
+
+fn merge_context_fields(mut span: SpanRecord) -> SpanRecord {
+    let mut fields = ContextFields::current();
+    fields.extend(span.fields);
+    span.fields = fields;
+    span
+}
+
+/// Merges the current task's [`ContextFields`] into `span` (call-site
+/// fields win on conflict) and forwards it to the installed
+/// [`Recorder`], or does nothing if none has been installed yet.
+pub fn record(span: SpanRecord) {
+    if let Some(recorder) = GLOBAL_RECORDER.get() {
+        recorder.record(merge_context_fields(span));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::SpanOutcome;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct CollectingRecorder {
+        spans: Mutex<Vec<SpanRecord>>,
+    }
+
+    impl Recorder for CollectingRecorder {
+        fn record(&self, span: SpanRecord) {
+            self.spans.lock().unwrap().push(span);
+        }
+    }
+
+    #[test]
+    fn record_forwards_to_the_installed_recorder() {
+        let recorder = Arc::new(CollectingRecorder::default());
+        install_global_recorder(recorder.clone());
+
+        record(SpanRecord {
+            function: "example",
+            module: "example::module",
+            args: None,
+            duration: Duration::from_millis(1),
+            outcome: SpanOutcome::Success,
+            fields: std::collections::BTreeMap::new(),
+        });
+
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].function, "example");
+    }
+
+    #[tokio::test]
+    async fn merge_context_fields_pulls_in_the_current_scope() {
+        let span = SpanRecord {
+            function: "example_with_context",
+            module: "example::module",
+            args: None,
+            duration: Duration::from_millis(1),
+            outcome: SpanOutcome::Success,
+            fields: std::collections::BTreeMap::new(),
+        };
+
+        let merged = ContextFields::scope([("request_id".to_string(), "r-1".to_string())], async {
+            merge_context_fields(span)
+        })
+        .await;
+
+        assert_eq!(merged.fields.get("request_id"), Some(&"r-1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn merge_context_fields_lets_call_site_fields_win_on_conflict() {
+        let mut explicit_fields = std::collections::BTreeMap::new();
+        explicit_fields.insert("request_id".to_string(), "explicit".to_string());
+        let span = SpanRecord {
+            function: "example_with_conflict",
+            module: "example::module",
+            args: None,
+            duration: Duration::from_millis(1),
+            outcome: SpanOutcome::Success,
+            fields: explicit_fields,
+        };
+
+        let merged = ContextFields::scope([("request_id".to_string(), "from_context".to_string())], async {
+            merge_context_fields(span)
+        })
+        .await;
+
+        assert_eq!(merged.fields.get("request_id"), Some(&"explicit".to_string()));
+    }
+}
@@ -0,0 +1,142 @@
+//! Runtime-adjustable knobs for logging and trace sampling, so an admin
+//! endpoint or a config hot-reload handler can turn up log verbosity for
+//! one module, or change what fraction of new traces get sampled,
+//! without restarting the process.
+//!
+//! Both knobs are process-wide, mirroring the [`crate::core::recorder`]/
+//! [`crate::core::metrics_registry`] global dispatch points — except
+//! these are read-write rather than install-once, since the whole point
+//! is changing them while the process keeps running.
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use crate::api::LogLevel;
+
+struct LevelState {
+    default_level: LogLevel,
+    per_target: HashMap<String, LogLevel>,
+}
+
+impl LevelState {
+    fn new(default_level: LogLevel) -> Self {
+        Self { default_level, per_target: HashMap::new() }
+    }
+
+    fn is_enabled(&self, target: &str, level: LogLevel) -> bool {
+        let threshold = self.per_target.get(target).copied().unwrap_or(self.default_level);
+        level >= threshold
+    }
+}
+
+static LOG_LEVELS: OnceLock<RwLock<LevelState>> = OnceLock::new();
+
+fn log_levels() -> &'static RwLock<LevelState> {
+    LOG_LEVELS.get_or_init(|| RwLock::new(LevelState::new(LogLevel::Info)))
+}
+
+/// Sets the minimum level kept for every target with no more specific
+/// override from [`set_log_level`]. Defaults to [`LogLevel::Info`].
+pub fn set_default_log_level(level: LogLevel) {
+    log_levels().write().unwrap().default_level = level;
+}
+
+/// Sets the minimum level kept for `target` (a module path, e.g.
+/// `"myapp::billing"`), overriding [`set_default_log_level`] for that
+/// target only. Matching is exact, not a path prefix.
+pub fn set_log_level(target: impl Into<String>, level: LogLevel) {
+    log_levels().write().unwrap().per_target.insert(target.into(), level);
+}
+
+/// Removes a target-specific override set by [`set_log_level`], falling
+/// back to the default level for that target again.
+pub fn clear_log_level(target: &str) {
+    log_levels().write().unwrap().per_target.remove(target);
+}
+
+/// Whether a record at `level` for `target` should be kept, given the
+/// currently configured default and per-target levels. Used by
+/// [`crate::core::logging::Logger::log`] ahead of its own sampling
+/// check.
+pub fn log_level_enabled(target: &str, level: LogLevel) -> bool {
+    log_levels().read().unwrap().is_enabled(target, level)
+}
+
+static TRACE_SAMPLING_RATE: OnceLock<RwLock<f64>> = OnceLock::new();
+
+fn trace_sampling_rate_lock() -> &'static RwLock<f64> {
+    TRACE_SAMPLING_RATE.get_or_init(|| RwLock::new(1.0))
+}
+
+/// Sets the fraction (`0.0..=1.0`) of new root traces
+/// ([`crate::api::TraceContext::new_root`]) marked as sampled. Defaults
+/// to `1.0` (sample everything).
+pub fn set_trace_sampling_rate(rate: f64) {
+    *trace_sampling_rate_lock().write().unwrap() = rate;
+}
+
+/// The currently configured trace sampling rate.
+pub fn trace_sampling_rate() -> f64 {
+    *trace_sampling_rate_lock().read().unwrap()
+}
+
+fn sampled(rate: f64, roll: f64) -> bool {
+    roll < rate
+}
+
+/// Rolls the dice against the current [`trace_sampling_rate`] for a new
+/// root trace.
+pub(crate) fn decide_trace_sampled() -> bool {
+    sampled(trace_sampling_rate(), rand::random())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_state_falls_back_to_the_default_level_with_no_override() {
+        let state = LevelState::new(LogLevel::Warn);
+        assert!(!state.is_enabled("myapp::billing", LogLevel::Info));
+        assert!(state.is_enabled("myapp::billing", LogLevel::Warn));
+    }
+
+    #[test]
+    fn level_state_per_target_override_wins_over_the_default() {
+        let mut state = LevelState::new(LogLevel::Warn);
+        state.per_target.insert("myapp::billing".to_string(), LogLevel::Debug);
+
+        assert!(state.is_enabled("myapp::billing", LogLevel::Debug));
+        assert!(!state.is_enabled("myapp::shipping", LogLevel::Debug));
+    }
+
+    #[test]
+    fn sampled_keeps_rolls_under_the_rate_and_drops_the_rest() {
+        assert!(sampled(1.0, 0.0));
+        assert!(sampled(0.5, 0.49));
+        assert!(!sampled(0.5, 0.5));
+        assert!(!sampled(0.0, 0.0));
+    }
+
+    // The two tests below touch the process-wide globals directly (as
+    // does one logging test, under a target of its own); every other
+    // assertion above goes through `LevelState`/`sampled` so it can't
+    // race with them.
+    #[test]
+    fn global_log_level_round_trips_through_set_and_clear() {
+        set_default_log_level(LogLevel::Info);
+        set_log_level("runtime_config::tests::target", LogLevel::Error);
+        assert!(!log_level_enabled("runtime_config::tests::target", LogLevel::Info));
+        assert!(log_level_enabled("runtime_config::tests::target", LogLevel::Error));
+
+        clear_log_level("runtime_config::tests::target");
+        assert!(log_level_enabled("runtime_config::tests::target", LogLevel::Info));
+    }
+
+    #[test]
+    fn global_trace_sampling_rate_round_trips() {
+        set_trace_sampling_rate(0.25);
+        assert_eq!(trace_sampling_rate(), 0.25);
+        set_trace_sampling_rate(1.0);
+    }
+}
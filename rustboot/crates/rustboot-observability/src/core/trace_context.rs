@@ -0,0 +1,269 @@
+//! W3C `traceparent` parsing, generation, and formatting, plus task-local
+//! propagation so a trace follows a request across `tokio::spawn`,
+//! messaging consumers, and outgoing HTTP calls without manually
+//! threading a [`TraceContext`] through every call site.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use crate::api::{TraceContext, TraceContextError};
+use crate::core::runtime_config::decide_trace_sampled;
+
+/// Header name used by [`TraceContext::inject_headers`]/
+/// [`TraceContext::extract_headers`] for plain string-keyed header maps
+/// (e.g. a messaging `Message`'s headers).
+pub const TRACEPARENT_HEADER_NAME: &str = "traceparent";
+
+tokio::task_local! {
+    static CURRENT_TRACE: TraceContext;
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_hex<const N: usize>(field: &str, header: &str) -> Result<[u8; N], TraceContextError> {
+    if field.len() != N * 2 {
+        return Err(TraceContextError::Malformed(header.to_string()));
+    }
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&field[i * 2..i * 2 + 2], 16)
+            .map_err(|_| TraceContextError::Malformed(header.to_string()))?;
+    }
+    Ok(bytes)
+}
+
+impl TraceContext {
+    /// Starts a brand new trace with a fresh trace id and parent id,
+    /// sampled according to the current
+    /// [`crate::core::runtime_config::trace_sampling_rate`] (defaults to
+    /// sampling everything). Use this when a request arrives with no
+    /// (or an unparseable) `traceparent` header.
+    pub fn new_root() -> Self {
+        Self {
+            trace_id: rand::random(),
+            parent_id: rand::random(),
+            sampled: decide_trace_sampled(),
+        }
+    }
+
+    /// Derives the context for a child span: keeps the same trace id and
+    /// sampling decision, and assigns a fresh parent id. Call this on a
+    /// successfully parsed incoming `traceparent` before propagating it
+    /// onward, so downstream services see this request's span as their
+    /// parent rather than the one that called it.
+    pub fn child(&self) -> Self {
+        Self {
+            trace_id: self.trace_id,
+            parent_id: rand::random(),
+            sampled: self.sampled,
+        }
+    }
+
+    /// Parses a `traceparent` header value
+    /// (`{version}-{trace_id}-{parent_id}-{flags}`).
+    pub fn parse(header: &str) -> Result<Self, TraceContextError> {
+        let fields: Vec<&str> = header.split('-').collect();
+        let [version, trace_id, parent_id, flags] = fields[..] else {
+            return Err(TraceContextError::Malformed(header.to_string()));
+        };
+
+        if version != "00" {
+            return Err(TraceContextError::UnsupportedVersion(version.to_string()));
+        }
+
+        let trace_id: [u8; 16] = decode_hex(trace_id, header)?;
+        let parent_id: [u8; 8] = decode_hex(parent_id, header)?;
+        if trace_id == [0; 16] || parent_id == [0; 8] {
+            return Err(TraceContextError::Malformed(header.to_string()));
+        }
+
+        let flags: [u8; 1] = decode_hex(flags, header)?;
+        let sampled = flags[0] & 0x01 != 0;
+
+        Ok(Self {
+            trace_id,
+            parent_id,
+            sampled,
+        })
+    }
+
+    /// Formats this context as a `traceparent` header value.
+    pub fn to_header_value(&self) -> String {
+        format!(
+            "00-{}-{}-{:02x}",
+            encode_hex(&self.trace_id),
+            encode_hex(&self.parent_id),
+            u8::from(self.sampled),
+        )
+    }
+
+    /// Returns the [`TraceContext`] the currently running task was
+    /// started with via [`TraceContext::scope`], or `None` outside of a
+    /// scope (e.g. before the first request-handling task is spawned).
+    pub fn current() -> Option<Self> {
+        CURRENT_TRACE.try_with(|ctx| *ctx).ok()
+    }
+
+    /// Runs `fut` with `self` as the task-local [`TraceContext::current`]
+    /// for its entire lifetime, including across any further
+    /// `tokio::spawn`ed subtasks that also call `scope` (or that inherit
+    /// it by running a child future from within this one).
+    ///
+    /// Call this once at the boundary where a trace enters the process —
+    /// an inbound HTTP request, a message pulled off a queue — so every
+    /// `#[timed]`/`#[traced]` span and outgoing call made while handling
+    /// it can reach back to [`TraceContext::current`] without the caller
+    /// threading a `TraceContext` through every function signature.
+    pub async fn scope<F: Future>(self, fut: F) -> F::Output {
+        CURRENT_TRACE.scope(self, fut).await
+    }
+
+    /// Sets the `traceparent` header on an outgoing HTTP request (or
+    /// response) to this context's value.
+    pub fn inject_http_headers(&self, headers: &mut http::HeaderMap) {
+        if let Ok(value) = http::HeaderValue::from_str(&self.to_header_value()) {
+            headers.insert(http::header::HeaderName::from_static("traceparent"), value);
+        }
+    }
+
+    /// Parses an incoming `traceparent` HTTP header, if present and
+    /// well-formed.
+    pub fn extract_http_headers(headers: &http::HeaderMap) -> Option<Self> {
+        headers
+            .get("traceparent")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| Self::parse(value).ok())
+    }
+
+    /// Sets the `traceparent` entry on a plain string-keyed header map
+    /// (e.g. a messaging `Message`'s headers) to this context's value.
+    pub fn inject_headers(&self, headers: &mut HashMap<String, String>) {
+        headers.insert(TRACEPARENT_HEADER_NAME.to_string(), self.to_header_value());
+    }
+
+    /// Parses an incoming `traceparent` entry from a plain string-keyed
+    /// header map, if present and well-formed.
+    pub fn extract_headers(headers: &HashMap<String, String>) -> Option<Self> {
+        headers
+            .get(TRACEPARENT_HEADER_NAME)
+            .and_then(|value| Self::parse(value).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_sampled_header() {
+        let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let ctx = TraceContext::parse(header).unwrap();
+        assert!(ctx.sampled);
+        assert_eq!(ctx.to_header_value(), header);
+    }
+
+    #[test]
+    fn parses_an_unsampled_header() {
+        let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00";
+        let ctx = TraceContext::parse(header).unwrap();
+        assert!(!ctx.sampled);
+    }
+
+    #[test]
+    fn rejects_an_unsupported_version() {
+        assert_eq!(
+            TraceContext::parse("ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            Err(TraceContextError::UnsupportedVersion("ff".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_the_wrong_number_of_fields() {
+        assert!(matches!(
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736"),
+            Err(TraceContextError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_an_all_zero_trace_id() {
+        assert!(matches!(
+            TraceContext::parse("00-00000000000000000000000000000000-00f067aa0ba902b7-01"),
+            Err(TraceContextError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn child_keeps_the_trace_id_and_assigns_a_new_parent_id() {
+        let parent = TraceContext::new_root();
+        let child = parent.child();
+        assert_eq!(child.trace_id, parent.trace_id);
+        assert_ne!(child.parent_id, parent.parent_id);
+        assert_eq!(child.sampled, parent.sampled);
+    }
+
+    #[test]
+    fn new_root_generates_distinct_contexts() {
+        assert_ne!(TraceContext::new_root(), TraceContext::new_root());
+    }
+
+    #[test]
+    fn current_is_none_outside_of_a_scope() {
+        assert_eq!(TraceContext::current(), None);
+    }
+
+    #[tokio::test]
+    async fn scope_makes_the_context_available_as_current() {
+        let ctx = TraceContext::new_root();
+        ctx.scope(async move {
+            assert_eq!(TraceContext::current(), Some(ctx));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn scope_propagates_into_a_spawned_subtask() {
+        let ctx = TraceContext::new_root();
+        ctx.scope(async move {
+            tokio::spawn(TraceContext::current().unwrap().scope(async move {
+                assert_eq!(TraceContext::current(), Some(ctx));
+            }))
+            .await
+            .unwrap();
+        })
+        .await;
+    }
+
+    #[test]
+    fn http_header_round_trips_through_inject_and_extract() {
+        let ctx = TraceContext::new_root();
+        let mut headers = http::HeaderMap::new();
+        ctx.inject_http_headers(&mut headers);
+        assert_eq!(TraceContext::extract_http_headers(&headers), Some(ctx));
+    }
+
+    #[test]
+    fn extract_http_headers_is_none_when_absent() {
+        let headers = http::HeaderMap::new();
+        assert_eq!(TraceContext::extract_http_headers(&headers), None);
+    }
+
+    #[test]
+    fn message_headers_round_trip_through_inject_and_extract() {
+        let ctx = TraceContext::new_root();
+        let mut headers = HashMap::new();
+        ctx.inject_headers(&mut headers);
+        assert_eq!(
+            headers.get(TRACEPARENT_HEADER_NAME).unwrap(),
+            &ctx.to_header_value()
+        );
+        assert_eq!(TraceContext::extract_headers(&headers), Some(ctx));
+    }
+
+    #[test]
+    fn extract_headers_is_none_when_absent() {
+        assert_eq!(TraceContext::extract_headers(&HashMap::new()), None);
+    }
+}
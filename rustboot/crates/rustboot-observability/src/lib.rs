@@ -0,0 +1,66 @@
+//! Metrics, tracing, and logging building blocks for the rustboot
+//! framework.
+//!
+//! - [`LatencyHistogram`]: a bounded-memory, logarithmic-bucket histogram
+//!   for p50/p95/p99 latency estimation.
+//! - [`InMemoryMetrics`]: an in-process counter and histogram registry,
+//!   for tests, debug endpoints, and adaptive concurrency controllers.
+//! - [`core::recorder`]: the global dispatch point for the
+//!   [`rustboot_observability_derive::timed`]/[`rustboot_observability_derive::traced`]
+//!   attribute macros, which emit a [`SpanRecord`] per call to a
+//!   pluggable [`spi::Recorder`] instead of a bare log line.
+//! - [`TraceContext`]: parses, generates, and formats W3C `traceparent`
+//!   headers for propagating a distributed trace across services, and
+//!   (via [`TraceContext::scope`]/[`TraceContext::current`]) across
+//!   `tokio::spawn`ed subtasks of the same request within this service.
+//! - [`PrometheusMetrics`]: a labeled counter/gauge/histogram registry
+//!   rendering the Prometheus text exposition format, for shipping
+//!   metrics out to an external monitoring stack (see
+//!   `rustboot_web::core::metrics` for a ready-made `/metrics` handler).
+//! - [`Metrics`] / [`core::metrics_registry`]: a pluggable counter/
+//!   gauge/histogram backend and its global dispatch point, so the
+//!   [`rustboot_observability_derive::metrics_histogram`] attribute
+//!   macro has somewhere real to record into.
+//! - [`core::logging`]: a [`LoggerBuilder`] producing a [`Logger`] that
+//!   formats records as JSON or a human-readable line, applies field
+//!   redaction and sampling, and dispatches to one or more pluggable
+//!   [`LogSink`]s (a ready-made [`core::logging::StdoutSink`] and
+//!   [`core::logging::RollingFileSink`], or your own for a remote
+//!   aggregator), configurable from [`rustboot_config::Config`] via
+//!   [`LoggerBuilder::from_config`].
+//! - [`core::log_parsing`]: the inverse of [`core::logging`] — parses a
+//!   JSON or pretty log line (or a whole file of them) back into
+//!   [`LogRecord`]s, for tailers, viewers, and tests asserting on
+//!   emitted lines.
+//! - [`ContextFields`]: task-local key/value fields middleware (session,
+//!   auth, request-id) can attach once, that then show up automatically
+//!   on every [`core::logging::Logger`] line and `#[timed]`/`#[traced]`
+//!   [`SpanRecord`] for the rest of that request's scope.
+//! - [`core::runtime_config`]: process-wide, runtime-adjustable log
+//!   level (globally or per target) and trace sampling rate, so an
+//!   admin endpoint or config hot-reload handler can turn up verbosity
+//!   for one module without a redeploy.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{
+    LogFormat, LogLevel, LogRecord, LoggingError, Percentiles, SamplingConfig, SpanOutcome,
+    SpanRecord, TraceContext, TraceContextError,
+};
+pub use core::context_fields::ContextFields;
+pub use core::histogram::LatencyHistogram;
+pub use core::in_memory::{HistogramConfig, InMemoryMetrics};
+pub use core::log_parsing::{parse_line, parse_lines};
+pub use core::logging::{Logger, LoggerBuilder, RollingFileSink, StdoutSink};
+pub use core::metrics_registry::{install_global_metrics, increment_counter, observe_histogram, record_gauge};
+pub use core::prometheus::{Labels, PrometheusMetrics};
+pub use core::recorder::{install_global_recorder, record};
+pub use core::runtime_config::{
+    clear_log_level, log_level_enabled, set_default_log_level, set_log_level, set_trace_sampling_rate,
+    trace_sampling_rate,
+};
+pub use core::trace_context::TRACEPARENT_HEADER_NAME;
+pub use rustboot_observability_derive::{metrics_histogram, timed, traced};
+pub use spi::{LogSink, Metrics, Recorder};
@@ -0,0 +1,47 @@
+//! Pluggable sink for the observability module.
+
+use crate::api::SpanRecord;
+
+/// Receives a [`SpanRecord`] for every `#[timed]`/`#[traced]` call, once
+/// installed via [`crate::core::recorder::install_global_recorder`].
+///
+/// Implement this to forward spans into a real metrics/tracing backend.
+/// Called synchronously and inline on every instrumented call, so
+/// implementations should be cheap (e.g. push onto a queue rather than
+/// block on network I/O).
+pub trait Recorder: Send + Sync {
+    /// Records a single completed span.
+    fn record(&self, span: SpanRecord);
+}
+
+/// A pluggable counter/gauge/histogram backend, with labels on every
+/// metric type.
+///
+/// Implemented by [`crate::core::prometheus::PrometheusMetrics`]; install
+/// one globally via
+/// [`crate::core::metrics_registry::install_global_metrics`] so
+/// `#[metrics_histogram]` (and any other caller) has a real backend to
+/// record into.
+pub trait Metrics: Send + Sync {
+    /// Increments the named counter, with the given labels, by `delta`.
+    fn counter(&self, name: &str, labels: &[(&str, &str)], delta: u64);
+    /// Sets the named gauge, with the given labels, to `value`.
+    fn gauge(&self, name: &str, labels: &[(&str, &str)], value: f64);
+    /// Records one observation for the named histogram, with the given
+    /// labels.
+    fn histogram(&self, name: &str, labels: &[(&str, &str)], value: f64);
+}
+
+/// A destination for formatted log lines, installed on a
+/// [`crate::core::logging::LoggerBuilder`].
+///
+/// Called synchronously and inline for every logged record that survives
+/// redaction and sampling, so implementations should be cheap (e.g. write
+/// to a buffered writer rather than block on network I/O). Implement this
+/// to ship logs to a remote aggregator; [`crate::core::logging::StdoutSink`]
+/// and [`crate::core::logging::RollingFileSink`] cover the common local
+/// cases.
+pub trait LogSink: Send + Sync {
+    /// Writes one already-formatted log line, without a trailing newline.
+    fn write_line(&self, line: &str);
+}
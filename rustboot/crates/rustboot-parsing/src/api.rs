@@ -0,0 +1,62 @@
+//! Public types for the parsing module.
+
+/// A markdown or HTML heading, as found by [`crate::core::markdown::extract_headers`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    /// The heading level, 1 for `#` through 6 for `######`.
+    pub level: u8,
+    /// The heading text, with markdown emphasis markers left in place.
+    pub text: String,
+    /// A URL-safe slug derived from `text`, suitable for an anchor.
+    pub slug: String,
+}
+
+/// A markdown link, as found by [`crate::core::markdown::extract_links`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub text: String,
+    pub url: String,
+}
+
+/// The delimiter style a [`FrontMatter`] block was fenced with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    /// Fenced with `---`, conventionally YAML.
+    Yaml,
+    /// Fenced with `+++`, conventionally TOML.
+    Toml,
+}
+
+/// A document's front matter block, as found by
+/// [`crate::core::markdown::extract_front_matter`]. The `raw` text is
+/// returned unparsed, since interpreting it is the caller's concern
+/// (rustboot-serialization can deserialize it once a format is picked).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatter {
+    pub format: FrontMatterFormat,
+    pub raw: String,
+}
+
+/// A fenced code block, as found by
+/// [`crate::core::markdown::extract_code_blocks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The info string after the opening fence, e.g. `rs` in ` ```rs`.
+    /// Empty if unspecified.
+    pub language: String,
+    pub code: String,
+}
+
+/// Errors produced while parsing markdown, HTML, or XML.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ParsingError {
+    /// A CSS selector passed to [`crate::core::html::Document::select`]
+    /// didn't parse.
+    #[error("invalid CSS selector: '{0}'")]
+    InvalidSelector(String),
+    /// The input given to [`crate::core::xml::XmlDocument::parse`]
+    /// wasn't well-formed XML, or a path given to an `XmlDocument` query
+    /// used a form the XPath-lite subset doesn't support (e.g. `//a/b`).
+    #[error("invalid XML: {0}")]
+    InvalidXml(String),
+}
@@ -0,0 +1,199 @@
+//! DOM-lite HTML parsing, CSS-selector extraction, and allowlist-based
+//! sanitization for user-generated content.
+//!
+//! Parsing and selector matching are delegated to `scraper` (built on
+//! `html5ever`), and sanitization to `ammonia`, rather than hand-rolled
+//! here — both are the same kind of "don't reinvent a w3c grammar"
+//! trade-off this crate's markdown analyzer deliberately avoids by
+//! staying simple.
+
+use std::collections::HashMap;
+
+use crate::api::ParsingError;
+
+/// A parsed HTML document. Cheap to construct; holds the parsed tree
+/// for repeated [`Document::select`]/[`Document::text`] calls.
+pub struct Document(scraper::Html);
+
+impl Document {
+    /// Parses `input` as an HTML document.
+    pub fn parse(input: &str) -> Self {
+        Self(scraper::Html::parse_document(input))
+    }
+
+    /// Returns the text content of every element matching the CSS
+    /// `selector`, in document order.
+    ///
+    /// ```
+    /// use rustboot_parsing::html::Document;
+    ///
+    /// let doc = Document::parse("<p>one</p><p>two</p>");
+    /// assert_eq!(doc.select_text("p").unwrap(), vec!["one", "two"]);
+    /// ```
+    pub fn select_text(&self, selector: &str) -> Result<Vec<String>, ParsingError> {
+        let selector = parse_selector(selector)?;
+        Ok(self
+            .0
+            .select(&selector)
+            .map(|element| element.text().collect::<Vec<_>>().join(""))
+            .collect())
+    }
+
+    /// Returns the outer HTML of every element matching the CSS
+    /// `selector`, in document order.
+    pub fn select_html(&self, selector: &str) -> Result<Vec<String>, ParsingError> {
+        let selector = parse_selector(selector)?;
+        Ok(self.0.select(&selector).map(|element| element.html()).collect())
+    }
+
+    /// Returns the value of `attribute` for every element matching the
+    /// CSS `selector` that has it set.
+    pub fn select_attribute(&self, selector: &str, attribute: &str) -> Result<Vec<String>, ParsingError> {
+        let selector = parse_selector(selector)?;
+        Ok(self
+            .0
+            .select(&selector)
+            .filter_map(|element| element.value().attr(attribute))
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// The text content of the whole document, with tags stripped.
+    pub fn text(&self) -> String {
+        self.0.root_element().text().collect::<Vec<_>>().join("")
+    }
+}
+
+fn parse_selector(selector: &str) -> Result<scraper::Selector, ParsingError> {
+    scraper::Selector::parse(selector).map_err(|_| ParsingError::InvalidSelector(selector.to_string()))
+}
+
+/// Which tags and attributes an allowlist-based sanitizer keeps.
+///
+/// Everything not explicitly allowed is stripped, including the tag
+/// itself (its text content is kept) and `<script>`/`<style>` content
+/// (removed entirely, per `ammonia`'s default).
+pub struct SanitizePolicy {
+    tags: Vec<String>,
+    attributes: HashMap<String, Vec<String>>,
+}
+
+impl SanitizePolicy {
+    /// A policy that strips every tag, keeping only text content.
+    pub fn new() -> Self {
+        Self {
+            tags: Vec::new(),
+            attributes: HashMap::new(),
+        }
+    }
+
+    /// A policy allowing the basic formatting, list, and link tags
+    /// typical of user-generated content — paragraphs, emphasis,
+    /// headings, lists, blockquotes, code, and links with `href`.
+    pub fn basic() -> Self {
+        Self::new()
+            .allow_tag("p")
+            .allow_tag("br")
+            .allow_tag("strong")
+            .allow_tag("em")
+            .allow_tag("code")
+            .allow_tag("pre")
+            .allow_tag("blockquote")
+            .allow_tag("ul")
+            .allow_tag("ol")
+            .allow_tag("li")
+            .allow_tag("h1")
+            .allow_tag("h2")
+            .allow_tag("h3")
+            .allow_tag("h4")
+            .allow_tag("h5")
+            .allow_tag("h6")
+            .allow_tag("a")
+            .allow_attribute("a", "href")
+    }
+
+    /// Allows `tag` to appear in sanitized output.
+    pub fn allow_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Allows `attribute` on `tag` in sanitized output.
+    pub fn allow_attribute(mut self, tag: impl Into<String>, attribute: impl Into<String>) -> Self {
+        self.attributes.entry(tag.into()).or_default().push(attribute.into());
+        self
+    }
+}
+
+impl Default for SanitizePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Strips everything from `input` not allowed by `policy`.
+///
+/// ```
+/// use rustboot_parsing::html::{sanitize, SanitizePolicy};
+///
+/// let output = sanitize(
+///     "<p>hi</p><script>alert(1)</script>",
+///     &SanitizePolicy::basic(),
+/// );
+/// assert_eq!(output, "<p>hi</p>");
+/// ```
+pub fn sanitize(input: &str, policy: &SanitizePolicy) -> String {
+    let mut builder = ammonia::Builder::default();
+    builder.tags(policy.tags.iter().map(String::as_str).collect());
+    builder.generic_attributes(std::collections::HashSet::new());
+
+    let attributes: HashMap<&str, std::collections::HashSet<&str>> = policy
+        .attributes
+        .iter()
+        .map(|(tag, attrs)| (tag.as_str(), attrs.iter().map(String::as_str).collect()))
+        .collect();
+    builder.tag_attributes(attributes);
+
+    builder.clean(input).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_text_returns_matched_elements_in_order() {
+        let doc = Document::parse("<div><p>one</p><p>two</p></div>");
+        assert_eq!(doc.select_text("p").unwrap(), vec!["one".to_string(), "two".to_string()]);
+    }
+
+    #[test]
+    fn select_attribute_returns_only_elements_with_the_attribute() {
+        let doc = Document::parse(r#"<a href="/a">a</a><a>b</a>"#);
+        assert_eq!(doc.select_attribute("a", "href").unwrap(), vec!["/a".to_string()]);
+    }
+
+    #[test]
+    fn select_rejects_an_invalid_selector() {
+        let doc = Document::parse("<p>one</p>");
+        assert!(doc.select_text(":::not-a-selector").is_err());
+    }
+
+    #[test]
+    fn text_strips_every_tag() {
+        let doc = Document::parse("<div><p>hello <strong>world</strong></p></div>");
+        assert_eq!(doc.text(), "hello world");
+    }
+
+    #[test]
+    fn sanitize_keeps_only_allowed_tags_and_attributes() {
+        let output = sanitize(r#"<p onclick="evil()">hi <script>bad()</script></p>"#, &SanitizePolicy::basic());
+        assert_eq!(output, "<p>hi </p>");
+    }
+
+    #[test]
+    fn sanitize_with_an_empty_policy_strips_every_tag() {
+        let output = sanitize("<p>hi</p>", &SanitizePolicy::new());
+        assert_eq!(output, "hi");
+    }
+}
@@ -0,0 +1,319 @@
+//! A lightweight markdown analyzer: structural extraction without a
+//! full CommonMark parse, for tooling that only needs headings, links,
+//! or a word count rather than a render pipeline.
+
+use crate::api::{CodeBlock, FrontMatter, FrontMatterFormat, Header, Link};
+
+/// Extracts every ATX heading (`#` through `######`) from `input`,
+/// along with a URL-safe slug for each.
+///
+/// ```
+/// use rustboot_parsing::extract_headers;
+///
+/// let headers = extract_headers("# Getting Started\n\nSome text.\n\n## Installation\n");
+/// assert_eq!(headers[0].level, 1);
+/// assert_eq!(headers[0].slug, "getting-started");
+/// assert_eq!(headers[1].level, 2);
+/// ```
+pub fn extract_headers(input: &str) -> Vec<Header> {
+    let mut headers = Vec::new();
+    let mut slugs = SlugCounter::new();
+
+    for line in input.lines() {
+        let trimmed = line.trim_start();
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if level == 0 || level > 6 {
+            continue;
+        }
+        let rest = trimmed[level..].trim();
+        if rest.is_empty() || !trimmed[level..].starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let text = rest.trim_end_matches('#').trim().to_string();
+        let slug = slugs.next(&slugify(&text));
+        headers.push(Header {
+            level: level as u8,
+            text,
+            slug,
+        });
+    }
+
+    headers
+}
+
+/// Extracts every inline markdown link (`[text](url)`) from `input`.
+/// Does not match reference-style (`[text][ref]`) or bare autolinks.
+///
+/// ```
+/// use rustboot_parsing::extract_links;
+///
+/// let links = extract_links("See the [docs](https://example.com/docs) for more.");
+/// assert_eq!(links[0].text, "docs");
+/// assert_eq!(links[0].url, "https://example.com/docs");
+/// ```
+pub fn extract_links(input: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let bytes = input.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'[' {
+            if let Some((text, url, next)) = parse_link_at(input, i) {
+                links.push(Link { text, url });
+                i = next;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    links
+}
+
+/// Parses one `[text](url)` link starting at byte offset `start` (which
+/// must point at `[`), returning the link and the offset just past it.
+fn parse_link_at(input: &str, start: usize) -> Option<(String, String, usize)> {
+    let rest = &input[start..];
+    let text_end = rest.find(']')?;
+    let text = &rest[1..text_end];
+
+    let after_text = &rest[text_end + 1..];
+    if !after_text.starts_with('(') {
+        return None;
+    }
+    let url_end = after_text.find(')')?;
+    let url = &after_text[1..url_end];
+
+    let consumed = start + text_end + 1 + url_end + 1;
+    Some((text.to_string(), url.to_string(), consumed))
+}
+
+/// Counts the words in `input`, treating markdown syntax characters
+/// (`#`, `*`, `_`, `` ` ``, `[`, `]`, `(`, `)`) as whitespace so they
+/// don't inflate the count.
+///
+/// ```
+/// use rustboot_parsing::word_count;
+///
+/// assert_eq!(word_count("# Hello **world**"), 2);
+/// ```
+pub fn word_count(input: &str) -> usize {
+    input
+        .split(|c: char| c.is_whitespace() || "#*_`[]()".contains(c))
+        .filter(|word| !word.is_empty())
+        .count()
+}
+
+/// Extracts a leading front matter block (`---`/`+++` fenced, YAML or
+/// TOML by convention) from `input`, returning it unparsed along with
+/// the remaining body. Returns `None` if `input` doesn't open with a
+/// recognized fence on its first line.
+///
+/// ```
+/// use rustboot_parsing::extract_front_matter;
+///
+/// let (front_matter, body) = extract_front_matter("---\ntitle: Hi\n---\n# Hi\n").unwrap();
+/// assert_eq!(front_matter.raw, "title: Hi");
+/// assert_eq!(body, "# Hi\n");
+/// ```
+pub fn extract_front_matter(input: &str) -> Option<(FrontMatter, &str)> {
+    let mut lines = input.split_inclusive('\n');
+    let first = lines.next()?;
+    let format = match first.trim_end() {
+        "---" => FrontMatterFormat::Yaml,
+        "+++" => FrontMatterFormat::Toml,
+        _ => return None,
+    };
+    let fence = first.trim_end();
+
+    let mut raw_end = 0;
+    let mut body_start = None;
+    let mut offset = first.len();
+    for line in lines {
+        if line.trim_end() == fence {
+            body_start = Some(offset + line.len());
+            break;
+        }
+        raw_end = offset + line.len();
+        offset += line.len();
+    }
+
+    let body_start = body_start?;
+    let raw = input[first.len()..raw_end].trim_end_matches('\n').to_string();
+    Some((FrontMatter { format, raw }, &input[body_start..]))
+}
+
+/// Extracts every fenced code block (` ``` ` or `~~~`, three or more
+/// characters) from `input`, in document order. An unterminated fence
+/// runs to the end of `input`.
+///
+/// ```
+/// use rustboot_parsing::extract_code_blocks;
+///
+/// let blocks = extract_code_blocks("```rs\nfn main() {}\n```\n");
+/// assert_eq!(blocks[0].language, "rs");
+/// assert_eq!(blocks[0].code, "fn main() {}\n");
+/// ```
+pub fn extract_code_blocks(input: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut lines = input.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let fence_char = trimmed.chars().next().filter(|&c| c == '`' || c == '~');
+        let Some(fence_char) = fence_char else { continue };
+
+        let fence_len = trimmed.chars().take_while(|&c| c == fence_char).count();
+        if fence_len < 3 {
+            continue;
+        }
+        let language = trimmed[fence_len..].trim().to_string();
+
+        let mut code = String::new();
+        for line in lines.by_ref() {
+            let closing = line.trim_start();
+            let closing_len = closing.chars().take_while(|&c| c == fence_char).count();
+            if closing_len >= fence_len && closing[closing_len..].trim().is_empty() {
+                break;
+            }
+            code.push_str(line);
+            code.push('\n');
+        }
+
+        blocks.push(CodeBlock { language, code });
+    }
+
+    blocks
+}
+
+/// Lowercases `text`, drops everything but letters, digits, spaces, and
+/// hyphens, and joins the remaining words with `-`.
+pub(crate) fn slugify(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() || c == '-' { c.to_ascii_lowercase() } else { ' ' })
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+/// Appends `-2`, `-3`, ... to repeated slugs, matching how most static
+/// site generators disambiguate duplicate heading anchors.
+pub(crate) struct SlugCounter {
+    seen: std::collections::HashMap<String, usize>,
+}
+
+impl SlugCounter {
+    pub(crate) fn new() -> Self {
+        Self {
+            seen: std::collections::HashMap::new(),
+        }
+    }
+
+    pub(crate) fn next(&mut self, slug: &str) -> String {
+        let count = self.seen.entry(slug.to_string()).or_insert(0);
+        *count += 1;
+        if *count == 1 {
+            slug.to_string()
+        } else {
+            format!("{slug}-{count}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_headers_reads_every_level() {
+        let headers = extract_headers("# One\n## Two\n### Three");
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers[2].level, 3);
+        assert_eq!(headers[2].text, "Three");
+    }
+
+    #[test]
+    fn extract_headers_ignores_non_heading_hashes() {
+        let headers = extract_headers("This is #not-a-heading\n#alsonotaheading");
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn extract_headers_disambiguates_duplicate_slugs() {
+        let headers = extract_headers("# Setup\n## Setup");
+        assert_eq!(headers[0].slug, "setup");
+        assert_eq!(headers[1].slug, "setup-2");
+    }
+
+    #[test]
+    fn extract_links_reads_every_inline_link() {
+        let links = extract_links("[a](https://a.com) and [b](https://b.com)");
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[1].text, "b");
+        assert_eq!(links[1].url, "https://b.com");
+    }
+
+    #[test]
+    fn extract_links_ignores_unclosed_brackets() {
+        assert!(extract_links("[incomplete").is_empty());
+    }
+
+    #[test]
+    fn word_count_ignores_markdown_syntax() {
+        assert_eq!(word_count("`code` and [a link](url)"), 5);
+    }
+
+    #[test]
+    fn slugify_strips_punctuation() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+    }
+
+    #[test]
+    fn extract_front_matter_reads_yaml_fence() {
+        let (front_matter, body) = extract_front_matter("---\ntitle: Hi\ndraft: true\n---\nBody text\n").unwrap();
+        assert_eq!(front_matter.format, FrontMatterFormat::Yaml);
+        assert_eq!(front_matter.raw, "title: Hi\ndraft: true");
+        assert_eq!(body, "Body text\n");
+    }
+
+    #[test]
+    fn extract_front_matter_reads_toml_fence() {
+        let (front_matter, _) = extract_front_matter("+++\ntitle = \"Hi\"\n+++\n").unwrap();
+        assert_eq!(front_matter.format, FrontMatterFormat::Toml);
+        assert_eq!(front_matter.raw, "title = \"Hi\"");
+    }
+
+    #[test]
+    fn extract_front_matter_returns_none_without_a_leading_fence() {
+        assert!(extract_front_matter("# Just a heading\n").is_none());
+    }
+
+    #[test]
+    fn extract_front_matter_returns_none_for_an_unterminated_fence() {
+        assert!(extract_front_matter("---\ntitle: Hi\n").is_none());
+    }
+
+    #[test]
+    fn extract_code_blocks_reads_language_and_body() {
+        let blocks = extract_code_blocks("Some text.\n```rs\nfn main() {}\n```\nMore text.\n");
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].language, "rs");
+        assert_eq!(blocks[0].code, "fn main() {}\n");
+    }
+
+    #[test]
+    fn extract_code_blocks_reads_multiple_blocks_and_tilde_fences() {
+        let blocks = extract_code_blocks("```\nfirst\n```\n~~~py\nsecond\n~~~\n");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].language, "");
+        assert_eq!(blocks[1].language, "py");
+        assert_eq!(blocks[1].code, "second\n");
+    }
+
+    #[test]
+    fn extract_code_blocks_runs_unterminated_fence_to_end() {
+        let blocks = extract_code_blocks("```rs\nfn main() {}\n");
+        assert_eq!(blocks[0].code, "fn main() {}\n");
+    }
+}
@@ -0,0 +1,6 @@
+//! Implementation details for the parsing module.
+
+pub mod html;
+pub mod markdown;
+pub mod render;
+pub mod xml;
@@ -0,0 +1,293 @@
+//! Markdown-to-HTML rendering with CommonMark extensions, heading
+//! anchors consistent with [`crate::core::markdown::extract_headers`],
+//! and sanitization of the output.
+//!
+//! Rendering itself is delegated to `pulldown-cmark` rather than
+//! hand-rolled, for the same "don't reinvent a w3c grammar" reason as
+//! [`crate::core::html`].
+
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+use super::html::{sanitize, SanitizePolicy};
+use super::markdown::{slugify, SlugCounter};
+
+/// A hook invoked for each fenced code block, given its language (empty
+/// if unspecified) and source, returning the HTML to emit in its place.
+type Highlighter = dyn Fn(&str, &str) -> String + Send + Sync;
+
+/// Options controlling [`render_markdown`]: which CommonMark extensions
+/// to enable, an optional syntax-highlighting hook, and the sanitizer
+/// allowlist applied to the rendered output.
+pub struct RenderOptions {
+    tables: bool,
+    footnotes: bool,
+    task_lists: bool,
+    strikethrough: bool,
+    sanitize_policy: Option<SanitizePolicy>,
+    highlighter: Option<Box<Highlighter>>,
+}
+
+impl RenderOptions {
+    /// Options with every extension disabled and the default sanitizer
+    /// allowlist.
+    pub fn new() -> Self {
+        Self {
+            tables: false,
+            footnotes: false,
+            task_lists: false,
+            strikethrough: false,
+            sanitize_policy: None,
+            highlighter: None,
+        }
+    }
+
+    /// Enables GitHub-style pipe tables.
+    pub fn tables(mut self, enabled: bool) -> Self {
+        self.tables = enabled;
+        self
+    }
+
+    /// Enables `[^note]` footnote references and definitions.
+    pub fn footnotes(mut self, enabled: bool) -> Self {
+        self.footnotes = enabled;
+        self
+    }
+
+    /// Enables `- [ ]`/`- [x]` task list items, rendered as checkboxes.
+    pub fn task_lists(mut self, enabled: bool) -> Self {
+        self.task_lists = enabled;
+        self
+    }
+
+    /// Enables `~~strikethrough~~`.
+    pub fn strikethrough(mut self, enabled: bool) -> Self {
+        self.strikethrough = enabled;
+        self
+    }
+
+    /// Overrides the sanitizer allowlist applied to the rendered output.
+    /// By default, a policy covering the enabled extensions (plus
+    /// heading `id` attributes and code block language classes) is
+    /// used.
+    pub fn sanitize_with(mut self, policy: SanitizePolicy) -> Self {
+        self.sanitize_policy = Some(policy);
+        self
+    }
+
+    /// Registers a hook producing highlighted HTML for fenced code
+    /// blocks. Without one, fenced blocks render as plain
+    /// `<pre><code class="language-...">`.
+    pub fn highlighter(mut self, highlighter: impl Fn(&str, &str) -> String + Send + Sync + 'static) -> Self {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Renders `input` as sanitized HTML.
+///
+/// ```
+/// use rustboot_parsing::render::{render_markdown, RenderOptions};
+///
+/// let html = render_markdown("# Title\n\nSome *text*.", &RenderOptions::new());
+/// assert_eq!(html, "<h1 id=\"title\">Title</h1>\n<p>Some <em>text</em>.</p>\n");
+/// ```
+pub fn render_markdown(input: &str, options: &RenderOptions) -> String {
+    let mut parser_options = Options::empty();
+    if options.tables {
+        parser_options.insert(Options::ENABLE_TABLES);
+    }
+    if options.footnotes {
+        parser_options.insert(Options::ENABLE_FOOTNOTES);
+    }
+    if options.task_lists {
+        parser_options.insert(Options::ENABLE_TASKLISTS);
+    }
+    if options.strikethrough {
+        parser_options.insert(Options::ENABLE_STRIKETHROUGH);
+    }
+
+    let events: Vec<Event> = Parser::new_ext(input, parser_options).collect();
+    let events = slugify_headings(events);
+    let events = highlight_code_blocks(events, options.highlighter.as_deref());
+
+    let mut rendered = String::new();
+    pulldown_cmark::html::push_html(&mut rendered, events.into_iter());
+
+    match &options.sanitize_policy {
+        Some(policy) => sanitize(&rendered, policy),
+        None => sanitize(&rendered, &default_sanitize_policy(options)),
+    }
+}
+
+/// Assigns each heading an `id` matching the slug
+/// [`crate::core::markdown::extract_headers`] would derive from its
+/// text, disambiguating duplicates the same way.
+fn slugify_headings(events: Vec<Event>) -> Vec<Event> {
+    let mut slugs = SlugCounter::new();
+    let mut output = Vec::with_capacity(events.len());
+    let mut i = 0;
+
+    while i < events.len() {
+        let Event::Start(Tag::Heading { level, classes, attrs, .. }) = &events[i] else {
+            output.push(events[i].clone());
+            i += 1;
+            continue;
+        };
+        let (level, classes, attrs) = (*level, classes.clone(), attrs.clone());
+
+        let mut text = String::new();
+        let mut j = i + 1;
+        while !matches!(events[j], Event::End(TagEnd::Heading(_))) {
+            if let Event::Text(t) | Event::Code(t) = &events[j] {
+                text.push_str(t);
+            }
+            j += 1;
+        }
+
+        let slug = slugs.next(&slugify(&text));
+        output.push(Event::Start(Tag::Heading {
+            level,
+            id: Some(CowStr::from(slug)),
+            classes,
+            attrs,
+        }));
+        output.extend(events[i + 1..j].iter().cloned());
+        output.push(Event::End(TagEnd::Heading(level)));
+        i = j + 1;
+    }
+
+    output
+}
+
+/// Replaces each fenced code block with `highlighter`'s output, if one
+/// is set; otherwise leaves events untouched.
+fn highlight_code_blocks<'a>(events: Vec<Event<'a>>, highlighter: Option<&Highlighter>) -> Vec<Event<'a>> {
+    let Some(highlighter) = highlighter else {
+        return events;
+    };
+
+    let mut output = Vec::with_capacity(events.len());
+    let mut i = 0;
+
+    while i < events.len() {
+        let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) = &events[i] else {
+            output.push(events[i].clone());
+            i += 1;
+            continue;
+        };
+        let lang = lang.to_string();
+
+        let mut code = String::new();
+        let mut j = i + 1;
+        while !matches!(events[j], Event::End(TagEnd::CodeBlock)) {
+            if let Event::Text(t) = &events[j] {
+                code.push_str(t);
+            }
+            j += 1;
+        }
+
+        output.push(Event::Html(highlighter(&lang, &code).into()));
+        i = j + 1;
+    }
+
+    output
+}
+
+fn default_sanitize_policy(options: &RenderOptions) -> SanitizePolicy {
+    let mut policy = SanitizePolicy::basic()
+        .allow_attribute("h1", "id")
+        .allow_attribute("h2", "id")
+        .allow_attribute("h3", "id")
+        .allow_attribute("h4", "id")
+        .allow_attribute("h5", "id")
+        .allow_attribute("h6", "id")
+        .allow_attribute("code", "class");
+
+    if options.tables {
+        policy = policy
+            .allow_tag("table")
+            .allow_tag("thead")
+            .allow_tag("tbody")
+            .allow_tag("tr")
+            .allow_tag("th")
+            .allow_tag("td");
+    }
+    if options.strikethrough {
+        policy = policy.allow_tag("del");
+    }
+    if options.footnotes {
+        policy = policy
+            .allow_tag("sup")
+            .allow_tag("section")
+            .allow_attribute("section", "class")
+            .allow_attribute("li", "id")
+            .allow_attribute("a", "href")
+            .allow_attribute("a", "id")
+            .allow_attribute("a", "class");
+    }
+    if options.task_lists {
+        policy = policy
+            .allow_tag("input")
+            .allow_attribute("input", "type")
+            .allow_attribute("input", "checked")
+            .allow_attribute("input", "disabled");
+    }
+    if options.highlighter.is_some() {
+        policy = policy.allow_tag("span").allow_attribute("span", "class").allow_attribute("pre", "class");
+    }
+
+    policy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_plain_markdown_to_sanitized_html() {
+        let html = render_markdown("Hello **world**.", &RenderOptions::new());
+        assert_eq!(html, "<p>Hello <strong>world</strong>.</p>\n");
+    }
+
+    #[test]
+    fn heading_ids_match_extract_headers_slugs() {
+        let html = render_markdown("# Getting Started\n\n## Getting Started", &RenderOptions::new());
+        assert!(html.contains(r#"<h1 id="getting-started">"#));
+        assert!(html.contains(r#"<h2 id="getting-started-2">"#));
+    }
+
+    #[test]
+    fn tables_require_the_tables_option() {
+        let input = "| a | b |\n| - | - |\n| 1 | 2 |\n";
+        assert!(!render_markdown(input, &RenderOptions::new()).contains("<table>"));
+        assert!(render_markdown(input, &RenderOptions::new().tables(true)).contains("<table>"));
+    }
+
+    #[test]
+    fn task_lists_render_as_checkboxes() {
+        let html = render_markdown("- [x] done\n- [ ] todo", &RenderOptions::new().task_lists(true));
+        assert!(html.contains(r#"<input disabled="" type="checkbox" checked="">"#));
+        assert!(html.contains(r#"<input disabled="" type="checkbox">"#));
+    }
+
+    #[test]
+    fn highlighter_hook_replaces_fenced_code_blocks() {
+        let options =
+            RenderOptions::new().highlighter(|lang, code| format!("<pre><code class=\"hl-{lang}\">{code}</code></pre>"));
+        let html = render_markdown("```rs\nfn main() {}\n```", &options);
+        assert_eq!(html, "<pre><code class=\"hl-rs\">fn main() {}\n</code></pre>");
+    }
+
+    #[test]
+    fn sanitizer_strips_disallowed_content_from_rendered_output() {
+        let html = render_markdown("<script>alert(1)</script>\n\nHi.", &RenderOptions::new());
+        assert!(!html.contains("script"));
+        assert!(html.contains("Hi."));
+    }
+}
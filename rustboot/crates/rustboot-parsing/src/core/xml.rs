@@ -0,0 +1,138 @@
+//! DOM-lite XML parsing and XPath-lite querying.
+//!
+//! Parsing is delegated to `roxmltree` — the same "don't reinvent a w3c
+//! grammar" trade-off as [`crate::core::html`] — but the query language
+//! is deliberately a small subset of XPath rather than the real thing:
+//! a slash-separated chain of element names (`a/b/c`), optionally
+//! anchored at the document root (`/a/b/c`), or a `//tag` descendant
+//! search. Predicates, axes, and functions are out of scope.
+
+use crate::api::ParsingError;
+
+/// A parsed XML document. Borrows from the source text for the
+/// lifetime `'d`, since `roxmltree` builds its tree over it rather than
+/// copying it.
+pub struct XmlDocument<'d>(roxmltree::Document<'d>);
+
+impl<'d> XmlDocument<'d> {
+    /// Parses `input` as an XML document.
+    pub fn parse(input: &'d str) -> Result<Self, ParsingError> {
+        roxmltree::Document::parse(input)
+            .map(Self)
+            .map_err(|err| ParsingError::InvalidXml(err.to_string()))
+    }
+
+    /// Returns the concatenated text content of every element matched
+    /// by the XPath-lite `path`, in document order.
+    ///
+    /// ```
+    /// use rustboot_parsing::xml::XmlDocument;
+    ///
+    /// let doc = XmlDocument::parse("<items><item>one</item><item>two</item></items>").unwrap();
+    /// assert_eq!(doc.select_text("items/item").unwrap(), vec!["one", "two"]);
+    /// assert_eq!(doc.select_text("//item").unwrap(), vec!["one", "two"]);
+    /// ```
+    pub fn select_text(&self, path: &str) -> Result<Vec<String>, ParsingError> {
+        Ok(self.select(path)?.into_iter().map(node_text).collect())
+    }
+
+    /// Returns the value of `attribute` for every element matched by
+    /// the XPath-lite `path` that has it set.
+    ///
+    /// ```
+    /// use rustboot_parsing::xml::XmlDocument;
+    ///
+    /// let doc = XmlDocument::parse(r#"<items><item id="1"/><item id="2"/></items>"#).unwrap();
+    /// assert_eq!(doc.select_attribute("items/item", "id").unwrap(), vec!["1", "2"]);
+    /// ```
+    pub fn select_attribute(&self, path: &str, attribute: &str) -> Result<Vec<String>, ParsingError> {
+        Ok(self
+            .select(path)?
+            .into_iter()
+            .filter_map(|node| node.attribute(attribute))
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// The text content of the whole document, with tags stripped.
+    pub fn text(&self) -> String {
+        node_text(self.0.root_element())
+    }
+
+    /// Resolves `path` to the element nodes it matches.
+    fn select(&self, path: &str) -> Result<Vec<roxmltree::Node<'_, 'd>>, ParsingError> {
+        if let Some(tag) = path.strip_prefix("//") {
+            if tag.is_empty() || tag.contains('/') {
+                return Err(ParsingError::InvalidXml(format!("unsupported XPath-lite path: '{path}'")));
+            }
+            return Ok(self
+                .0
+                .descendants()
+                .filter(|node| node.is_element() && node.tag_name().name() == tag)
+                .collect());
+        }
+
+        let mut matches = vec![self.0.root_element()];
+        for (i, segment) in path.split('/').filter(|s| !s.is_empty()).enumerate() {
+            if i == 0 && matches[0].tag_name().name() != segment {
+                return Ok(Vec::new());
+            }
+            if i == 0 {
+                continue;
+            }
+            matches = matches
+                .into_iter()
+                .flat_map(|node| node.children().filter(|c| c.is_element() && c.tag_name().name() == segment))
+                .collect();
+        }
+
+        Ok(matches)
+    }
+}
+
+/// Concatenates every text descendant of `node`, matching how
+/// [`crate::core::html::Document::select_text`] joins an element's text
+/// nodes.
+fn node_text(node: roxmltree::Node) -> String {
+    node.descendants().filter(|n| n.is_text()).filter_map(|n| n.text()).collect::<Vec<_>>().join("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_text_follows_an_absolute_tag_path() {
+        let doc = XmlDocument::parse("<feed><entry><title>Hi</title></entry></feed>").unwrap();
+        assert_eq!(doc.select_text("feed/entry/title").unwrap(), vec!["Hi".to_string()]);
+    }
+
+    #[test]
+    fn select_text_finds_descendants_anywhere_with_a_double_slash() {
+        let doc = XmlDocument::parse("<a><b><c>one</c></b><c>two</c></a>").unwrap();
+        assert_eq!(doc.select_text("//c").unwrap(), vec!["one".to_string(), "two".to_string()]);
+    }
+
+    #[test]
+    fn select_attribute_returns_only_elements_with_the_attribute() {
+        let doc = XmlDocument::parse(r#"<items><item id="1"/><item/></items>"#).unwrap();
+        assert_eq!(doc.select_attribute("items/item", "id").unwrap(), vec!["1".to_string()]);
+    }
+
+    #[test]
+    fn select_returns_empty_when_the_root_tag_does_not_match() {
+        let doc = XmlDocument::parse("<a><b>hi</b></a>").unwrap();
+        assert!(doc.select_text("z/b").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_xml() {
+        assert!(XmlDocument::parse("<a><b></a>").is_err());
+    }
+
+    #[test]
+    fn text_strips_every_tag() {
+        let doc = XmlDocument::parse("<a>hello <b>world</b></a>").unwrap();
+        assert_eq!(doc.text(), "hello world");
+    }
+}
@@ -0,0 +1,28 @@
+//! Markdown analysis and HTML parsing/sanitization for the rustboot
+//! framework.
+//!
+//! - [`extract_headers`]/[`extract_links`]/[`word_count`]/
+//!   [`extract_front_matter`]/[`extract_code_blocks`]: a lightweight
+//!   markdown analyzer — structural extraction without a full
+//!   CommonMark parse or render.
+//! - [`html::Document`]: DOM-lite HTML parsing with CSS-selector based
+//!   extraction ([`html::Document::select_text`],
+//!   [`html::Document::select_html`],
+//!   [`html::Document::select_attribute`]) and whole-document text
+//!   extraction ([`html::Document::text`]).
+//! - [`html::sanitize`]/[`html::SanitizePolicy`]: an allowlist-based
+//!   HTML sanitizer for rendering untrusted, user-generated content.
+//! - [`render::render_markdown`]: renders markdown to sanitized HTML,
+//!   with table/footnote/task-list extensions, a syntax-highlighting
+//!   hook, and heading anchors consistent with [`extract_headers`].
+//! - [`xml::XmlDocument`]: DOM-lite XML parsing with XPath-lite (`a/b/c`,
+//!   `//tag`) text and attribute extraction.
+
+pub mod api;
+pub mod core;
+
+pub use api::{CodeBlock, FrontMatter, FrontMatterFormat, Header, Link, ParsingError};
+pub use core::html;
+pub use core::markdown::{extract_code_blocks, extract_front_matter, extract_headers, extract_links, word_count};
+pub use core::render;
+pub use core::xml;
@@ -0,0 +1,87 @@
+//! Public configuration types for the resilience module.
+
+use std::time::Duration;
+
+/// Configures [`crate::core::hedge::Hedge`].
+#[derive(Debug, Clone)]
+pub struct HedgeConfig {
+    /// Delay before launching the next hedged attempt.
+    pub delay: Duration,
+    /// Maximum number of concurrent attempts (including the first).
+    pub max_attempts: usize,
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(100),
+            max_attempts: 2,
+        }
+    }
+}
+
+/// Returns the default [`HedgeConfig`]: a 100 ms delay and two total attempts.
+pub fn default_hedge_config() -> HedgeConfig {
+    HedgeConfig::default()
+}
+
+/// Errors shared across resilience patterns.
+#[derive(Debug, thiserror::Error)]
+pub enum ResilienceError {
+    /// A shared state backend (e.g. Redis) could not be reached or
+    /// returned invalid data.
+    #[error("resilience state store error: {0}")]
+    Store(String),
+}
+
+/// The outcome of a rate limit check, shared by every limiter
+/// implementation ([`crate::core::gcra::Gcra`] and, with the `redis`
+/// feature, [`crate::core::rate_limit`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    /// Whether the request is allowed.
+    pub allowed: bool,
+    /// Requests remaining in the current window or burst allowance.
+    pub remaining: u64,
+    /// How long to wait before retrying, once denied.
+    pub retry_after: Duration,
+}
+
+/// A point-in-time read of a limiter's quota for a key, without consuming
+/// any of it. Lets a handler show a caller their remaining quota, and
+/// lets middleware populate `RateLimit-*` response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaStatus {
+    /// Maximum number of requests allowed within the current window or
+    /// burst allowance.
+    pub limit: u64,
+    /// Requests remaining right now.
+    pub remaining: u64,
+    /// Time until the quota fully resets to `limit`.
+    pub reset: Duration,
+}
+
+/// Configures [`crate::core::timeout_pool::TimeoutPool`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeoutPoolConfig {
+    /// How many tasks must [`TaskOutcome::Completed`] before the rest of
+    /// the pool is cancelled. `None` waits for every task to settle
+    /// (complete, time out, or fail) before returning.
+    pub quorum: Option<usize>,
+}
+
+/// The outcome of a single task run through a
+/// [`crate::core::timeout_pool::TimeoutPool`].
+#[derive(Debug, Clone)]
+pub enum TaskOutcome<T, E> {
+    /// The task finished within its deadline.
+    Completed(T),
+    /// The task's individual deadline elapsed before it finished.
+    TimedOut,
+    /// The task returned an error within its deadline.
+    Failed(E),
+    /// The pool reached quorum before this task settled, so it was
+    /// aborted without running to completion; a task that panicked is
+    /// also reported this way.
+    Cancelled,
+}
@@ -0,0 +1,462 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::api::ResilienceError;
+
+/// The state of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests pass through normally.
+    Closed,
+    /// Requests are rejected without being attempted.
+    Open,
+    /// A limited number of trial requests are allowed through to probe
+    /// recovery.
+    HalfOpen,
+}
+
+/// Configures a [`CircuitBreaker`].
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures in `Closed` before tripping to `Open`.
+    pub failure_threshold: u32,
+    /// Consecutive successes in `HalfOpen` before resetting to `Closed`.
+    pub success_threshold: u32,
+    /// How long the breaker stays `Open` before probing with `HalfOpen`.
+    pub open_duration: Duration,
+    /// How many requests may be in flight at once while `HalfOpen`. Bounds
+    /// the trial batch so a flood of concurrent callers can't all hit a
+    /// still-recovering dependency the moment it half-opens; each admitted
+    /// request frees its slot for another once it completes.
+    pub half_open_max_requests: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            success_threshold: 2,
+            open_duration: Duration::from_secs(30),
+            half_open_max_requests: 1,
+        }
+    }
+}
+
+/// A state transition emitted by a [`CircuitBreaker`].
+///
+/// Subscribe via [`CircuitBreaker::subscribe`] or
+/// [`CircuitBreaker::on_state_change`] to drive dashboards and alerts —
+/// an `Open` transition is almost always worth paging on.
+#[derive(Debug, Clone)]
+pub struct CircuitTransition {
+    /// Name of the breaker that transitioned.
+    pub name: String,
+    /// State before the transition.
+    pub from: CircuitState,
+    /// State after the transition.
+    pub to: CircuitState,
+    /// When the transition occurred.
+    pub at: SystemTime,
+}
+
+/// Persisted breaker state, suitable for storing in a shared backend (e.g.
+/// Redis) so multiple instances of a service observe the same breaker.
+#[derive(Debug, Clone)]
+pub struct StoredBreakerState {
+    /// Breaker state at the time of persistence.
+    pub state: CircuitState,
+    /// Consecutive failure count.
+    pub failures: u32,
+    /// Consecutive success count.
+    pub successes: u32,
+    /// When the breaker last opened, if it is currently open.
+    pub opened_at: Option<SystemTime>,
+}
+
+/// A shared backend for circuit breaker state.
+///
+/// Without this, each process keeps its own private breaker and a fleet
+/// of instances can flap independently against a struggling dependency.
+/// Implement this over Redis (or any shared store) to have every instance
+/// observe and honor the same open/closed decision.
+#[async_trait]
+pub trait BreakerStateStore: Send + Sync {
+    /// Loads the last known state for `name`, if any has been persisted.
+    async fn load(&self, name: &str) -> Result<Option<StoredBreakerState>, ResilienceError>;
+
+    /// Persists the current state for `name`.
+    async fn save(&self, name: &str, state: &StoredBreakerState) -> Result<(), ResilienceError>;
+}
+
+/// Error returned by [`CircuitBreaker::execute`].
+#[derive(Debug, thiserror::Error)]
+pub enum CircuitError<E> {
+    /// The breaker is open; the operation was not attempted.
+    #[error("circuit breaker '{0}' is open")]
+    Open(String),
+    /// The operation was attempted and failed.
+    #[error(transparent)]
+    Operation(E),
+}
+
+struct Inner {
+    state: CircuitState,
+    failures: u32,
+    successes: u32,
+    opened_at: Option<Instant>,
+    /// Requests currently admitted as `HalfOpen` trial probes and not yet
+    /// completed. Bounded by [`CircuitBreakerConfig::half_open_max_requests`].
+    half_open_in_flight: u32,
+}
+
+type StateChangeListener = Box<dyn Fn(&CircuitTransition) + Send + Sync>;
+
+/// A circuit breaker that trips `Open` after consecutive failures, probes
+/// recovery via `HalfOpen`, and resets to `Closed` after consecutive
+/// successes, optionally backed by a shared [`BreakerStateStore`].
+pub struct CircuitBreaker {
+    name: String,
+    config: CircuitBreakerConfig,
+    store: Option<Arc<dyn BreakerStateStore>>,
+    inner: Mutex<Inner>,
+    listeners: Mutex<Vec<StateChangeListener>>,
+    events: broadcast::Sender<CircuitTransition>,
+}
+
+impl CircuitBreaker {
+    /// Creates a new breaker with purely in-process state.
+    pub fn new(name: impl Into<String>, config: CircuitBreakerConfig) -> Self {
+        Self::with_store(name, config, None)
+    }
+
+    /// Creates a new breaker backed by a shared [`BreakerStateStore`] so
+    /// that multiple process instances observe the same trip decisions.
+    ///
+    /// Call [`Self::hydrate`] after construction to load any existing
+    /// shared state before serving traffic.
+    pub fn with_shared_store(
+        name: impl Into<String>,
+        config: CircuitBreakerConfig,
+        store: Arc<dyn BreakerStateStore>,
+    ) -> Self {
+        Self::with_store(name, config, Some(store))
+    }
+
+    fn with_store(
+        name: impl Into<String>,
+        config: CircuitBreakerConfig,
+        store: Option<Arc<dyn BreakerStateStore>>,
+    ) -> Self {
+        let (events, _) = broadcast::channel(32);
+        Self {
+            name: name.into(),
+            config,
+            store,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                failures: 0,
+                successes: 0,
+                opened_at: None,
+                half_open_in_flight: 0,
+            }),
+            listeners: Mutex::new(Vec::new()),
+            events,
+        }
+    }
+
+    /// Loads this breaker's state from its shared store, if one is
+    /// configured. A no-op for in-process-only breakers.
+    pub async fn hydrate(&self) -> Result<(), ResilienceError> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        if let Some(stored) = store.load(&self.name).await? {
+            let mut inner = self.inner.lock().unwrap();
+            inner.state = stored.state;
+            inner.failures = stored.failures;
+            inner.successes = stored.successes;
+            // `opened_at` is only used to time the Open->HalfOpen probe
+            // window locally; re-anchor it to "now" on hydration since
+            // `Instant`s don't survive process boundaries.
+            inner.opened_at = stored.opened_at.map(|_| Instant::now());
+            inner.half_open_in_flight = 0;
+        }
+        Ok(())
+    }
+
+    /// Returns the breaker's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> CircuitState {
+        self.inner.lock().unwrap().state
+    }
+
+    /// Registers a callback invoked synchronously on every state
+    /// transition.
+    pub fn on_state_change(&self, listener: impl Fn(&CircuitTransition) + Send + Sync + 'static) {
+        self.listeners.lock().unwrap().push(Box::new(listener));
+    }
+
+    /// Subscribes to a stream of state transitions.
+    pub fn subscribe(&self) -> broadcast::Receiver<CircuitTransition> {
+        self.events.subscribe()
+    }
+
+    /// Executes `operation` through the breaker.
+    pub async fn execute<F, Fut, T, E>(&self, operation: F) -> Result<T, CircuitError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let Some(half_open_probe) = self.try_admit() else {
+            return Err(CircuitError::Open(self.name.clone()));
+        };
+
+        let result = operation().await;
+        self.record_result(result.is_ok(), half_open_probe).await;
+        result.map_err(CircuitError::Operation)
+    }
+
+    /// Returns `None` if the request should be rejected, or `Some(bool)`
+    /// if it's admitted — the bool is `true` when this request consumed a
+    /// bounded `HalfOpen` trial slot, which [`Self::record_result`] must
+    /// release once the request completes.
+    fn try_admit(&self) -> Option<bool> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Closed => Some(false),
+            CircuitState::HalfOpen => {
+                if inner.half_open_in_flight < self.config.half_open_max_requests {
+                    inner.half_open_in_flight += 1;
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+            CircuitState::Open => {
+                let elapsed = inner
+                    .opened_at
+                    .map(|at| at.elapsed() >= self.config.open_duration)
+                    .unwrap_or(true);
+                if elapsed {
+                    inner.state = CircuitState::HalfOpen;
+                    inner.successes = 0;
+                    inner.half_open_in_flight = 1;
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    async fn record_result(&self, success: bool, half_open_probe: bool) {
+        let transition = {
+            let mut inner = self.inner.lock().unwrap();
+            let from = inner.state;
+
+            if half_open_probe {
+                inner.half_open_in_flight = inner.half_open_in_flight.saturating_sub(1);
+            }
+
+            match (inner.state, success) {
+                (CircuitState::Closed, true) => {
+                    inner.failures = 0;
+                    None
+                }
+                (CircuitState::Closed, false) => {
+                    inner.failures += 1;
+                    if inner.failures >= self.config.failure_threshold {
+                        inner.state = CircuitState::Open;
+                        inner.opened_at = Some(Instant::now());
+                        Some(CircuitState::Open)
+                    } else {
+                        None
+                    }
+                }
+                (CircuitState::HalfOpen, true) => {
+                    inner.successes += 1;
+                    if inner.successes >= self.config.success_threshold {
+                        inner.state = CircuitState::Closed;
+                        inner.failures = 0;
+                        inner.opened_at = None;
+                        Some(CircuitState::Closed)
+                    } else {
+                        None
+                    }
+                }
+                (CircuitState::HalfOpen, false) => {
+                    inner.state = CircuitState::Open;
+                    inner.opened_at = Some(Instant::now());
+                    Some(CircuitState::Open)
+                }
+                (CircuitState::Open, _) => None,
+            }
+            .map(|to| (from, to, inner.failures, inner.successes, inner.opened_at))
+        };
+
+        let Some((from, to, failures, successes, opened_at)) = transition else {
+            return;
+        };
+
+        let event = CircuitTransition {
+            name: self.name.clone(),
+            from,
+            to,
+            at: SystemTime::now(),
+        };
+        for listener in self.listeners.lock().unwrap().iter() {
+            listener(&event);
+        }
+        let _ = self.events.send(event);
+
+        if let Some(store) = &self.store {
+            let _ = store
+                .save(
+                    &self.name,
+                    &StoredBreakerState {
+                        state: to,
+                        failures,
+                        successes,
+                        opened_at: opened_at.map(|_| SystemTime::now()),
+                    },
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct InMemoryStore {
+        data: Mutex<HashMap<String, StoredBreakerState>>,
+    }
+
+    #[async_trait]
+    impl BreakerStateStore for InMemoryStore {
+        async fn load(&self, name: &str) -> Result<Option<StoredBreakerState>, ResilienceError> {
+            Ok(self.data.lock().unwrap().get(name).cloned())
+        }
+
+        async fn save(&self, name: &str, state: &StoredBreakerState) -> Result<(), ResilienceError> {
+            self.data
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), state.clone());
+            Ok(())
+        }
+    }
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: 2,
+            success_threshold: 1,
+            open_duration: Duration::from_millis(20),
+            half_open_max_requests: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn trips_open_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new("test", config());
+        for _ in 0..2 {
+            let _ = breaker.execute(|| async { Err::<(), _>("boom") }).await;
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let result = breaker.execute(|| async { Ok::<_, &str>(()) }).await;
+        assert!(matches!(result, Err(CircuitError::Open(_))));
+    }
+
+    #[tokio::test]
+    async fn emits_transition_events_to_listeners_and_subscribers() {
+        let breaker = CircuitBreaker::new("test", config());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = seen.clone();
+        breaker.on_state_change(move |t| seen_clone.lock().unwrap().push(t.to));
+        let mut events = breaker.subscribe();
+
+        for _ in 0..2 {
+            let _ = breaker.execute(|| async { Err::<(), _>("boom") }).await;
+        }
+
+        assert_eq!(*seen.lock().unwrap(), vec![CircuitState::Open]);
+        assert_eq!(events.recv().await.unwrap().to, CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn half_opens_after_duration_and_closes_on_success() {
+        let breaker = CircuitBreaker::new("test", config());
+        for _ in 0..2 {
+            let _ = breaker.execute(|| async { Err::<(), _>("boom") }).await;
+        }
+
+        tokio::time::sleep(Duration::from_millis(25)).await;
+        let result = breaker.execute(|| async { Ok::<_, &str>(()) }).await;
+
+        assert!(result.is_ok());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn half_open_admits_only_a_bounded_number_of_concurrent_probes() {
+        let breaker = Arc::new(CircuitBreaker::new("test", config()));
+        for _ in 0..2 {
+            let _ = breaker.execute(|| async { Err::<(), _>("boom") }).await;
+        }
+        tokio::time::sleep(Duration::from_millis(25)).await;
+
+        let (release_tx, release_rx) = tokio::sync::oneshot::channel();
+        let probe_breaker = breaker.clone();
+        let probe = tokio::spawn(async move {
+            probe_breaker
+                .execute(|| async move {
+                    release_rx.await.ok();
+                    Ok::<_, &str>(())
+                })
+                .await
+        });
+        // Let the spawned probe run until it's admitted and blocked on
+        // `release_rx`, holding the breaker's one `HalfOpen` slot.
+        tokio::task::yield_now().await;
+
+        let rejected = breaker.execute(|| async { Ok::<_, &str>(()) }).await;
+        assert!(matches!(rejected, Err(CircuitError::Open(_))));
+
+        release_tx.send(()).unwrap();
+        assert!(probe.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn hydrates_state_from_shared_store() {
+        let store = Arc::new(InMemoryStore::default());
+        store
+            .save(
+                "shared",
+                &StoredBreakerState {
+                    state: CircuitState::Open,
+                    failures: 5,
+                    successes: 0,
+                    opened_at: Some(SystemTime::now()),
+                },
+            )
+            .await
+            .unwrap();
+
+        let breaker = CircuitBreaker::with_shared_store("shared", config(), store);
+        breaker.hydrate().await.unwrap();
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+}
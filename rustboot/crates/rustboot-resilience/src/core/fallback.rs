@@ -0,0 +1,50 @@
+use std::future::Future;
+
+/// Extension trait adding a `.or_else(...)` fallback combinator to any
+/// fallible async operation.
+///
+/// Unlike a plain retry, the fallback receives the primary's error and
+/// produces a *different* operation to run, which may itself fail with a
+/// different error type. This is useful for "try the fast path, degrade to
+/// the slow path" patterns.
+pub trait FallbackExt<T, E>: Future<Output = Result<T, E>> + Sized {
+    /// Runs `self`; if it fails, runs `fallback` with the original error
+    /// and returns its result instead.
+    fn or_else<F, Fut2, E2>(self, fallback: F) -> impl Future<Output = Result<T, E2>>
+    where
+        F: FnOnce(E) -> Fut2,
+        Fut2: Future<Output = Result<T, E2>>,
+    {
+        async move {
+            match self.await {
+                Ok(value) => Ok(value),
+                Err(err) => fallback(err).await,
+            }
+        }
+    }
+}
+
+impl<T, E, Fut> FallbackExt<T, E> for Fut where Fut: Future<Output = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn falls_back_on_error() {
+        let result = async { Err::<u32, &str>("primary down") }
+            .or_else(|_err| async { Ok::<u32, &str>(7) })
+            .await;
+
+        assert_eq!(result, Ok(7));
+    }
+
+    #[tokio::test]
+    async fn skips_fallback_on_success() {
+        let result = async { Ok::<u32, &str>(1) }
+            .or_else(|_err| async { Ok::<u32, &str>(99) })
+            .await;
+
+        assert_eq!(result, Ok(1));
+    }
+}
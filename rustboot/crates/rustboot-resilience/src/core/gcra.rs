@@ -0,0 +1,158 @@
+//! The generic cell rate algorithm (GCRA): a rate limiter that enforces a
+//! steady emission rate with a bounded burst allowance, without the
+//! boundary artifacts of fixed or sliding windows, and with a precise
+//! `retry_after` for a denied request.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::api::{QuotaStatus, RateLimitDecision};
+
+/// Configures a [`Gcra`] limiter.
+#[derive(Debug, Clone)]
+pub struct GcraConfig {
+    /// The steady-state emission interval: one request is allowed every
+    /// `period` on average.
+    pub period: Duration,
+    /// Additional requests allowed in a burst beyond the steady-state
+    /// rate, before requests start being denied.
+    pub burst: u32,
+}
+
+/// A single-key GCRA limiter. Pair with [`crate::KeyedRateLimiter`] to
+/// limit many keys (IPs, API keys, tenants) independently.
+pub struct Gcra {
+    config: GcraConfig,
+    tat: Mutex<Option<Instant>>,
+}
+
+impl Gcra {
+    /// Creates a new limiter from `config`.
+    pub fn new(config: GcraConfig) -> Self {
+        Self {
+            config,
+            tat: Mutex::new(None),
+        }
+    }
+
+    /// Checks whether a request may proceed now, advancing the limiter's
+    /// internal "theoretical arrival time" if it does.
+    pub fn check(&self) -> RateLimitDecision {
+        let now = Instant::now();
+        let variance = self.config.period * self.config.burst;
+
+        let mut tat_guard = self.tat.lock().unwrap();
+        let tat = tat_guard.unwrap_or(now);
+
+        if now + variance >= tat {
+            let new_tat = tat.max(now) + self.config.period;
+            *tat_guard = Some(new_tat);
+
+            let used_cells = new_tat
+                .saturating_duration_since(now)
+                .as_secs_f64()
+                / self.config.period.as_secs_f64();
+            let remaining = (f64::from(self.config.burst) + 1.0 - used_cells).max(0.0) as u64;
+
+            RateLimitDecision {
+                allowed: true,
+                remaining,
+                retry_after: Duration::ZERO,
+            }
+        } else {
+            let retry_after = tat.saturating_duration_since(now) - variance;
+            RateLimitDecision {
+                allowed: false,
+                remaining: 0,
+                retry_after,
+            }
+        }
+    }
+
+    /// Reads the current quota without consuming any of it, for exposing
+    /// `RateLimit-*` response headers or a quota-remaining API to callers.
+    pub fn quota_status(&self) -> QuotaStatus {
+        let now = Instant::now();
+        let limit = u64::from(self.config.burst) + 1;
+
+        let tat = self.tat.lock().unwrap().unwrap_or(now);
+        let used_cells = tat
+            .saturating_duration_since(now)
+            .as_secs_f64()
+            / self.config.period.as_secs_f64();
+        let remaining = (limit as f64 - used_cells).max(0.0) as u64;
+
+        QuotaStatus {
+            limit,
+            remaining,
+            reset: tat.saturating_duration_since(now),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> GcraConfig {
+        GcraConfig {
+            period: Duration::from_millis(100),
+            burst: 2,
+        }
+    }
+
+    #[test]
+    fn allows_burst_up_to_configured_size() {
+        let limiter = Gcra::new(config());
+
+        // 1 steady-state slot + 2 burst = 3 immediate allows.
+        assert!(limiter.check().allowed);
+        assert!(limiter.check().allowed);
+        assert!(limiter.check().allowed);
+        assert!(!limiter.check().allowed);
+    }
+
+    #[test]
+    fn denied_request_carries_precise_retry_after() {
+        let limiter = Gcra::new(GcraConfig {
+            period: Duration::from_millis(100),
+            burst: 0,
+        });
+
+        assert!(limiter.check().allowed);
+        let decision = limiter.check();
+
+        assert!(!decision.allowed);
+        assert!(decision.retry_after <= Duration::from_millis(100));
+        assert!(decision.retry_after > Duration::ZERO);
+    }
+
+    #[test]
+    fn refills_after_waiting_the_emission_interval() {
+        let limiter = Gcra::new(GcraConfig {
+            period: Duration::from_millis(10),
+            burst: 0,
+        });
+
+        assert!(limiter.check().allowed);
+        assert!(!limiter.check().allowed);
+
+        std::thread::sleep(Duration::from_millis(15));
+
+        assert!(limiter.check().allowed);
+    }
+
+    #[test]
+    fn quota_status_reflects_consumption_without_advancing_it() {
+        let limiter = Gcra::new(config());
+        assert_eq!(limiter.quota_status().remaining, 3);
+
+        limiter.check();
+        let status = limiter.quota_status();
+        assert_eq!(status.limit, 3);
+        assert_eq!(status.remaining, 2);
+
+        // Reading the status again should not consume further quota.
+        assert_eq!(limiter.quota_status().remaining, 2);
+    }
+}
@@ -0,0 +1,123 @@
+use std::future::Future;
+
+use tokio::task::JoinSet;
+
+use crate::api::HedgeConfig;
+
+/// Error returned when a [`Hedge`] exhausts all attempts without success.
+#[derive(Debug, thiserror::Error)]
+#[error("hedge exhausted after all attempts: {last_err}")]
+pub struct HedgeExhaustedError<E: std::fmt::Debug + std::fmt::Display> {
+    /// The error returned by the last attempt to complete.
+    pub last_err: E,
+}
+
+/// Starts a second (and subsequent) attempt after a configurable delay and
+/// returns whichever attempt succeeds first, cancelling the others.
+///
+/// Useful for tail-latency-sensitive calls to flaky upstreams: a slow
+/// primary attempt is raced against one or more hedged retries instead of
+/// being awaited on its own.
+pub struct Hedge {
+    config: HedgeConfig,
+}
+
+impl Hedge {
+    /// Creates a new `Hedge` with the given configuration.
+    pub fn new(config: HedgeConfig) -> Self {
+        Self { config }
+    }
+
+    /// Executes `operation`, launching additional hedged attempts every
+    /// [`HedgeConfig::delay`] until either an attempt succeeds, all
+    /// attempts are exhausted, or [`HedgeConfig::max_attempts`] is reached.
+    ///
+    /// The first attempt to complete successfully wins; all other
+    /// in-flight attempts are aborted.
+    pub async fn execute<F, Fut, T, E>(&self, operation: F) -> Result<T, HedgeExhaustedError<E>>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+        T: Send + 'static,
+        E: std::fmt::Debug + std::fmt::Display + Send + 'static,
+    {
+        let mut set: JoinSet<Result<T, E>> = JoinSet::new();
+        set.spawn(operation());
+        let mut attempts_spawned = 1usize;
+
+        loop {
+            let more_hedges_available = attempts_spawned < self.config.max_attempts;
+            tokio::select! {
+                res = set.join_next(), if !set.is_empty() => {
+                    match res {
+                        Some(Ok(Ok(value))) => {
+                            set.abort_all();
+                            return Ok(value);
+                        }
+                        Some(Ok(Err(err))) => {
+                            if set.is_empty() && !more_hedges_available {
+                                return Err(HedgeExhaustedError { last_err: err });
+                            }
+                        }
+                        // The task was aborted or panicked; nothing else to do.
+                        Some(Err(_)) | None => {}
+                    }
+                }
+                _ = tokio::time::sleep(self.config.delay), if more_hedges_available => {
+                    set.spawn(operation());
+                    attempts_spawned += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn returns_fast_attempt_without_waiting_for_slow_one() {
+        let hedge = Hedge::new(HedgeConfig {
+            delay: Duration::from_millis(10),
+            max_attempts: 2,
+        });
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let result = hedge
+            .execute({
+                let calls = calls.clone();
+                move || {
+                    let calls = calls.clone();
+                    async move {
+                        let attempt = calls.fetch_add(1, Ordering::SeqCst);
+                        if attempt == 0 {
+                            tokio::time::sleep(Duration::from_millis(200)).await;
+                        }
+                        Ok::<_, String>(attempt)
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result, 1);
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_all_attempts_fail() {
+        let hedge = Hedge::new(HedgeConfig {
+            delay: Duration::from_millis(5),
+            max_attempts: 2,
+        });
+
+        let result = hedge
+            .execute(|| async { Err::<(), _>("upstream unavailable") })
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! Implementation details for the resilience module.
+
+pub mod circuit_breaker;
+pub mod fallback;
+pub mod gcra;
+pub mod hedge;
+pub mod keyed_limiter;
+#[cfg(feature = "redis")]
+pub mod rate_limit;
+pub mod retry;
+pub mod timeout_pool;
@@ -0,0 +1,209 @@
+//! Distributed rate limiting backed by a shared store (e.g. Redis), so a
+//! limit applies across every instance of a service instead of per-process.
+//!
+//! Only available with the `redis` feature enabled.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::api::{RateLimitDecision, ResilienceError};
+
+/// Executes the atomic script operations a distributed limiter needs.
+///
+/// Implement this over a Redis client (using `EVAL`/`EVALSHA` so the
+/// check-and-decrement happens as a single atomic operation) to have every
+/// instance of a service observe the same limit.
+#[async_trait]
+pub trait RedisTransport: Send + Sync {
+    /// Atomically refills and withdraws from the token bucket keyed by
+    /// `key`, returning the tokens remaining after the withdrawal attempt
+    /// (or `-1` if the withdrawal was denied).
+    async fn token_bucket_take(
+        &self,
+        key: &str,
+        capacity: u64,
+        refill_per_sec: f64,
+    ) -> Result<i64, ResilienceError>;
+
+    /// Atomically records a hit in the sliding window keyed by `key` and
+    /// returns the number of hits within the window after recording (or
+    /// `-1` if the window was already full and the hit was rejected).
+    async fn sliding_window_hit(
+        &self,
+        key: &str,
+        limit: u64,
+        window: Duration,
+    ) -> Result<i64, ResilienceError>;
+}
+
+/// A token bucket rate limiter whose state lives in a shared backend, so
+/// the limit is enforced across every instance of a service.
+pub struct DistributedTokenBucket<T: RedisTransport> {
+    transport: T,
+    key_prefix: String,
+    capacity: u64,
+    refill_per_sec: f64,
+}
+
+impl<T: RedisTransport> DistributedTokenBucket<T> {
+    /// Creates a new bucket of `capacity` tokens, refilled at
+    /// `refill_per_sec` tokens per second, keyed under `key_prefix`.
+    pub fn new(transport: T, key_prefix: impl Into<String>, capacity: u64, refill_per_sec: f64) -> Self {
+        Self {
+            transport,
+            key_prefix: key_prefix.into(),
+            capacity,
+            refill_per_sec,
+        }
+    }
+
+    /// Checks whether a request identified by `key` (e.g. a client id or
+    /// API key) may proceed.
+    pub async fn check(&self, key: &str) -> Result<RateLimitDecision, ResilienceError> {
+        let remaining = self
+            .transport
+            .token_bucket_take(&format!("{}:{key}", self.key_prefix), self.capacity, self.refill_per_sec)
+            .await?;
+
+        Ok(if remaining >= 0 {
+            RateLimitDecision {
+                allowed: true,
+                remaining: remaining as u64,
+                retry_after: Duration::ZERO,
+            }
+        } else {
+            RateLimitDecision {
+                allowed: false,
+                remaining: 0,
+                retry_after: Duration::from_secs_f64(1.0 / self.refill_per_sec.max(f64::MIN_POSITIVE)),
+            }
+        })
+    }
+}
+
+/// A sliding-window rate limiter whose state lives in a shared backend, so
+/// the limit is enforced across every instance of a service.
+pub struct DistributedSlidingWindow<T: RedisTransport> {
+    transport: T,
+    key_prefix: String,
+    limit: u64,
+    window: Duration,
+}
+
+impl<T: RedisTransport> DistributedSlidingWindow<T> {
+    /// Creates a new limiter allowing up to `limit` hits per `window`,
+    /// keyed under `key_prefix`.
+    pub fn new(transport: T, key_prefix: impl Into<String>, limit: u64, window: Duration) -> Self {
+        Self {
+            transport,
+            key_prefix: key_prefix.into(),
+            limit,
+            window,
+        }
+    }
+
+    /// Checks whether a request identified by `key` may proceed.
+    pub async fn check(&self, key: &str) -> Result<RateLimitDecision, ResilienceError> {
+        let hits = self
+            .transport
+            .sliding_window_hit(&format!("{}:{key}", self.key_prefix), self.limit, self.window)
+            .await?;
+
+        Ok(if hits >= 0 {
+            RateLimitDecision {
+                allowed: true,
+                remaining: self.limit.saturating_sub(hits as u64),
+                retry_after: Duration::ZERO,
+            }
+        } else {
+            RateLimitDecision {
+                allowed: false,
+                remaining: 0,
+                retry_after: self.window,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Mimics the atomic script behavior in-process, in place of a real
+    /// Redis round trip.
+    #[derive(Default)]
+    struct FakeTransport {
+        buckets: Mutex<std::collections::HashMap<String, u64>>,
+        windows: Mutex<std::collections::HashMap<String, u64>>,
+    }
+
+    #[async_trait]
+    impl RedisTransport for FakeTransport {
+        async fn token_bucket_take(
+            &self,
+            key: &str,
+            capacity: u64,
+            _refill_per_sec: f64,
+        ) -> Result<i64, ResilienceError> {
+            let mut buckets = self.buckets.lock().unwrap();
+            let tokens = buckets.entry(key.to_string()).or_insert(capacity);
+            if *tokens == 0 {
+                Ok(-1)
+            } else {
+                *tokens -= 1;
+                Ok(*tokens as i64)
+            }
+        }
+
+        async fn sliding_window_hit(
+            &self,
+            key: &str,
+            limit: u64,
+            _window: Duration,
+        ) -> Result<i64, ResilienceError> {
+            let mut windows = self.windows.lock().unwrap();
+            let hits = windows.entry(key.to_string()).or_insert(0);
+            if *hits >= limit {
+                Ok(-1)
+            } else {
+                *hits += 1;
+                Ok(*hits as i64)
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn token_bucket_denies_once_exhausted() {
+        let bucket = DistributedTokenBucket::new(FakeTransport::default(), "api", 2, 1.0);
+
+        assert!(bucket.check("client-a").await.unwrap().allowed);
+        assert!(bucket.check("client-a").await.unwrap().allowed);
+        let decision = bucket.check("client-a").await.unwrap();
+        assert!(!decision.allowed);
+    }
+
+    #[tokio::test]
+    async fn token_bucket_tracks_keys_independently() {
+        let bucket = DistributedTokenBucket::new(FakeTransport::default(), "api", 1, 1.0);
+
+        assert!(bucket.check("client-a").await.unwrap().allowed);
+        assert!(!bucket.check("client-a").await.unwrap().allowed);
+        assert!(bucket.check("client-b").await.unwrap().allowed);
+    }
+
+    #[tokio::test]
+    async fn sliding_window_denies_once_full() {
+        let window = DistributedSlidingWindow::new(
+            FakeTransport::default(),
+            "api",
+            2,
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(window.check("client-a").await.unwrap().remaining, 1);
+        assert_eq!(window.check("client-a").await.unwrap().remaining, 0);
+        assert!(!window.check("client-a").await.unwrap().allowed);
+    }
+}
@@ -0,0 +1,347 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Configures a [`RetryExecutor`].
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of attempts, including the first.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Multiplier applied to the delay after each retry.
+    pub backoff_factor: f64,
+    /// Upper bound on the delay between retries.
+    pub max_delay: Duration,
+    /// Fraction of the delay to randomize, in `[0.0, 1.0]`.
+    pub jitter: f64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            backoff_factor: 2.0,
+            max_delay: Duration::from_secs(10),
+            jitter: 0.1,
+        }
+    }
+}
+
+/// Configures a [`RetryBudget`].
+#[derive(Debug, Clone)]
+pub struct RetryBudgetConfig {
+    /// Multiplied by `ttl` to give the maximum balance, in retries. The
+    /// balance is not refilled over time: with zero request volume, no
+    /// retries are earned.
+    pub min_retries_per_second: f64,
+    /// Retry tokens deposited per original (non-retry) attempt, as a
+    /// fraction of one retry. `0.2` means "allow at most one retry for
+    /// every five original requests".
+    pub retry_ratio: f64,
+    /// Multiplied by `min_retries_per_second` to give the maximum
+    /// balance. The balance starts empty regardless.
+    pub ttl: Duration,
+}
+
+impl Default for RetryBudgetConfig {
+    fn default() -> Self {
+        Self {
+            min_retries_per_second: 1.0,
+            retry_ratio: 0.2,
+            ttl: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Point-in-time counters for a [`RetryBudget`].
+#[derive(Debug, Clone, Copy)]
+pub struct RetryBudgetMetrics {
+    /// Retries allowed because the budget had a token available.
+    pub allowed: u64,
+    /// Retries denied because the budget was exhausted.
+    pub denied: u64,
+    /// Current balance, in whole retry tokens.
+    pub balance: f64,
+}
+
+const MILLI: i64 = 1000;
+
+/// A token-bucket budget shared between [`RetryExecutor`] instances so
+/// that retries are suppressed once the failure rate gets high enough to
+/// amplify load instead of recovering from it.
+///
+/// Every original attempt deposits a fraction of a token
+/// ([`RetryBudgetConfig::retry_ratio`]); every retry withdraws a whole
+/// token. When the balance is empty, retries are denied outright instead
+/// of piling more load onto a struggling dependency.
+pub struct RetryBudget {
+    config: RetryBudgetConfig,
+    balance_milli: AtomicI64,
+    allowed: AtomicU64,
+    denied: AtomicU64,
+}
+
+impl RetryBudget {
+    /// Creates a new budget, starting with an empty balance: retries must
+    /// be earned from deposits rather than spent immediately at startup.
+    pub fn new(config: RetryBudgetConfig) -> Self {
+        Self {
+            config,
+            balance_milli: AtomicI64::new(0),
+            allowed: AtomicU64::new(0),
+            denied: AtomicU64::new(0),
+        }
+    }
+
+    fn max_balance_milli(config: &RetryBudgetConfig) -> i64 {
+        (config.min_retries_per_second * config.ttl.as_secs_f64() * MILLI as f64) as i64
+    }
+
+    /// Deposits a fractional token for an original (non-retry) attempt.
+    pub fn deposit(&self) {
+        let amount = (self.config.retry_ratio * MILLI as f64) as i64;
+        let max = Self::max_balance_milli(&self.config);
+        self.balance_milli
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |balance| {
+                Some((balance + amount).min(max))
+            })
+            .ok();
+    }
+
+    /// Attempts to withdraw one token to authorize a retry, returning
+    /// `false` if the budget is exhausted.
+    pub fn try_withdraw(&self) -> bool {
+        let withdrawn = self
+            .balance_milli
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |balance| {
+                if balance >= MILLI {
+                    Some(balance - MILLI)
+                } else {
+                    None
+                }
+            })
+            .is_ok();
+
+        if withdrawn {
+            self.allowed.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.denied.fetch_add(1, Ordering::Relaxed);
+        }
+        withdrawn
+    }
+
+    /// Sets the balance directly, clamped to `[0, max]`.
+    ///
+    /// Useful for restoring a budget's balance after a restart, when it
+    /// would otherwise need to re-earn credit from scratch.
+    pub fn seed(&self, tokens: f64) {
+        let max = Self::max_balance_milli(&self.config);
+        let milli = (tokens * MILLI as f64) as i64;
+        self.balance_milli.store(milli.clamp(0, max), Ordering::SeqCst);
+    }
+
+    /// Returns current allow/deny counters and balance for metrics export.
+    pub fn metrics(&self) -> RetryBudgetMetrics {
+        RetryBudgetMetrics {
+            allowed: self.allowed.load(Ordering::Relaxed),
+            denied: self.denied.load(Ordering::Relaxed),
+            balance: self.balance_milli.load(Ordering::Relaxed) as f64 / MILLI as f64,
+        }
+    }
+}
+
+/// Error returned by [`RetryExecutor::execute`].
+#[derive(Debug, thiserror::Error)]
+pub enum RetryError<E> {
+    /// All configured attempts were used without success.
+    #[error("retry exhausted after {attempts} attempts: {last_err}")]
+    Exhausted {
+        /// Number of attempts actually made.
+        attempts: u32,
+        /// Error returned by the last attempt.
+        last_err: E,
+    },
+    /// A retry was denied by the shared [`RetryBudget`] before all
+    /// attempts were used.
+    #[error("retry budget exhausted after {attempts} attempts: {last_err}")]
+    BudgetExhausted {
+        /// Number of attempts actually made.
+        attempts: u32,
+        /// Error returned by the last attempt.
+        last_err: E,
+    },
+}
+
+/// Executes operations with exponential backoff retry, optionally
+/// suppressing retries via a shared [`RetryBudget`].
+pub struct RetryExecutor {
+    config: RetryConfig,
+    budget: Option<Arc<RetryBudget>>,
+}
+
+impl RetryExecutor {
+    /// Creates a new executor with no shared budget.
+    pub fn new(config: RetryConfig) -> Self {
+        Self {
+            config,
+            budget: None,
+        }
+    }
+
+    /// Creates a new executor that shares `budget` with any other
+    /// executor holding the same `Arc`.
+    pub fn with_budget(config: RetryConfig, budget: Arc<RetryBudget>) -> Self {
+        Self {
+            config,
+            budget: Some(budget),
+        }
+    }
+
+    /// Runs `operation` with retries until it succeeds, attempts are
+    /// exhausted, or the retry budget denies a further attempt.
+    pub async fn execute<F, Fut, T, E>(&self, mut operation: F) -> Result<T, RetryError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut delay = self.config.base_delay;
+
+        for attempt in 1..=self.config.max_attempts {
+            if let Some(budget) = &self.budget {
+                budget.deposit();
+            }
+
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    if attempt == self.config.max_attempts {
+                        return Err(RetryError::Exhausted {
+                            attempts: attempt,
+                            last_err: err,
+                        });
+                    }
+
+                    if let Some(budget) = &self.budget {
+                        if !budget.try_withdraw() {
+                            return Err(RetryError::BudgetExhausted {
+                                attempts: attempt,
+                                last_err: err,
+                            });
+                        }
+                    }
+
+                    let jitter_range = delay.as_secs_f64() * self.config.jitter;
+                    let jitter = rand::random::<f64>() * 2.0 * jitter_range - jitter_range;
+                    let sleep_for = delay.as_secs_f64() + jitter;
+                    tokio::time::sleep(Duration::from_secs_f64(sleep_for.max(0.0))).await;
+
+                    delay = delay
+                        .mul_f64(self.config.backoff_factor)
+                        .min(self.config.max_delay);
+                }
+            }
+        }
+
+        unreachable!("loop always returns on the final attempt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    fn fast_config() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            backoff_factor: 1.0,
+            max_delay: Duration::from_millis(5),
+            jitter: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn succeeds_without_retrying_on_first_success() {
+        let executor = RetryExecutor::new(fast_config());
+        let result = executor.execute(|| async { Ok::<_, &str>(42) }).await;
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn exhausts_attempts_and_returns_last_error() {
+        let executor = RetryExecutor::new(fast_config());
+        let attempts = AtomicU32::new(0);
+
+        let result = executor
+            .execute(|| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err::<(), _>("boom") }
+            })
+            .await;
+
+        assert!(matches!(result, Err(RetryError::Exhausted { attempts: 3, .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn denies_retry_once_budget_is_empty() {
+        let budget = Arc::new(RetryBudget::new(RetryBudgetConfig {
+            min_retries_per_second: 0.0,
+            retry_ratio: 0.0,
+            ttl: Duration::from_secs(1),
+        }));
+        let executor = RetryExecutor::with_budget(fast_config(), budget.clone());
+
+        let result = executor.execute(|| async { Err::<(), _>("boom") }).await;
+
+        assert!(matches!(result, Err(RetryError::BudgetExhausted { attempts: 1, .. })));
+        assert_eq!(budget.metrics().denied, 1);
+    }
+
+    #[test]
+    fn budget_caps_balance_and_tracks_allow_deny_metrics() {
+        let budget = RetryBudget::new(RetryBudgetConfig {
+            min_retries_per_second: 1.0,
+            retry_ratio: 1.0,
+            ttl: Duration::from_secs(1),
+        });
+
+        // Deposits accumulate but are capped at min_retries_per_second * ttl.
+        for _ in 0..10 {
+            budget.deposit();
+        }
+        assert_eq!(budget.metrics().balance, 1.0);
+
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+
+        let metrics = budget.metrics();
+        assert_eq!(metrics.allowed, 1);
+        assert_eq!(metrics.denied, 1);
+        assert_eq!(metrics.balance, 0.0);
+    }
+
+    #[tokio::test]
+    async fn shared_budget_is_consumed_across_executors() {
+        let budget = Arc::new(RetryBudget::new(RetryBudgetConfig {
+            min_retries_per_second: 1.0,
+            retry_ratio: 0.0,
+            ttl: Duration::from_secs(1),
+        }));
+        // `retry_ratio` is zero, so neither executor's own attempts
+        // replenish the budget; seed one token's worth of credit up front
+        // and spend it via `a` so `b` finds the shared budget empty.
+        budget.seed(1.0);
+        let a = RetryExecutor::with_budget(fast_config(), budget.clone());
+        let b = RetryExecutor::with_budget(fast_config(), budget.clone());
+
+        let _ = a.execute(|| async { Err::<(), _>("boom") }).await;
+        let result = b.execute(|| async { Err::<(), _>("boom") }).await;
+
+        assert!(matches!(result, Err(RetryError::BudgetExhausted { attempts: 1, .. })));
+    }
+}
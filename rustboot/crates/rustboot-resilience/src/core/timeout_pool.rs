@@ -0,0 +1,160 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+
+use crate::api::{TaskOutcome, TimeoutPoolConfig};
+
+/// A single unit of work for a [`TimeoutPool`]: a deadline paired with a
+/// boxed future. Boxing lets a pool mix tasks built from unrelated
+/// closures/async blocks, since each has its own anonymous future type.
+pub type PooledTask<T, E> = (Duration, Pin<Box<dyn Future<Output = Result<T, E>> + Send>>);
+
+/// Runs a set of tasks concurrently, each against its own deadline, and
+/// collects a structured [`TaskOutcome`] per task instead of failing the
+/// whole batch on the first timeout or error.
+///
+/// Useful for scatter-gather fan-out calls: query several backends at
+/// once, optionally stop as soon as a [`TimeoutPoolConfig::quorum`] of
+/// them has answered, and let the caller decide what to do with
+/// stragglers, timeouts, and failures individually.
+pub struct TimeoutPool {
+    config: TimeoutPoolConfig,
+}
+
+impl TimeoutPool {
+    /// Creates a new `TimeoutPool` with the given configuration.
+    pub fn new(config: TimeoutPoolConfig) -> Self {
+        Self { config }
+    }
+
+    /// Runs `tasks` concurrently, each paired with its own deadline, and
+    /// returns one [`TaskOutcome`] per task in the same order they were
+    /// given.
+    ///
+    /// Once [`TimeoutPoolConfig::quorum`] tasks have
+    /// [`TaskOutcome::Completed`], every task still running is aborted
+    /// and reported as [`TaskOutcome::Cancelled`]. With no quorum
+    /// configured, every task runs to completion, timeout, or failure.
+    pub async fn run<T, E>(&self, tasks: Vec<PooledTask<T, E>>) -> Vec<TaskOutcome<T, E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let total = tasks.len();
+        let quorum = self.config.quorum.unwrap_or(total);
+        let mut outcomes: Vec<Option<TaskOutcome<T, E>>> = (0..total).map(|_| None).collect();
+
+        let mut set: JoinSet<(usize, TaskOutcome<T, E>)> = JoinSet::new();
+        for (index, (deadline, operation)) in tasks.into_iter().enumerate() {
+            set.spawn(async move {
+                let outcome = match tokio::time::timeout(deadline, operation).await {
+                    Ok(Ok(value)) => TaskOutcome::Completed(value),
+                    Ok(Err(err)) => TaskOutcome::Failed(err),
+                    Err(_) => TaskOutcome::TimedOut,
+                };
+                (index, outcome)
+            });
+        }
+
+        let mut completed = 0usize;
+        while let Some(joined) = set.join_next().await {
+            let Ok((index, outcome)) = joined else {
+                // The task panicked or was aborted; its slot stays `None`
+                // and is reported as `Cancelled` below.
+                continue;
+            };
+            if matches!(outcome, TaskOutcome::Completed(_)) {
+                completed += 1;
+            }
+            outcomes[index] = Some(outcome);
+            if completed >= quorum {
+                set.abort_all();
+                break;
+            }
+        }
+
+        outcomes
+            .into_iter()
+            .map(|outcome| outcome.unwrap_or(TaskOutcome::Cancelled))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ready<T: Send + 'static>(value: T) -> Pin<Box<dyn Future<Output = Result<T, String>> + Send>> {
+        Box::pin(std::future::ready(Ok(value)))
+    }
+
+    #[tokio::test]
+    async fn collects_completions_in_order() {
+        let pool = TimeoutPool::new(TimeoutPoolConfig::default());
+        let outcomes = pool
+            .run(vec![
+                (Duration::from_millis(50), ready(1)),
+                (Duration::from_millis(50), ready(2)),
+            ])
+            .await;
+
+        assert!(matches!(outcomes[0], TaskOutcome::Completed(1)));
+        assert!(matches!(outcomes[1], TaskOutcome::Completed(2)));
+    }
+
+    #[tokio::test]
+    async fn reports_individual_timeouts() {
+        let pool = TimeoutPool::new(TimeoutPoolConfig::default());
+        let outcomes = pool
+            .run(vec![
+                (
+                    Duration::from_millis(5),
+                    Box::pin(async {
+                        tokio::time::sleep(Duration::from_millis(200)).await;
+                        Ok::<_, String>(1)
+                    }) as Pin<Box<dyn Future<Output = Result<i32, String>> + Send>>,
+                ),
+                (Duration::from_millis(50), ready(2)),
+            ])
+            .await;
+
+        assert!(matches!(outcomes[0], TaskOutcome::TimedOut));
+        assert!(matches!(outcomes[1], TaskOutcome::Completed(2)));
+    }
+
+    #[tokio::test]
+    async fn reports_task_failures() {
+        let pool = TimeoutPool::new(TimeoutPoolConfig::default());
+        let outcomes = pool
+            .run(vec![(
+                Duration::from_millis(50),
+                Box::pin(async { Err::<i32, _>("upstream error".to_string()) })
+                    as Pin<Box<dyn Future<Output = Result<i32, String>> + Send>>,
+            )])
+            .await;
+
+        assert!(matches!(outcomes[0], TaskOutcome::Failed(ref err) if err == "upstream error"));
+    }
+
+    #[tokio::test]
+    async fn cancels_remaining_tasks_once_quorum_is_reached() {
+        let pool = TimeoutPool::new(TimeoutPoolConfig { quorum: Some(1) });
+        let outcomes = pool
+            .run(vec![
+                (Duration::from_millis(50), ready(1)),
+                (
+                    Duration::from_secs(5),
+                    Box::pin(async {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        Ok::<_, String>(2)
+                    }) as Pin<Box<dyn Future<Output = Result<i32, String>> + Send>>,
+                ),
+            ])
+            .await;
+
+        assert!(matches!(outcomes[0], TaskOutcome::Completed(1)));
+        assert!(matches!(outcomes[1], TaskOutcome::Cancelled));
+    }
+}
@@ -0,0 +1,48 @@
+//! Fault tolerance patterns for the rustboot framework.
+//!
+//! This crate provides resilience primitives for building robust,
+//! fault-tolerant applications:
+//!
+//! - [`Hedge`]: races a primary attempt against delayed hedged attempts,
+//!   returning whichever succeeds first.
+//! - [`FallbackExt`]: a `.or_else(...)` combinator for degrading to a
+//!   different operation on failure.
+//! - [`core::timeout_pool::TimeoutPool`]: runs tasks concurrently against
+//!   individual deadlines, collecting a structured outcome per task and
+//!   optionally cancelling stragglers once a quorum has completed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use rustboot_resilience::{Hedge, HedgeConfig};
+//!
+//! # async fn call_upstream() -> Result<String, String> { Ok("ok".into()) }
+//! # async fn run() {
+//! let hedge = Hedge::new(HedgeConfig {
+//!     delay: Duration::from_millis(50),
+//!     max_attempts: 2,
+//! });
+//! let result = hedge.execute(call_upstream).await;
+//! # let _ = result;
+//! # }
+//! ```
+
+pub mod api;
+pub mod core;
+
+pub use api::{HedgeConfig, QuotaStatus, RateLimitDecision, ResilienceError, TaskOutcome, TimeoutPoolConfig};
+pub use core::circuit_breaker::{
+    BreakerStateStore, CircuitBreaker, CircuitBreakerConfig, CircuitError, CircuitState,
+    CircuitTransition, StoredBreakerState,
+};
+pub use core::fallback::FallbackExt;
+pub use core::gcra::{Gcra, GcraConfig};
+pub use core::hedge::{Hedge, HedgeExhaustedError};
+pub use core::keyed_limiter::{KeyedRateLimiter, KeyedRateLimiterConfig, KeyedRateLimiterStats};
+#[cfg(feature = "redis")]
+pub use core::rate_limit::{DistributedSlidingWindow, DistributedTokenBucket, RedisTransport};
+pub use core::retry::{
+    RetryBudget, RetryBudgetConfig, RetryBudgetMetrics, RetryConfig, RetryError, RetryExecutor,
+};
+pub use core::timeout_pool::{PooledTask, TimeoutPool};
@@ -0,0 +1,86 @@
+//! Public types for the scheduler module.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// How a job's next scheduled run is determined.
+#[derive(Debug, Clone)]
+pub enum Schedule {
+    /// Run on a fixed, wall-clock cadence described by a cron
+    /// expression, evaluated in UTC.
+    Cron(Box<CronSchedule>),
+    /// Run every `Duration`, measured from when the scheduler is started
+    /// (or the job's last run, once it has one).
+    Interval(Duration),
+}
+
+impl Schedule {
+    /// Parses a standard 5-field cron expression (`minute hour
+    /// day-of-month month day-of-week`).
+    pub fn cron(expression: &str) -> Result<Self, SchedulerError> {
+        Ok(Self::Cron(Box::new(CronSchedule::parse(expression)?)))
+    }
+
+    /// Runs every `interval`.
+    pub fn every(interval: Duration) -> Self {
+        Self::Interval(interval)
+    }
+}
+
+/// A parsed 5-field cron expression (`minute hour day-of-month month
+/// day-of-week`), evaluated in UTC. Each field accepts `*`, a single
+/// value, a range (`a-b`), a step (`*/n` or `a-b/n`), or a
+/// comma-separated list of any of those.
+///
+/// As in standard cron, if both day-of-month and day-of-week are
+/// restricted (neither is `*`), a time matches if it satisfies either
+/// one.
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    pub(crate) minutes: HashSet<u32>,
+    pub(crate) hours: HashSet<u32>,
+    pub(crate) days_of_month: HashSet<u32>,
+    pub(crate) months: HashSet<u32>,
+    pub(crate) days_of_week: HashSet<u32>,
+    pub(crate) dom_restricted: bool,
+    pub(crate) dow_restricted: bool,
+    pub(crate) expression: String,
+}
+
+/// What a [`crate::core::scheduler::Scheduler`] does when a job's next
+/// scheduled run arrives while a previous run of the same job is still
+/// in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlapPolicy {
+    /// Skip this run and wait for the next scheduled one.
+    #[default]
+    Skip,
+    /// Run this invocation as soon as the in-progress one finishes,
+    /// serializing all runs of this job.
+    Queue,
+    /// Run this invocation immediately, alongside the in-progress one.
+    Concurrent,
+}
+
+/// Errors from registering a job or driving a
+/// [`crate::core::scheduler::Scheduler`].
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum SchedulerError {
+    /// A cron expression was malformed or a field's value was out of
+    /// range.
+    #[error("invalid cron expression '{0}'")]
+    InvalidCronExpression(String),
+    /// A job was registered under a name that's already in use.
+    #[error("a job named '{0}' is already registered")]
+    DuplicateJob(String),
+    /// No job is registered under this name.
+    #[error("no job named '{0}' is registered")]
+    UnknownJob(String),
+    /// The scheduler was already started.
+    #[error("scheduler is already running")]
+    AlreadyRunning,
+    /// A cron schedule has no time in the searched horizon that
+    /// matches it (e.g. February 30th).
+    #[error("cron expression '{0}' never matches within the search horizon")]
+    NoUpcomingRun(String),
+}
@@ -0,0 +1,241 @@
+//! Cron expression parsing and next-run computation, evaluated in UTC
+//! using a hand-rolled proleptic Gregorian calendar conversion (no
+//! timezone database, leap seconds, or DST — wall-clock UTC only).
+
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::api::{CronSchedule, SchedulerError};
+
+/// Upper bound on how far ahead `next_after` will search before giving
+/// up on an expression that can never match (e.g. February 30th).
+const SEARCH_HORIZON_MINUTES: u64 = 4 * 366 * 24 * 60;
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<HashSet<u32>, SchedulerError> {
+    let invalid = || SchedulerError::InvalidCronExpression(field.to_string());
+
+    let mut values = HashSet::new();
+    for part in field.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((range_part, step)) => {
+                (range_part, step.parse::<u32>().map_err(|_| invalid())?)
+            }
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(invalid());
+        }
+
+        let (start, end) = if range_part == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range_part.split_once('-') {
+            (
+                start.parse::<u32>().map_err(|_| invalid())?,
+                end.parse::<u32>().map_err(|_| invalid())?,
+            )
+        } else {
+            let value = range_part.parse::<u32>().map_err(|_| invalid())?;
+            (value, value)
+        };
+
+        if start < min || end > max || start > end {
+            return Err(invalid());
+        }
+
+        let mut value = start;
+        while value <= end {
+            values.insert(value);
+            value += step;
+        }
+    }
+    Ok(values)
+}
+
+impl CronSchedule {
+    /// Parses a standard 5-field cron expression. See [`CronSchedule`]'s
+    /// type documentation for the accepted field syntax.
+    pub fn parse(expression: &str) -> Result<Self, SchedulerError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(SchedulerError::InvalidCronExpression(
+                expression.to_string(),
+            ));
+        };
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days_of_month: parse_field(day_of_month, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            days_of_week: parse_field(day_of_week, 0, 6)?,
+            dom_restricted: day_of_month != "*",
+            dow_restricted: day_of_week != "*",
+            expression: expression.to_string(),
+        })
+    }
+
+    fn matches(&self, year: i64, month: u32, day: u32, hour: u32, minute: u32) -> bool {
+        if !self.minutes.contains(&minute) || !self.hours.contains(&hour) {
+            return false;
+        }
+        if !self.months.contains(&month) {
+            return false;
+        }
+
+        let weekday = weekday_from_civil(year, month, day);
+        match (self.dom_restricted, self.dow_restricted) {
+            (false, false) => true,
+            (true, false) => self.days_of_month.contains(&day),
+            (false, true) => self.days_of_week.contains(&weekday),
+            (true, true) => self.days_of_month.contains(&day) || self.days_of_week.contains(&weekday),
+        }
+    }
+
+    /// Returns the first whole minute, after `from`, at which this
+    /// schedule matches.
+    pub(crate) fn next_after(&self, from: SystemTime) -> Result<SystemTime, SchedulerError> {
+        let epoch_seconds = from
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_secs();
+        let start = epoch_seconds / 60 + 1;
+
+        for minute_index in start..start + SEARCH_HORIZON_MINUTES {
+            let (year, month, day, hour, minute) = civil_from_minute_index(minute_index);
+            if self.matches(year, month, day, hour, minute) {
+                return Ok(UNIX_EPOCH + Duration::from_secs(minute_index * 60));
+            }
+        }
+
+        Err(SchedulerError::NoUpcomingRun(self.expression.clone()))
+    }
+}
+
+/// Days since the Unix epoch (1970-01-01) for the given proleptic
+/// Gregorian civil date, using Howard Hinnant's `days_from_civil`
+/// algorithm.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Inverse of [`days_from_civil`]: the proleptic Gregorian civil date
+/// for the given day count since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+/// 0 = Sunday .. 6 = Saturday, matching [`CronSchedule`]'s day-of-week
+/// field.
+fn weekday_from_civil(year: i64, month: u32, day: u32) -> u32 {
+    let days = days_from_civil(year, month, day);
+    // 1970-01-01 (epoch day 0) was a Thursday (weekday 4).
+    (((days + 4) % 7 + 7) % 7) as u32
+}
+
+fn civil_from_minute_index(minute_index: u64) -> (i64, u32, u32, u32, u32) {
+    let days = (minute_index / (24 * 60)) as i64;
+    let minute_of_day = (minute_index % (24 * 60)) as u32;
+    let (year, month, day) = civil_from_days(days);
+    (year, month, day, minute_of_day / 60, minute_of_day % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_from_civil_and_civil_from_days_round_trip_the_epoch() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+    }
+
+    #[test]
+    fn weekday_from_civil_matches_known_dates() {
+        assert_eq!(weekday_from_civil(1970, 1, 1), 4); // Thursday
+        assert_eq!(weekday_from_civil(2000, 1, 1), 6); // Saturday
+        assert_eq!(weekday_from_civil(2024, 2, 29), 4); // leap day, Thursday
+    }
+
+    #[test]
+    fn parse_rejects_a_field_with_too_few_parts() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_an_out_of_range_value() {
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+    }
+
+    #[test]
+    fn parse_accepts_wildcards_ranges_steps_and_lists() {
+        let schedule = CronSchedule::parse("0,30 9-17 * * 1-5").unwrap();
+        assert_eq!(schedule.minutes, HashSet::from([0, 30]));
+        assert_eq!(schedule.hours, HashSet::from([9, 10, 11, 12, 13, 14, 15, 16, 17]));
+        assert_eq!(schedule.days_of_week, HashSet::from([1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn next_after_finds_the_next_matching_minute_same_day() {
+        let schedule = CronSchedule::parse("30 9 * * *").unwrap();
+        let from = UNIX_EPOCH + Duration::from_secs(days_from_civil(2024, 1, 1) as u64 * 86400 + 8 * 3600);
+
+        let next = schedule.next_after(from).unwrap();
+
+        let seconds = next.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(seconds, days_from_civil(2024, 1, 1) as u64 * 86400 + 9 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn next_after_rolls_over_to_the_next_day() {
+        let schedule = CronSchedule::parse("0 0 * * *").unwrap();
+        let from = UNIX_EPOCH + Duration::from_secs(days_from_civil(2024, 1, 1) as u64 * 86400 + 23 * 3600);
+
+        let next = schedule.next_after(from).unwrap();
+
+        let seconds = next.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(seconds, days_from_civil(2024, 1, 2) as u64 * 86400);
+    }
+
+    #[test]
+    fn next_after_honors_the_day_of_month_or_day_of_week_rule() {
+        // The 1st of the month OR a Monday, at midnight.
+        let schedule = CronSchedule::parse("0 0 1 * 1").unwrap();
+        // 2024-01-08 is a Monday, not the 1st; start the search one
+        // minute before midnight so the next match is that same day.
+        let from = UNIX_EPOCH
+            + Duration::from_secs(days_from_civil(2024, 1, 7) as u64 * 86400 + 23 * 3600 + 59 * 60);
+
+        let next = schedule.next_after(from).unwrap();
+
+        let seconds = next.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(seconds, days_from_civil(2024, 1, 8) as u64 * 86400);
+    }
+
+    #[test]
+    fn next_after_errors_when_the_expression_can_never_match() {
+        // February never has a 30th day.
+        let schedule = CronSchedule::parse("0 0 30 2 *").unwrap();
+        let from = UNIX_EPOCH;
+
+        assert!(matches!(
+            schedule.next_after(from),
+            Err(SchedulerError::NoUpcomingRun(_))
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+//! Implementation details for the scheduler module.
+
+pub mod cron;
+pub mod scheduler;
@@ -0,0 +1,515 @@
+//! A [`Scheduler`] that drives registered jobs on cron or interval
+//! schedules, replacing the hand-rolled `tokio::time::interval` loop
+//! every service eventually grows.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use rustboot_async::CancellationToken;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::api::{OverlapPolicy, Schedule, SchedulerError};
+use crate::spi::Job;
+
+/// A boxed, owned future, used for job closures so a job's return type
+/// doesn't depend on a generic future parameter.
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// A job handler: run on every scheduled tick, returning `Err` with a
+/// human-readable reason on failure.
+pub type BoxedJob = Box<dyn Fn() -> BoxFuture<Result<(), String>> + Send + Sync>;
+
+fn next_run(schedule: &Schedule, from: SystemTime) -> Result<SystemTime, SchedulerError> {
+    match schedule {
+        Schedule::Cron(cron) => cron.next_after(from),
+        Schedule::Interval(interval) => Ok(from + *interval),
+    }
+}
+
+fn record_run(name: &str, duration: Duration, success: bool) {
+    rustboot_observability::observe_histogram(
+        "scheduler_job_duration_seconds",
+        &[("job", name), ("outcome", if success { "success" } else { "failure" })],
+        duration.as_secs_f64(),
+    );
+}
+
+struct RegisteredJob {
+    name: String,
+    schedule: Schedule,
+    overlap: OverlapPolicy,
+    handler: BoxedJob,
+    running: AtomicBool,
+    run_lock: tokio::sync::Mutex<()>,
+}
+
+async fn execute_once(job: Arc<RegisteredJob>) {
+    let started = Instant::now();
+    let result = (job.handler)().await;
+    record_run(&job.name, started.elapsed(), result.is_ok());
+}
+
+async fn dispatch(job: Arc<RegisteredJob>, run_handles: &Mutex<Vec<JoinHandle<()>>>) {
+    match job.overlap {
+        OverlapPolicy::Concurrent => {
+            let handle = tokio::spawn(execute_once(job));
+            run_handles.lock().unwrap().push(handle);
+        }
+        OverlapPolicy::Skip => {
+            if job.running.swap(true, Ordering::SeqCst) {
+                return;
+            }
+            let job_for_run = job.clone();
+            execute_once(job_for_run).await;
+            job.running.store(false, Ordering::SeqCst);
+        }
+        OverlapPolicy::Queue => {
+            // Spawned rather than awaited, so the driver keeps ticking and
+            // a run that comes due meanwhile waits on `run_lock` instead
+            // of being dropped.
+            let handle = tokio::spawn(async move {
+                let _permit = job.run_lock.lock().await;
+                execute_once(job.clone()).await;
+            });
+            run_handles.lock().unwrap().push(handle);
+        }
+    }
+}
+
+/// Drives registered jobs on their cron or interval schedules, applying
+/// each job's [`OverlapPolicy`] when a new run is due while a previous
+/// one is still in progress.
+///
+/// ```ignore
+/// use std::sync::Arc;
+/// use rustboot_scheduler::{OverlapPolicy, Schedule, Scheduler};
+///
+/// let scheduler = Arc::new(Scheduler::new());
+/// scheduler.register(
+///     "cleanup",
+///     Schedule::cron("0 * * * *").unwrap(),
+///     OverlapPolicy::Skip,
+///     || Box::pin(async { Ok(()) }),
+/// ).unwrap();
+/// scheduler.start().await.unwrap();
+/// scheduler.stop().await;
+/// ```
+pub struct Scheduler {
+    jobs: Mutex<HashMap<String, Arc<RegisteredJob>>>,
+    driver_handles: Mutex<Vec<JoinHandle<()>>>,
+    run_handles: Mutex<Vec<JoinHandle<()>>>,
+    shutdown_tx: watch::Sender<bool>,
+    token: CancellationToken,
+    started: AtomicBool,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    /// Creates an empty, unstarted scheduler with its own root
+    /// cancellation token.
+    pub fn new() -> Self {
+        Self::with_cancellation(CancellationToken::new())
+    }
+
+    /// Creates an empty, unstarted scheduler tied to `token` — cancelling
+    /// it, directly or by cancelling a parent it was derived from, stops
+    /// every driver task the same way [`Scheduler::stop`] does. Use this
+    /// to fold the scheduler into a wider app shutdown alongside the web
+    /// server, messaging consumers, and stream tasks.
+    pub fn with_cancellation(token: CancellationToken) -> Self {
+        let (shutdown_tx, _) = watch::channel(false);
+        Self {
+            jobs: Mutex::new(HashMap::new()),
+            driver_handles: Mutex::new(Vec::new()),
+            run_handles: Mutex::new(Vec::new()),
+            shutdown_tx,
+            token,
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns a child of this scheduler's cancellation token, so another
+    /// subsystem can be cancelled in lockstep when this scheduler stops.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.child_token()
+    }
+
+    /// Registers a closure-based job. `name` must be unique among
+    /// currently registered jobs.
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        schedule: Schedule,
+        overlap: OverlapPolicy,
+        handler: impl Fn() -> BoxFuture<Result<(), String>> + Send + Sync + 'static,
+    ) -> Result<(), SchedulerError> {
+        self.register_boxed(name.into(), schedule, overlap, Box::new(handler))
+    }
+
+    /// Registers a trait-based job — useful when the handler needs
+    /// fields (a database pool, a config value) rather than capturing
+    /// them in a closure environment.
+    pub fn register_job(
+        &self,
+        name: impl Into<String>,
+        schedule: Schedule,
+        overlap: OverlapPolicy,
+        job: Arc<dyn Job>,
+    ) -> Result<(), SchedulerError> {
+        let handler: BoxedJob = Box::new(move || {
+            let job = job.clone();
+            Box::pin(async move { job.run().await })
+        });
+        self.register_boxed(name.into(), schedule, overlap, handler)
+    }
+
+    fn register_boxed(
+        &self,
+        name: String,
+        schedule: Schedule,
+        overlap: OverlapPolicy,
+        handler: BoxedJob,
+    ) -> Result<(), SchedulerError> {
+        let mut jobs = self.jobs.lock().unwrap();
+        if jobs.contains_key(&name) {
+            return Err(SchedulerError::DuplicateJob(name));
+        }
+        jobs.insert(
+            name.clone(),
+            Arc::new(RegisteredJob {
+                name,
+                schedule,
+                overlap,
+                handler,
+                running: AtomicBool::new(false),
+                run_lock: tokio::sync::Mutex::new(()),
+            }),
+        );
+        Ok(())
+    }
+
+    /// Returns the names of every registered job.
+    pub fn jobs(&self) -> Vec<String> {
+        self.jobs.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// Starts a driver task per registered job, each sleeping until its
+    /// next scheduled run and then dispatching it per its
+    /// [`OverlapPolicy`].
+    pub async fn start(self: &Arc<Self>) -> Result<(), SchedulerError> {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return Err(SchedulerError::AlreadyRunning);
+        }
+        let _ = self.shutdown_tx.send(false);
+
+        let jobs: Vec<Arc<RegisteredJob>> = self.jobs.lock().unwrap().values().cloned().collect();
+        let mut driver_handles = self.driver_handles.lock().unwrap();
+        for job in jobs {
+            let scheduler = self.clone();
+            let shutdown_rx = self.shutdown_tx.subscribe();
+            driver_handles.push(tokio::spawn(async move {
+                scheduler.drive(job, shutdown_rx).await;
+            }));
+        }
+        Ok(())
+    }
+
+    async fn drive(self: Arc<Self>, job: Arc<RegisteredJob>, mut shutdown_rx: watch::Receiver<bool>) {
+        loop {
+            if *shutdown_rx.borrow() || self.token.is_cancelled() {
+                return;
+            }
+
+            let next = match next_run(&job.schedule, SystemTime::now()) {
+                Ok(next) => next,
+                Err(_) => return,
+            };
+            let sleep_for = next
+                .duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO);
+
+            tokio::select! {
+                _ = tokio::time::sleep(sleep_for) => {}
+                _ = shutdown_rx.changed() => {}
+                _ = self.token.cancelled() => {}
+            }
+
+            if *shutdown_rx.borrow() || self.token.is_cancelled() {
+                return;
+            }
+
+            dispatch(job.clone(), &self.run_handles).await;
+        }
+    }
+
+    /// Runs a registered job immediately, outside its schedule, honoring
+    /// its [`OverlapPolicy`].
+    pub async fn run_now(&self, name: &str) -> Result<(), SchedulerError> {
+        let job = self
+            .jobs
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| SchedulerError::UnknownJob(name.to_string()))?;
+        dispatch(job, &self.run_handles).await;
+        Ok(())
+    }
+
+    /// Signals every driver task to stop scheduling new runs, then waits
+    /// for all driver tasks and any in-flight job runs to finish. Also
+    /// cancels this scheduler's cancellation token, so any subsystem
+    /// holding a child from [`Scheduler::cancellation_token`] shuts down
+    /// too.
+    pub async fn stop(&self) {
+        let _ = self.shutdown_tx.send(true);
+        self.token.cancel();
+
+        let driver_handles: Vec<JoinHandle<()>> =
+            std::mem::take(&mut self.driver_handles.lock().unwrap());
+        for handle in driver_handles {
+            let _ = handle.await;
+        }
+
+        let run_handles: Vec<JoinHandle<()>> = std::mem::take(&mut self.run_handles.lock().unwrap());
+        for handle in run_handles {
+            let _ = handle.await;
+        }
+
+        self.started.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn run_now_invokes_the_job_immediately() {
+        let scheduler = Arc::new(Scheduler::new());
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_for_job = count.clone();
+        scheduler
+            .register(
+                "count",
+                Schedule::every(Duration::from_secs(3600)),
+                OverlapPolicy::Skip,
+                move || {
+                    let count = count_for_job.clone();
+                    Box::pin(async move {
+                        count.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                },
+            )
+            .unwrap();
+
+        scheduler.run_now("count").await.unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn run_now_errors_for_an_unregistered_job() {
+        let scheduler = Scheduler::new();
+        let err = scheduler.run_now("missing").await.unwrap_err();
+        assert_eq!(err, SchedulerError::UnknownJob("missing".to_string()));
+    }
+
+    #[tokio::test]
+    async fn register_rejects_a_duplicate_name() {
+        let scheduler = Scheduler::new();
+        scheduler
+            .register(
+                "job",
+                Schedule::every(Duration::from_secs(1)),
+                OverlapPolicy::Skip,
+                || Box::pin(async { Ok(()) }),
+            )
+            .unwrap();
+
+        let err = scheduler
+            .register(
+                "job",
+                Schedule::every(Duration::from_secs(1)),
+                OverlapPolicy::Skip,
+                || Box::pin(async { Ok(()) }),
+            )
+            .unwrap_err();
+
+        assert_eq!(err, SchedulerError::DuplicateJob("job".to_string()));
+    }
+
+    // Uses a paused clock so the tick count is deterministic instead of
+    // racing real OS scheduling jitter at 10ms granularity (see
+    // rustboot-messaging's in_memory.rs tests for the same pattern).
+    #[tokio::test(start_paused = true)]
+    async fn start_drives_an_interval_job_and_stop_waits_for_it_to_settle() {
+        let scheduler = Arc::new(Scheduler::new());
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_for_job = count.clone();
+        scheduler
+            .register(
+                "tick",
+                Schedule::every(Duration::from_millis(10)),
+                OverlapPolicy::Skip,
+                move || {
+                    let count = count_for_job.clone();
+                    Box::pin(async move {
+                        count.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                },
+            )
+            .unwrap();
+
+        scheduler.start().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(35)).await;
+        scheduler.stop().await;
+
+        assert!(count.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[tokio::test]
+    async fn cancelling_a_parent_token_stops_a_scheduler_derived_from_it() {
+        let app_shutdown = CancellationToken::new();
+        let scheduler = Arc::new(Scheduler::with_cancellation(app_shutdown.child_token()));
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_for_job = count.clone();
+        scheduler
+            .register(
+                "tick",
+                Schedule::every(Duration::from_millis(10)),
+                OverlapPolicy::Skip,
+                move || {
+                    let count = count_for_job.clone();
+                    Box::pin(async move {
+                        count.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                },
+            )
+            .unwrap();
+
+        scheduler.start().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(15)).await;
+        app_shutdown.cancel();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let seen_after_cancel = count.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(count.load(Ordering::SeqCst), seen_after_cancel);
+    }
+
+    #[tokio::test]
+    async fn stopping_a_scheduler_cancels_a_child_token_it_handed_out() {
+        let scheduler = Scheduler::new();
+        let child = scheduler.cancellation_token();
+        assert!(!child.is_cancelled());
+
+        scheduler.stop().await;
+
+        assert!(child.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn start_twice_without_stopping_errors() {
+        let scheduler = Arc::new(Scheduler::new());
+        scheduler
+            .register(
+                "job",
+                Schedule::every(Duration::from_secs(3600)),
+                OverlapPolicy::Skip,
+                || Box::pin(async { Ok(()) }),
+            )
+            .unwrap();
+
+        scheduler.start().await.unwrap();
+        let err = scheduler.start().await.unwrap_err();
+        scheduler.stop().await;
+
+        assert_eq!(err, SchedulerError::AlreadyRunning);
+    }
+
+    #[tokio::test]
+    async fn skip_overlap_policy_drops_a_run_while_one_is_in_progress() {
+        let scheduler = Arc::new(Scheduler::new());
+        let started = Arc::new(tokio::sync::Notify::new());
+        let release = Arc::new(tokio::sync::Notify::new());
+        let count = Arc::new(AtomicUsize::new(0));
+
+        let started_for_job = started.clone();
+        let release_for_job = release.clone();
+        let count_for_job = count.clone();
+        scheduler
+            .register(
+                "slow",
+                Schedule::every(Duration::from_secs(3600)),
+                OverlapPolicy::Skip,
+                move || {
+                    let started = started_for_job.clone();
+                    let release = release_for_job.clone();
+                    let count = count_for_job.clone();
+                    Box::pin(async move {
+                        count.fetch_add(1, Ordering::SeqCst);
+                        started.notify_one();
+                        release.notified().await;
+                        Ok(())
+                    })
+                },
+            )
+            .unwrap();
+
+        let scheduler_for_first = scheduler.clone();
+        let first = tokio::spawn(async move { scheduler_for_first.run_now("slow").await });
+        started.notified().await;
+
+        scheduler.run_now("slow").await.unwrap();
+        release.notify_one();
+        first.await.unwrap().unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn job_trait_is_invoked_via_run_now() {
+        struct CountingJob {
+            count: Arc<AtomicUsize>,
+        }
+
+        #[async_trait::async_trait]
+        impl crate::spi::Job for CountingJob {
+            async fn run(&self) -> Result<(), String> {
+                self.count.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        let scheduler = Scheduler::new();
+        let count = Arc::new(AtomicUsize::new(0));
+        scheduler
+            .register_job(
+                "trait-job",
+                Schedule::every(Duration::from_secs(3600)),
+                OverlapPolicy::Skip,
+                Arc::new(CountingJob { count: count.clone() }),
+            )
+            .unwrap();
+
+        scheduler.run_now("trait-job").await.unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,27 @@
+//! Cron and interval job scheduling for the rustboot framework.
+//!
+//! - [`Schedule`]: a cron expression (evaluated in UTC) or a fixed
+//!   interval.
+//! - [`Scheduler`]: registers jobs — closures or [`spi::Job`]
+//!   implementations — against a [`Schedule`] and an [`OverlapPolicy`],
+//!   drives them on their own background tasks, and shuts them down
+//!   gracefully.
+//! - [`OverlapPolicy`]: what happens when a job's next run is due while
+//!   its previous run is still in progress.
+//!
+//! [`Scheduler::with_cancellation`] ties a scheduler's shutdown to an
+//! app-wide `rustboot_async::CancellationToken`, and
+//! [`Scheduler::cancellation_token`] hands out a child token so other
+//! subsystems shut down in lockstep with it.
+//!
+//! Each job run is recorded as a `scheduler_job_duration_seconds`
+//! histogram observation via `rustboot-observability`, tagged with the
+//! job name and outcome.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{CronSchedule, OverlapPolicy, Schedule, SchedulerError};
+pub use core::scheduler::{BoxFuture, BoxedJob, Scheduler};
+pub use spi::Job;
@@ -0,0 +1,14 @@
+//! Service provider interfaces for the scheduler module.
+
+use async_trait::async_trait;
+
+/// A job handler registered with a [`crate::core::scheduler::Scheduler`]
+/// as a struct rather than a closure — useful when the job needs fields
+/// (a database pool, a config value) rather than capturing them in a
+/// closure environment.
+#[async_trait]
+pub trait Job: Send + Sync {
+    /// Runs the job once. Returning `Err` records a failed run but does
+    /// not affect future scheduling.
+    async fn run(&self) -> Result<(), String>;
+}
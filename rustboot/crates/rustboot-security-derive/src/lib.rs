@@ -0,0 +1,85 @@
+//! Attribute macro for `rustboot_security::core::audit`.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, ItemFn, LitStr};
+
+/// Wraps an async function so every call emits an [`AuditEvent`] to the
+/// globally installed [`AuditSink`] (see
+/// `rustboot_security::core::audit::install_global_sink`), recording
+/// [`AuditOutcome::Success`] or [`AuditOutcome::Failure`] from whether
+/// the function returned `Ok` or `Err`.
+///
+/// Requires a string literal `action`, and applies only to an `async
+/// fn` returning `Result<T, E>`:
+///
+/// ```ignore
+/// #[audit("user.login")]
+/// async fn login(user_id: &str) -> Result<Session, LoginError> {
+///     // ...
+/// }
+/// ```
+///
+/// [`AuditEvent`]: ../rustboot_security/api/struct.AuditEvent.html
+/// [`AuditSink`]: ../rustboot_security/spi/trait.AuditSink.html
+/// [`AuditOutcome::Success`]: ../rustboot_security/api/enum.AuditOutcome.html
+#[proc_macro_attribute]
+pub fn audit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let action = parse_macro_input!(attr as LitStr);
+    let func = parse_macro_input!(item as ItemFn);
+
+    let vis = &func.vis;
+    let sig = &func.sig;
+    let block = &func.block;
+    let attrs = &func.attrs;
+    let fn_name = &sig.ident;
+
+    if sig.asyncness.is_none() {
+        return syn::Error::new_spanned(sig, "#[audit] can only be applied to an `async fn`")
+            .to_compile_error()
+            .into();
+    }
+
+    let inner_name = format_ident!("__{}_audited", fn_name);
+    let mut inner_sig = sig.clone();
+    inner_sig.ident = inner_name.clone();
+
+    let call_args = sig.inputs.iter().map(|arg| match arg {
+        syn::FnArg::Receiver(_) => quote! { self },
+        syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
+            syn::Pat::Ident(pat_ident) => {
+                let ident = &pat_ident.ident;
+                quote! { #ident }
+            }
+            other => syn::Error::new_spanned(other, "#[audit] requires simple argument names")
+                .to_compile_error(),
+        },
+    });
+
+    let expanded = quote! {
+        #(#attrs)*
+        #vis #sig {
+            #[allow(non_snake_case)]
+            #inner_sig #block
+
+            let __audit_result = #inner_name(#(#call_args),*).await;
+
+            let __audit_outcome = if __audit_result.is_ok() {
+                ::rustboot_security::api::AuditOutcome::Success
+            } else {
+                ::rustboot_security::api::AuditOutcome::Failure
+            };
+
+            ::rustboot_security::core::audit::emit(::rustboot_security::api::AuditEvent {
+                action: #action.to_string(),
+                outcome: __audit_outcome,
+                ..::std::default::Default::default()
+            })
+            .await;
+
+            __audit_result
+        }
+    };
+
+    expanded.into()
+}
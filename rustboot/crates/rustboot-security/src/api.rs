@@ -0,0 +1,227 @@
+//! Public types for the security module.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// The JSON Web Signature algorithm used to sign or verify a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JwtAlgorithm {
+    /// HMAC using SHA-256 (symmetric).
+    Hs256,
+    /// RSASSA-PKCS1-v1_5 using SHA-256 (asymmetric).
+    Rs256,
+    /// ECDSA using the P-256 curve and SHA-256 (asymmetric).
+    Es256,
+}
+
+impl JwtAlgorithm {
+    /// The `alg` value used in a JWT header, per RFC 7518.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            JwtAlgorithm::Hs256 => "HS256",
+            JwtAlgorithm::Rs256 => "RS256",
+            JwtAlgorithm::Es256 => "ES256",
+        }
+    }
+
+    /// Parses a JWT header `alg` value.
+    pub fn from_header_name(name: &str) -> Option<Self> {
+        match name {
+            "HS256" => Some(JwtAlgorithm::Hs256),
+            "RS256" => Some(JwtAlgorithm::Rs256),
+            "ES256" => Some(JwtAlgorithm::Es256),
+            _ => None,
+        }
+    }
+}
+
+/// Registered and custom claims carried by a token.
+///
+/// `exp`, `nbf`, and `iat` are Unix timestamps (seconds), per RFC 7519.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Claims {
+    /// The subject (`sub`) the token was issued for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    /// The issuer (`iss`) that signed the token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    /// The intended audience (`aud`) of the token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+    /// The expiration time (`exp`), after which the token is invalid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u64>,
+    /// The not-before time (`nbf`), before which the token is invalid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<u64>,
+    /// The issued-at time (`iat`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<u64>,
+    /// Any additional, application-defined claims.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// A single key from a JSON Web Key Set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Jwk {
+    /// The key ID (`kid`), used to select a key out of a [`Jwks`].
+    pub kid: String,
+    /// The algorithm (`alg`) this key is used with, as published by the
+    /// issuer (e.g. `"RS256"`). Kept as a raw string, rather than
+    /// [`JwtAlgorithm`], so an unrecognized value can round-trip through
+    /// (de)serialization instead of failing the whole key set.
+    pub alg: String,
+    /// The raw key material, in whatever form the issuer published it
+    /// (e.g. a PEM-encoded public key, or the base64url-encoded `k`
+    /// member of a symmetric key). Interpreting it is the concern of a
+    /// [`crate::spi::JwsVerifier`] implementation, not this crate.
+    pub material: String,
+}
+
+/// A JSON Web Key Set, as published at an issuer's `jwks_uri`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Jwks {
+    /// The keys in the set.
+    pub keys: Vec<Jwk>,
+}
+
+/// Constraints applied to a token's claims during validation, beyond
+/// signature verification.
+#[derive(Debug, Clone)]
+pub struct ValidationOptions {
+    /// Clock skew tolerance applied to `exp` and `nbf` checks.
+    pub leeway: Duration,
+    /// The issuer every token must match via `iss`, if set.
+    pub expected_issuer: Option<String>,
+    /// The audience every token must match via `aud`, if set.
+    pub expected_audience: Option<String>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            leeway: Duration::from_secs(0),
+            expected_issuer: None,
+            expected_audience: None,
+        }
+    }
+}
+
+/// Errors produced while issuing, validating, or fetching keys for JWTs.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum SecurityError {
+    /// The token was not a well-formed `header.payload.signature` triple.
+    #[error("malformed token")]
+    Malformed,
+    /// The token's `alg` header is not one this crate supports.
+    #[error("unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+    /// Signature verification failed.
+    #[error("signature verification failed")]
+    SignatureInvalid,
+    /// The token's `exp` claim is in the past.
+    #[error("token has expired")]
+    Expired,
+    /// The token's `nbf` claim is in the future.
+    #[error("token is not yet valid")]
+    NotYetValid,
+    /// The token's `iss` claim did not match the expected issuer.
+    #[error("issuer mismatch: expected '{expected}', got '{actual}'")]
+    IssuerMismatch {
+        /// The issuer the validator required.
+        expected: String,
+        /// The issuer the token actually carried.
+        actual: String,
+    },
+    /// The token's `aud` claim did not match the expected audience.
+    #[error("audience mismatch: expected '{expected}', got '{actual}'")]
+    AudienceMismatch {
+        /// The audience the validator required.
+        expected: String,
+        /// The audience the token actually carried.
+        actual: String,
+    },
+    /// No key in the JWKS matched the token's `kid`.
+    #[error("no key found for kid '{0}'")]
+    KeyNotFound(String),
+    /// Fetching the JWKS document from the issuer failed.
+    #[error("failed to fetch JWKS: {0}")]
+    JwksFetchFailed(String),
+}
+
+/// Whether an audited action succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    /// The action completed successfully.
+    #[default]
+    Success,
+    /// The action failed.
+    Failure,
+}
+
+/// A structured record of a security-relevant action, independent of
+/// whatever sink it's ultimately written to.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// The identity that performed the action (e.g. a user or service ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    /// The action taken (e.g. `"user.login"`, `"role.grant"`).
+    pub action: String,
+    /// The resource the action was taken on (e.g. a record ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    /// Whether the action succeeded.
+    pub outcome: AuditOutcome,
+    /// The source IP address of the request, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// A distributed trace ID correlating this event with request logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    /// Any additional, application-defined context.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// An [`AuditEvent`] sealed into a hash chain, so any sink that
+/// preserves the records in order can later detect tampering or
+/// reordering via [`crate::core::audit::verify_chain`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// This record's position in the chain, starting at 0.
+    pub sequence: u64,
+    /// The hex-encoded SHA-256 hash of the record immediately before
+    /// this one (the all-zero digest for the first record).
+    pub prev_hash: String,
+    /// The hex-encoded SHA-256 hash of `prev_hash` and `event`,
+    /// chaining this record to the one before it. Cryptographic, so an
+    /// attacker who edits one record in place can't search for a
+    /// colliding replacement; it is still not a substitute for
+    /// write-once storage or a signed ledger against an attacker who
+    /// controls the sink and can rewrite the whole chain from that
+    /// point forward.
+    pub hash: String,
+    /// The event this record carries.
+    pub event: AuditEvent,
+}
+
+/// Errors produced while recording or verifying audit events.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum AuditError {
+    /// The configured sink could not accept the record.
+    #[error("audit sink unavailable: {0}")]
+    SinkUnavailable(String),
+    /// A record's hash did not match its recomputed value, or its
+    /// `prev_hash` did not match the preceding record's `hash`.
+    #[error("audit chain broken at sequence {sequence}")]
+    ChainBroken {
+        /// The sequence number of the first record that failed to verify.
+        sequence: u64,
+    },
+}
@@ -0,0 +1,358 @@
+//! Tamper-evident audit logging: a hash chain of [`AuditEvent`]s over a
+//! pluggable [`AuditSink`].
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use rustboot_crypto::Sha256Digest;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+pub use crate::api::{AuditError, AuditEvent, AuditOutcome, AuditRecord};
+use crate::spi::AuditSink;
+
+/// The `prev_hash` of the first record in a chain.
+fn genesis_hash() -> String {
+    Sha256Digest::from_bytes([0u8; 32]).to_string()
+}
+
+fn record_hash(prev_hash: &str, event: &AuditEvent) -> String {
+    let mut bytes = prev_hash.as_bytes().to_vec();
+    bytes.extend_from_slice(
+        &serde_json::to_vec(event).expect("AuditEvent always serializes"),
+    );
+    Sha256Digest::of(&bytes).to_string()
+}
+
+/// Seals [`AuditEvent`]s into a hash chain, tracking the running
+/// sequence number and last hash.
+///
+/// The sequence number and the hash link are advanced together under a
+/// single lock: assigning them as two separate steps would let
+/// concurrent callers interleave (e.g. one thread's `fetch_add` racing
+/// ahead of another's hash update), handing out a `sequence` that
+/// doesn't correspond to the record its `prev_hash` actually chains
+/// from and making [`verify_chain`] flag a legitimate concurrent audit
+/// trail as tampered.
+pub struct AuditChain {
+    state: std::sync::Mutex<ChainState>,
+}
+
+impl Default for AuditChain {
+    fn default() -> Self {
+        Self {
+            state: std::sync::Mutex::new(ChainState {
+                next_sequence: 0,
+                last_hash: genesis_hash(),
+            }),
+        }
+    }
+}
+
+struct ChainState {
+    next_sequence: u64,
+    last_hash: String,
+}
+
+impl AuditChain {
+    /// Creates a chain starting at sequence 0 with the all-zero genesis
+    /// hash.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seals `event` onto the chain, producing the next [`AuditRecord`].
+    pub fn seal(&self, event: AuditEvent) -> AuditRecord {
+        let mut state = self.state.lock().unwrap();
+        let sequence = state.next_sequence;
+        let prev_hash = state.last_hash.clone();
+        let hash = record_hash(&prev_hash, &event);
+        state.next_sequence = sequence + 1;
+        state.last_hash = hash.clone();
+        drop(state);
+
+        AuditRecord {
+            sequence,
+            prev_hash,
+            hash,
+            event,
+        }
+    }
+}
+
+/// Recomputes each record's hash and checks that each one's `prev_hash`
+/// matches the preceding record's `hash`, in order.
+pub fn verify_chain(records: &[AuditRecord]) -> Result<(), AuditError> {
+    let mut expected_prev_hash = genesis_hash();
+    for record in records {
+        if record.prev_hash != expected_prev_hash {
+            return Err(AuditError::ChainBroken {
+                sequence: record.sequence,
+            });
+        }
+        if record_hash(&record.prev_hash, &record.event) != record.hash {
+            return Err(AuditError::ChainBroken {
+                sequence: record.sequence,
+            });
+        }
+        expected_prev_hash = record.hash.clone();
+    }
+    Ok(())
+}
+
+/// Seals events onto an [`AuditChain`] and writes each sealed record to
+/// an [`AuditSink`].
+pub struct AuditLogger<S: AuditSink> {
+    chain: AuditChain,
+    sink: S,
+    /// Serializes seal-then-append so records reach the sink in the
+    /// same order `AuditChain` assigned them. Sealing and appending are
+    /// otherwise two unsynchronized steps: without this lock, two
+    /// concurrent `record` calls can seal in one order but race each
+    /// other to `sink.append`, landing out of sequence order in a sink
+    /// that (per [`AuditSink`]'s contract) is supposed to durably store
+    /// records in order — which `verify_chain` would then flag as a
+    /// broken chain even though nothing was tampered with.
+    write_lock: Mutex<()>,
+}
+
+impl<S: AuditSink> AuditLogger<S> {
+    /// Creates a logger with a fresh chain, writing to `sink`.
+    pub fn new(sink: S) -> Self {
+        Self {
+            chain: AuditChain::new(),
+            sink,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Seals `event` and writes the resulting record to the sink.
+    pub async fn record(&self, event: AuditEvent) -> Result<AuditRecord, AuditError> {
+        let _guard = self.write_lock.lock().await;
+        let record = self.chain.seal(event);
+        self.sink.append(&record).await?;
+        Ok(record)
+    }
+}
+
+/// Appends each [`AuditRecord`] as a line of JSON to a file, opened in
+/// append mode.
+///
+/// Hash-chaining (via [`AuditChain`]) makes tampering or reordering
+/// detectable on replay with [`verify_chain`]; it does not make the
+/// file itself write-once. Pair with filesystem permissions or a
+/// write-once mount for stronger guarantees.
+pub struct FileAuditSink {
+    path: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl FileAuditSink {
+    /// Creates a sink that appends to the file at `path`, creating it
+    /// if it doesn't exist.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            write_lock: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait]
+impl AuditSink for FileAuditSink {
+    async fn append(&self, record: &AuditRecord) -> Result<(), AuditError> {
+        let _guard = self.write_lock.lock().await;
+        let mut line =
+            serde_json::to_vec(record).map_err(|err| AuditError::SinkUnavailable(err.to_string()))?;
+        line.push(b'\n');
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|err| AuditError::SinkUnavailable(err.to_string()))?;
+        file.write_all(&line)
+            .await
+            .map_err(|err| AuditError::SinkUnavailable(err.to_string()))
+    }
+}
+
+static GLOBAL_LOGGER: OnceLock<AuditLogger<Arc<dyn AuditSink>>> = OnceLock::new();
+
+#[async_trait]
+impl AuditSink for Arc<dyn AuditSink> {
+    async fn append(&self, record: &AuditRecord) -> Result<(), AuditError> {
+        self.as_ref().append(record).await
+    }
+}
+
+/// Installs `sink` as the destination for events emitted by the
+/// `#[audit]` attribute macro. Installing a second sink after the
+/// first has no effect; install once, at startup.
+pub fn install_global_sink(sink: Arc<dyn AuditSink>) {
+    let _ = GLOBAL_LOGGER.set(AuditLogger::new(sink));
+}
+
+/// Seals and writes `event` to the globally installed sink, if one has
+/// been installed via [`install_global_sink`]. With no sink installed,
+/// this is a no-op rather than an error, so `#[audit]`-wrapped code
+/// behaves the same whether or not auditing has been wired up yet.
+pub async fn emit(event: AuditEvent) {
+    if let Some(logger) = GLOBAL_LOGGER.get() {
+        let _ = logger.record(event).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn login_event(actor: &str) -> AuditEvent {
+        AuditEvent {
+            actor: Some(actor.to_string()),
+            action: "user.login".to_string(),
+            outcome: AuditOutcome::Success,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn chain_links_successive_records() {
+        let chain = AuditChain::new();
+        let first = chain.seal(login_event("alice"));
+        let second = chain.seal(login_event("bob"));
+
+        assert_eq!(first.sequence, 0);
+        assert_eq!(first.prev_hash, genesis_hash());
+        assert_eq!(second.sequence, 1);
+        assert_eq!(second.prev_hash, first.hash);
+    }
+
+    #[test]
+    fn verify_chain_accepts_an_untampered_chain() {
+        let chain = AuditChain::new();
+        let records = vec![chain.seal(login_event("alice")), chain.seal(login_event("bob"))];
+        assert_eq!(verify_chain(&records), Ok(()));
+    }
+
+    #[test]
+    fn verify_chain_detects_a_tampered_event() {
+        let chain = AuditChain::new();
+        let mut records = vec![chain.seal(login_event("alice")), chain.seal(login_event("bob"))];
+        records[0].event.actor = Some("mallory".to_string());
+
+        assert_eq!(verify_chain(&records), Err(AuditError::ChainBroken { sequence: 0 }));
+    }
+
+    #[test]
+    fn seal_is_consistent_under_concurrent_callers() {
+        let chain = Arc::new(AuditChain::new());
+        let records: std::sync::Mutex<Vec<AuditRecord>> = std::sync::Mutex::new(Vec::new());
+
+        std::thread::scope(|scope| {
+            for i in 0..8 {
+                let chain = Arc::clone(&chain);
+                let records = &records;
+                scope.spawn(move || {
+                    let record = chain.seal(login_event(&format!("user-{i}")));
+                    records.lock().unwrap().push(record);
+                });
+            }
+        });
+
+        let mut records = records.into_inner().unwrap();
+        assert_eq!(records.len(), 8);
+        records.sort_by_key(|record| record.sequence);
+        assert_eq!(verify_chain(&records), Ok(()));
+    }
+
+    #[test]
+    fn verify_chain_detects_reordering() {
+        let chain = AuditChain::new();
+        let mut records = vec![chain.seal(login_event("alice")), chain.seal(login_event("bob"))];
+        records.swap(0, 1);
+
+        assert_eq!(verify_chain(&records), Err(AuditError::ChainBroken { sequence: 1 }));
+    }
+
+    struct InMemorySink {
+        records: Mutex<Vec<AuditRecord>>,
+    }
+
+    #[async_trait]
+    impl AuditSink for InMemorySink {
+        async fn append(&self, record: &AuditRecord) -> Result<(), AuditError> {
+            self.records.lock().await.push(record.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn logger_seals_and_forwards_to_the_sink() {
+        let sink = InMemorySink {
+            records: Mutex::new(Vec::new()),
+        };
+        let logger = AuditLogger::new(sink);
+
+        logger.record(login_event("alice")).await.unwrap();
+        logger.record(login_event("bob")).await.unwrap();
+
+        let recorded = logger.sink.records.lock().await;
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(verify_chain(&recorded), Ok(()));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn concurrent_record_calls_append_in_sequence_order() {
+        let sink = InMemorySink {
+            records: Mutex::new(Vec::new()),
+        };
+        let logger = Arc::new(AuditLogger::new(sink));
+
+        let mut tasks = Vec::new();
+        for i in 0..16 {
+            let logger = Arc::clone(&logger);
+            tasks.push(tokio::spawn(async move {
+                logger.record(login_event(&format!("user-{i}"))).await.unwrap();
+            }));
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let recorded = logger.sink.records.lock().await;
+        assert_eq!(recorded.len(), 16);
+        let sequences: Vec<u64> = recorded.iter().map(|record| record.sequence).collect();
+        assert_eq!(sequences, (0..16).collect::<Vec<_>>());
+        assert_eq!(verify_chain(&recorded), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn file_sink_appends_one_json_line_per_record() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!(
+            "rustboot_security_audit_test_{:?}.jsonl",
+            std::thread::current().id()
+        ));
+        let _ = tokio::fs::remove_file(&path).await;
+
+        let sink = FileAuditSink::new(&path);
+        let logger = AuditLogger::new(sink);
+        logger.record(login_event("alice")).await.unwrap();
+        logger.record(login_event("bob")).await.unwrap();
+
+        let contents = tokio::fs::read_to_string(&path).await.unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let records: Vec<AuditRecord> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(verify_chain(&records), Ok(()));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+//! Unpadded base64url encoding, as used for JWT segments (RFC 7515 §2).
+//!
+//! Hand-rolled rather than pulling in a base64 crate for three small,
+//! fixed-alphabet functions.
+
+const ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub fn encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        if let Some(b1) = b1 {
+            out.push(ALPHABET[((b1 & 0x0f) << 2 | b2.unwrap_or(0) >> 6) as usize] as char);
+        }
+        if let Some(b2) = b2 {
+            out.push(ALPHABET[(b2 & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+pub fn decode(input: &str) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'-' => Some(62),
+            b'_' => Some(63),
+            _ => None,
+        }
+    }
+
+    let symbols: Vec<u8> = input
+        .bytes()
+        .map(value)
+        .collect::<Option<Vec<u8>>>()?;
+
+    let mut out = Vec::with_capacity(symbols.len() * 3 / 4);
+    for chunk in symbols.chunks(4) {
+        let s0 = chunk[0];
+        let s1 = *chunk.get(1)?;
+        out.push((s0 << 2) | (s1 >> 4));
+        if let Some(&s2) = chunk.get(2) {
+            out.push((s1 << 4) | (s2 >> 2));
+            if let Some(&s3) = chunk.get(3) {
+                out.push((s2 << 6) | s3);
+            }
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_arbitrary_byte_lengths() {
+        for input in [&b""[..], b"f", b"fo", b"foo", b"foob", b"fooba", b"foobar"] {
+            assert_eq!(decode(&encode(input)).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn uses_url_safe_alphabet_without_padding() {
+        let encoded = encode(&[0xfb, 0xff, 0xbf]);
+        assert!(!encoded.contains('+'));
+        assert!(!encoded.contains('/'));
+        assert!(!encoded.contains('='));
+    }
+
+    #[test]
+    fn rejects_invalid_characters() {
+        assert_eq!(decode("not valid base64url!"), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! A local, asymmetric ES256 [`JwsSigner`]/[`JwsVerifier`] over
+//! [`rustboot_crypto`]'s ECDSA P-256 signatures.
+
+use rustboot_crypto::{sign, verify, Signature, SignatureAlgorithm, SigningKey, VerifyingKey};
+
+use crate::api::{JwtAlgorithm, SecurityError};
+use crate::spi::{JwsSigner, JwsVerifier};
+
+/// Signs ES256 JWTs with an ECDSA P-256 private key.
+pub struct EcdsaSigner {
+    key: SigningKey,
+    key_id: Option<String>,
+}
+
+impl EcdsaSigner {
+    /// Wraps `key` as an ES256 signer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` isn't a [`SignatureAlgorithm::Es256`] key.
+    pub fn new(key: SigningKey) -> Self {
+        assert_eq!(
+            key.algorithm(),
+            SignatureAlgorithm::Es256,
+            "EcdsaSigner requires an ES256 key"
+        );
+        Self { key, key_id: None }
+    }
+
+    /// Sets the `kid` recorded in the header of tokens issued with this
+    /// key.
+    pub fn with_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+}
+
+impl JwsSigner for EcdsaSigner {
+    fn algorithm(&self) -> JwtAlgorithm {
+        JwtAlgorithm::Es256
+    }
+
+    fn key_id(&self) -> Option<&str> {
+        self.key_id.as_deref()
+    }
+
+    fn sign(&self, signing_input: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        Ok(sign(&self.key, signing_input).as_bytes().to_vec())
+    }
+}
+
+/// Verifies ES256 JWTs with an ECDSA P-256 public key.
+pub struct EcdsaVerifier {
+    key: VerifyingKey,
+}
+
+impl EcdsaVerifier {
+    /// Wraps `key` as an ES256 verifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` isn't a [`SignatureAlgorithm::Es256`] key.
+    pub fn new(key: VerifyingKey) -> Self {
+        assert_eq!(
+            key.algorithm(),
+            SignatureAlgorithm::Es256,
+            "EcdsaVerifier requires an ES256 key"
+        );
+        Self { key }
+    }
+}
+
+impl JwsVerifier for EcdsaVerifier {
+    fn verify(
+        &self,
+        algorithm: JwtAlgorithm,
+        _key_id: Option<&str>,
+        signing_input: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, SecurityError> {
+        if algorithm != JwtAlgorithm::Es256 {
+            return Err(SecurityError::UnsupportedAlgorithm(
+                algorithm.header_name().to_string(),
+            ));
+        }
+        let signature = Signature::from_bytes(signature.to_vec());
+        Ok(verify(&self.key, signing_input, &signature).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Claims;
+    use crate::core::jwt::JwtAuthenticator;
+
+    #[test]
+    fn round_trips_claims_through_a_real_ecdsa_keypair() {
+        let key = SigningKey::generate(SignatureAlgorithm::Es256);
+        let verifying_key = key.verifying_key();
+        let auth = JwtAuthenticator::new(
+            EcdsaSigner::new(key).with_key_id("test-key"),
+            EcdsaVerifier::new(verifying_key),
+            Default::default(),
+        );
+
+        let token = auth
+            .issue(&Claims {
+                sub: Some("alice".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        let claims = auth.validate(&token).unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn rejects_a_token_signed_by_a_different_keypair() {
+        let key = SigningKey::generate(SignatureAlgorithm::Es256);
+        let other_key = SigningKey::generate(SignatureAlgorithm::Es256);
+
+        let auth = JwtAuthenticator::new(
+            EcdsaSigner::new(key),
+            EcdsaVerifier::new(other_key.verifying_key()),
+            Default::default(),
+        );
+
+        let token = auth.issue(&Claims::default()).unwrap();
+        assert_eq!(auth.validate(&token), Err(SecurityError::SignatureInvalid));
+    }
+}
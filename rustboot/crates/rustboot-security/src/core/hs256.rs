@@ -0,0 +1,112 @@
+//! A local, symmetric HS256 [`JwsSigner`]/[`JwsVerifier`], so an
+//! application that just needs shared-secret JWTs doesn't have to write
+//! its own HMAC glue against [`JwtAuthenticator`](crate::JwtAuthenticator)
+//! first.
+
+use rustboot_crypto::{hmac_sha256, hmac_sha256_verify};
+
+use crate::api::{JwtAlgorithm, SecurityError};
+use crate::spi::{JwsSigner, JwsVerifier};
+
+/// Signs and verifies HS256 JWTs with a shared secret, via
+/// [`rustboot_crypto::hmac_sha256`].
+pub struct HmacKey {
+    secret: Vec<u8>,
+    key_id: Option<String>,
+}
+
+impl HmacKey {
+    /// Creates an HS256 signer/verifier over `secret`.
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+            key_id: None,
+        }
+    }
+
+    /// Sets the `kid` recorded in the header of tokens issued with this
+    /// key.
+    pub fn with_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+}
+
+impl JwsSigner for HmacKey {
+    fn algorithm(&self) -> JwtAlgorithm {
+        JwtAlgorithm::Hs256
+    }
+
+    fn key_id(&self) -> Option<&str> {
+        self.key_id.as_deref()
+    }
+
+    fn sign(&self, signing_input: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        Ok(hmac_sha256(&self.secret, signing_input))
+    }
+}
+
+impl JwsVerifier for HmacKey {
+    fn verify(
+        &self,
+        algorithm: JwtAlgorithm,
+        _key_id: Option<&str>,
+        signing_input: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, SecurityError> {
+        if algorithm != JwtAlgorithm::Hs256 {
+            return Err(SecurityError::UnsupportedAlgorithm(
+                algorithm.header_name().to_string(),
+            ));
+        }
+        Ok(hmac_sha256_verify(&self.secret, signing_input, signature).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::jwt::JwtAuthenticator;
+    use crate::api::Claims;
+
+    fn authenticator(secret: &'static [u8]) -> JwtAuthenticator<HmacKey, HmacKey> {
+        JwtAuthenticator::new(
+            HmacKey::new(secret).with_key_id("test-key"),
+            HmacKey::new(secret),
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn round_trips_claims_through_a_real_hmac() {
+        let auth = authenticator(b"shared secret");
+        let token = auth
+            .issue(&Claims {
+                sub: Some("alice".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let claims = auth.validate(&token).unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn rejects_a_token_signed_with_a_different_secret() {
+        let auth = authenticator(b"shared secret");
+        let token = auth.issue(&Claims::default()).unwrap();
+
+        let other = authenticator(b"a different secret");
+        assert_eq!(other.validate(&token), Err(SecurityError::SignatureInvalid));
+    }
+
+    #[test]
+    fn verify_rejects_a_non_hs256_algorithm() {
+        let key = HmacKey::new(b"shared secret");
+        let result = key.verify(JwtAlgorithm::Es256, None, b"input", b"sig");
+        assert_eq!(
+            result,
+            Err(SecurityError::UnsupportedAlgorithm("ES256".to_string()))
+        );
+    }
+}
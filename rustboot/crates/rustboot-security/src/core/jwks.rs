@@ -0,0 +1,188 @@
+//! Fetches and caches a JSON Web Key Set from an issuer, so a verifier
+//! doesn't refetch it on every token.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use crate::api::{Jwk, Jwks, SecurityError};
+use crate::spi::JwksProvider;
+
+struct CacheEntry {
+    jwks: Arc<Jwks>,
+    fetched_at: Instant,
+}
+
+/// A time-to-live cache in front of a [`JwksProvider`].
+///
+/// The cache's lock is held across a fetch, so concurrent callers that
+/// observe a cold or expired cache wait for that one fetch and then read
+/// its result; every caller, including those with a fresh entry to read,
+/// waits while a fetch is in flight.
+pub struct JwksCache<P: JwksProvider> {
+    provider: P,
+    issuer: String,
+    ttl: Duration,
+    entry: Mutex<Option<CacheEntry>>,
+}
+
+impl<P: JwksProvider> JwksCache<P> {
+    /// Creates a cache that fetches `issuer`'s JWKS via `provider`,
+    /// keeping each fetched document for `ttl` before refetching.
+    pub fn new(provider: P, issuer: impl Into<String>, ttl: Duration) -> Self {
+        Self {
+            provider,
+            issuer: issuer.into(),
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached JWKS, fetching (or refetching, if expired) as
+    /// needed.
+    pub async fn get(&self) -> Result<Arc<Jwks>, SecurityError> {
+        let mut entry = self.entry.lock().await;
+        if let Some(cached) = entry.as_ref() {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.jwks.clone());
+            }
+        }
+
+        let jwks = Arc::new(self.provider.fetch_jwks(&self.issuer).await?);
+        *entry = Some(CacheEntry {
+            jwks: jwks.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(jwks)
+    }
+
+    /// Forces the next [`JwksCache::get`] to refetch, discarding any
+    /// cached document. Useful after a [`SecurityError::KeyNotFound`],
+    /// in case the issuer rotated keys ahead of the cache's TTL.
+    pub async fn invalidate(&self) {
+        *self.entry.lock().await = None;
+    }
+}
+
+/// Finds the key matching `key_id` in `jwks`, or the sole key if the
+/// set has exactly one and no `key_id` was given.
+pub fn find_key<'a>(jwks: &'a Jwks, key_id: Option<&str>) -> Result<&'a Jwk, SecurityError> {
+    match key_id {
+        Some(kid) => jwks
+            .keys
+            .iter()
+            .find(|key| key.kid == kid)
+            .ok_or_else(|| SecurityError::KeyNotFound(kid.to_string())),
+        None => match jwks.keys.as_slice() {
+            [single] => Ok(single),
+            [] => Err(SecurityError::KeyNotFound(String::new())),
+            _ => Err(SecurityError::KeyNotFound(
+                "ambiguous: multiple keys and no kid".to_string(),
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProvider {
+        fetches: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl JwksProvider for CountingProvider {
+        async fn fetch_jwks(&self, _issuer: &str) -> Result<Jwks, SecurityError> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(Jwks {
+                keys: vec![Jwk {
+                    kid: "key-1".to_string(),
+                    alg: "RS256".to_string(),
+                    material: "...".to_string(),
+                }],
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn caches_across_calls_within_ttl() {
+        let provider = CountingProvider {
+            fetches: AtomicUsize::new(0),
+        };
+        let cache = JwksCache::new(provider, "https://issuer.example", Duration::from_secs(300));
+
+        cache.get().await.unwrap();
+        cache.get().await.unwrap();
+        cache.get().await.unwrap();
+
+        assert_eq!(cache.provider.fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn refetches_once_ttl_has_elapsed() {
+        let provider = CountingProvider {
+            fetches: AtomicUsize::new(0),
+        };
+        let cache = JwksCache::new(provider, "https://issuer.example", Duration::from_secs(0));
+
+        cache.get().await.unwrap();
+        cache.get().await.unwrap();
+
+        assert_eq!(cache.provider.fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn invalidate_forces_a_refetch() {
+        let provider = CountingProvider {
+            fetches: AtomicUsize::new(0),
+        };
+        let cache = JwksCache::new(provider, "https://issuer.example", Duration::from_secs(300));
+
+        cache.get().await.unwrap();
+        cache.invalidate().await;
+        cache.get().await.unwrap();
+
+        assert_eq!(cache.provider.fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn find_key_matches_by_kid() {
+        let jwks = Jwks {
+            keys: vec![
+                Jwk {
+                    kid: "a".to_string(),
+                    alg: "RS256".to_string(),
+                    material: "...".to_string(),
+                },
+                Jwk {
+                    kid: "b".to_string(),
+                    alg: "RS256".to_string(),
+                    material: "...".to_string(),
+                },
+            ],
+        };
+
+        assert_eq!(find_key(&jwks, Some("b")).unwrap().kid, "b");
+        assert_eq!(
+            find_key(&jwks, Some("missing")),
+            Err(SecurityError::KeyNotFound("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn find_key_falls_back_to_sole_key_without_kid() {
+        let jwks = Jwks {
+            keys: vec![Jwk {
+                kid: "only".to_string(),
+                alg: "RS256".to_string(),
+                material: "...".to_string(),
+            }],
+        };
+
+        assert_eq!(find_key(&jwks, None).unwrap().kid, "only");
+    }
+}
@@ -0,0 +1,293 @@
+//! JWT issuance and validation (RFC 7519) over a pluggable [`JwsSigner`]
+//! / [`JwsVerifier`] pair, so this crate never has to depend on a
+//! specific crypto library.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Claims, JwtAlgorithm, SecurityError, ValidationOptions};
+use crate::core::codec;
+use crate::spi::{JwsSigner, JwsVerifier};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JwtHeader {
+    alg: String,
+    typ: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kid: Option<String>,
+}
+
+/// Issues and validates compact JWTs using a caller-supplied signer and
+/// verifier.
+///
+/// ```
+/// use rustboot_security::{Claims, JwtAlgorithm, JwtAuthenticator, SecurityError};
+/// use rustboot_security::spi::{JwsSigner, JwsVerifier};
+///
+/// struct FixedSecret(&'static [u8]);
+/// impl JwsSigner for FixedSecret {
+///     fn algorithm(&self) -> JwtAlgorithm { JwtAlgorithm::Hs256 }
+///     fn sign(&self, input: &[u8]) -> Result<Vec<u8>, SecurityError> {
+///         Ok(input.iter().zip(self.0.iter().cycle()).map(|(a, b)| a ^ b).collect())
+///     }
+/// }
+/// impl JwsVerifier for FixedSecret {
+///     fn verify(&self, _alg: JwtAlgorithm, _kid: Option<&str>, input: &[u8], sig: &[u8]) -> Result<bool, SecurityError> {
+///         Ok(self.sign(input)? == sig)
+///     }
+/// }
+///
+/// let authenticator = JwtAuthenticator::new(FixedSecret(b"secret"), FixedSecret(b"secret"), Default::default());
+/// let token = authenticator.issue(&Claims { sub: Some("alice".into()), ..Default::default() }).unwrap();
+/// let claims = authenticator.validate(&token).unwrap();
+/// assert_eq!(claims.sub.as_deref(), Some("alice"));
+/// ```
+pub struct JwtAuthenticator<S, V> {
+    signer: S,
+    verifier: V,
+    options: ValidationOptions,
+}
+
+impl<S: JwsSigner, V: JwsVerifier> JwtAuthenticator<S, V> {
+    /// Creates an authenticator that signs with `signer`, verifies with
+    /// `verifier`, and enforces `options` on every validated token.
+    pub fn new(signer: S, verifier: V, options: ValidationOptions) -> Self {
+        Self {
+            signer,
+            verifier,
+            options,
+        }
+    }
+
+    /// Signs `claims` into a compact JWT.
+    pub fn issue(&self, claims: &Claims) -> Result<String, SecurityError> {
+        let header = JwtHeader {
+            alg: self.signer.algorithm().header_name().to_string(),
+            typ: "JWT".to_string(),
+            kid: self.signer.key_id().map(str::to_string),
+        };
+        let header_segment =
+            codec::encode(&serde_json::to_vec(&header).map_err(|_| SecurityError::Malformed)?);
+        let payload_segment =
+            codec::encode(&serde_json::to_vec(claims).map_err(|_| SecurityError::Malformed)?);
+        let signing_input = format!("{header_segment}.{payload_segment}");
+
+        let signature = self.signer.sign(signing_input.as_bytes())?;
+        let signature_segment = codec::encode(&signature);
+
+        Ok(format!("{signing_input}.{signature_segment}"))
+    }
+
+    /// Verifies `token`'s signature and claims, returning the claims on
+    /// success.
+    pub fn validate(&self, token: &str) -> Result<Claims, SecurityError> {
+        let mut parts = token.split('.');
+        let (Some(header_segment), Some(payload_segment), Some(signature_segment)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(SecurityError::Malformed);
+        };
+        if parts.next().is_some() {
+            return Err(SecurityError::Malformed);
+        }
+
+        let header_bytes = codec::decode(header_segment).ok_or(SecurityError::Malformed)?;
+        let header: JwtHeader =
+            serde_json::from_slice(&header_bytes).map_err(|_| SecurityError::Malformed)?;
+        let algorithm = JwtAlgorithm::from_header_name(&header.alg)
+            .ok_or(SecurityError::UnsupportedAlgorithm(header.alg))?;
+
+        let signature = codec::decode(signature_segment).ok_or(SecurityError::Malformed)?;
+        let signing_input = format!("{header_segment}.{payload_segment}");
+
+        let verified = self.verifier.verify(
+            algorithm,
+            header.kid.as_deref(),
+            signing_input.as_bytes(),
+            &signature,
+        )?;
+        if !verified {
+            return Err(SecurityError::SignatureInvalid);
+        }
+
+        let payload_bytes = codec::decode(payload_segment).ok_or(SecurityError::Malformed)?;
+        let claims: Claims =
+            serde_json::from_slice(&payload_bytes).map_err(|_| SecurityError::Malformed)?;
+
+        self.check_claims(&claims)?;
+        Ok(claims)
+    }
+
+    fn check_claims(&self, claims: &Claims) -> Result<(), SecurityError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let leeway = self.options.leeway.as_secs();
+
+        if let Some(exp) = claims.exp {
+            if now.saturating_sub(leeway) >= exp {
+                return Err(SecurityError::Expired);
+            }
+        }
+        if let Some(nbf) = claims.nbf {
+            if now + leeway < nbf {
+                return Err(SecurityError::NotYetValid);
+            }
+        }
+        if let Some(expected) = &self.options.expected_issuer {
+            match &claims.iss {
+                Some(actual) if actual == expected => {}
+                actual => {
+                    return Err(SecurityError::IssuerMismatch {
+                        expected: expected.clone(),
+                        actual: actual.clone().unwrap_or_default(),
+                    })
+                }
+            }
+        }
+        if let Some(expected) = &self.options.expected_audience {
+            match &claims.aud {
+                Some(actual) if actual == expected => {}
+                actual => {
+                    return Err(SecurityError::AudienceMismatch {
+                        expected: expected.clone(),
+                        actual: actual.clone().unwrap_or_default(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    struct FixedSecret(&'static [u8]);
+
+    impl JwsSigner for FixedSecret {
+        fn algorithm(&self) -> JwtAlgorithm {
+            JwtAlgorithm::Hs256
+        }
+
+        fn key_id(&self) -> Option<&str> {
+            Some("test-key")
+        }
+
+        fn sign(&self, input: &[u8]) -> Result<Vec<u8>, SecurityError> {
+            Ok(input
+                .iter()
+                .zip(self.0.iter().cycle())
+                .map(|(a, b)| a ^ b)
+                .collect())
+        }
+    }
+
+    impl JwsVerifier for FixedSecret {
+        fn verify(
+            &self,
+            _algorithm: JwtAlgorithm,
+            _key_id: Option<&str>,
+            signing_input: &[u8],
+            signature: &[u8],
+        ) -> Result<bool, SecurityError> {
+            Ok(self.sign(signing_input)? == signature)
+        }
+    }
+
+    fn authenticator() -> JwtAuthenticator<FixedSecret, FixedSecret> {
+        JwtAuthenticator::new(FixedSecret(b"secret"), FixedSecret(b"secret"), Default::default())
+    }
+
+    fn claims_expiring_in(seconds: i64) -> Claims {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        Claims {
+            sub: Some("alice".to_string()),
+            exp: Some((now + seconds) as u64),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn round_trips_claims_through_issue_and_validate() {
+        let auth = authenticator();
+        let token = auth.issue(&claims_expiring_in(60)).unwrap();
+        let claims = auth.validate(&token).unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let auth = authenticator();
+        let token = auth.issue(&claims_expiring_in(60)).unwrap();
+        let mut segments: Vec<&str> = token.split('.').collect();
+        let tampered_payload = codec::encode(br#"{"sub":"mallory"}"#);
+        segments[1] = &tampered_payload;
+        let tampered = segments.join(".");
+
+        assert_eq!(auth.validate(&tampered), Err(SecurityError::SignatureInvalid));
+    }
+
+    #[test]
+    fn rejects_malformed_tokens() {
+        let auth = authenticator();
+        assert_eq!(auth.validate("not-a-jwt"), Err(SecurityError::Malformed));
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        let auth = authenticator();
+        let token = auth.issue(&claims_expiring_in(-60)).unwrap();
+        assert_eq!(auth.validate(&token), Err(SecurityError::Expired));
+    }
+
+    #[test]
+    fn leeway_tolerates_small_clock_skew_past_expiry() {
+        let options = ValidationOptions {
+            leeway: Duration::from_secs(120),
+            ..Default::default()
+        };
+        let auth = JwtAuthenticator::new(FixedSecret(b"secret"), FixedSecret(b"secret"), options);
+        let token = auth.issue(&claims_expiring_in(-60)).unwrap();
+        assert!(auth.validate(&token).is_ok());
+    }
+
+    #[test]
+    fn rejects_issuer_mismatch() {
+        let options = ValidationOptions {
+            expected_issuer: Some("https://issuer.example".to_string()),
+            ..Default::default()
+        };
+        let auth = JwtAuthenticator::new(FixedSecret(b"secret"), FixedSecret(b"secret"), options);
+        let token = auth.issue(&claims_expiring_in(60)).unwrap();
+
+        assert_eq!(
+            auth.validate(&token),
+            Err(SecurityError::IssuerMismatch {
+                expected: "https://issuer.example".to_string(),
+                actual: String::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_matching_audience() {
+        let options = ValidationOptions {
+            expected_audience: Some("my-api".to_string()),
+            ..Default::default()
+        };
+        let auth = JwtAuthenticator::new(FixedSecret(b"secret"), FixedSecret(b"secret"), options);
+        let mut claims = claims_expiring_in(60);
+        claims.aud = Some("my-api".to_string());
+        let token = auth.issue(&claims).unwrap();
+
+        assert_eq!(auth.validate(&token).unwrap().aud.as_deref(), Some("my-api"));
+    }
+}
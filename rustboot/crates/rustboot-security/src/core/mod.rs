@@ -0,0 +1,8 @@
+//! Implementation details for the security module.
+
+mod codec;
+pub mod audit;
+pub mod es256;
+pub mod hs256;
+pub mod jwks;
+pub mod jwt;
@@ -0,0 +1,33 @@
+//! JWT authentication for the rustboot framework.
+//!
+//! - [`JwtAuthenticator`]: issues and validates compact JWTs, checking
+//!   `exp`/`nbf`/`iss`/`aud` per [`ValidationOptions`].
+//! - [`spi::JwsSigner`] / [`spi::JwsVerifier`]: the pluggable signing
+//!   and verification backends. [`core::hs256::HmacKey`] and
+//!   [`core::es256::EcdsaSigner`]/[`core::es256::EcdsaVerifier`] are
+//!   concrete implementations over [`rustboot_crypto`], covering HS256
+//!   and ES256 without a caller needing to write their own crypto glue;
+//!   RS256 has no implementation in this crate yet, since
+//!   rustboot-crypto doesn't vendor an RSA library — bring your own
+//!   [`spi::JwsSigner`]/[`spi::JwsVerifier`] for it in the meantime.
+//! - [`core::jwks::JwksCache`]: fetches and time-caches a JWKS document
+//!   from an issuer, for verifiers that check against published keys.
+//! - [`core::audit`]: tamper-evident audit logging over a pluggable
+//!   [`spi::AuditSink`], with the [`macro@audit`] attribute macro to
+//!   emit events from ordinary `async fn`s.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{
+    AuditError, AuditEvent, AuditOutcome, AuditRecord, Claims, Jwk, Jwks, JwtAlgorithm,
+    SecurityError, ValidationOptions,
+};
+pub use core::audit::{install_global_sink, verify_chain, AuditChain, AuditLogger, FileAuditSink};
+pub use core::es256::{EcdsaSigner, EcdsaVerifier};
+pub use core::hs256::HmacKey;
+pub use core::jwks::{find_key, JwksCache};
+pub use core::jwt::JwtAuthenticator;
+pub use rustboot_security_derive::audit;
+pub use spi::{AuditSink, JwksProvider, JwsSigner, JwsVerifier};
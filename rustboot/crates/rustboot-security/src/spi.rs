@@ -0,0 +1,66 @@
+//! Service provider interfaces for the security module.
+//!
+//! Implement [`JwsSigner`] and [`JwsVerifier`] to plug in a concrete
+//! cryptographic backend (a local HMAC secret, an RSA/EC keypair, an
+//! HSM, ...) — or use [`crate::core::hs256::HmacKey`] /
+//! [`crate::core::es256::EcdsaSigner`] and
+//! [`crate::core::es256::EcdsaVerifier`] for HS256/ES256 over a local
+//! key — and [`JwksProvider`] to fetch a JWKS document from an issuer's
+//! `jwks_uri`.
+
+use async_trait::async_trait;
+
+use crate::api::{AuditError, AuditRecord, Jwks, JwtAlgorithm, SecurityError};
+
+/// Produces a JWS signature over a signing input (`base64url(header) +
+/// "." + base64url(payload)`).
+pub trait JwsSigner: Send + Sync {
+    /// The algorithm this signer produces signatures for.
+    fn algorithm(&self) -> JwtAlgorithm;
+
+    /// The key ID to record in the `kid` header, if any.
+    fn key_id(&self) -> Option<&str> {
+        None
+    }
+
+    /// Signs `signing_input`, returning the raw signature bytes.
+    fn sign(&self, signing_input: &[u8]) -> Result<Vec<u8>, SecurityError>;
+}
+
+/// Verifies a JWS signature over a signing input.
+///
+/// Implement this to check a signature against a local secret, an
+/// RSA/EC public key, or a key looked up (e.g. by `kid`) in a JWKS
+/// fetched via [`JwksProvider`].
+pub trait JwsVerifier: Send + Sync {
+    /// Verifies `signature` over `signing_input` for `algorithm`,
+    /// optionally keyed by `key_id` (from the token's `kid` header).
+    fn verify(
+        &self,
+        algorithm: JwtAlgorithm,
+        key_id: Option<&str>,
+        signing_input: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, SecurityError>;
+}
+
+/// Fetches a JSON Web Key Set from an identity provider.
+///
+/// Implement this with whatever HTTP client the application already
+/// uses; rustboot-security stays client-agnostic.
+#[async_trait]
+pub trait JwksProvider: Send + Sync {
+    /// Fetches the JWKS document published by `issuer`.
+    async fn fetch_jwks(&self, issuer: &str) -> Result<Jwks, SecurityError>;
+}
+
+/// Durably stores sealed [`AuditRecord`]s, in order.
+///
+/// Implement this for a file, a database table, or a messaging topic;
+/// [`crate::core::audit::FileAuditSink`] is the one concrete
+/// implementation this crate ships, since it needs no client library.
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    /// Appends `record` to the sink.
+    async fn append(&self, record: &AuditRecord) -> Result<(), AuditError>;
+}
@@ -0,0 +1,48 @@
+//! Public types for the serialization module.
+
+/// Errors produced while reading or writing a supported data format.
+#[derive(Debug, thiserror::Error)]
+pub enum SerializationError {
+    /// One line of an NDJSON document failed to parse or (de)serialize
+    /// as JSON.
+    #[error("invalid NDJSON on line {line}: {source}")]
+    InvalidLine {
+        /// 1-indexed line number within the document.
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// A value failed to (de)serialize as JSON outside the per-line
+    /// NDJSON context (e.g. while canonicalizing).
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    /// A JSON Schema document passed to
+    /// [`crate::core::schema::JsonSchema::compile`] was itself invalid.
+    #[error("invalid JSON Schema: {0}")]
+    SchemaCompile(String),
+    /// An instance failed to satisfy a [`crate::core::schema::JsonSchema`].
+    #[error("schema validation failed: {0}")]
+    SchemaViolation(String),
+    /// A value failed to (de)serialize as CBOR.
+    #[error("CBOR error: {0}")]
+    Cbor(String),
+    /// A byte slice passed to
+    /// [`crate::core::protobuf_lite::ProtoMessage::decode`] wasn't a
+    /// well-formed protobuf wire-format message.
+    #[error("invalid protobuf wire format: {0}")]
+    Protobuf(String),
+    /// A row failed to parse, coerce, or deserialize while reading a
+    /// CSV document with [`crate::core::csv::from_csv`].
+    #[error("invalid CSV: {0}")]
+    Csv(String),
+    /// A value failed to (de)serialize as YAML.
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    /// A value failed to (de)serialize as TOML, or wasn't a table at the
+    /// top level (TOML has no non-table document root).
+    #[error("TOML error: {0}")]
+    Toml(String),
+    /// A value failed to (de)serialize as MessagePack.
+    #[error("MessagePack error: {0}")]
+    MessagePack(String),
+}
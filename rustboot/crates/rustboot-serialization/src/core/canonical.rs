@@ -0,0 +1,79 @@
+//! Canonical JSON: a byte-stable serialization suitable for hashing,
+//! signing, or diffing, where two equal values must always produce the
+//! same bytes regardless of source key order or whitespace.
+//!
+//! Object keys are sorted lexicographically by relying on
+//! `serde_json::Map`'s `BTreeMap` backing (this workspace never enables
+//! `serde_json`'s `preserve_order` feature), and the output has no
+//! insignificant whitespace. Number formatting otherwise follows
+//! `serde_json`'s own rules, which is stable for a given input but does
+//! not renormalize every equivalent representation (`1` and `1.0` stay
+//! distinct, as an integer and a float).
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::SerializationError;
+
+/// Serializes `value` to its canonical JSON form: sorted object keys,
+/// no insignificant whitespace.
+///
+/// ```
+/// use rustboot_serialization::to_canonical_json;
+/// use serde_json::json;
+///
+/// let value = json!({"b": 1, "a": 2});
+/// assert_eq!(to_canonical_json(&value).unwrap(), r#"{"a":2,"b":1}"#);
+/// ```
+pub fn to_canonical_json<T: Serialize>(value: &T) -> Result<String, SerializationError> {
+    let value = serde_json::to_value(value)?;
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Parses `input` as JSON and re-serializes it in canonical form,
+/// regardless of the key order or whitespace it was written with.
+///
+/// ```
+/// use rustboot_serialization::canonicalize_json;
+///
+/// let canonical = canonicalize_json(r#"{ "b" : 1 , "a" : 2 }"#).unwrap();
+/// assert_eq!(canonical, r#"{"a":2,"b":1}"#);
+/// ```
+pub fn canonicalize_json(input: &str) -> Result<String, SerializationError> {
+    let value: Value = serde_json::from_str(input)?;
+    to_canonical_json(&value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sorts_top_level_keys() {
+        assert_eq!(to_canonical_json(&json!({"z": 1, "a": 2})).unwrap(), r#"{"a":2,"z":1}"#);
+    }
+
+    #[test]
+    fn sorts_nested_object_keys() {
+        let value = json!({"outer": {"z": 1, "a": 2}});
+        assert_eq!(to_canonical_json(&value).unwrap(), r#"{"outer":{"a":2,"z":1}}"#);
+    }
+
+    #[test]
+    fn preserves_array_order() {
+        assert_eq!(to_canonical_json(&json!([3, 1, 2])).unwrap(), "[3,1,2]");
+    }
+
+    #[test]
+    fn canonicalize_json_ignores_source_key_order_and_whitespace() {
+        let a = canonicalize_json(r#"{"a": 1, "b": 2}"#).unwrap();
+        let b = canonicalize_json(r#"{ "b" :2,"a":1 }"#).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn canonicalize_json_rejects_malformed_input() {
+        assert!(canonicalize_json("{not json}").is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! CBOR (Concise Binary Object Representation) reading and writing,
+//! delegated to `ciborium` — the same "don't reinvent a w3c grammar"
+//! trade-off as [`crate::core::schema`]'s `jsonschema` dependency, only
+//! here the grammar is an IETF binary format (RFC 8949) instead of a
+//! w3c one.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::api::SerializationError;
+
+/// Serializes `value` to CBOR bytes.
+///
+/// ```
+/// use rustboot_serialization::to_cbor;
+///
+/// let bytes = to_cbor(&42).unwrap();
+/// assert_eq!(bytes, vec![0x18, 0x2a]);
+/// ```
+pub fn to_cbor<T: Serialize>(value: &T) -> Result<Vec<u8>, SerializationError> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).map_err(|err| SerializationError::Cbor(err.to_string()))?;
+    Ok(bytes)
+}
+
+/// Deserializes `bytes` from CBOR into a `T`.
+///
+/// ```
+/// use rustboot_serialization::{from_cbor, to_cbor};
+///
+/// let bytes = to_cbor(&vec!["a", "b"]).unwrap();
+/// let items: Vec<String> = from_cbor(&bytes).unwrap();
+/// assert_eq!(items, vec!["a".to_string(), "b".to_string()]);
+/// ```
+pub fn from_cbor<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, SerializationError> {
+    ciborium::from_reader(bytes).map_err(|err| SerializationError::Cbor(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Event {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn round_trips_a_struct() {
+        let event = Event { id: 7, name: "created".to_string() };
+        let bytes = to_cbor(&event).unwrap();
+        assert_eq!(from_cbor::<Event>(&bytes).unwrap(), event);
+    }
+
+    #[test]
+    fn from_cbor_rejects_truncated_input() {
+        assert!(from_cbor::<Event>(&[0xa2, 0x62]).is_err());
+    }
+}
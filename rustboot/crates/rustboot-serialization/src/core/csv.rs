@@ -0,0 +1,205 @@
+//! CSV reading with header-to-field remapping and per-column type
+//! coercion, on top of the `csv` crate's row parsing.
+//!
+//! `csv`'s own `serde` integration matches columns to struct fields by
+//! position, not by header name, and treats every cell as the string
+//! serde expects it to deserialize as. Neither fits a CSV export whose
+//! headers don't match the target struct's field names, or whose cells
+//! ("1", "true") need coercing into the JSON types `serde` expects
+//! before deserializing — so this builds a `serde_json::Value` per row
+//! with both applied, then hands that to `serde_json`, matching
+//! [`crate::core::schema`]'s "validate/coerce the JSON, then
+//! deserialize" split.
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::api::SerializationError;
+
+/// The JSON type a column's cells should be coerced into before
+/// deserializing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Left as a JSON string (the default for unmapped columns).
+    String,
+    /// Parsed as a JSON integer.
+    Integer,
+    /// Parsed as a JSON float.
+    Float,
+    /// Parsed as a JSON boolean (`"true"`/`"false"`, case-insensitive,
+    /// or `"1"`/`"0"`).
+    Boolean,
+}
+
+/// Options controlling [`from_csv`]: the field delimiter, a mapping
+/// from CSV header names to target struct field names, and per-field
+/// type coercion.
+pub struct CsvOptions {
+    delimiter: u8,
+    header_aliases: HashMap<String, String>,
+    column_types: HashMap<String, ColumnType>,
+}
+
+impl CsvOptions {
+    /// Comma-delimited, no header renaming, every column left as a
+    /// string.
+    pub fn new() -> Self {
+        Self {
+            delimiter: b',',
+            header_aliases: HashMap::new(),
+            column_types: HashMap::new(),
+        }
+    }
+
+    /// Sets the field delimiter (e.g. `b'\t'` for TSV).
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Maps a CSV header name to the target struct's field name, for
+    /// documents whose headers don't already match.
+    pub fn map_header(mut self, csv_header: impl Into<String>, field: impl Into<String>) -> Self {
+        self.header_aliases.insert(csv_header.into(), field.into());
+        self
+    }
+
+    /// Coerces `field`'s cells to `column_type` before deserializing.
+    pub fn column_type(mut self, field: impl Into<String>, column_type: ColumnType) -> Self {
+        self.column_types.insert(field.into(), column_type);
+        self
+    }
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn coerce(field: &str, raw: &str, row: usize, column_type: ColumnType) -> Result<Value, SerializationError> {
+    let malformed = |expected: &str| {
+        SerializationError::Csv(format!("row {row}, column '{field}': '{raw}' is not a valid {expected}"))
+    };
+    match column_type {
+        ColumnType::String => Ok(Value::String(raw.to_string())),
+        ColumnType::Integer => raw.parse::<i64>().map(Value::from).map_err(|_| malformed("integer")),
+        ColumnType::Float => raw.parse::<f64>().map(Value::from).map_err(|_| malformed("float")),
+        ColumnType::Boolean => match raw.to_ascii_lowercase().as_str() {
+            "true" | "1" => Ok(Value::Bool(true)),
+            "false" | "0" => Ok(Value::Bool(false)),
+            _ => Err(malformed("boolean")),
+        },
+    }
+}
+
+/// Parses `input` as CSV, remapping headers and coercing column types
+/// per `options`, then deserializes each row into a `T`. Fails on the
+/// first malformed row or cell.
+///
+/// ```
+/// use rustboot_serialization::{from_csv, ColumnType, CsvOptions};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct User { name: String, age: u32 }
+///
+/// let options = CsvOptions::new()
+///     .map_header("full_name", "name")
+///     .column_type("age", ColumnType::Integer);
+/// let users: Vec<User> = from_csv("full_name,age\nAda,30\n", &options).unwrap();
+/// assert_eq!(users[0].name, "Ada");
+/// assert_eq!(users[0].age, 30);
+/// ```
+pub fn from_csv<T: DeserializeOwned>(input: &str, options: &CsvOptions) -> Result<Vec<T>, SerializationError> {
+    let mut reader = ::csv::ReaderBuilder::new().delimiter(options.delimiter).from_reader(input.as_bytes());
+
+    let fields: Vec<String> = reader
+        .headers()
+        .map_err(|err| SerializationError::Csv(format!("failed to read header row: {err}")))?
+        .iter()
+        .map(|header| options.header_aliases.get(header).cloned().unwrap_or_else(|| header.to_string()))
+        .collect();
+
+    let mut rows = Vec::new();
+    for (row_index, record) in reader.records().enumerate() {
+        let row = row_index + 2; // 1-indexed, plus the header row
+        let record = record.map_err(|err| SerializationError::Csv(format!("row {row}: {err}")))?;
+
+        let mut object = serde_json::Map::new();
+        for (field, raw) in fields.iter().zip(record.iter()) {
+            let column_type = options.column_types.get(field).copied().unwrap_or(ColumnType::String);
+            object.insert(field.clone(), coerce(field, raw, row, column_type)?);
+        }
+
+        let value = serde_json::from_value(Value::Object(object))
+            .map_err(|err| SerializationError::Csv(format!("row {row}: {err}")))?;
+        rows.push(value);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct User {
+        name: String,
+        age: u32,
+        active: bool,
+    }
+
+    #[test]
+    fn from_csv_maps_headers_and_coerces_types() {
+        let input = "full_name,years,is_active\nAda,30,true\nGrace,85,false\n";
+        let options = CsvOptions::new()
+            .map_header("full_name", "name")
+            .map_header("years", "age")
+            .map_header("is_active", "active")
+            .column_type("age", ColumnType::Integer)
+            .column_type("active", ColumnType::Boolean);
+
+        let users: Vec<User> = from_csv(input, &options).unwrap();
+        assert_eq!(
+            users,
+            vec![
+                User { name: "Ada".to_string(), age: 30, active: true },
+                User { name: "Grace".to_string(), age: 85, active: false },
+            ]
+        );
+    }
+
+    #[test]
+    fn from_csv_uses_original_headers_without_mapping() {
+        let input = "name,age,active\nAda,30,1\n";
+        let options = CsvOptions::new().column_type("age", ColumnType::Integer).column_type("active", ColumnType::Boolean);
+        let users: Vec<User> = from_csv(input, &options).unwrap();
+        assert_eq!(users[0], User { name: "Ada".to_string(), age: 30, active: true });
+    }
+
+    #[test]
+    fn from_csv_reports_the_row_and_column_of_a_coercion_failure() {
+        let input = "name,age,active\nAda,not-a-number,true\n";
+        let options = CsvOptions::new().column_type("age", ColumnType::Integer).column_type("active", ColumnType::Boolean);
+        let err = from_csv::<User>(input, &options).unwrap_err();
+        let SerializationError::Csv(message) = err else { panic!("expected a Csv error") };
+        assert!(message.contains("row 2"));
+        assert!(message.contains("age"));
+    }
+
+    #[test]
+    fn from_csv_supports_a_custom_delimiter() {
+        let input = "name\tage\tactive\nAda\t30\ttrue\n";
+        let options = CsvOptions::new()
+            .delimiter(b'\t')
+            .column_type("age", ColumnType::Integer)
+            .column_type("active", ColumnType::Boolean);
+        let users: Vec<User> = from_csv(input, &options).unwrap();
+        assert_eq!(users[0].age, 30);
+    }
+}
@@ -0,0 +1,10 @@
+//! Implementation details for the serialization module.
+
+pub mod canonical;
+pub mod cbor;
+pub mod csv;
+pub mod ndjson;
+pub mod protobuf_lite;
+pub mod schema;
+pub mod transcode;
+pub mod yaml;
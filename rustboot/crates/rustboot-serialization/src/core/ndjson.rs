@@ -0,0 +1,111 @@
+//! NDJSON (newline-delimited JSON, a.k.a. JSON Lines): one JSON value
+//! per line, for streaming or appending records without holding a whole
+//! array in memory.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::api::SerializationError;
+
+/// Serializes `items` as an NDJSON document: one compact JSON value
+/// per line, each terminated by `\n`.
+///
+/// ```
+/// use rustboot_serialization::to_ndjson;
+///
+/// let doc = to_ndjson(&[1, 2, 3]).unwrap();
+/// assert_eq!(doc, "1\n2\n3\n");
+/// ```
+pub fn to_ndjson<T: Serialize>(items: &[T]) -> Result<String, SerializationError> {
+    let mut doc = String::new();
+    for (i, item) in items.iter().enumerate() {
+        let line = serde_json::to_string(item).map_err(|source| SerializationError::InvalidLine { line: i + 1, source })?;
+        doc.push_str(&line);
+        doc.push('\n');
+    }
+    Ok(doc)
+}
+
+/// Parses every line of `input` as a `T`, failing on the first
+/// malformed line.
+///
+/// ```
+/// use rustboot_serialization::from_ndjson;
+///
+/// let items: Vec<i32> = from_ndjson("1\n2\n3\n").unwrap();
+/// assert_eq!(items, vec![1, 2, 3]);
+/// ```
+pub fn from_ndjson<T: DeserializeOwned>(input: &str) -> Result<Vec<T>, SerializationError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|source| SerializationError::InvalidLine { line: i + 1, source })
+        })
+        .collect()
+}
+
+/// Parses every line of `input` as a `T`, reporting each line's result
+/// independently so one malformed line doesn't discard the rest of the
+/// document.
+///
+/// ```
+/// use rustboot_serialization::read_ndjson_lenient;
+///
+/// let results: Vec<Result<i32, _>> = read_ndjson_lenient("1\nnot json\n3\n");
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// assert!(results[2].is_ok());
+/// ```
+pub fn read_ndjson_lenient<T: DeserializeOwned>(input: &str) -> Vec<Result<T, SerializationError>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|source| SerializationError::InvalidLine { line: i + 1, source })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Event {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn to_ndjson_writes_one_compact_line_per_item() {
+        let events = vec![Event { id: 1, name: "a".to_string() }, Event { id: 2, name: "b".to_string() }];
+        let doc = to_ndjson(&events).unwrap();
+        assert_eq!(doc, "{\"id\":1,\"name\":\"a\"}\n{\"id\":2,\"name\":\"b\"}\n");
+    }
+
+    #[test]
+    fn from_ndjson_round_trips_and_skips_blank_lines() {
+        let doc = "{\"id\":1,\"name\":\"a\"}\n\n{\"id\":2,\"name\":\"b\"}\n";
+        let events: Vec<Event> = from_ndjson(doc).unwrap();
+        assert_eq!(events, vec![Event { id: 1, name: "a".to_string() }, Event { id: 2, name: "b".to_string() }]);
+    }
+
+    #[test]
+    fn from_ndjson_fails_fast_on_a_malformed_line() {
+        let err = from_ndjson::<Event>("{\"id\":1,\"name\":\"a\"}\nnot json\n").unwrap_err();
+        assert!(matches!(err, SerializationError::InvalidLine { line: 2, .. }));
+    }
+
+    #[test]
+    fn read_ndjson_lenient_reports_every_lines_result() {
+        let results: Vec<Result<i32, _>> = read_ndjson_lenient("1\nnope\n3\n");
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert_eq!(*results[2].as_ref().unwrap(), 3);
+    }
+}
@@ -0,0 +1,221 @@
+//! A minimal protobuf wire-format (varint and length-delimited fields
+//! only) encoder/decoder, for services that need wire compatibility
+//! with a protobuf message without pulling in a `.proto` compiler and
+//! codegen pipeline.
+//!
+//! Deliberately "lite": no `.proto` schema, no generated types, and no
+//! support for the fixed32/fixed64 wire types, packed repeated fields,
+//! or nested message validation. [`ProtoMessage`] is a bag of field
+//! numbers to repeated raw values — callers interpret field numbers
+//! and nesting themselves.
+
+use std::collections::BTreeMap;
+
+use crate::api::SerializationError;
+
+/// One decoded protobuf field value, per the two wire types this
+/// module understands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtoValue {
+    /// Wire type 0: a variable-length integer.
+    Varint(u64),
+    /// Wire type 2: a length-delimited byte string (also used for
+    /// strings and embedded messages, which callers decode via a
+    /// nested [`ProtoMessage::decode`]).
+    Bytes(Vec<u8>),
+}
+
+/// A protobuf message as a field-number-keyed bag of values, preserving
+/// repeated fields as multiple values under the same number.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProtoMessage {
+    fields: BTreeMap<u32, Vec<ProtoValue>>,
+}
+
+impl ProtoMessage {
+    /// An empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a varint value under `field`.
+    pub fn push_varint(mut self, field: u32, value: u64) -> Self {
+        self.fields.entry(field).or_default().push(ProtoValue::Varint(value));
+        self
+    }
+
+    /// Appends a length-delimited value (bytes, a string, or an
+    /// embedded message's encoded bytes) under `field`.
+    pub fn push_bytes(mut self, field: u32, value: impl Into<Vec<u8>>) -> Self {
+        self.fields.entry(field).or_default().push(ProtoValue::Bytes(value.into()));
+        self
+    }
+
+    /// The first varint value under `field`, if any and if it's the
+    /// right wire type.
+    pub fn varint(&self, field: u32) -> Option<u64> {
+        self.fields.get(&field)?.iter().find_map(|value| match value {
+            ProtoValue::Varint(v) => Some(*v),
+            ProtoValue::Bytes(_) => None,
+        })
+    }
+
+    /// The first length-delimited value under `field`, if any and if
+    /// it's the right wire type.
+    pub fn bytes(&self, field: u32) -> Option<&[u8]> {
+        self.fields.get(&field)?.iter().find_map(|value| match value {
+            ProtoValue::Bytes(b) => Some(b.as_slice()),
+            ProtoValue::Varint(_) => None,
+        })
+    }
+
+    /// Every value recorded under `field`, in encounter order — for
+    /// repeated fields.
+    pub fn values(&self, field: u32) -> &[ProtoValue] {
+        self.fields.get(&field).map_or(&[], Vec::as_slice)
+    }
+
+    /// Encodes the message to its protobuf wire-format bytes.
+    ///
+    /// ```
+    /// use rustboot_serialization::ProtoMessage;
+    ///
+    /// let message = ProtoMessage::new().push_varint(1, 150).push_bytes(2, "hi");
+    /// let bytes = message.encode();
+    /// assert_eq!(ProtoMessage::decode(&bytes).unwrap(), message);
+    /// ```
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (&field, values) in &self.fields {
+            for value in values {
+                match value {
+                    ProtoValue::Varint(v) => {
+                        encode_varint(u64::from(field) << 3, &mut bytes);
+                        encode_varint(*v, &mut bytes);
+                    }
+                    ProtoValue::Bytes(v) => {
+                        encode_varint((u64::from(field) << 3) | 2, &mut bytes);
+                        encode_varint(v.len() as u64, &mut bytes);
+                        bytes.extend_from_slice(v);
+                    }
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a protobuf wire-format message. Rejects every wire type
+    /// other than 0 and 2 (including fixed64 (1) and fixed32 (5)), since
+    /// this module only understands varint and length-delimited fields.
+    pub fn decode(bytes: &[u8]) -> Result<Self, SerializationError> {
+        let mut message = Self::new();
+        let mut cursor = 0;
+
+        while cursor < bytes.len() {
+            let (key, next) = decode_varint(bytes, cursor)?;
+            cursor = next;
+            let field = (key >> 3) as u32;
+            let wire_type = key & 0x7;
+
+            match wire_type {
+                0 => {
+                    let (value, next) = decode_varint(bytes, cursor)?;
+                    cursor = next;
+                    message = message.push_varint(field, value);
+                }
+                2 => {
+                    let (len, next) = decode_varint(bytes, cursor)?;
+                    let len = len as usize;
+                    let end = next.checked_add(len).filter(|&e| e <= bytes.len()).ok_or_else(|| {
+                        SerializationError::Protobuf(format!("length-delimited field {field} runs past the end"))
+                    })?;
+                    message = message.push_bytes(field, bytes[next..end].to_vec());
+                    cursor = end;
+                }
+                other => {
+                    return Err(SerializationError::Protobuf(format!(
+                        "unsupported wire type {other} on field {field}"
+                    )))
+                }
+            }
+        }
+
+        Ok(message)
+    }
+}
+
+fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn decode_varint(bytes: &[u8], start: usize) -> Result<(u64, usize), SerializationError> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    let mut cursor = start;
+
+    loop {
+        let byte = *bytes
+            .get(cursor)
+            .ok_or_else(|| SerializationError::Protobuf("truncated varint".to_string()))?;
+        value |= u64::from(byte & 0x7f) << shift;
+        cursor += 1;
+        if byte & 0x80 == 0 {
+            return Ok((value, cursor));
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err(SerializationError::Protobuf("varint too long".to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_a_single_varint_field() {
+        let message = ProtoMessage::new().push_varint(1, 150);
+        assert_eq!(ProtoMessage::decode(&message.encode()).unwrap(), message);
+    }
+
+    #[test]
+    fn round_trips_bytes_and_repeated_fields() {
+        let message = ProtoMessage::new().push_bytes(2, "hi").push_varint(3, 1).push_varint(3, 2);
+        let decoded = ProtoMessage::decode(&message.encode()).unwrap();
+        assert_eq!(decoded.bytes(2), Some(b"hi".as_slice()));
+        assert_eq!(decoded.values(3).len(), 2);
+    }
+
+    #[test]
+    fn varint_encoding_matches_the_protobuf_spec_example() {
+        let message = ProtoMessage::new().push_varint(1, 150);
+        assert_eq!(message.encode(), vec![0x08, 0x96, 0x01]);
+    }
+
+    #[test]
+    fn decode_rejects_a_fixed64_wire_type() {
+        assert!(ProtoMessage::decode(&[0x09]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_a_truncated_length_delimited_field() {
+        assert!(ProtoMessage::decode(&[0x12, 0x05, 0x01]).is_err());
+    }
+
+    #[test]
+    fn missing_field_accessors_return_none_or_empty() {
+        let message = ProtoMessage::new();
+        assert_eq!(message.varint(1), None);
+        assert_eq!(message.bytes(1), None);
+        assert!(message.values(1).is_empty());
+    }
+}
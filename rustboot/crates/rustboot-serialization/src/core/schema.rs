@@ -0,0 +1,138 @@
+//! Schema-validated deserialization: compiles a JSON Schema document
+//! once, then validates arbitrary JSON values against it before (or
+//! instead of) deserializing them into a typed struct.
+//!
+//! Validation is delegated to `jsonschema` rather than hand-rolled —
+//! the same "don't reinvent a w3c grammar" trade-off as
+//! [`crate::core::canonical`]'s neighbors in `rustboot-parsing`.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::api::SerializationError;
+
+/// A compiled JSON Schema, reusable across many validations without
+/// recompiling.
+pub struct JsonSchema(jsonschema::Validator);
+
+impl JsonSchema {
+    /// Compiles `schema` into a reusable validator.
+    pub fn compile(schema: &Value) -> Result<Self, SerializationError> {
+        jsonschema::Validator::new(schema)
+            .map(Self)
+            .map_err(|err| SerializationError::SchemaCompile(err.to_string()))
+    }
+
+    /// Returns whether `instance` satisfies the schema.
+    ///
+    /// ```
+    /// use rustboot_serialization::JsonSchema;
+    /// use serde_json::json;
+    ///
+    /// let schema = JsonSchema::compile(&json!({"type": "integer"})).unwrap();
+    /// assert!(schema.is_valid(&json!(1)));
+    /// assert!(!schema.is_valid(&json!("not an integer")));
+    /// ```
+    pub fn is_valid(&self, instance: &Value) -> bool {
+        self.0.is_valid(instance)
+    }
+
+    /// Validates `instance` against the schema, collecting every
+    /// violation (not just the first) into a single error.
+    pub fn validate(&self, instance: &Value) -> Result<(), SerializationError> {
+        let violations: Vec<String> = self.0.iter_errors(instance).map(|err| err.to_string()).collect();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(SerializationError::SchemaViolation(violations.join("; ")))
+        }
+    }
+}
+
+/// Validates `value` against `schema`, then deserializes it into `T`.
+/// Fails with every schema violation collected, without attempting the
+/// deserialization.
+///
+/// ```
+/// use rustboot_serialization::{from_value_validated, JsonSchema};
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Deserialize)]
+/// struct User { name: String }
+///
+/// let schema = JsonSchema::compile(&json!({
+///     "type": "object",
+///     "required": ["name"],
+///     "properties": {"name": {"type": "string"}},
+/// })).unwrap();
+///
+/// let user: User = from_value_validated(&schema, json!({"name": "Ada"})).unwrap();
+/// assert_eq!(user.name, "Ada");
+/// assert!(from_value_validated::<User>(&schema, json!({})).is_err());
+/// ```
+pub fn from_value_validated<T: DeserializeOwned>(schema: &JsonSchema, value: Value) -> Result<T, SerializationError> {
+    schema.validate(&value)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    fn user_schema() -> JsonSchema {
+        JsonSchema::compile(&json!({
+            "type": "object",
+            "required": ["name", "age"],
+            "properties": {
+                "name": {"type": "string"},
+                "age": {"type": "integer", "minimum": 0},
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn compile_rejects_a_malformed_schema() {
+        assert!(JsonSchema::compile(&json!({"type": "not-a-type"})).is_err());
+    }
+
+    #[test]
+    fn is_valid_checks_without_collecting_errors() {
+        let schema = user_schema();
+        assert!(schema.is_valid(&json!({"name": "Ada", "age": 30})));
+        assert!(!schema.is_valid(&json!({"name": "Ada"})));
+    }
+
+    #[test]
+    fn validate_collects_every_violation() {
+        let schema = user_schema();
+        let err = schema.validate(&json!({"age": -1})).unwrap_err();
+        let SerializationError::SchemaViolation(message) = err else {
+            panic!("expected a SchemaViolation");
+        };
+        assert!(message.contains("name"));
+        assert!(message.contains("-1"));
+    }
+
+    #[test]
+    fn from_value_validated_deserializes_a_conforming_value() {
+        let schema = user_schema();
+        let user: User = from_value_validated(&schema, json!({"name": "Ada", "age": 30})).unwrap();
+        assert_eq!(user, User { name: "Ada".to_string(), age: 30 });
+    }
+
+    #[test]
+    fn from_value_validated_rejects_before_deserializing() {
+        let schema = user_schema();
+        assert!(from_value_validated::<User>(&schema, json!({"name": "Ada", "age": -1})).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Converting between data formats through a dynamic value model,
+//! without a Rust type to deserialize into.
+//!
+//! [`crate::core::yaml`], [`crate::core::cbor`], and the rest of this
+//! module's siblings all convert between bytes and a typed `T`. Tools
+//! that just move a file from one format to another (a CLI `convert`
+//! command, a config migration) don't have a `T` — they need bytes in,
+//! bytes out, with everything in between as generic as possible.
+//! [`serde_yaml::Value`] is that generic model: unlike
+//! [`serde_json::Value`] (whose `Map` is a `BTreeMap`, since the
+//! workspace never enables `serde_json`'s `preserve_order` feature — see
+//! [`crate::core::canonical`]) it's backed by an `IndexMap`, so mapping
+//! key order survives a round trip through it.
+
+use crate::api::SerializationError;
+
+/// A data format [`transcode`] can read or write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// JSON.
+    Json,
+    /// YAML.
+    Yaml,
+    /// TOML. Only tables are valid documents, so transcoding a
+    /// non-table value to TOML fails.
+    Toml,
+    /// MessagePack.
+    MessagePack,
+}
+
+fn decode(input: &[u8], format: Format) -> Result<serde_yaml::Value, SerializationError> {
+    match format {
+        Format::Json => Ok(serde_json::from_slice(input)?),
+        Format::Yaml => Ok(serde_yaml::from_slice(input)?),
+        Format::Toml => {
+            let text =
+                std::str::from_utf8(input).map_err(|err| SerializationError::Toml(err.to_string()))?;
+            toml::from_str(text).map_err(|err| SerializationError::Toml(err.to_string()))
+        }
+        Format::MessagePack => {
+            rmp_serde::from_slice(input).map_err(|err| SerializationError::MessagePack(err.to_string()))
+        }
+    }
+}
+
+fn encode(value: &serde_yaml::Value, format: Format) -> Result<Vec<u8>, SerializationError> {
+    match format {
+        Format::Json => Ok(serde_json::to_vec(value)?),
+        Format::Yaml => Ok(serde_yaml::to_string(value)?.into_bytes()),
+        Format::Toml => {
+            toml::to_string(value).map(|text| text.into_bytes()).map_err(|err| SerializationError::Toml(err.to_string()))
+        }
+        Format::MessagePack => {
+            rmp_serde::to_vec(value).map_err(|err| SerializationError::MessagePack(err.to_string()))
+        }
+    }
+}
+
+/// Converts `input` from `from` to `to`, without defining a Rust type
+/// for the data — decodes into a dynamic value model and re-encodes it,
+/// preserving mapping key order where the source and target formats
+/// both support it.
+///
+/// ```
+/// use rustboot_serialization::{transcode, Format};
+///
+/// let yaml = b"name: Ada\nrole: admin\n";
+/// let json = transcode(yaml, Format::Yaml, Format::Json).unwrap();
+/// assert_eq!(json, br#"{"name":"Ada","role":"admin"}"#);
+/// ```
+pub fn transcode(input: &[u8], from: Format, to: Format) -> Result<Vec<u8>, SerializationError> {
+    let value = decode(input, from)?;
+    encode(&value, to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcode_yaml_to_json_preserves_key_order() {
+        let yaml = b"z: 1\na: 2\n";
+        let json = transcode(yaml, Format::Yaml, Format::Json).unwrap();
+        assert_eq!(json, br#"{"z":1,"a":2}"#);
+    }
+
+    #[test]
+    fn transcode_json_to_toml_round_trips_a_table() {
+        let json = br#"{"name":"Ada","role":"admin"}"#;
+        let toml_bytes = transcode(json, Format::Json, Format::Toml).unwrap();
+        let toml_text = String::from_utf8(toml_bytes).unwrap();
+        assert!(toml_text.contains("name = \"Ada\""));
+        assert!(toml_text.contains("role = \"admin\""));
+    }
+
+    #[test]
+    fn transcode_toml_to_messagepack_and_back() {
+        let toml_text = b"name = \"Ada\"\nrole = \"admin\"\n";
+        let msgpack = transcode(toml_text, Format::Toml, Format::MessagePack).unwrap();
+        let back = transcode(&msgpack, Format::MessagePack, Format::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&back).unwrap();
+        assert_eq!(value["name"], "Ada");
+        assert_eq!(value["role"], "admin");
+    }
+
+    #[test]
+    fn transcode_rejects_a_non_table_value_as_toml() {
+        let json = b"42";
+        assert!(transcode(json, Format::Json, Format::Toml).is_err());
+    }
+
+    #[test]
+    fn transcode_rejects_malformed_input() {
+        assert!(transcode(b"{not valid", Format::Json, Format::Yaml).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+//! YAML reading with `---`-separated multi-document streams and `<<`
+//! merge-key resolution, on top of `serde_yaml`.
+//!
+//! `serde_yaml::from_str` only accepts a single document and leaves `<<`
+//! merge keys as literal mapping entries rather than merging them into
+//! the surrounding mapping, so this applies [`serde_yaml::Value::apply_merge`]
+//! before deserializing into the caller's type, and drives
+//! [`serde_yaml::Deserializer`]'s `Iterator` impl for multi-document input.
+
+use serde::de::DeserializeOwned;
+
+use crate::api::SerializationError;
+
+/// Deserializes a single YAML document as `T`, resolving any `<<` merge
+/// keys first.
+///
+/// ```
+/// use rustboot_serialization::from_yaml;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Task { command: String, args: String }
+///
+/// let input = "\
+/// build: &shared
+///   command: webpack
+///   args: build
+/// start:
+///   <<: *shared
+///   args: start
+/// ";
+/// let value: serde_yaml::Value = serde_yaml::from_str(input).unwrap();
+/// let start: Task = from_yaml(&serde_yaml::to_string(&value["start"]).unwrap()).unwrap();
+/// assert_eq!(start.command, "webpack");
+/// assert_eq!(start.args, "start");
+/// ```
+pub fn from_yaml<T: DeserializeOwned>(input: &str) -> Result<T, SerializationError> {
+    let mut value: serde_yaml::Value = serde_yaml::from_str(input)?;
+    value.apply_merge()?;
+    Ok(serde_yaml::from_value(value)?)
+}
+
+/// Deserializes a `---`-separated stream of YAML documents, resolving
+/// `<<` merge keys within each document independently.
+///
+/// ```
+/// use rustboot_serialization::from_yaml_multi;
+///
+/// let input = "name: Ada\n---\nname: Grace\n";
+/// let docs: Vec<serde_yaml::Value> = from_yaml_multi(input).unwrap();
+/// assert_eq!(docs.len(), 2);
+/// assert_eq!(docs[1]["name"], "Grace");
+/// ```
+pub fn from_yaml_multi<T: DeserializeOwned>(input: &str) -> Result<Vec<T>, SerializationError> {
+    serde_yaml::Deserializer::from_str(input)
+        .map(|document| {
+            let mut value = serde_yaml::Value::deserialize(document)?;
+            value.apply_merge()?;
+            Ok(serde_yaml::from_value(value)?)
+        })
+        .collect()
+}
+
+use serde::Deserialize as _;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Task {
+        command: String,
+        args: String,
+    }
+
+    #[test]
+    fn from_yaml_resolves_a_merge_key() {
+        let input = "\
+shared: &shared
+  command: webpack
+  args: build
+task:
+  <<: *shared
+  args: start
+";
+        let value: serde_yaml::Value = serde_yaml::from_str(input).unwrap();
+        let task_yaml = serde_yaml::to_string(&value["task"]).unwrap();
+        let task: Task = from_yaml(&task_yaml).unwrap();
+        assert_eq!(task, Task { command: "webpack".to_string(), args: "start".to_string() });
+    }
+
+    #[test]
+    fn from_yaml_multi_splits_on_document_markers() {
+        let input = "name: Ada\n---\nname: Grace\n";
+        let docs: Vec<serde_yaml::Value> = from_yaml_multi(input).unwrap();
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0]["name"], "Ada");
+        assert_eq!(docs[1]["name"], "Grace");
+    }
+
+    #[test]
+    fn from_yaml_multi_resolves_merge_keys_per_document() {
+        let input = "\
+shared: &shared
+  role: admin
+user:
+  <<: *shared
+  name: Ada
+---
+shared: &shared
+  role: guest
+user:
+  <<: *shared
+  name: Grace
+";
+        let docs: Vec<serde_yaml::Value> = from_yaml_multi(input).unwrap();
+        assert_eq!(docs[0]["user"]["role"], "admin");
+        assert_eq!(docs[1]["user"]["role"], "guest");
+    }
+
+    #[test]
+    fn from_yaml_rejects_malformed_input() {
+        let err = from_yaml::<Task>("command: [unterminated").unwrap_err();
+        assert!(matches!(err, SerializationError::Yaml(_)));
+    }
+}
@@ -0,0 +1,44 @@
+//! Data format serialization utilities for the rustboot framework.
+//!
+//! - [`to_ndjson`]/[`from_ndjson`]/[`read_ndjson_lenient`]: newline-
+//!   delimited JSON (JSON Lines) reading and writing, for streaming or
+//!   appending records without holding a whole array in memory.
+//! - [`to_canonical_json`]/[`canonicalize_json`]: byte-stable JSON
+//!   serialization (sorted keys, no insignificant whitespace) for
+//!   hashing, signing, or diffing.
+//! - [`JsonSchema`]/[`from_value_validated`]: compiles a JSON Schema
+//!   document once and validates arbitrary values against it, either
+//!   standalone or as a precondition to deserializing into a typed
+//!   struct.
+//! - [`to_cbor`]/[`from_cbor`]: CBOR (RFC 8949) reading and writing.
+//! - [`ProtoMessage`]: a schema-less protobuf wire-format encoder/
+//!   decoder (varint and length-delimited fields only) for wire
+//!   compatibility without a `.proto` codegen pipeline.
+//! - [`from_csv`]/[`CsvOptions`]: CSV reading with header-to-field
+//!   remapping and per-column type coercion ahead of deserialization.
+//! - [`from_yaml`]/[`from_yaml_multi`]: YAML reading with `<<` merge-key
+//!   resolution and support for `---`-separated multi-document streams.
+//! - [`transcode`]/[`Format`]: converts a document between JSON, YAML,
+//!   TOML, and MessagePack through a dynamic value model, for tools
+//!   that move data between formats without a Rust type to deserialize
+//!   into.
+//! - [`roundtrip_prop`] (`arbitrary` feature): asserts that arbitrary
+//!   values of a type survive a JSON/YAML/MessagePack encode/decode
+//!   round trip unchanged.
+
+pub mod api;
+pub mod core;
+#[cfg(feature = "arbitrary")]
+pub mod testing;
+
+pub use api::SerializationError;
+pub use core::canonical::{canonicalize_json, to_canonical_json};
+pub use core::cbor::{from_cbor, to_cbor};
+pub use core::csv::{from_csv, ColumnType, CsvOptions};
+pub use core::ndjson::{from_ndjson, read_ndjson_lenient, to_ndjson};
+pub use core::protobuf_lite::{ProtoMessage, ProtoValue};
+pub use core::schema::{from_value_validated, JsonSchema};
+pub use core::transcode::{transcode, Format};
+pub use core::yaml::{from_yaml, from_yaml_multi};
+#[cfg(feature = "arbitrary")]
+pub use testing::roundtrip_prop;
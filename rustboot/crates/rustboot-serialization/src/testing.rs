@@ -0,0 +1,114 @@
+//! Property-based round-trip testing, for crate users who want more
+//! confidence than a handful of hand-picked fixtures give them.
+//! Feature-gated behind `arbitrary` since it pulls in the `arbitrary`
+//! and `rand` crates, which most consumers of this crate's normal
+//! (de)serialization functions have no use for.
+
+use arbitrary::{Arbitrary, Unstructured};
+use rand::RngCore;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::api::SerializationError;
+use crate::core::transcode::Format;
+
+fn encode<T: Serialize>(value: &T, format: Format) -> Result<Vec<u8>, SerializationError> {
+    match format {
+        Format::Json => Ok(serde_json::to_vec(value)?),
+        Format::Yaml => Ok(serde_yaml::to_string(value)?.into_bytes()),
+        Format::MessagePack => {
+            rmp_serde::to_vec(value).map_err(|err| SerializationError::MessagePack(err.to_string()))
+        }
+        Format::Toml => {
+            toml::to_string(value).map(|text| text.into_bytes()).map_err(|err| SerializationError::Toml(err.to_string()))
+        }
+    }
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8], format: Format) -> Result<T, SerializationError> {
+    match format {
+        Format::Json => Ok(serde_json::from_slice(bytes)?),
+        Format::Yaml => Ok(serde_yaml::from_slice(bytes)?),
+        Format::MessagePack => {
+            rmp_serde::from_slice(bytes).map_err(|err| SerializationError::MessagePack(err.to_string()))
+        }
+        Format::Toml => {
+            let text = std::str::from_utf8(bytes).map_err(|err| SerializationError::Toml(err.to_string()))?;
+            toml::from_str(text).map_err(|err| SerializationError::Toml(err.to_string()))
+        }
+    }
+}
+
+/// Generates 256 arbitrary `T` values and asserts each one survives an
+/// encode/decode round trip through `format` unchanged.
+///
+/// Values that `T::arbitrary` can't produce from a given byte buffer
+/// (`Err(_)`, e.g. ran out of entropy for a `Vec` length) are skipped
+/// rather than treated as failures — they're an artifact of the random
+/// buffer, not of the format.
+///
+/// ```
+/// use arbitrary::Arbitrary;
+/// use serde::{Deserialize, Serialize};
+/// use rustboot_serialization::{roundtrip_prop, Format};
+///
+/// #[derive(Debug, Serialize, Deserialize, Arbitrary, PartialEq)]
+/// struct Event {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// roundtrip_prop::<Event>(Format::Json);
+/// roundtrip_prop::<Event>(Format::Yaml);
+/// roundtrip_prop::<Event>(Format::MessagePack);
+/// ```
+pub fn roundtrip_prop<T>(format: Format)
+where
+    T: Serialize + DeserializeOwned + for<'a> Arbitrary<'a> + PartialEq + std::fmt::Debug,
+{
+    const ITERATIONS: usize = 256;
+    const ENTROPY_BYTES: usize = 512;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..ITERATIONS {
+        let mut entropy = vec![0u8; ENTROPY_BYTES];
+        rng.fill_bytes(&mut entropy);
+        let mut unstructured = Unstructured::new(&entropy);
+        let Ok(value) = T::arbitrary(&mut unstructured) else {
+            continue;
+        };
+
+        let encoded = encode(&value, format).unwrap_or_else(|err| panic!("failed to encode {value:?} as {format:?}: {err}"));
+        let decoded: T = decode(&encoded, format).unwrap_or_else(|err| panic!("failed to decode {value:?} back from {format:?}: {err}"));
+        assert_eq!(value, decoded, "round trip through {format:?} did not preserve the value");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize, Arbitrary, PartialEq)]
+    struct Event {
+        id: u32,
+        name: String,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn json_round_trips_an_arbitrary_struct() {
+        roundtrip_prop::<Event>(Format::Json);
+    }
+
+    #[test]
+    fn yaml_round_trips_an_arbitrary_struct() {
+        roundtrip_prop::<Event>(Format::Yaml);
+    }
+
+    #[test]
+    fn messagepack_round_trips_an_arbitrary_struct() {
+        roundtrip_prop::<Event>(Format::MessagePack);
+    }
+}
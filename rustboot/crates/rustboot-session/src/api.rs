@@ -0,0 +1,46 @@
+//! Public types for the session module.
+
+/// The `SameSite` cookie attribute (RFC 6265bis).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    /// The cookie is withheld from all cross-site requests.
+    Strict,
+    /// The cookie is sent on top-level cross-site navigations.
+    Lax,
+    /// The cookie is sent on all requests; requires `Secure`.
+    None,
+}
+
+impl SameSite {
+    /// The `Set-Cookie` attribute value.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// Errors raised when a [`crate::SessionConfig`]'s cookie name prefix
+/// implies constraints the rest of the configuration does not satisfy.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Cookies#cookie_prefixes>.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum SessionConfigError {
+    /// A `__Host-`-prefixed cookie must set `Secure`.
+    #[error("cookie name '{0}' uses the __Host- prefix, which requires Secure")]
+    HostPrefixRequiresSecure(String),
+    /// A `__Host-`-prefixed cookie must use `Path=/`.
+    #[error("cookie name '{0}' uses the __Host- prefix, which requires Path=/")]
+    HostPrefixRequiresRootPath(String),
+    /// A `__Host-`-prefixed cookie must not set a `Domain`.
+    #[error("cookie name '{0}' uses the __Host- prefix, which forbids a Domain attribute")]
+    HostPrefixForbidsDomain(String),
+    /// A `__Secure-`-prefixed cookie must set `Secure`.
+    #[error("cookie name '{0}' uses the __Secure- prefix, which requires Secure")]
+    SecurePrefixRequiresSecure(String),
+    /// `SameSite=None` was set without `Secure`, which browsers reject.
+    #[error("SameSite=None requires Secure")]
+    SameSiteNoneRequiresSecure,
+}
@@ -0,0 +1,201 @@
+//! Implementation details for the session module.
+
+use std::time::Duration;
+
+use crate::api::{SameSite, SessionConfigError};
+
+/// Configures a session cookie's name and attributes.
+///
+/// Hand-assembling `Secure`/`HttpOnly`/`SameSite`/`Path`/`Domain` tends
+/// to drift out of a coherent, safe combination; prefer
+/// [`SessionConfig::strict`] or [`SessionConfig::lax_for_dev`] and
+/// adjust individual fields from there, then call
+/// [`SessionConfig::validate`] before using the result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionConfig {
+    /// The session lifetime.
+    pub ttl: Duration,
+    /// The cookie name. A `__Host-` or `__Secure-` prefix activates the
+    /// corresponding browser-enforced constraints, checked by
+    /// [`SessionConfig::validate`].
+    pub cookie_name: String,
+    /// The cookie `Path` attribute.
+    pub cookie_path: String,
+    /// The cookie `Domain` attribute. `None` omits the attribute,
+    /// scoping the cookie to the exact host that set it.
+    pub cookie_domain: Option<String>,
+    /// The cookie `Secure` attribute.
+    pub secure: bool,
+    /// The cookie `HttpOnly` attribute.
+    pub http_only: bool,
+    /// The cookie `SameSite` attribute.
+    pub same_site: SameSite,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self::lax_for_dev()
+    }
+}
+
+impl SessionConfig {
+    /// A locked-down preset suitable for production: `__Host-`-prefixed
+    /// cookie name, `Secure`, `HttpOnly`, `SameSite=Strict`, `Path=/`,
+    /// and no `Domain` (required by the `__Host-` prefix).
+    pub fn strict() -> Self {
+        Self {
+            ttl: Duration::from_secs(24 * 60 * 60),
+            cookie_name: "__Host-session".to_string(),
+            cookie_path: "/".to_string(),
+            cookie_domain: None,
+            secure: true,
+            http_only: true,
+            same_site: SameSite::Strict,
+        }
+    }
+
+    /// A preset for local development over plain HTTP: same shape as
+    /// [`SessionConfig::strict`], but with `SameSite=Lax` and without
+    /// `Secure` or the `__Host-` prefix, since those two require HTTPS.
+    pub fn lax_for_dev() -> Self {
+        Self {
+            ttl: Duration::from_secs(24 * 60 * 60),
+            cookie_name: "session".to_string(),
+            cookie_path: "/".to_string(),
+            cookie_domain: None,
+            secure: false,
+            http_only: true,
+            same_site: SameSite::Lax,
+        }
+    }
+
+    /// Checks that the configuration is an internally coherent,
+    /// browser-acceptable combination: that `SameSite=None` implies
+    /// `Secure`, and that a `__Host-`/`__Secure-`-prefixed
+    /// [`SessionConfig::cookie_name`] satisfies the constraints the
+    /// prefix implies.
+    pub fn validate(&self) -> Result<(), SessionConfigError> {
+        if self.same_site == SameSite::None && !self.secure {
+            return Err(SessionConfigError::SameSiteNoneRequiresSecure);
+        }
+
+        if self.cookie_name.starts_with("__Host-") {
+            if !self.secure {
+                return Err(SessionConfigError::HostPrefixRequiresSecure(
+                    self.cookie_name.clone(),
+                ));
+            }
+            if self.cookie_path != "/" {
+                return Err(SessionConfigError::HostPrefixRequiresRootPath(
+                    self.cookie_name.clone(),
+                ));
+            }
+            if self.cookie_domain.is_some() {
+                return Err(SessionConfigError::HostPrefixForbidsDomain(
+                    self.cookie_name.clone(),
+                ));
+            }
+        } else if self.cookie_name.starts_with("__Secure-") && !self.secure {
+            return Err(SessionConfigError::SecurePrefixRequiresSecure(
+                self.cookie_name.clone(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_preset_validates() {
+        assert_eq!(SessionConfig::strict().validate(), Ok(()));
+    }
+
+    #[test]
+    fn lax_for_dev_preset_validates() {
+        assert_eq!(SessionConfig::lax_for_dev().validate(), Ok(()));
+    }
+
+    #[test]
+    fn host_prefix_without_secure_is_rejected() {
+        let config = SessionConfig {
+            secure: false,
+            ..SessionConfig::strict()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SessionConfigError::HostPrefixRequiresSecure(
+                "__Host-session".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn host_prefix_with_non_root_path_is_rejected() {
+        let config = SessionConfig {
+            cookie_path: "/app".to_string(),
+            ..SessionConfig::strict()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SessionConfigError::HostPrefixRequiresRootPath(
+                "__Host-session".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn host_prefix_with_domain_is_rejected() {
+        let config = SessionConfig {
+            cookie_domain: Some("example.com".to_string()),
+            ..SessionConfig::strict()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SessionConfigError::HostPrefixForbidsDomain(
+                "__Host-session".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn secure_prefix_without_secure_is_rejected() {
+        let config = SessionConfig {
+            cookie_name: "__Secure-session".to_string(),
+            secure: false,
+            ..SessionConfig::lax_for_dev()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SessionConfigError::SecurePrefixRequiresSecure(
+                "__Secure-session".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn same_site_none_without_secure_is_rejected() {
+        let config = SessionConfig {
+            same_site: SameSite::None,
+            secure: false,
+            ..SessionConfig::lax_for_dev()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(SessionConfigError::SameSiteNoneRequiresSecure)
+        );
+    }
+
+    #[test]
+    fn same_site_none_with_secure_validates() {
+        let config = SessionConfig {
+            same_site: SameSite::None,
+            secure: true,
+            ..SessionConfig::lax_for_dev()
+        };
+        assert_eq!(config.validate(), Ok(()));
+    }
+}
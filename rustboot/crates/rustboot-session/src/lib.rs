@@ -0,0 +1,7 @@
+//! Session cookie configuration for the rustboot framework.
+
+pub mod api;
+pub mod core;
+
+pub use api::{SameSite, SessionConfigError};
+pub use core::SessionConfig;
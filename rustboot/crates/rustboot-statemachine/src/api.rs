@@ -0,0 +1,195 @@
+//! Public types for the state machine module.
+
+use std::fmt;
+use std::time::SystemTime;
+
+/// A named state in a [`crate::core::StateMachine`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State(String);
+
+impl State {
+    /// Returns the state's name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for State {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for State {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A named event that can trigger a transition in a
+/// [`crate::core::StateMachine`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Event(String);
+
+impl Event {
+    /// Returns the event's name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Event {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Event {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A completed transition, as recorded in a
+/// [`crate::core::StateMachine`]'s history.
+#[derive(Debug, Clone)]
+pub struct TransitionRecord<D> {
+    /// The state transitioned out of.
+    pub from: State,
+    /// The state transitioned into.
+    pub to: State,
+    /// The event that triggered the transition.
+    pub event: Event,
+    /// Wall-clock time the transition completed.
+    pub timestamp: SystemTime,
+    /// The data the triggering `fire` call carried.
+    pub data: D,
+}
+
+/// One transition, flattened out of a [`crate::core::StateMachine`] for
+/// visualization tooling ([`crate::core::visualize::StateMachineVisualizer`])
+/// rather than for firing events.
+#[derive(Debug, Clone)]
+pub struct TransitionView {
+    /// The state transitioned out of.
+    pub from: State,
+    /// The state transitioned into.
+    pub to: State,
+    /// The event that triggers this transition.
+    pub event: Event,
+    /// The names of every guard that must pass for this transition to fire.
+    pub guards: Vec<String>,
+}
+
+/// A point-in-time copy of a [`crate::core::StateMachine`]'s current
+/// state and history, as saved to and restored from a
+/// [`crate::spi::Repository`] by a [`crate::core::PersistentStateMachine`].
+#[derive(Debug, Clone)]
+pub struct StateMachineSnapshot<D> {
+    /// The state the machine was in when the snapshot was taken.
+    pub current: State,
+    /// The machine's full transition history at the time of the snapshot.
+    pub history: Vec<TransitionRecord<D>>,
+}
+
+/// One domain event read from an [`crate::spi::EventSource`], tagged
+/// with its position in the stream so
+/// [`crate::core::event_sourced::EventSourcedMachine`] can resume after
+/// the last event it folded and snapshot its progress.
+#[derive(Debug, Clone)]
+pub struct EventEnvelope<E> {
+    /// The event's position in the stream, strictly increasing.
+    pub sequence: u64,
+    /// The domain event itself.
+    pub event: E,
+    /// Wall-clock time the event was read.
+    pub timestamp: SystemTime,
+}
+
+/// A point-in-time fold result, saved to a
+/// [`crate::spi::SnapshotStore`] by an
+/// [`crate::core::event_sourced::EventSourcedMachine`] so a restart
+/// doesn't require replaying its entire event history.
+#[derive(Debug, Clone)]
+pub struct EventSnapshot<S> {
+    /// The sequence number of the last event folded into `state`.
+    pub sequence: u64,
+    /// The folded state as of `sequence`.
+    pub state: S,
+}
+
+/// Errors from firing an event against a [`crate::core::StateMachine`].
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum TransitionError {
+    /// No transition is registered from the current state on this event.
+    #[error("no transition from '{from}' on event '{event}'")]
+    NoTransition {
+        /// The state the machine was in.
+        from: State,
+        /// The event that was fired.
+        event: Event,
+    },
+    /// A transition's guard rejected the triggering data.
+    #[error("guard '{guard}' rejected transition from '{from}' to '{to}' on event '{event}'")]
+    GuardRejected {
+        /// The state transitioned out of.
+        from: State,
+        /// The state that would have been transitioned into.
+        to: State,
+        /// The event that was fired.
+        event: Event,
+        /// The name of the guard that rejected the transition.
+        guard: String,
+    },
+    /// The source state's exit callback returned an error.
+    #[error("exit handler for state '{state}' failed: {reason}")]
+    ExitActionFailed {
+        /// The state being exited.
+        state: State,
+        /// The callback's error message.
+        reason: String,
+    },
+    /// A transition action returned an error.
+    #[error("action for transition from '{from}' to '{to}' failed: {reason}")]
+    ActionFailed {
+        /// The state transitioned out of.
+        from: State,
+        /// The state transitioned into.
+        to: State,
+        /// The callback's error message.
+        reason: String,
+    },
+    /// The destination state's entry callback returned an error.
+    #[error("enter handler for state '{state}' failed: {reason}")]
+    EnterActionFailed {
+        /// The state being entered.
+        state: State,
+        /// The callback's error message.
+        reason: String,
+    },
+    /// A [`crate::spi::Repository`] failed to save or load a snapshot.
+    #[error("state machine persistence failed: {0}")]
+    PersistenceFailed(String),
+    /// A [`crate::core::StateMachineBuilder`] transition referenced a
+    /// state that was never registered with `state`/`composite_state`.
+    #[error("transition references unregistered state '{state}'")]
+    UnknownState {
+        /// The unregistered state.
+        state: State,
+    },
+    /// A [`crate::spi::EventSource`] failed to read events.
+    #[error("event source read failed: {0}")]
+    EventSourceFailed(String),
+}
@@ -0,0 +1,1046 @@
+//! The guard/action finite state machine: [`StateMachine`],
+//! [`StateMachineBuilder`], and [`PersistentStateMachine`].
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crate::api::{Event, State, StateMachineSnapshot, TransitionError, TransitionRecord, TransitionView};
+use crate::spi::Repository;
+
+/// A boxed, owned future, used for async entry/exit/transition callbacks
+/// so a [`StateMachine`] can hold callbacks built from unrelated
+/// closures/async blocks without a generic callback type parameter.
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// An async callback run when entering or exiting a state.
+pub type StateCallback<D> = Box<dyn Fn(State, D) -> BoxFuture<Result<(), String>> + Send + Sync>;
+
+/// An async callback run during a transition, after its guards pass.
+pub type TransitionAction<D> =
+    Box<dyn Fn(State, State, D) -> BoxFuture<Result<(), String>> + Send + Sync>;
+
+/// A named, synchronous precondition on a transition's triggering data.
+/// Carries a name so a rejection can be reported in a
+/// [`TransitionError::GuardRejected`].
+pub struct Guard<D> {
+    name: &'static str,
+    check: Box<dyn Fn(&D) -> bool + Send + Sync>,
+}
+
+impl<D> Guard<D> {
+    /// Creates a named guard from a predicate over the transition's data.
+    pub fn new(name: &'static str, check: impl Fn(&D) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            name,
+            check: Box::new(check),
+        }
+    }
+}
+
+#[derive(Default)]
+struct StateConfig<D> {
+    on_enter: Option<StateCallback<D>>,
+    on_exit: Option<StateCallback<D>>,
+    substate: Option<StateMachine<D>>,
+}
+
+struct Transition<D> {
+    from: State,
+    to: State,
+    event: Event,
+    guards: Vec<Guard<D>>,
+    actions: Vec<TransitionAction<D>>,
+}
+
+/// A finite state machine over named [`State`]s and [`Event`]s, with
+/// per-transition guards and async actions, and per-state async entry/
+/// exit callbacks.
+///
+/// Data of type `D` flows through every guard and callback for a single
+/// [`StateMachine::fire`] call, so transition logic can depend on the
+/// triggering event's payload (an order, a form submission, ...) without
+/// closing over shared mutable state.
+///
+/// ```ignore
+/// use rustboot_statemachine::{Guard, StateMachine};
+///
+/// let mut machine: StateMachine<u32> = StateMachine::new("draft");
+/// machine.add_transition("draft", "review", "submit");
+/// machine.add_transition_with_guard(
+///     "review",
+///     "approved",
+///     "approve",
+///     Guard::new("is_reviewer", |role: &u32| *role == 1),
+/// );
+///
+/// machine.fire("submit", 0).await.unwrap();
+/// assert!(machine.fire("approve", 0).await.is_err());
+/// machine.fire("approve", 1).await.unwrap();
+/// assert_eq!(machine.current_state().as_str(), "approved");
+/// ```
+pub struct StateMachine<D> {
+    current: Mutex<State>,
+    initial: State,
+    states: HashMap<State, StateConfig<D>>,
+    transitions: Vec<Transition<D>>,
+    history: Mutex<Vec<TransitionRecord<D>>>,
+    last_error: Mutex<Option<TransitionError>>,
+}
+
+impl<D: Clone + Send + 'static> StateMachine<D> {
+    /// Creates a state machine starting in `initial`, with no states or
+    /// transitions registered yet.
+    pub fn new(initial: impl Into<State>) -> Self {
+        let initial = initial.into();
+        Self {
+            current: Mutex::new(initial.clone()),
+            initial,
+            states: HashMap::new(),
+            transitions: Vec::new(),
+            history: Mutex::new(Vec::new()),
+            last_error: Mutex::new(None),
+        }
+    }
+
+    /// Registers a state, with optional async entry/exit callbacks run
+    /// whenever [`StateMachine::fire`] transitions into/out of it.
+    pub fn add_state(
+        &mut self,
+        state: impl Into<State>,
+        on_enter: Option<StateCallback<D>>,
+        on_exit: Option<StateCallback<D>>,
+    ) {
+        self.states.insert(
+            state.into(),
+            StateConfig {
+                on_enter,
+                on_exit,
+                substate: None,
+            },
+        );
+    }
+
+    /// Registers `state` as a composite state backed by `substate`: while
+    /// the machine is in `state`, [`StateMachine::fire`] tries `substate`
+    /// first, only falling back to this machine's own transitions out of
+    /// `state` if `substate` has no transition registered for the event.
+    ///
+    /// This models a nested sub-workflow (e.g. a multi-step "processing"
+    /// state with its own internal states) without flattening it into the
+    /// parent machine's transition table.
+    pub fn add_composite_state(
+        &mut self,
+        state: impl Into<State>,
+        substate: StateMachine<D>,
+        on_enter: Option<StateCallback<D>>,
+        on_exit: Option<StateCallback<D>>,
+    ) {
+        self.states.insert(
+            state.into(),
+            StateConfig {
+                on_enter,
+                on_exit,
+                substate: Some(substate),
+            },
+        );
+    }
+
+    /// Adds a transition with no guards or actions.
+    pub fn add_transition(
+        &mut self,
+        from: impl Into<State>,
+        to: impl Into<State>,
+        event: impl Into<Event>,
+    ) {
+        self.add_transition_full(from, to, event, Vec::new(), Vec::new());
+    }
+
+    /// Adds a transition guarded by `guard`: [`StateMachine::fire`]
+    /// rejects the event with [`TransitionError::GuardRejected`] naming
+    /// `guard` if its predicate returns `false` for the call's data.
+    pub fn add_transition_with_guard(
+        &mut self,
+        from: impl Into<State>,
+        to: impl Into<State>,
+        event: impl Into<Event>,
+        guard: Guard<D>,
+    ) {
+        self.add_transition_full(from, to, event, vec![guard], Vec::new());
+    }
+
+    /// Adds a transition with explicit guards and async actions: guards
+    /// are checked in order before any action or callback runs, and
+    /// actions run in order, after the source state's exit callback and
+    /// before the destination state's entry callback.
+    pub fn add_transition_full(
+        &mut self,
+        from: impl Into<State>,
+        to: impl Into<State>,
+        event: impl Into<Event>,
+        guards: Vec<Guard<D>>,
+        actions: Vec<TransitionAction<D>>,
+    ) {
+        self.transitions.push(Transition {
+            from: from.into(),
+            to: to.into(),
+            event: event.into(),
+            guards,
+            actions,
+        });
+    }
+
+    /// Returns whether a transition is registered from the current state
+    /// on `event`, ignoring guards.
+    pub fn can_fire(&self, event: impl Into<Event>) -> bool {
+        let event = event.into();
+        let current = self.current.lock().unwrap();
+        self.transitions
+            .iter()
+            .any(|t| t.from == *current && t.event == event)
+    }
+
+    /// Fires `event` with `data`: finds the transition matching the
+    /// current state and `event`, checks its guards, runs the source
+    /// state's exit callback, runs the transition's actions, records the
+    /// transition in [`StateMachine::history`], runs the destination
+    /// state's entry callback, and finally updates the current state.
+    ///
+    /// Returns a [`TransitionError`] without changing the current state
+    /// if no transition matches, a guard rejects `data`, or any callback
+    /// fails. On failure, the error is also recorded and available via
+    /// [`StateMachine::last_error`].
+    pub async fn fire(
+        &self,
+        event: impl Into<Event>,
+        data: D,
+    ) -> Result<(), TransitionError> {
+        let result = self.fire_inner(event, data).await;
+        if let Err(error) = &result {
+            *self.last_error.lock().unwrap() = Some(error.clone());
+        }
+        result
+    }
+
+    async fn fire_inner(
+        &self,
+        event: impl Into<Event>,
+        data: D,
+    ) -> Result<(), TransitionError> {
+        let event = event.into();
+        let current = self.current.lock().unwrap().clone();
+
+        if let Some(substate) = self
+            .states
+            .get(&current)
+            .and_then(|config| config.substate.as_ref())
+        {
+            match Box::pin(substate.fire(event.clone(), data.clone())).await {
+                Ok(()) => return Ok(()),
+                Err(TransitionError::NoTransition { .. }) => {
+                    // Unhandled by the substate: fall through and look
+                    // for a transition out of `current` on this machine.
+                }
+                Err(other) => return Err(other),
+            }
+        }
+
+        let transition = self
+            .transitions
+            .iter()
+            .find(|t| t.from == current && t.event == event)
+            .ok_or_else(|| TransitionError::NoTransition {
+                from: current.clone(),
+                event: event.clone(),
+            })?;
+
+        for guard in &transition.guards {
+            if !(guard.check)(&data) {
+                return Err(TransitionError::GuardRejected {
+                    from: transition.from.clone(),
+                    to: transition.to.clone(),
+                    event: event.clone(),
+                    guard: guard.name.to_string(),
+                });
+            }
+        }
+
+        if let Some(on_exit) = self
+            .states
+            .get(&current)
+            .and_then(|config| config.on_exit.as_ref())
+        {
+            on_exit(current.clone(), data.clone())
+                .await
+                .map_err(|reason| TransitionError::ExitActionFailed {
+                    state: current.clone(),
+                    reason,
+                })?;
+        }
+
+        for action in &transition.actions {
+            action(transition.from.clone(), transition.to.clone(), data.clone())
+                .await
+                .map_err(|reason| TransitionError::ActionFailed {
+                    from: transition.from.clone(),
+                    to: transition.to.clone(),
+                    reason,
+                })?;
+        }
+
+        self.history.lock().unwrap().push(TransitionRecord {
+            from: transition.from.clone(),
+            to: transition.to.clone(),
+            event: event.clone(),
+            timestamp: SystemTime::now(),
+            data: data.clone(),
+        });
+
+        if let Some(on_enter) = self
+            .states
+            .get(&transition.to)
+            .and_then(|config| config.on_enter.as_ref())
+        {
+            on_enter(transition.to.clone(), data.clone())
+                .await
+                .map_err(|reason| TransitionError::EnterActionFailed {
+                    state: transition.to.clone(),
+                    reason,
+                })?;
+        }
+
+        *self.current.lock().unwrap() = transition.to.clone();
+
+        Ok(())
+    }
+
+    /// Returns the current state.
+    pub fn current_state(&self) -> State {
+        self.current.lock().unwrap().clone()
+    }
+
+    /// Returns every transition recorded so far, oldest first.
+    pub fn history(&self) -> Vec<TransitionRecord<D>> {
+        self.history.lock().unwrap().clone()
+    }
+
+    /// Resets the machine to its initial state and clears its history
+    /// and last error.
+    pub fn reset(&self) {
+        *self.current.lock().unwrap() = self.initial.clone();
+        self.history.lock().unwrap().clear();
+        *self.last_error.lock().unwrap() = None;
+    }
+
+    /// Returns the error from the most recent failed [`StateMachine::fire`]
+    /// call, if any. Not cleared by a subsequent successful `fire`; call
+    /// [`StateMachine::reset`] to clear it.
+    pub fn last_error(&self) -> Option<TransitionError> {
+        self.last_error.lock().unwrap().clone()
+    }
+
+    /// Captures the current state and history, for persisting via a
+    /// [`Repository`].
+    pub fn snapshot(&self) -> StateMachineSnapshot<D> {
+        StateMachineSnapshot {
+            current: self.current_state(),
+            history: self.history(),
+        }
+    }
+
+    /// Overwrites the current state and history from a previously saved
+    /// [`StateMachineSnapshot`]. Call this after registering the
+    /// machine's states and transitions with `add_state`/`add_transition`
+    /// as usual, and before firing any events.
+    pub fn restore(&self, snapshot: StateMachineSnapshot<D>) {
+        *self.current.lock().unwrap() = snapshot.current;
+        *self.history.lock().unwrap() = snapshot.history;
+    }
+
+    /// Alias for [`StateMachine::can_fire`], matching the naming used by
+    /// [`StateMachineBuilder`]-declared machines.
+    pub fn can_trigger(&self, event: impl Into<Event>) -> bool {
+        self.can_fire(event)
+    }
+
+    /// Alias for [`StateMachine::fire`], matching the naming used by
+    /// [`StateMachineBuilder`]-declared machines.
+    pub async fn trigger(&self, event: impl Into<Event>, data: D) -> Result<(), TransitionError> {
+        self.fire(event, data).await
+    }
+
+    /// Renders the machine's states and transitions as a Graphviz DOT
+    /// digraph, for visualizing with any DOT-compatible tool. Guarded
+    /// transitions are labeled with the event name and every guard's
+    /// name; composite states are rendered as a labeled cluster
+    /// subgraph containing their substate's own transitions.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph state_machine {\n");
+        for (name, config) in &self.states {
+            if let Some(substate) = &config.substate {
+                dot.push_str(&format!("  subgraph \"cluster_{name}\" {{\n"));
+                dot.push_str(&format!("    label=\"{name}\";\n"));
+                for transition in &substate.transitions {
+                    dot.push_str(&dot_edge_line(transition, "    "));
+                }
+                dot.push_str("  }\n");
+            }
+        }
+        for transition in &self.transitions {
+            dot.push_str(&dot_edge_line(transition, "  "));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Flattens this machine's own transitions (not those of any
+    /// composite substate) into [`TransitionView`]s, for visualization
+    /// tooling ([`crate::core::visualize::StateMachineVisualizer`])
+    /// beyond [`StateMachine::to_dot`]'s fixed DOT output.
+    pub fn transition_views(&self) -> Vec<TransitionView> {
+        self.transitions.iter().map(transition_view).collect()
+    }
+
+    /// Returns each composite state's substate transitions, labeled with
+    /// the composite state's name. This machine's own transitions are not
+    /// included; [`StateMachine::transition_views`] returns those.
+    pub fn composite_transition_views(&self) -> Vec<(State, Vec<TransitionView>)> {
+        self.states
+            .iter()
+            .filter_map(|(name, config)| {
+                config.substate.as_ref().map(|substate| (name.clone(), substate.transition_views()))
+            })
+            .collect()
+    }
+}
+
+fn transition_view<D>(transition: &Transition<D>) -> TransitionView {
+    TransitionView {
+        from: transition.from.clone(),
+        to: transition.to.clone(),
+        event: transition.event.clone(),
+        guards: transition.guards.iter().map(|guard| guard.name.to_string()).collect(),
+    }
+}
+
+fn dot_edge_line<D>(transition: &Transition<D>, indent: &str) -> String {
+    let mut label = transition.event.to_string();
+    for guard in &transition.guards {
+        label.push_str(&format!(" [{}]", guard.name));
+    }
+    format!(
+        "{indent}\"{}\" -> \"{}\" [label=\"{}\"];\n",
+        transition.from, transition.to, label
+    )
+}
+
+/// Declares a [`StateMachine`]'s states and transitions up front, so
+/// [`StateMachineBuilder::build`] can reject a transition that
+/// references a state that was never registered, instead of letting it
+/// silently become unreachable dead configuration.
+pub struct StateMachineBuilder<D> {
+    machine: StateMachine<D>,
+    known_states: std::collections::HashSet<State>,
+}
+
+impl<D: Clone + Send + 'static> StateMachineBuilder<D> {
+    /// Starts a builder for a machine beginning in `initial`.
+    pub fn new(initial: impl Into<State>) -> Self {
+        let initial = initial.into();
+        let mut known_states = std::collections::HashSet::new();
+        known_states.insert(initial.clone());
+        Self {
+            machine: StateMachine::new(initial),
+            known_states,
+        }
+    }
+
+    /// Registers a state, with optional async entry/exit callbacks.
+    pub fn state(
+        mut self,
+        state: impl Into<State>,
+        on_enter: Option<StateCallback<D>>,
+        on_exit: Option<StateCallback<D>>,
+    ) -> Self {
+        let state = state.into();
+        self.known_states.insert(state.clone());
+        self.machine.add_state(state, on_enter, on_exit);
+        self
+    }
+
+    /// Registers a composite state backed by `substate`. See
+    /// [`StateMachine::add_composite_state`].
+    pub fn composite_state(
+        mut self,
+        state: impl Into<State>,
+        substate: StateMachine<D>,
+        on_enter: Option<StateCallback<D>>,
+        on_exit: Option<StateCallback<D>>,
+    ) -> Self {
+        let state = state.into();
+        self.known_states.insert(state.clone());
+        self.machine.add_composite_state(state, substate, on_enter, on_exit);
+        self
+    }
+
+    /// Adds a transition with no guards or actions.
+    pub fn transition(
+        self,
+        from: impl Into<State>,
+        to: impl Into<State>,
+        event: impl Into<Event>,
+    ) -> Self {
+        self.transition_full(from, to, event, Vec::new(), Vec::new())
+    }
+
+    /// Adds a transition guarded by `guard`. See
+    /// [`StateMachine::add_transition_with_guard`].
+    pub fn transition_with_guard(
+        self,
+        from: impl Into<State>,
+        to: impl Into<State>,
+        event: impl Into<Event>,
+        guard: Guard<D>,
+    ) -> Self {
+        self.transition_full(from, to, event, vec![guard], Vec::new())
+    }
+
+    /// Adds a transition with explicit guards and async actions. See
+    /// [`StateMachine::add_transition_full`].
+    pub fn transition_full(
+        mut self,
+        from: impl Into<State>,
+        to: impl Into<State>,
+        event: impl Into<Event>,
+        guards: Vec<Guard<D>>,
+        actions: Vec<TransitionAction<D>>,
+    ) -> Self {
+        self.machine
+            .add_transition_full(from, to, event, guards, actions);
+        self
+    }
+
+    /// Validates that every transition's `from`/`to` state was
+    /// registered with [`StateMachineBuilder::state`] or
+    /// [`StateMachineBuilder::composite_state`] (the initial state
+    /// counts as registered automatically), then returns the built
+    /// [`StateMachine`].
+    pub fn build(self) -> Result<StateMachine<D>, TransitionError> {
+        for transition in &self.machine.transitions {
+            if !self.known_states.contains(&transition.from) {
+                return Err(TransitionError::UnknownState {
+                    state: transition.from.clone(),
+                });
+            }
+            if !self.known_states.contains(&transition.to) {
+                return Err(TransitionError::UnknownState {
+                    state: transition.to.clone(),
+                });
+            }
+        }
+        Ok(self.machine)
+    }
+}
+
+/// A [`StateMachine`] that saves a [`StateMachineSnapshot`] to a
+/// [`Repository`] after every successful [`PersistentStateMachine::fire`]
+/// call, and can restore one on startup — so a long-running workflow
+/// (e.g. order fulfillment) survives a process restart in whatever state
+/// it was last left in.
+pub struct PersistentStateMachine<D> {
+    machine: StateMachine<D>,
+    repository: Arc<dyn Repository<D>>,
+}
+
+impl<D: Clone + Send + 'static> PersistentStateMachine<D> {
+    /// Wraps `machine`, whose states and transitions should already be
+    /// registered, so it persists its state and history to `repository`.
+    pub fn new(machine: StateMachine<D>, repository: Arc<dyn Repository<D>>) -> Self {
+        Self { machine, repository }
+    }
+
+    /// Restores the wrapped machine from the most recently saved
+    /// snapshot, if any. Call this once on startup, after registering
+    /// the machine's states and transitions and before firing any
+    /// events.
+    pub async fn restore(&self) -> Result<(), TransitionError> {
+        if let Some(snapshot) = self.repository.load().await? {
+            self.machine.restore(snapshot);
+        }
+        Ok(())
+    }
+
+    /// Fires `event` against the wrapped machine, then saves a snapshot
+    /// of its resulting state and history to the repository.
+    ///
+    /// The transition is not rolled back if the snapshot save fails: the
+    /// in-memory machine has already moved on, so a failed save should
+    /// be treated as a durability warning to retry, not a rejected
+    /// transition.
+    pub async fn fire(&self, event: impl Into<Event>, data: D) -> Result<(), TransitionError> {
+        self.machine.fire(event, data).await?;
+        self.repository.save(self.machine.snapshot()).await
+    }
+
+    /// Returns whether a transition is registered from the current state
+    /// on `event`, ignoring guards.
+    pub fn can_fire(&self, event: impl Into<Event>) -> bool {
+        self.machine.can_fire(event)
+    }
+
+    /// Returns the wrapped machine's current state.
+    pub fn current_state(&self) -> State {
+        self.machine.current_state()
+    }
+
+    /// Returns the wrapped machine's full transition history.
+    pub fn history(&self) -> Vec<TransitionRecord<D>> {
+        self.machine.history()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn ok_callback<D: Send + 'static>() -> TransitionAction<D> {
+        Box::new(|_from, _to, _data| Box::pin(async { Ok(()) }))
+    }
+
+    #[tokio::test]
+    async fn fires_a_registered_transition() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+
+        machine.fire("submit", ()).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "review");
+    }
+
+    #[tokio::test]
+    async fn rejects_an_event_with_no_matching_transition() {
+        let machine: StateMachine<()> = StateMachine::new("draft");
+
+        let err = machine.fire("submit", ()).await.unwrap_err();
+
+        assert!(matches!(err, TransitionError::NoTransition { .. }));
+        assert_eq!(machine.current_state().as_str(), "draft");
+    }
+
+    #[tokio::test]
+    async fn last_error_reports_the_most_recent_failure_until_reset() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+        assert_eq!(machine.last_error(), None);
+
+        machine.fire("bogus", ()).await.unwrap_err();
+        assert!(matches!(machine.last_error(), Some(TransitionError::NoTransition { .. })));
+
+        machine.fire("submit", ()).await.unwrap();
+        assert!(machine.last_error().is_some(), "a successful fire doesn't clear the last error");
+
+        machine.reset();
+        assert_eq!(machine.last_error(), None);
+    }
+
+    #[tokio::test]
+    async fn guard_rejection_names_the_guard_and_leaves_the_state_unchanged() {
+        let mut machine: StateMachine<bool> = StateMachine::new("review");
+        machine.add_transition_with_guard(
+            "review",
+            "approved",
+            "approve",
+            Guard::new("is_reviewer", |is_reviewer: &bool| *is_reviewer),
+        );
+
+        let err = machine.fire("approve", false).await.unwrap_err();
+
+        match err {
+            TransitionError::GuardRejected { guard, .. } => assert_eq!(guard, "is_reviewer"),
+            other => panic!("expected GuardRejected, got {other:?}"),
+        }
+        assert_eq!(machine.current_state().as_str(), "review");
+    }
+
+    #[tokio::test]
+    async fn passing_guard_allows_the_transition() {
+        let mut machine: StateMachine<bool> = StateMachine::new("review");
+        machine.add_transition_with_guard(
+            "review",
+            "approved",
+            "approve",
+            Guard::new("is_reviewer", |is_reviewer: &bool| *is_reviewer),
+        );
+
+        machine.fire("approve", true).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "approved");
+    }
+
+    #[tokio::test]
+    async fn entry_and_exit_callbacks_run_in_order_around_the_transition() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        let exit_calls = calls.clone();
+        machine.add_state(
+            "draft",
+            None,
+            Some(Box::new(move |state, _data| {
+                let calls = exit_calls.clone();
+                Box::pin(async move {
+                    calls.lock().unwrap().push(format!("exit:{state}"));
+                    Ok(())
+                })
+            })),
+        );
+        let enter_calls = calls.clone();
+        machine.add_state(
+            "review",
+            Some(Box::new(move |state, _data| {
+                let calls = enter_calls.clone();
+                Box::pin(async move {
+                    calls.lock().unwrap().push(format!("enter:{state}"));
+                    Ok(())
+                })
+            })),
+            None,
+        );
+        machine.add_transition("draft", "review", "submit");
+
+        machine.fire("submit", ()).await.unwrap();
+
+        assert_eq!(*calls.lock().unwrap(), vec!["exit:draft", "enter:review"]);
+    }
+
+    #[tokio::test]
+    async fn actions_run_between_exit_and_entry_and_can_fail_the_transition() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition_full(
+            "draft",
+            "review",
+            "submit",
+            Vec::new(),
+            vec![Box::new(|_from, _to, _data| {
+                Box::pin(async { Err("disk full".to_string()) })
+            })],
+        );
+
+        let err = machine.fire("submit", ()).await.unwrap_err();
+
+        assert!(matches!(err, TransitionError::ActionFailed { .. }));
+        assert_eq!(machine.current_state().as_str(), "draft");
+    }
+
+    #[tokio::test]
+    async fn successful_actions_run_in_registration_order() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let first = count.clone();
+        let second = count.clone();
+
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition_full(
+            "draft",
+            "review",
+            "submit",
+            Vec::new(),
+            vec![
+                Box::new(move |_from, _to, _data| {
+                    let first = first.clone();
+                    Box::pin(async move {
+                        assert_eq!(first.fetch_add(1, Ordering::SeqCst), 0);
+                        Ok(())
+                    })
+                }),
+                Box::new(move |_from, _to, _data| {
+                    let second = second.clone();
+                    Box::pin(async move {
+                        assert_eq!(second.fetch_add(1, Ordering::SeqCst), 1);
+                        Ok(())
+                    })
+                }),
+            ],
+        );
+
+        machine.fire("submit", ()).await.unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn history_records_completed_transitions_with_data() {
+        let mut machine: StateMachine<u32> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+
+        machine.fire("submit", 42).await.unwrap();
+
+        let history = machine.history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].from.as_str(), "draft");
+        assert_eq!(history[0].to.as_str(), "review");
+        assert_eq!(history[0].data, 42);
+    }
+
+    #[tokio::test]
+    async fn reset_restores_initial_state_and_clears_history() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+        machine.fire("submit", ()).await.unwrap();
+
+        machine.reset();
+
+        assert_eq!(machine.current_state().as_str(), "draft");
+        assert!(machine.history().is_empty());
+    }
+
+    #[tokio::test]
+    async fn can_fire_reflects_the_current_state() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+
+        assert!(machine.can_fire("submit"));
+        assert!(!machine.can_fire("approve"));
+
+        machine.fire("submit", ()).await.unwrap();
+        assert!(!machine.can_fire("submit"));
+    }
+
+    #[tokio::test]
+    async fn add_transition_full_accepts_pre_built_ok_action() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition_full("draft", "review", "submit", Vec::new(), vec![ok_callback()]);
+
+        machine.fire("submit", ()).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "review");
+    }
+
+    #[tokio::test]
+    async fn composite_state_handles_events_its_substate_knows_about() {
+        let mut substate: StateMachine<()> = StateMachine::new("packing");
+        substate.add_transition("packing", "shipped_internally", "pack");
+
+        let mut machine: StateMachine<()> = StateMachine::new("pending");
+        machine.add_composite_state("processing", substate, None, None);
+        machine.add_transition("pending", "processing", "accept");
+
+        machine.fire("accept", ()).await.unwrap();
+        machine.fire("pack", ()).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "processing");
+    }
+
+    #[tokio::test]
+    async fn composite_state_falls_back_to_the_parent_on_an_unhandled_event() {
+        let mut substate: StateMachine<()> = StateMachine::new("packing");
+        substate.add_transition("packing", "shipped_internally", "pack");
+
+        let mut machine: StateMachine<()> = StateMachine::new("pending");
+        machine.add_composite_state("processing", substate, None, None);
+        machine.add_transition("pending", "processing", "accept");
+        machine.add_transition("processing", "cancelled", "cancel");
+
+        machine.fire("accept", ()).await.unwrap();
+        machine.fire("cancel", ()).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "cancelled");
+    }
+
+    #[tokio::test]
+    async fn composite_state_propagates_a_substate_guard_rejection() {
+        let mut substate: StateMachine<bool> = StateMachine::new("packing");
+        substate.add_transition_with_guard(
+            "packing",
+            "shipped_internally",
+            "pack",
+            Guard::new("has_stock", |has_stock: &bool| *has_stock),
+        );
+
+        let mut machine: StateMachine<bool> = StateMachine::new("pending");
+        machine.add_composite_state("processing", substate, None, None);
+        machine.add_transition("pending", "processing", "accept");
+
+        machine.fire("accept", true).await.unwrap();
+        let err = machine.fire("pack", false).await.unwrap_err();
+
+        assert!(matches!(err, TransitionError::GuardRejected { .. }));
+        assert_eq!(machine.current_state().as_str(), "processing");
+    }
+
+    #[tokio::test]
+    async fn snapshot_and_restore_round_trip_state_and_history() {
+        let mut machine: StateMachine<u32> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+        machine.fire("submit", 7).await.unwrap();
+
+        let snapshot = machine.snapshot();
+
+        let mut restored: StateMachine<u32> = StateMachine::new("draft");
+        restored.add_transition("draft", "review", "submit");
+        restored.restore(snapshot);
+
+        assert_eq!(restored.current_state().as_str(), "review");
+        assert_eq!(restored.history().len(), 1);
+    }
+
+    struct InMemoryRepository<D> {
+        snapshot: Mutex<Option<StateMachineSnapshot<D>>>,
+    }
+
+    impl<D> InMemoryRepository<D> {
+        fn new() -> Self {
+            Self {
+                snapshot: Mutex::new(None),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<D: Clone + Send + Sync + 'static> Repository<D> for InMemoryRepository<D> {
+        async fn save(&self, snapshot: StateMachineSnapshot<D>) -> Result<(), TransitionError> {
+            *self.snapshot.lock().unwrap() = Some(snapshot);
+            Ok(())
+        }
+
+        async fn load(&self) -> Result<Option<StateMachineSnapshot<D>>, TransitionError> {
+            Ok(self.snapshot.lock().unwrap().clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn persistent_state_machine_saves_a_snapshot_after_each_fire() {
+        let mut machine: StateMachine<u32> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+        let repository = Arc::new(InMemoryRepository::new());
+        let persistent = PersistentStateMachine::new(machine, repository.clone());
+
+        persistent.fire("submit", 9).await.unwrap();
+
+        let snapshot = repository.load().await.unwrap().unwrap();
+        assert_eq!(snapshot.current.as_str(), "review");
+        assert_eq!(snapshot.history.len(), 1);
+        assert_eq!(snapshot.history[0].data, 9);
+    }
+
+    #[tokio::test]
+    async fn persistent_state_machine_restores_a_saved_snapshot() {
+        let mut first: StateMachine<u32> = StateMachine::new("draft");
+        first.add_transition("draft", "review", "submit");
+        let repository = Arc::new(InMemoryRepository::new());
+        let first_persistent = PersistentStateMachine::new(first, repository.clone());
+        first_persistent.fire("submit", 3).await.unwrap();
+
+        let mut second: StateMachine<u32> = StateMachine::new("draft");
+        second.add_transition("draft", "review", "submit");
+        let second_persistent = PersistentStateMachine::new(second, repository);
+        second_persistent.restore().await.unwrap();
+
+        assert_eq!(second_persistent.current_state().as_str(), "review");
+        assert_eq!(second_persistent.history().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn persistent_state_machine_restore_is_a_no_op_with_nothing_saved() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+        let repository = Arc::new(InMemoryRepository::new());
+        let persistent = PersistentStateMachine::new(machine, repository);
+
+        persistent.restore().await.unwrap();
+
+        assert_eq!(persistent.current_state().as_str(), "draft");
+    }
+
+    #[tokio::test]
+    async fn trigger_and_can_trigger_alias_fire_and_can_fire() {
+        let mut machine: StateMachine<()> = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+
+        assert!(machine.can_trigger("submit"));
+        machine.trigger("submit", ()).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "review");
+    }
+
+    #[tokio::test]
+    async fn builder_builds_a_machine_with_registered_states_and_transitions() {
+        let machine: StateMachine<()> = StateMachineBuilder::new("draft")
+            .state("review", None, None)
+            .transition("draft", "review", "submit")
+            .build()
+            .unwrap();
+
+        machine.trigger("submit", ()).await.unwrap();
+
+        assert_eq!(machine.current_state().as_str(), "review");
+    }
+
+    #[test]
+    fn builder_rejects_a_transition_to_an_unregistered_state() {
+        let err = match StateMachineBuilder::<()>::new("draft")
+            .transition("draft", "review", "submit")
+            .build()
+        {
+            Err(err) => err,
+            Ok(_) => panic!("expected UnknownState"),
+        };
+
+        match err {
+            TransitionError::UnknownState { state } => assert_eq!(state.as_str(), "review"),
+            other => panic!("expected UnknownState, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn builder_rejects_a_transition_from_an_unregistered_state() {
+        let err = match StateMachineBuilder::<()>::new("draft")
+            .state("review", None, None)
+            .transition("submitted", "review", "approve")
+            .build()
+        {
+            Err(err) => err,
+            Ok(_) => panic!("expected UnknownState"),
+        };
+
+        match err {
+            TransitionError::UnknownState { state } => assert_eq!(state.as_str(), "submitted"),
+            other => panic!("expected UnknownState, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn to_dot_renders_an_edge_for_each_transition() {
+        let mut machine: StateMachine<bool> = StateMachine::new("draft");
+        machine.add_transition_with_guard(
+            "draft",
+            "review",
+            "submit",
+            Guard::new("is_complete", |complete: &bool| *complete),
+        );
+
+        let dot = machine.to_dot();
+
+        assert!(dot.starts_with("digraph state_machine {\n"));
+        assert!(dot.contains("\"draft\" -> \"review\" [label=\"submit [is_complete]\"];"));
+    }
+
+    #[test]
+    fn to_dot_renders_a_composite_state_as_a_cluster_subgraph() {
+        let mut substate: StateMachine<()> = StateMachine::new("packing");
+        substate.add_transition("packing", "shipped_internally", "pack");
+
+        let mut machine: StateMachine<()> = StateMachine::new("pending");
+        machine.add_composite_state("processing", substate, None, None);
+        machine.add_transition("pending", "processing", "accept");
+
+        let dot = machine.to_dot();
+
+        assert!(dot.contains("subgraph \"cluster_processing\""));
+        assert!(dot.contains("\"packing\" -> \"shipped_internally\" [label=\"pack\"];"));
+        assert!(dot.contains("\"pending\" -> \"processing\" [label=\"accept\"];"));
+    }
+}
@@ -0,0 +1,5 @@
+//! Implementation details for the state machine module.
+
+pub mod event_sourced;
+pub mod fsm;
+pub mod visualize;
@@ -0,0 +1,268 @@
+//! Exports a [`StateMachine`]'s states and transitions in Graphviz DOT,
+//! Mermaid, or PlantUML, optionally overlaid with a live machine's
+//! runtime data (current state, per-transition fire counts, last error)
+//! — enough to embed a live workflow diagram in the diagnostics endpoint.
+
+use std::collections::HashMap;
+
+use crate::api::{Event, State, TransitionView};
+use crate::core::fsm::StateMachine;
+
+/// A live machine's runtime data to overlay onto an exported diagram.
+struct Overlay {
+    current: State,
+    transition_counts: HashMap<(State, State, Event), u64>,
+    last_error: Option<String>,
+}
+
+fn overlay_for<D: Clone + Send + 'static>(machine: &StateMachine<D>) -> Overlay {
+    let mut transition_counts = HashMap::new();
+    for record in machine.history() {
+        *transition_counts.entry((record.from, record.to, record.event)).or_insert(0) += 1;
+    }
+    Overlay {
+        current: machine.current_state(),
+        transition_counts,
+        last_error: machine.last_error().map(|error| error.to_string()),
+    }
+}
+
+/// Renders a [`StateMachine`] as Graphviz DOT, Mermaid, or PlantUML, with
+/// an optional overlay of a live machine's current state, transition
+/// counts, and last error.
+///
+/// ```
+/// use rustboot_statemachine::{StateMachine, StateMachineVisualizer};
+///
+/// let mut machine: StateMachine<()> = StateMachine::new("draft");
+/// machine.add_transition("draft", "review", "submit");
+///
+/// let mermaid = StateMachineVisualizer::new().to_mermaid(&machine);
+/// assert!(mermaid.contains("draft --> review"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StateMachineVisualizer;
+
+impl StateMachineVisualizer {
+    /// Creates a visualizer. Stateless: every render call takes the
+    /// machine to render.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Renders `machine` as a Graphviz DOT digraph. Composite states are
+    /// rendered as a labeled cluster subgraph containing their
+    /// substate's own transitions.
+    pub fn to_dot<D: Clone + Send + 'static>(&self, machine: &StateMachine<D>) -> String {
+        machine.to_dot()
+    }
+
+    /// As [`StateMachineVisualizer::to_dot`], but the current state's
+    /// node is highlighted, each edge is labeled with how many times it
+    /// has fired, and the last error (if any) is rendered as a comment.
+    ///
+    /// Fire counts come from `machine`'s own history, which does not
+    /// record transitions fired inside a composite state's substate, so
+    /// the edges in a composite state's cluster always show `(0x)`.
+    pub fn to_dot_with_overlay<D: Clone + Send + 'static>(&self, machine: &StateMachine<D>) -> String {
+        let overlay = overlay_for(machine);
+        let mut dot = String::from("digraph state_machine {\n");
+        for (name, views) in machine.composite_transition_views() {
+            dot.push_str(&format!("  subgraph \"cluster_{name}\" {{\n"));
+            dot.push_str(&format!("    label=\"{name}\";\n"));
+            for view in &views {
+                dot.push_str(&dot_edge_line(view, &overlay, "    "));
+            }
+            dot.push_str("  }\n");
+        }
+        for view in machine.transition_views() {
+            dot.push_str(&dot_edge_line(&view, &overlay, "  "));
+        }
+        dot.push_str(&format!(
+            "  \"{}\" [style=filled, fillcolor=lightgreen];\n",
+            overlay.current
+        ));
+        if let Some(error) = &overlay.last_error {
+            dot.push_str(&format!("  // last error: {error}\n"));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders `machine` as a Mermaid `stateDiagram-v2`.
+    pub fn to_mermaid<D: Clone + Send + 'static>(&self, machine: &StateMachine<D>) -> String {
+        let mut mermaid = String::from("stateDiagram-v2\n");
+        for view in machine.transition_views() {
+            mermaid.push_str(&mermaid_edge_line(&view, None, "    "));
+        }
+        for (name, views) in machine.composite_transition_views() {
+            mermaid.push_str(&format!("    state {name} {{\n"));
+            for view in &views {
+                mermaid.push_str(&mermaid_edge_line(view, None, "        "));
+            }
+            mermaid.push_str("    }\n");
+        }
+        mermaid
+    }
+
+    /// As [`StateMachineVisualizer::to_mermaid`], but the current state
+    /// is marked and each edge is labeled with its fire count (counted
+    /// as in [`StateMachineVisualizer::to_dot_with_overlay`]).
+    pub fn to_mermaid_with_overlay<D: Clone + Send + 'static>(&self, machine: &StateMachine<D>) -> String {
+        let overlay = overlay_for(machine);
+        let mut mermaid = String::from("stateDiagram-v2\n");
+        for view in machine.transition_views() {
+            mermaid.push_str(&mermaid_edge_line(&view, Some(&overlay), "    "));
+        }
+        for (name, views) in machine.composite_transition_views() {
+            mermaid.push_str(&format!("    state {name} {{\n"));
+            for view in &views {
+                mermaid.push_str(&mermaid_edge_line(view, Some(&overlay), "        "));
+            }
+            mermaid.push_str("    }\n");
+        }
+        mermaid.push_str(&format!("    state \"{}\" as current_state\n", overlay.current));
+        mermaid.push_str(&format!("    note right of {}: current state\n", overlay.current));
+        if let Some(error) = &overlay.last_error {
+            mermaid.push_str(&format!("    note left of {}: last error: {error}\n", overlay.current));
+        }
+        mermaid
+    }
+
+    /// Renders `machine` as a PlantUML state diagram.
+    pub fn to_plantuml<D: Clone + Send + 'static>(&self, machine: &StateMachine<D>) -> String {
+        let mut plantuml = String::from("@startuml\n");
+        for view in machine.transition_views() {
+            plantuml.push_str(&plantuml_edge_line(&view, None));
+        }
+        for (name, views) in machine.composite_transition_views() {
+            plantuml.push_str(&format!("state {name} {{\n"));
+            for view in &views {
+                plantuml.push_str(&plantuml_edge_line(view, None));
+            }
+            plantuml.push_str("}\n");
+        }
+        plantuml.push_str("@enduml\n");
+        plantuml
+    }
+
+    /// As [`StateMachineVisualizer::to_plantuml`], but the current state
+    /// is highlighted, each edge is labeled with its fire count (counted
+    /// as in [`StateMachineVisualizer::to_dot_with_overlay`]), and the
+    /// last error (if any) is attached as a note.
+    pub fn to_plantuml_with_overlay<D: Clone + Send + 'static>(&self, machine: &StateMachine<D>) -> String {
+        let overlay = overlay_for(machine);
+        let mut plantuml = String::from("@startuml\n");
+        for view in machine.transition_views() {
+            plantuml.push_str(&plantuml_edge_line(&view, Some(&overlay)));
+        }
+        for (name, views) in machine.composite_transition_views() {
+            plantuml.push_str(&format!("state {name} {{\n"));
+            for view in &views {
+                plantuml.push_str(&plantuml_edge_line(view, Some(&overlay)));
+            }
+            plantuml.push_str("}\n");
+        }
+        plantuml.push_str(&format!("state \"{}\" as current_state #LightGreen\n", overlay.current));
+        if let Some(error) = &overlay.last_error {
+            plantuml.push_str(&format!("note right of {} : last error: {error}\n", overlay.current));
+        }
+        plantuml.push_str("@enduml\n");
+        plantuml
+    }
+}
+
+fn edge_label(view: &TransitionView) -> String {
+    let mut label = view.event.to_string();
+    for guard in &view.guards {
+        label.push_str(&format!(" [{guard}]"));
+    }
+    label
+}
+
+fn transition_count(overlay: &Overlay, view: &TransitionView) -> u64 {
+    overlay
+        .transition_counts
+        .get(&(view.from.clone(), view.to.clone(), view.event.clone()))
+        .copied()
+        .unwrap_or(0)
+}
+
+fn dot_edge_line(view: &TransitionView, overlay: &Overlay, indent: &str) -> String {
+    let count = transition_count(overlay, view);
+    format!(
+        "{indent}\"{}\" -> \"{}\" [label=\"{} ({count}x)\"];\n",
+        view.from,
+        view.to,
+        edge_label(view)
+    )
+}
+
+fn mermaid_edge_line(view: &TransitionView, overlay: Option<&Overlay>, indent: &str) -> String {
+    match overlay {
+        Some(overlay) => {
+            let count = transition_count(overlay, view);
+            format!("{indent}{} --> {}: {} ({count}x)\n", view.from, view.to, edge_label(view))
+        }
+        None => format!("{indent}{} --> {}: {}\n", view.from, view.to, edge_label(view)),
+    }
+}
+
+fn plantuml_edge_line(view: &TransitionView, overlay: Option<&Overlay>) -> String {
+    match overlay {
+        Some(overlay) => {
+            let count = transition_count(overlay, view);
+            format!("{} --> {} : {} ({count}x)\n", view.from, view.to, edge_label(view))
+        }
+        None => format!("{} --> {} : {}\n", view.from, view.to, edge_label(view)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fsm::StateMachine;
+
+    fn machine() -> StateMachine<()> {
+        let mut machine = StateMachine::new("draft");
+        machine.add_transition("draft", "review", "submit");
+        machine.add_transition("review", "approved", "approve");
+        machine
+    }
+
+    #[test]
+    fn to_mermaid_renders_every_transition() {
+        let mermaid = StateMachineVisualizer::new().to_mermaid(&machine());
+        assert!(mermaid.starts_with("stateDiagram-v2\n"));
+        assert!(mermaid.contains("draft --> review: submit"));
+        assert!(mermaid.contains("review --> approved: approve"));
+    }
+
+    #[test]
+    fn to_plantuml_renders_every_transition() {
+        let plantuml = StateMachineVisualizer::new().to_plantuml(&machine());
+        assert!(plantuml.starts_with("@startuml\n"));
+        assert!(plantuml.ends_with("@enduml\n"));
+        assert!(plantuml.contains("draft --> review : submit"));
+    }
+
+    #[tokio::test]
+    async fn overlay_variants_report_current_state_transition_counts_and_last_error() {
+        let machine = machine();
+        machine.fire("submit", ()).await.unwrap();
+        machine.fire("bogus", ()).await.unwrap_err();
+
+        let dot = StateMachineVisualizer::new().to_dot_with_overlay(&machine);
+        assert!(dot.contains("\"draft\" -> \"review\" [label=\"submit (1x)\"];"));
+        assert!(dot.contains("\"review\" [style=filled, fillcolor=lightgreen];"));
+        assert!(dot.contains("last error"));
+
+        let mermaid = StateMachineVisualizer::new().to_mermaid_with_overlay(&machine);
+        assert!(mermaid.contains("draft --> review: submit (1x)"));
+        assert!(mermaid.contains("current state"));
+
+        let plantuml = StateMachineVisualizer::new().to_plantuml_with_overlay(&machine);
+        assert!(plantuml.contains("draft --> review : submit (1x)"));
+        assert!(plantuml.contains("#LightGreen"));
+    }
+}
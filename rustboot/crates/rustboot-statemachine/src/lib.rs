@@ -0,0 +1,45 @@
+//! Finite state machine building blocks for the rustboot framework.
+//!
+//! - [`StateMachine`]: states and transitions keyed by name, with
+//!   per-transition [`Guard`]s, async transition actions, and async
+//!   per-state entry/exit callbacks.
+//! - [`TransitionError`]: reports exactly what stopped a `fire` call —
+//!   no matching transition, a named guard's rejection, or which
+//!   callback failed.
+//! - [`StateMachine::add_composite_state`]: nests a sub-[`StateMachine`]
+//!   inside a state, so an event unhandled by the substate bubbles up to
+//!   the parent machine's own transitions.
+//! - [`PersistentStateMachine`]: wraps a [`StateMachine`] so it snapshots
+//!   its state and history to a pluggable [`spi::Repository`] after
+//!   every transition, and can restore one on startup.
+//! - [`StateMachineBuilder`]: a fluent, validated alternative to
+//!   [`StateMachine`]'s direct `add_state`/`add_transition` methods —
+//!   `build()` rejects a transition that references a state nobody
+//!   registered. Produces `trigger`/`can_trigger` machines and a
+//!   [`StateMachine::to_dot`] export for any DOT-compatible visualizer.
+//! - [`EventSourcedMachine`]: derives its current state by folding a
+//!   stream of domain events read from a pluggable [`spi::EventSource`]
+//!   (a `rustboot-messaging` consumer or a database event table),
+//!   rather than firing transitions against a fixed graph, with
+//!   periodic snapshotting to a [`spi::SnapshotStore`] and an
+//!   [`EventSourcedMachine::replay`] API for debugging.
+//! - [`StateMachineVisualizer`]: exports a machine as DOT, Mermaid, or
+//!   PlantUML, with an optional overlay of a live machine's current
+//!   state, per-transition fire counts, and last error — enough to embed
+//!   a live workflow diagram in a diagnostics endpoint.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{
+    Event, EventEnvelope, EventSnapshot, State, StateMachineSnapshot, TransitionError,
+    TransitionRecord, TransitionView,
+};
+pub use core::event_sourced::EventSourcedMachine;
+pub use core::fsm::{
+    Guard, PersistentStateMachine, StateCallback, StateMachine, StateMachineBuilder,
+    TransitionAction,
+};
+pub use core::visualize::StateMachineVisualizer;
+pub use spi::{EventSource, Repository, SnapshotStore};
@@ -0,0 +1,41 @@
+//! Service provider interfaces for the state machine module.
+
+use async_trait::async_trait;
+
+use crate::api::{EventEnvelope, EventSnapshot, StateMachineSnapshot, TransitionError};
+
+/// Persists and restores a [`crate::core::StateMachine`]'s state and
+/// history, so a [`crate::core::PersistentStateMachine`] can survive
+/// process restarts. Implement this over a database, file, or object
+/// store.
+#[async_trait]
+pub trait Repository<D>: Send + Sync {
+    /// Persists `snapshot`, replacing any previously saved snapshot.
+    async fn save(&self, snapshot: StateMachineSnapshot<D>) -> Result<(), TransitionError>;
+
+    /// Loads the most recently saved snapshot, if one exists.
+    async fn load(&self) -> Result<Option<StateMachineSnapshot<D>>, TransitionError>;
+}
+
+/// A source of domain events for an
+/// [`crate::core::event_sourced::EventSourcedMachine`] to fold.
+/// Implement this over a `rustboot-messaging` consumer or a database
+/// event table.
+#[async_trait]
+pub trait EventSource<E>: Send + Sync {
+    /// Returns every event recorded after `sequence`, oldest first.
+    async fn events_since(&self, sequence: u64) -> Result<Vec<EventEnvelope<E>>, TransitionError>;
+}
+
+/// Persists and restores an
+/// [`crate::core::event_sourced::EventSourcedMachine`]'s folded state,
+/// so it doesn't need to replay its entire event history on every
+/// restart.
+#[async_trait]
+pub trait SnapshotStore<S>: Send + Sync {
+    /// Persists `snapshot`, replacing any previously saved snapshot.
+    async fn save_snapshot(&self, snapshot: EventSnapshot<S>) -> Result<(), TransitionError>;
+
+    /// Loads the most recently saved snapshot, if one exists.
+    async fn load_snapshot(&self) -> Result<Option<EventSnapshot<S>>, TransitionError>;
+}
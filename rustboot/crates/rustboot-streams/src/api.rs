@@ -0,0 +1,93 @@
+//! Public types for the streams module.
+
+use std::sync::Arc;
+
+/// A single message yielded by [`crate::core::EventReceiver::recv`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamItem<T> {
+    /// An application-level event.
+    Item(T),
+    /// The producer finished normally. No further [`StreamItem::Item`]s
+    /// follow. Carries an optional terminal event (e.g. a summary or
+    /// final result), set via
+    /// [`crate::core::EventSender::complete`]/[`crate::core::SendGuard::complete`].
+    Complete(Option<T>),
+}
+
+/// The error returned when sending on a stream whose receiver has been
+/// dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("stream receiver has been dropped")]
+pub struct SendError;
+
+/// What a [`crate::core::bus::BroadcastStream`] does when it falls far
+/// enough behind [`crate::core::bus::EventBus::publish`] that the
+/// broadcast channel's ring buffer overwrites events it hasn't read yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Skip past the overwritten events and resume receiving from the
+    /// oldest one still buffered, recording the gap as a metric.
+    #[default]
+    DropOldest,
+    /// Surface the gap to the subscriber as [`BusError::Lagged`] instead
+    /// of silently skipping past it.
+    Error,
+}
+
+/// Errors from publishing to or receiving from a
+/// [`crate::core::bus::EventBus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum BusError {
+    /// A [`crate::core::bus::BroadcastStream`] with
+    /// [`OverflowPolicy::Error`] fell behind and missed this many events,
+    /// overwritten in the channel's ring buffer before it could read
+    /// them.
+    #[error("subscriber lagged behind and missed {0} events")]
+    Lagged(u64),
+}
+
+/// What [`crate::core::channel::EventSender::send`] does when the
+/// channel is at capacity and the receiver hasn't caught up.
+///
+/// Only [`OverflowStrategy::Block`] can make `send` wait; the others
+/// resolve immediately by discarding or replacing a buffered item
+/// instead, for producers that would rather lose data than stall.
+pub enum OverflowStrategy<T> {
+    /// Wait for room, same as an unconfigured
+    /// [`crate::core::channel::channel`].
+    Block,
+    /// Discard the incoming event, keeping everything already buffered.
+    DropNewest,
+    /// Discard the oldest buffered event to make room for the incoming
+    /// one.
+    DropOldest,
+    /// Key buffered events with the given function; an incoming event
+    /// replaces the buffered one with the same key instead of taking a
+    /// new slot. Falls back to [`OverflowStrategy::DropOldest`] when the
+    /// incoming event's key isn't already buffered and there's no room
+    /// left.
+    CoalesceByKey(Arc<dyn Fn(&T) -> u64 + Send + Sync>),
+}
+
+impl<T> Clone for OverflowStrategy<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Block => Self::Block,
+            Self::DropNewest => Self::DropNewest,
+            Self::DropOldest => Self::DropOldest,
+            Self::CoalesceByKey(key_fn) => Self::CoalesceByKey(key_fn.clone()),
+        }
+    }
+}
+
+/// The error returned by
+/// [`crate::core::channel::EventSender::send_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum SendTimeoutError {
+    /// The receiver was dropped before the send could complete.
+    #[error("stream receiver has been dropped")]
+    Closed,
+    /// The timeout elapsed before there was room to send.
+    #[error("timed out waiting for room to send")]
+    Timeout,
+}
@@ -0,0 +1,172 @@
+//! A fan-out event bus for delivering every event to every subscriber,
+//! built on `tokio::sync::broadcast`.
+
+use tokio::sync::broadcast;
+
+use crate::api::{BusError, OverflowPolicy, SendError};
+
+fn record_lag(bus: &str, skipped: u64) {
+    rustboot_observability::observe_histogram(
+        "event_bus_lagged_events",
+        &[("bus", bus)],
+        skipped as f64,
+    );
+}
+
+/// A named, fan-out event bus: every event [`EventBus::publish`]ed is
+/// delivered to every [`EventBus::subscribe`]r, independently of how
+/// fast each one reads, unlike [`crate::core::channel::channel`]'s
+/// single-consumer queue.
+///
+/// A subscriber that falls behind far enough for the broadcast ring
+/// buffer to overwrite events it hasn't read yet is handled per the
+/// bus's [`OverflowPolicy`].
+pub struct EventBus<T> {
+    name: String,
+    sender: broadcast::Sender<T>,
+    policy: OverflowPolicy,
+}
+
+impl<T: Clone> EventBus<T> {
+    /// Creates a bus with room for `capacity` unread events per
+    /// subscriber before the oldest is overwritten. `name` identifies
+    /// this bus in the `event_bus_lagged_events` metric recorded for
+    /// [`OverflowPolicy::DropOldest`] subscribers.
+    pub fn new(name: impl Into<String>, capacity: usize, policy: OverflowPolicy) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self {
+            name: name.into(),
+            sender,
+            policy,
+        }
+    }
+
+    /// Publishes an event to every current subscriber.
+    ///
+    /// Returns [`SendError`] if there are no active subscribers — a
+    /// published event is otherwise silently dropped once every
+    /// subscriber has read past it or been dropped.
+    pub fn publish(&self, event: T) -> Result<usize, SendError> {
+        self.sender.send(event).map_err(|_| SendError)
+    }
+
+    /// Subscribes to this bus. The returned stream only receives events
+    /// published after this call.
+    pub fn subscribe(&self) -> BroadcastStream<T> {
+        BroadcastStream {
+            bus_name: self.name.clone(),
+            receiver: self.sender.subscribe(),
+            policy: self.policy,
+        }
+    }
+
+    /// Returns the number of active subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+/// A single subscriber's view of an [`EventBus`].
+pub struct BroadcastStream<T> {
+    bus_name: String,
+    receiver: broadcast::Receiver<T>,
+    policy: OverflowPolicy,
+}
+
+impl<T: Clone> BroadcastStream<T> {
+    /// Receives the next event, applying this subscriber's
+    /// [`OverflowPolicy`] if it has lagged behind the publisher.
+    ///
+    /// Returns `Ok(None)` once the bus has been dropped and every
+    /// already-published event has been received.
+    pub async fn recv(&mut self) -> Result<Option<T>, BusError> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) => return Ok(Some(event)),
+                Err(broadcast::error::RecvError::Closed) => return Ok(None),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    record_lag(&self.bus_name, skipped);
+                    match self.policy {
+                        OverflowPolicy::DropOldest => continue,
+                        OverflowPolicy::Error => return Err(BusError::Lagged(skipped)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn every_subscriber_receives_every_event() {
+        let bus = EventBus::new("test", 8, OverflowPolicy::DropOldest);
+        let mut a = bus.subscribe();
+        let mut b = bus.subscribe();
+
+        bus.publish(1).unwrap();
+        bus.publish(2).unwrap();
+
+        assert_eq!(a.recv().await, Ok(Some(1)));
+        assert_eq!(a.recv().await, Ok(Some(2)));
+        assert_eq!(b.recv().await, Ok(Some(1)));
+        assert_eq!(b.recv().await, Ok(Some(2)));
+    }
+
+    #[tokio::test]
+    async fn publish_errors_with_no_subscribers() {
+        let bus = EventBus::new("test", 8, OverflowPolicy::DropOldest);
+        assert_eq!(bus.publish(1), Err(SendError));
+    }
+
+    #[tokio::test]
+    async fn recv_returns_none_once_the_bus_is_dropped_and_drained() {
+        let bus = EventBus::new("test", 8, OverflowPolicy::DropOldest);
+        let mut sub = bus.subscribe();
+        bus.publish(1).unwrap();
+        drop(bus);
+
+        assert_eq!(sub.recv().await, Ok(Some(1)));
+        assert_eq!(sub.recv().await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_policy_skips_past_a_lag_and_keeps_receiving() {
+        let bus = EventBus::new("test", 2, OverflowPolicy::DropOldest);
+        let mut sub = bus.subscribe();
+
+        for event in 1..=5 {
+            bus.publish(event).unwrap();
+        }
+
+        assert_eq!(sub.recv().await, Ok(Some(4)));
+        assert_eq!(sub.recv().await, Ok(Some(5)));
+    }
+
+    #[tokio::test]
+    async fn error_policy_surfaces_a_lag_instead_of_skipping_past_it() {
+        let bus = EventBus::new("test", 2, OverflowPolicy::Error);
+        let mut sub = bus.subscribe();
+
+        for event in 1..=5 {
+            bus.publish(event).unwrap();
+        }
+
+        assert_eq!(sub.recv().await, Err(BusError::Lagged(3)));
+        assert_eq!(sub.recv().await, Ok(Some(4)));
+    }
+
+    #[tokio::test]
+    async fn subscriber_count_tracks_active_subscriptions() {
+        let bus = EventBus::<i32>::new("test", 8, OverflowPolicy::DropOldest);
+        assert_eq!(bus.subscriber_count(), 0);
+
+        let sub = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 1);
+
+        drop(sub);
+        assert_eq!(bus.subscriber_count(), 0);
+    }
+}
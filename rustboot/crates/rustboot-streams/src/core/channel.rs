@@ -0,0 +1,589 @@
+//! The event channel itself.
+//!
+//! [`EventSender`]/[`EventReceiver`] share a bounded queue guarded by a
+//! [`std::sync::Mutex`], with an explicit completion signal, so a
+//! consumer draining the channel to its end can tell a producer that
+//! finished cleanly (observed [`StreamItem::Complete`]) from one that
+//! crashed or was dropped mid-stream (the channel just closed). A
+//! [`named_channel`] additionally takes an [`OverflowStrategy`] for
+//! producers that would rather drop or replace buffered events than
+//! block when the consumer falls behind, and reports its depth on the
+//! `event_stream_queue_depth` gauge.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{oneshot, watch, Notify};
+
+use crate::api::{OverflowStrategy, SendError, SendTimeoutError, StreamItem};
+
+enum WireMessage<T> {
+    Item(T),
+    Complete(Option<T>),
+    Flush(oneshot::Sender<()>),
+}
+
+struct QueueState<T> {
+    messages: VecDeque<WireMessage<T>>,
+    item_count: usize,
+    closed: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<QueueState<T>>,
+    capacity: usize,
+    overflow: OverflowStrategy<T>,
+    not_empty: Notify,
+    not_full: Notify,
+    completed: AtomicBool,
+    receiver_alive: watch::Sender<bool>,
+    name: Option<String>,
+    sender_count: AtomicUsize,
+}
+
+fn record_depth<T>(shared: &Shared<T>) {
+    if let Some(name) = &shared.name {
+        let depth = shared.state.lock().unwrap().item_count as f64;
+        rustboot_observability::record_gauge("event_stream_queue_depth", &[("stream", name)], depth);
+    }
+}
+
+fn build_channel<T>(
+    name: Option<String>,
+    capacity: usize,
+    overflow: OverflowStrategy<T>,
+) -> (EventSender<T>, EventReceiver<T>) {
+    let (receiver_alive, _) = watch::channel(true);
+    let shared = Arc::new(Shared {
+        state: Mutex::new(QueueState {
+            messages: VecDeque::new(),
+            item_count: 0,
+            closed: false,
+        }),
+        capacity,
+        overflow,
+        not_empty: Notify::new(),
+        not_full: Notify::new(),
+        completed: AtomicBool::new(false),
+        receiver_alive,
+        name,
+        sender_count: AtomicUsize::new(1),
+    });
+    (
+        EventSender {
+            shared: shared.clone(),
+        },
+        EventReceiver { shared },
+    )
+}
+
+/// Creates a bounded event stream, returning the sending and receiving
+/// halves. `capacity` is the channel's buffer size. A send blocks while
+/// the channel is at capacity; use [`named_channel`] for a producer that
+/// should drop or replace events instead of waiting.
+pub fn channel<T>(capacity: usize) -> (EventSender<T>, EventReceiver<T>) {
+    build_channel(None, capacity, OverflowStrategy::Block)
+}
+
+/// As [`channel`], but with an explicit [`OverflowStrategy`] for when
+/// the channel is at capacity, and a `name` used to label the
+/// `event_stream_queue_depth` gauge this channel reports.
+pub fn named_channel<T>(
+    name: impl Into<String>,
+    capacity: usize,
+    overflow: OverflowStrategy<T>,
+) -> (EventSender<T>, EventReceiver<T>) {
+    build_channel(Some(name.into()), capacity, overflow)
+}
+
+/// The sending half of an event stream.
+///
+/// Cloning an `EventSender` shares the underlying channel and the
+/// completion flag: completing the stream through any clone marks it
+/// complete for all of them.
+pub struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_count.fetch_add(1, Ordering::AcqRel);
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        if self.shared.sender_count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.state.lock().unwrap().closed = true;
+            self.shared.not_empty.notify_one();
+        }
+    }
+}
+
+impl<T> EventSender<T> {
+    /// Sends an application event.
+    ///
+    /// With [`OverflowStrategy::Block`] (the default, via [`channel`]),
+    /// waits for room if the channel is at capacity. The other
+    /// strategies never wait: they drop or replace a buffered event
+    /// instead, so this always resolves immediately.
+    ///
+    /// Returns [`SendError`] once the stream has already been completed
+    /// via [`EventSender::complete`]/[`EventSender::close`], or once the
+    /// receiver has been dropped.
+    pub async fn send(&self, event: T) -> Result<(), SendError> {
+        if self.shared.completed.load(Ordering::Acquire) {
+            return Err(SendError);
+        }
+        match self.shared.overflow {
+            OverflowStrategy::Block => self.send_blocking(event).await,
+            _ => {
+                self.push_overflow_aware(event);
+                Ok(())
+            }
+        }
+    }
+
+    /// As [`EventSender::send`], but gives up and returns
+    /// [`SendTimeoutError::Timeout`] instead of waiting past `timeout`
+    /// for room. Only meaningful with [`OverflowStrategy::Block`]; the
+    /// other strategies never wait, so this always resolves immediately.
+    pub async fn send_timeout(&self, event: T, timeout: Duration) -> Result<(), SendTimeoutError> {
+        match tokio::time::timeout(timeout, self.send(event)).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(SendError)) => Err(SendTimeoutError::Closed),
+            Err(_) => Err(SendTimeoutError::Timeout),
+        }
+    }
+
+    async fn send_blocking(&self, event: T) -> Result<(), SendError> {
+        let mut event = Some(event);
+        loop {
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if state.item_count < self.shared.capacity {
+                    state.messages.push_back(WireMessage::Item(event.take().unwrap()));
+                    state.item_count += 1;
+                    drop(state);
+                    self.shared.not_empty.notify_one();
+                    record_depth(&self.shared);
+                    return Ok(());
+                }
+            }
+
+            let mut receiver_alive = self.shared.receiver_alive.subscribe();
+            if !*receiver_alive.borrow() {
+                return Err(SendError);
+            }
+            tokio::select! {
+                _ = self.shared.not_full.notified() => {}
+                _ = receiver_alive.changed() => {}
+            }
+        }
+    }
+
+    fn push_overflow_aware(&self, event: T) {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.item_count < self.shared.capacity {
+            state.messages.push_back(WireMessage::Item(event));
+            state.item_count += 1;
+        } else {
+            match &self.shared.overflow {
+                OverflowStrategy::Block => unreachable!("Block is handled by send_blocking"),
+                OverflowStrategy::DropNewest => {}
+                OverflowStrategy::DropOldest => {
+                    evict_oldest_item(&mut state.messages);
+                    state.messages.push_back(WireMessage::Item(event));
+                }
+                OverflowStrategy::CoalesceByKey(key_fn) => {
+                    let new_key = key_fn(&event);
+                    let existing = state.messages.iter().position(|message| {
+                        matches!(message, WireMessage::Item(item) if key_fn(item) == new_key)
+                    });
+                    match existing {
+                        Some(pos) => state.messages[pos] = WireMessage::Item(event),
+                        None => {
+                            evict_oldest_item(&mut state.messages);
+                            state.messages.push_back(WireMessage::Item(event));
+                        }
+                    }
+                }
+            }
+        }
+        drop(state);
+        self.shared.not_empty.notify_one();
+        record_depth(&self.shared);
+    }
+
+    /// Marks the stream complete with no terminal event. Equivalent to
+    /// `complete(None)`.
+    pub async fn close(&self) -> Result<(), SendError> {
+        self.complete(None).await
+    }
+
+    /// Marks the stream complete, optionally carrying a terminal event
+    /// (e.g. a summary or final result) that the receiver observes as
+    /// [`StreamItem::Complete`].
+    ///
+    /// Idempotent: completing an already-completed stream is a no-op.
+    pub async fn complete(&self, terminal: Option<T>) -> Result<(), SendError> {
+        if self.shared.completed.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        if !*self.shared.receiver_alive.borrow() {
+            return Err(SendError);
+        }
+        self.push_control(WireMessage::Complete(terminal));
+        Ok(())
+    }
+
+    /// Waits until every event sent before this call has been received
+    /// off the channel.
+    ///
+    /// Does not wait for the receiver to have finished *processing*
+    /// those events, only for it to have dequeued them — enough to
+    /// bound how far a producer can run ahead of a slow consumer.
+    pub async fn flush(&self) -> Result<(), SendError> {
+        if !*self.shared.receiver_alive.borrow() {
+            return Err(SendError);
+        }
+        let (ack_tx, ack_rx) = oneshot::channel();
+        self.push_control(WireMessage::Flush(ack_tx));
+        ack_rx.await.map_err(|_| SendError)
+    }
+
+    fn push_control(&self, message: WireMessage<T>) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.messages.push_back(message);
+        drop(state);
+        self.shared.not_empty.notify_one();
+    }
+
+    /// The channel's name, if created via [`named_channel`].
+    pub fn name(&self) -> Option<&str> {
+        self.shared.name.as_deref()
+    }
+
+    /// The number of items currently buffered in the channel, for
+    /// diagnostics. The same figure reported on the
+    /// `event_stream_queue_depth` gauge for a [`named_channel`].
+    pub fn queue_depth(&self) -> usize {
+        self.shared.state.lock().unwrap().item_count
+    }
+
+    /// Returns a [`SendGuard`] that completes the stream when dropped,
+    /// unless it's already been completed (via this sender, another
+    /// clone, or the guard itself) by then.
+    ///
+    /// Does not guard against a panic: if the owning task unwinds, the
+    /// guard intentionally skips sending a completion signal, so the
+    /// channel simply closes and the receiver observes a crash rather
+    /// than a false "done".
+    pub fn send_guard(&self) -> SendGuard<T> {
+        SendGuard {
+            sender: self.clone(),
+        }
+    }
+}
+
+fn evict_oldest_item<T>(messages: &mut VecDeque<WireMessage<T>>) {
+    if let Some(pos) = messages.iter().position(|message| matches!(message, WireMessage::Item(_))) {
+        messages.remove(pos);
+    }
+}
+
+/// Completes its [`EventSender`] on drop, unless the stream has already
+/// been completed by then — so a producer that returns early (an early
+/// `return`, a `?`, a dropped future) still signals "done" to its
+/// receiver instead of leaving it to infer a crash from a closed
+/// channel.
+///
+/// Best-effort only: since `Drop` can't `.await`, completion from a
+/// guard drop is silently skipped if the receiver has already been
+/// dropped. Call [`EventSender::complete`] directly when you need to
+/// guarantee delivery.
+pub struct SendGuard<T> {
+    sender: EventSender<T>,
+}
+
+impl<T> SendGuard<T> {
+    /// Marks the stream complete, optionally carrying a terminal event.
+    /// Equivalent to calling [`EventSender::complete`] on the
+    /// underlying sender.
+    pub async fn complete(&self, terminal: Option<T>) -> Result<(), SendError> {
+        self.sender.complete(terminal).await
+    }
+}
+
+impl<T> Drop for SendGuard<T> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            return;
+        }
+        if self.sender.shared.completed.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        if *self.sender.shared.receiver_alive.borrow() {
+            self.sender.push_control(WireMessage::Complete(None));
+        }
+    }
+}
+
+/// The receiving half of an event stream.
+pub struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        // `send` is a no-op when nobody has subscribed yet (e.g. no
+        // sender has ever blocked), so use `send_replace` to guarantee
+        // the flag flips regardless of whether anyone is watching it.
+        self.shared.receiver_alive.send_replace(false);
+    }
+}
+
+impl<T> EventReceiver<T> {
+    /// Receives the next item, transparently acknowledging any
+    /// [`EventSender::flush`] calls queued ahead of it.
+    ///
+    /// Returns `None` once the channel is closed without ever having
+    /// seen a [`StreamItem::Complete`] — i.e. the producer was dropped
+    /// (panicked, was cancelled, ...) without signaling completion.
+    pub async fn recv(&mut self) -> Option<StreamItem<T>> {
+        loop {
+            loop {
+                let mut state = self.shared.state.lock().unwrap();
+                match state.messages.pop_front() {
+                    Some(WireMessage::Item(item)) => {
+                        state.item_count -= 1;
+                        drop(state);
+                        self.shared.not_full.notify_one();
+                        record_depth(&self.shared);
+                        return Some(StreamItem::Item(item));
+                    }
+                    Some(WireMessage::Complete(terminal)) => {
+                        return Some(StreamItem::Complete(terminal));
+                    }
+                    Some(WireMessage::Flush(ack)) => {
+                        let _ = ack.send(());
+                    }
+                    None => {
+                        if state.closed {
+                            return None;
+                        }
+                        break;
+                    }
+                }
+            }
+            self.shared.not_empty.notified().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn items_are_received_in_order() {
+        let (tx, mut rx) = channel::<i32>(8);
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(2)));
+    }
+
+    #[tokio::test]
+    async fn queue_depth_reflects_buffered_items() {
+        let (tx, mut rx) = channel::<i32>(8);
+        assert_eq!(tx.queue_depth(), 0);
+
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+        assert_eq!(tx.queue_depth(), 2);
+
+        rx.recv().await;
+        assert_eq!(tx.queue_depth(), 1);
+    }
+
+    #[tokio::test]
+    async fn name_reports_none_for_an_unnamed_channel_and_some_for_a_named_one() {
+        let (tx, _rx) = channel::<i32>(8);
+        assert_eq!(tx.name(), None);
+
+        let (named_tx, _named_rx) = named_channel::<i32>("orders", 8, OverflowStrategy::Block);
+        assert_eq!(named_tx.name(), Some("orders"));
+    }
+
+    #[tokio::test]
+    async fn close_signals_completion_with_no_terminal_event() {
+        let (tx, mut rx) = channel::<i32>(8);
+        tx.send(1).await.unwrap();
+        tx.close().await.unwrap();
+        drop(tx);
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(rx.recv().await, Some(StreamItem::Complete(None)));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn complete_carries_a_terminal_event() {
+        let (tx, mut rx) = channel::<i32>(8);
+        tx.complete(Some(99)).await.unwrap();
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Complete(Some(99))));
+    }
+
+    #[tokio::test]
+    async fn complete_is_idempotent() {
+        let (tx, mut rx) = channel::<i32>(8);
+        tx.complete(Some(1)).await.unwrap();
+        tx.complete(Some(2)).await.unwrap();
+        drop(tx);
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Complete(Some(1))));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn send_after_complete_is_rejected() {
+        let (tx, _rx) = channel::<i32>(8);
+        tx.complete(None).await.unwrap();
+        assert_eq!(tx.send(1).await, Err(SendError));
+    }
+
+    #[tokio::test]
+    async fn a_dropped_sender_with_no_completion_closes_without_a_complete_item() {
+        let (tx, mut rx) = channel::<i32>(8);
+        tx.send(1).await.unwrap();
+        drop(tx);
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn flush_resolves_once_prior_items_are_dequeued() {
+        let (tx, mut rx) = channel::<i32>(8);
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        let recv_task = tokio::spawn(async move {
+            let mut items = Vec::new();
+            while let Some(item) = rx.recv().await {
+                items.push(item);
+            }
+            items
+        });
+
+        tx.flush().await.unwrap();
+        drop(tx);
+
+        let items = recv_task.await.unwrap();
+        assert_eq!(items, vec![StreamItem::Item(1), StreamItem::Item(2)]);
+    }
+
+    #[tokio::test]
+    async fn send_guard_completes_the_stream_on_drop() {
+        let (tx, mut rx) = channel::<i32>(8);
+        {
+            let _guard = tx.send_guard();
+            tx.send(1).await.unwrap();
+        }
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(rx.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn send_guard_defers_to_an_explicit_completion() {
+        let (tx, mut rx) = channel::<i32>(8);
+        {
+            let guard = tx.send_guard();
+            guard.complete(Some(42)).await.unwrap();
+        }
+        drop(tx);
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Complete(Some(42))));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn a_blocked_send_completes_once_the_receiver_makes_room() {
+        let (tx, mut rx) = channel::<i32>(1);
+        tx.send(1).await.unwrap();
+
+        let tx2 = tx.clone();
+        let send_task = tokio::spawn(async move { tx2.send(2).await });
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(1)));
+        send_task.await.unwrap().unwrap();
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(2)));
+    }
+
+    #[tokio::test]
+    async fn a_blocked_send_errors_once_the_receiver_is_dropped() {
+        let (tx, rx) = channel::<i32>(1);
+        tx.send(1).await.unwrap();
+        drop(rx);
+
+        assert_eq!(tx.send(2).await, Err(SendError));
+    }
+
+    #[tokio::test]
+    async fn send_timeout_times_out_while_the_channel_is_full() {
+        let (tx, _rx) = channel::<i32>(1);
+        tx.send(1).await.unwrap();
+
+        assert_eq!(
+            tx.send_timeout(2, Duration::from_millis(10)).await,
+            Err(SendTimeoutError::Timeout)
+        );
+    }
+
+    #[tokio::test]
+    async fn drop_newest_discards_the_incoming_event_when_full() {
+        let (tx, mut rx) = named_channel("test", 1, OverflowStrategy::DropNewest);
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(1)));
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_evicts_the_oldest_buffered_event_when_full() {
+        let (tx, mut rx) = named_channel("test", 1, OverflowStrategy::DropOldest);
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(2)));
+    }
+
+    #[tokio::test]
+    async fn coalesce_by_key_replaces_a_buffered_event_with_the_same_key() {
+        let (tx, mut rx) =
+            named_channel("test", 1, OverflowStrategy::CoalesceByKey(Arc::new(|n: &i32| (*n % 2) as u64)));
+        tx.send(2).await.unwrap();
+        tx.send(4).await.unwrap();
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(4)));
+    }
+
+    #[tokio::test]
+    async fn coalesce_by_key_falls_back_to_drop_oldest_for_an_unseen_key() {
+        let (tx, mut rx) =
+            named_channel("test", 1, OverflowStrategy::CoalesceByKey(Arc::new(|n: &i32| *n as u64)));
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        assert_eq!(rx.recv().await, Some(StreamItem::Item(2)));
+    }
+}
@@ -0,0 +1,333 @@
+//! Aggregating and rate-limiting combinators over an [`EventReceiver`].
+
+use std::time::Duration;
+
+use rustboot_async::CancellationToken;
+use tokio::time::{sleep, Instant};
+
+use super::channel::{channel, EventReceiver};
+use super::window::{SlidingWindow, TumblingWindow};
+use crate::api::StreamItem;
+
+/// Output channel capacity for streams produced by [`EventStreamExt`]
+/// combinators. Each combinator holds back at most one pending item (or
+/// batch) at a time, so a small buffer is enough to avoid needless
+/// backpressure on the downstream consumer.
+pub(crate) const DEFAULT_CAPACITY: usize = 32;
+
+/// Combinators over an [`EventReceiver`] that aggregate or rate-limit
+/// items on a background task, so a consumer that only needs "batches
+/// of events" or "at most one event per interval" doesn't reimplement a
+/// select loop over the raw channel every time.
+///
+/// Each combinator consumes its receiver and spawns a task driving it,
+/// returning a new [`EventReceiver`] fed by that task. A
+/// [`StreamItem::Complete`] from the upstream flushes any item the
+/// combinator is holding back before propagating completion downstream;
+/// a dropped upstream sender closes the new stream the same way it
+/// would have closed the original one.
+pub trait EventStreamExt<T>: Sized {
+    /// Batches items into `Vec<T>` chunks of up to `n` items, flushing
+    /// early if `timeout` elapses since the first item of the current
+    /// chunk without reaching `n`.
+    ///
+    /// The upstream's terminal event, if any, is wrapped in a
+    /// single-item chunk on [`StreamItem::Complete`].
+    fn chunks_timeout(self, n: usize, timeout: Duration) -> EventReceiver<Vec<T>>;
+
+    /// Emits only the most recent item once `delay` has passed without a
+    /// newer one arriving, dropping every item superseded within that
+    /// window.
+    fn debounce(self, delay: Duration) -> EventReceiver<T>;
+
+    /// Emits an item immediately, then drops subsequent items until
+    /// `interval` has elapsed since the last emission.
+    fn throttle(self, interval: Duration) -> EventReceiver<T>;
+
+    /// Groups items into fixed-size, non-overlapping `duration`
+    /// windows measured from when this call returns. Chain
+    /// [`TumblingWindow::aggregate`] to fold each window into a single
+    /// value.
+    fn window_tumbling(self, duration: Duration) -> TumblingWindow<T>;
+
+    /// Groups items into overlapping windows covering the last `size`
+    /// of arrivals, re-evaluated every `slide`. Chain
+    /// [`SlidingWindow::aggregate`] to fold each window into a single
+    /// value.
+    fn window_sliding(self, size: Duration, slide: Duration) -> SlidingWindow<T>;
+
+    /// Forwards items as they arrive until `token` is cancelled, at
+    /// which point the returned stream completes (with no terminal
+    /// event) instead of forwarding whatever the upstream does next.
+    /// Lets a long-lived consumer task join a wider app shutdown instead
+    /// of running until its upstream happens to close.
+    fn take_until_cancelled(self, token: CancellationToken) -> EventReceiver<T>;
+}
+
+impl<T> EventStreamExt<T> for EventReceiver<T>
+where
+    T: Send + 'static,
+{
+    fn chunks_timeout(mut self, n: usize, timeout: Duration) -> EventReceiver<Vec<T>> {
+        assert!(n > 0, "chunks_timeout requires n > 0");
+        let (tx, rx) = channel(DEFAULT_CAPACITY);
+        tokio::spawn(async move {
+            loop {
+                let first = match self.recv().await {
+                    Some(StreamItem::Item(item)) => item,
+                    Some(StreamItem::Complete(terminal)) => {
+                        let _ = tx.complete(terminal.map(|item| vec![item])).await;
+                        return;
+                    }
+                    None => return,
+                };
+
+                let mut chunk = vec![first];
+                let deadline = sleep(timeout);
+                tokio::pin!(deadline);
+
+                while chunk.len() < n {
+                    tokio::select! {
+                        item = self.recv() => match item {
+                            Some(StreamItem::Item(item)) => chunk.push(item),
+                            Some(StreamItem::Complete(terminal)) => {
+                                if tx.send(chunk).await.is_err() {
+                                    return;
+                                }
+                                let _ = tx.complete(terminal.map(|item| vec![item])).await;
+                                return;
+                            }
+                            None => {
+                                let _ = tx.send(chunk).await;
+                                return;
+                            }
+                        },
+                        _ = &mut deadline => break,
+                    }
+                }
+
+                if tx.send(chunk).await.is_err() {
+                    return;
+                }
+            }
+        });
+        rx
+    }
+
+    fn debounce(mut self, delay: Duration) -> EventReceiver<T> {
+        let (tx, rx) = channel(DEFAULT_CAPACITY);
+        tokio::spawn(async move {
+            let mut pending: Option<T> = None;
+            loop {
+                let Some(item) = pending.take() else {
+                    match self.recv().await {
+                        Some(StreamItem::Item(item)) => {
+                            pending = Some(item);
+                            continue;
+                        }
+                        Some(StreamItem::Complete(terminal)) => {
+                            let _ = tx.complete(terminal).await;
+                            return;
+                        }
+                        None => return,
+                    }
+                };
+
+                let deadline = sleep(delay);
+                tokio::pin!(deadline);
+                tokio::select! {
+                    next = self.recv() => match next {
+                        Some(StreamItem::Item(next_item)) => pending = Some(next_item),
+                        Some(StreamItem::Complete(terminal)) => {
+                            if tx.send(item).await.is_err() {
+                                return;
+                            }
+                            let _ = tx.complete(terminal).await;
+                            return;
+                        }
+                        None => {
+                            let _ = tx.send(item).await;
+                            return;
+                        }
+                    },
+                    _ = &mut deadline => {
+                        if tx.send(item).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+        rx
+    }
+
+    fn throttle(mut self, interval: Duration) -> EventReceiver<T> {
+        let (tx, rx) = channel(DEFAULT_CAPACITY);
+        tokio::spawn(async move {
+            let mut last_emitted: Option<Instant> = None;
+            loop {
+                match self.recv().await {
+                    Some(StreamItem::Item(item)) => {
+                        let now = Instant::now();
+                        let ready = last_emitted.is_none_or(|last| now.duration_since(last) >= interval);
+                        if ready {
+                            last_emitted = Some(now);
+                            if tx.send(item).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Some(StreamItem::Complete(terminal)) => {
+                        let _ = tx.complete(terminal).await;
+                        return;
+                    }
+                    None => return,
+                }
+            }
+        });
+        rx
+    }
+
+    fn window_tumbling(self, duration: Duration) -> TumblingWindow<T> {
+        TumblingWindow {
+            receiver: self,
+            duration,
+            grace: Duration::ZERO,
+        }
+    }
+
+    fn window_sliding(self, size: Duration, slide: Duration) -> SlidingWindow<T> {
+        SlidingWindow {
+            receiver: self,
+            size,
+            slide,
+        }
+    }
+
+    fn take_until_cancelled(mut self, token: CancellationToken) -> EventReceiver<T> {
+        let (tx, rx) = channel(DEFAULT_CAPACITY);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    item = self.recv() => match item {
+                        Some(StreamItem::Item(item)) => {
+                            if tx.send(item).await.is_err() {
+                                return;
+                            }
+                        }
+                        Some(StreamItem::Complete(terminal)) => {
+                            let _ = tx.complete(terminal).await;
+                            return;
+                        }
+                        None => return,
+                    },
+                    _ = token.cancelled() => {
+                        let _ = tx.complete(None).await;
+                        return;
+                    }
+                }
+            }
+        });
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::channel::channel;
+
+    #[tokio::test]
+    async fn chunks_timeout_flushes_once_n_items_arrive() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut chunks = rx.chunks_timeout(2, Duration::from_secs(3600));
+
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+        tx.send(3).await.unwrap();
+
+        assert_eq!(chunks.recv().await, Some(StreamItem::Item(vec![1, 2])));
+        tx.close().await.unwrap();
+        assert_eq!(chunks.recv().await, Some(StreamItem::Item(vec![3])));
+    }
+
+    #[tokio::test]
+    async fn chunks_timeout_flushes_a_partial_chunk_after_the_timeout() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut chunks = rx.chunks_timeout(10, Duration::from_millis(20));
+
+        tx.send(1).await.unwrap();
+
+        assert_eq!(chunks.recv().await, Some(StreamItem::Item(vec![1])));
+    }
+
+    #[tokio::test]
+    async fn chunks_timeout_wraps_the_terminal_event_in_a_final_chunk() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut chunks = rx.chunks_timeout(10, Duration::from_secs(3600));
+
+        tx.complete(Some(99)).await.unwrap();
+
+        assert_eq!(chunks.recv().await, Some(StreamItem::Complete(Some(vec![99]))));
+    }
+
+    #[tokio::test]
+    async fn debounce_emits_only_the_last_item_after_a_quiet_period() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut debounced = rx.debounce(Duration::from_millis(20));
+
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+        tx.send(3).await.unwrap();
+
+        assert_eq!(debounced.recv().await, Some(StreamItem::Item(3)));
+    }
+
+    #[tokio::test]
+    async fn debounce_flushes_a_pending_item_on_completion() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut debounced = rx.debounce(Duration::from_secs(3600));
+
+        tx.send(1).await.unwrap();
+        tx.close().await.unwrap();
+
+        assert_eq!(debounced.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(debounced.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn throttle_drops_items_until_the_interval_elapses() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut throttled = rx.throttle(Duration::from_secs(3600));
+
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+        tx.close().await.unwrap();
+
+        assert_eq!(throttled.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(throttled.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn take_until_cancelled_forwards_items_received_before_cancellation() {
+        let (tx, rx) = channel::<i32>(8);
+        let token = CancellationToken::new();
+        let mut taken = rx.take_until_cancelled(token.clone());
+
+        tx.send(1).await.unwrap();
+        assert_eq!(taken.recv().await, Some(StreamItem::Item(1)));
+
+        token.cancel();
+        assert_eq!(taken.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn take_until_cancelled_completes_immediately_for_an_already_cancelled_token() {
+        let (_tx, rx) = channel::<i32>(8);
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let mut taken = rx.take_until_cancelled(token);
+        assert_eq!(taken.recv().await, Some(StreamItem::Complete(None)));
+    }
+}
@@ -0,0 +1,208 @@
+//! Combining multiple event streams into one.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::future::select_all;
+use futures::FutureExt;
+
+use super::channel::{channel, EventReceiver};
+use super::combinators::DEFAULT_CAPACITY;
+use crate::api::StreamItem;
+
+/// Merges `sources` into a single stream, forwarding each item as soon
+/// as it arrives from whichever source produced it first. No source is
+/// favored over another.
+///
+/// Each source's own terminal event (if any) is discarded — the merged
+/// stream completes with no terminal event once every source has
+/// completed or closed.
+pub fn merge_streams<T>(sources: Vec<EventReceiver<T>>) -> EventReceiver<T>
+where
+    T: Send + 'static,
+{
+    let (tx, rx) = channel(DEFAULT_CAPACITY);
+
+    let remaining = Arc::new(AtomicUsize::new(sources.len()));
+    if sources.is_empty() {
+        tokio::spawn(async move {
+            let _ = tx.complete(None).await;
+        });
+        return rx;
+    }
+
+    for mut source in sources {
+        let tx = tx.clone();
+        let remaining = remaining.clone();
+        tokio::spawn(async move {
+            while let Some(StreamItem::Item(item)) = source.recv().await {
+                if tx.send(item).await.is_err() {
+                    return;
+                }
+            }
+            if remaining.fetch_sub(1, Ordering::AcqRel) == 1 {
+                let _ = tx.complete(None).await;
+            }
+        });
+    }
+    rx
+}
+
+/// One source of a [`create_priority_stream`], with lower `priority`
+/// values served first.
+pub struct PrioritySource<T> {
+    pub receiver: EventReceiver<T>,
+    pub priority: u8,
+}
+
+/// Merges `sources` into a single stream that, whenever more than one
+/// source has an item ready, always emits the one from the lowest
+/// [`PrioritySource::priority`] first. Sources at the same priority are
+/// served in the order they became ready, same as [`merge_streams`].
+///
+/// As with [`merge_streams`], each source's own terminal event is
+/// discarded, and the merged stream completes with no terminal event
+/// once every source has completed or closed.
+pub fn create_priority_stream<T>(mut sources: Vec<PrioritySource<T>>) -> EventReceiver<T>
+where
+    T: Send + 'static,
+{
+    sources.sort_by_key(|source| source.priority);
+    let (tx, rx) = channel(DEFAULT_CAPACITY);
+
+    tokio::spawn(async move {
+        let mut receivers: Vec<EventReceiver<T>> = sources.into_iter().map(|s| s.receiver).collect();
+        let mut done = vec![false; receivers.len()];
+
+        loop {
+            if done.iter().all(|d| *d) {
+                let _ = tx.complete(None).await;
+                return;
+            }
+
+            // Priority scan: `receivers` is already sorted by priority,
+            // so the first source with an item ready right now wins.
+            let ready = receivers
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| !done[*i])
+                .find_map(|(i, source)| source.recv().now_or_never().map(|item| (i, item)));
+
+            let (index, item) = match ready {
+                Some(found) => found,
+                None => {
+                    let mut active = Vec::new();
+                    let mut pending = Vec::new();
+                    for (i, source) in receivers.iter_mut().enumerate() {
+                        if !done[i] {
+                            active.push(i);
+                            pending.push(Box::pin(source.recv()));
+                        }
+                    }
+                    let (item, position, _) = select_all(pending).await;
+                    (active[position], item)
+                }
+            };
+
+            match item {
+                Some(StreamItem::Item(event)) => {
+                    if tx.send(event).await.is_err() {
+                        return;
+                    }
+                }
+                Some(StreamItem::Complete(_)) | None => {
+                    done[index] = true;
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn merge_streams_forwards_items_from_every_source() {
+        let (tx_a, rx_a) = channel::<i32>(8);
+        let (tx_b, rx_b) = channel::<i32>(8);
+        tx_a.send(1).await.unwrap();
+        tx_b.send(2).await.unwrap();
+        tx_a.close().await.unwrap();
+        tx_b.close().await.unwrap();
+
+        let mut merged = merge_streams(vec![rx_a, rx_b]);
+        let mut items = Vec::new();
+        while let Some(StreamItem::Item(item)) = merged.recv().await {
+            items.push(item);
+        }
+        items.sort_unstable();
+        assert_eq!(items, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn merge_streams_completes_once_every_source_is_done() {
+        let (tx_a, rx_a) = channel::<i32>(8);
+        let (tx_b, rx_b) = channel::<i32>(8);
+        drop(tx_a);
+        drop(tx_b);
+
+        let mut merged = merge_streams(vec![rx_a, rx_b]);
+        assert_eq!(merged.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn merge_streams_with_no_sources_completes_immediately() {
+        let mut merged = merge_streams::<i32>(vec![]);
+        assert_eq!(merged.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn priority_stream_prefers_the_higher_priority_source_when_both_are_ready() {
+        let (tx_high, rx_high) = channel::<&'static str>(8);
+        let (tx_low, rx_low) = channel::<&'static str>(8);
+        tx_high.send("high").await.unwrap();
+        tx_low.send("low").await.unwrap();
+        tx_high.close().await.unwrap();
+        tx_low.close().await.unwrap();
+
+        let mut merged = create_priority_stream(vec![
+            PrioritySource {
+                receiver: rx_low,
+                priority: 10,
+            },
+            PrioritySource {
+                receiver: rx_high,
+                priority: 0,
+            },
+        ]);
+
+        assert_eq!(merged.recv().await, Some(StreamItem::Item("high")));
+        assert_eq!(merged.recv().await, Some(StreamItem::Item("low")));
+    }
+
+    #[tokio::test]
+    async fn priority_stream_falls_back_to_a_lower_priority_source_once_others_are_drained() {
+        let (tx_high, rx_high) = channel::<i32>(8);
+        let (tx_low, rx_low) = channel::<i32>(8);
+        tx_high.close().await.unwrap();
+        tx_low.send(1).await.unwrap();
+
+        let mut merged = create_priority_stream(vec![
+            PrioritySource {
+                receiver: rx_high,
+                priority: 0,
+            },
+            PrioritySource {
+                receiver: rx_low,
+                priority: 1,
+            },
+        ]);
+
+        assert_eq!(merged.recv().await, Some(StreamItem::Item(1)));
+        drop(tx_low);
+        assert_eq!(merged.recv().await, Some(StreamItem::Complete(None)));
+    }
+}
@@ -0,0 +1,7 @@
+//! Implementation details for the streams module.
+
+pub mod bus;
+pub mod channel;
+pub mod combinators;
+pub mod merge;
+pub mod window;
@@ -0,0 +1,257 @@
+//! Time-windowed aggregation over an [`EventReceiver`], returned by
+//! [`super::combinators::EventStreamExt::window_tumbling`]/
+//! [`super::combinators::EventStreamExt::window_sliding`].
+//!
+//! Windows are measured against wall-clock arrival time at the
+//! windowing operator, not an event-time field on `T` — there's no
+//! generic way to extract one. [`TumblingWindow::with_grace`] is the
+//! watermark: it holds a window open past its nominal end for a bit
+//! longer, admitting events that were already in flight when the
+//! window closed instead of dropping them.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+
+use super::channel::{channel, EventReceiver};
+use super::combinators::DEFAULT_CAPACITY;
+use crate::api::StreamItem;
+
+/// A tumbling (fixed-size, non-overlapping) window builder, produced by
+/// [`super::combinators::EventStreamExt::window_tumbling`].
+pub struct TumblingWindow<T> {
+    pub(super) receiver: EventReceiver<T>,
+    pub(super) duration: Duration,
+    pub(super) grace: Duration,
+}
+
+impl<T> TumblingWindow<T> {
+    /// Keeps a window open for `grace` past its nominal end, still
+    /// folding in events that arrive during that time, before emitting
+    /// and starting the next window. Zero by default: a window closes
+    /// the instant its `duration` elapses.
+    pub fn with_grace(mut self, grace: Duration) -> Self {
+        self.grace = grace;
+        self
+    }
+
+    /// Aggregates each window's events into one value, emitted once the
+    /// window (plus any [`TumblingWindow::with_grace`] period) closes.
+    /// `init` builds the starting value for each new window; `fold`
+    /// folds one event into the running aggregate.
+    ///
+    /// The upstream's terminal event is discarded; the current window's
+    /// aggregate is flushed and the aggregated stream is completed
+    /// (with no terminal event) in its place.
+    pub fn aggregate<A, F>(self, mut init: impl FnMut() -> A + Send + 'static, fold: F) -> EventReceiver<A>
+    where
+        T: Send + 'static,
+        A: Send + 'static,
+        F: Fn(A, T) -> A + Send + 'static,
+    {
+        let TumblingWindow {
+            mut receiver,
+            duration,
+            grace,
+        } = self;
+        let (tx, rx) = channel(DEFAULT_CAPACITY);
+
+        tokio::spawn(async move {
+            loop {
+                let mut acc = init();
+
+                let deadline = sleep(duration);
+                tokio::pin!(deadline);
+                loop {
+                    tokio::select! {
+                        item = receiver.recv() => match item {
+                            Some(StreamItem::Item(event)) => acc = fold(acc, event),
+                            Some(StreamItem::Complete(_)) => {
+                                let _ = tx.send(acc).await;
+                                let _ = tx.complete(None).await;
+                                return;
+                            }
+                            None => {
+                                let _ = tx.send(acc).await;
+                                return;
+                            }
+                        },
+                        _ = &mut deadline => break,
+                    }
+                }
+
+                if !grace.is_zero() {
+                    let grace_deadline = sleep(grace);
+                    tokio::pin!(grace_deadline);
+                    loop {
+                        tokio::select! {
+                            item = receiver.recv() => match item {
+                                Some(StreamItem::Item(event)) => acc = fold(acc, event),
+                                Some(StreamItem::Complete(_)) => {
+                                    let _ = tx.send(acc).await;
+                                    let _ = tx.complete(None).await;
+                                    return;
+                                }
+                                None => {
+                                    let _ = tx.send(acc).await;
+                                    return;
+                                }
+                            },
+                            _ = &mut grace_deadline => break,
+                        }
+                    }
+                }
+
+                if tx.send(acc).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        rx
+    }
+}
+
+/// A sliding (fixed-size, overlapping) window builder, produced by
+/// [`super::combinators::EventStreamExt::window_sliding`].
+pub struct SlidingWindow<T> {
+    pub(super) receiver: EventReceiver<T>,
+    pub(super) size: Duration,
+    pub(super) slide: Duration,
+}
+
+impl<T> SlidingWindow<T> {
+    /// Aggregates the events received in the last [`size`](SlidingWindow)
+    /// every [`slide`](SlidingWindow) interval, over a window that
+    /// overlaps the previous one by `size - slide`. `init`/`fold` are as
+    /// in [`TumblingWindow::aggregate`].
+    ///
+    /// Requires `T: Clone` because a sliding window re-aggregates from
+    /// scratch on every tick as old events fall out of the window,
+    /// unlike a tumbling window's fold-once-and-discard.
+    pub fn aggregate<A, F>(self, mut init: impl FnMut() -> A + Send + 'static, fold: F) -> EventReceiver<A>
+    where
+        T: Clone + Send + 'static,
+        A: Send + 'static,
+        F: Fn(A, T) -> A + Send + 'static,
+    {
+        let SlidingWindow {
+            mut receiver,
+            size,
+            slide,
+        } = self;
+        let (tx, rx) = channel(DEFAULT_CAPACITY);
+
+        tokio::spawn(async move {
+            let mut buffer: VecDeque<(Instant, T)> = VecDeque::new();
+            loop {
+                let deadline = sleep(slide);
+                tokio::pin!(deadline);
+                loop {
+                    tokio::select! {
+                        item = receiver.recv() => match item {
+                            Some(StreamItem::Item(event)) => buffer.push_back((Instant::now(), event)),
+                            Some(StreamItem::Complete(_)) => {
+                                let _ = tx.send(fold_window(&mut init, &fold, &buffer)).await;
+                                let _ = tx.complete(None).await;
+                                return;
+                            }
+                            None => {
+                                let _ = tx.send(fold_window(&mut init, &fold, &buffer)).await;
+                                return;
+                            }
+                        },
+                        _ = &mut deadline => break,
+                    }
+                }
+
+                let cutoff = Instant::now() - size;
+                while matches!(buffer.front(), Some((observed, _)) if *observed < cutoff) {
+                    buffer.pop_front();
+                }
+
+                if tx.send(fold_window(&mut init, &fold, &buffer)).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        rx
+    }
+}
+
+fn fold_window<T, A>(
+    init: &mut impl FnMut() -> A,
+    fold: &impl Fn(A, T) -> A,
+    buffer: &VecDeque<(Instant, T)>,
+) -> A
+where
+    T: Clone,
+{
+    buffer
+        .iter()
+        .fold(init(), |acc, (_, event)| fold(acc, event.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::combinators::EventStreamExt;
+    use super::*;
+
+    #[tokio::test]
+    async fn tumbling_window_emits_one_aggregate_per_window() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut sums = rx.window_tumbling(Duration::from_millis(20)).aggregate(|| 0, |acc, n| acc + n);
+
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+        assert_eq!(sums.recv().await, Some(StreamItem::Item(3)));
+
+        tx.send(10).await.unwrap();
+        assert_eq!(sums.recv().await, Some(StreamItem::Item(10)));
+    }
+
+    #[tokio::test]
+    async fn tumbling_window_flushes_the_current_window_on_completion() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut sums = rx
+            .window_tumbling(Duration::from_secs(3600))
+            .aggregate(|| 0, |acc, n| acc + n);
+
+        tx.send(1).await.unwrap();
+        tx.close().await.unwrap();
+
+        assert_eq!(sums.recv().await, Some(StreamItem::Item(1)));
+        assert_eq!(sums.recv().await, Some(StreamItem::Complete(None)));
+    }
+
+    #[tokio::test]
+    async fn tumbling_window_grace_period_admits_a_late_event() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut sums = rx
+            .window_tumbling(Duration::from_millis(20))
+            .with_grace(Duration::from_millis(100))
+            .aggregate(|| 0, |acc, n| acc + n);
+
+        tx.send(1).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        tx.send(2).await.unwrap();
+
+        assert_eq!(sums.recv().await, Some(StreamItem::Item(3)));
+    }
+
+    #[tokio::test]
+    async fn sliding_window_re_aggregates_as_old_events_fall_out() {
+        let (tx, rx) = channel::<i32>(8);
+        let mut sums = rx
+            .window_sliding(Duration::from_millis(60), Duration::from_millis(30))
+            .aggregate(|| 0, |acc, n| acc + n);
+
+        tx.send(1).await.unwrap();
+
+        assert_eq!(sums.recv().await, Some(StreamItem::Item(1)));
+        tokio::time::sleep(Duration::from_millis(70)).await;
+        assert_eq!(sums.recv().await, Some(StreamItem::Item(0)));
+    }
+}
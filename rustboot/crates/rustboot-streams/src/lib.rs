@@ -0,0 +1,49 @@
+//! Async event streams with explicit completion signaling for the
+//! rustboot framework.
+//!
+//! - [`core::channel::EventSender`] / [`core::channel::EventReceiver`]:
+//!   a bounded channel pair where the producer can
+//!   [`core::channel::EventSender::close`] or
+//!   [`core::channel::EventSender::complete`] the stream, and
+//!   [`core::channel::EventSender::flush`] waits for previously sent
+//!   events to be dequeued. A receiver sees [`api::StreamItem::Complete`]
+//!   on a clean finish, or the channel simply closing on a crash — so
+//!   "done" and "producer crashed" are no longer indistinguishable.
+//!   [`core::channel::named_channel`] additionally takes an
+//!   [`api::OverflowStrategy`] and reports queue depth, for a producer
+//!   that would rather drop or replace buffered events than block when
+//!   the consumer falls behind.
+//! - [`core::channel::SendGuard`]: completes the stream when dropped
+//!   (unless already completed), so an early return or cancellation
+//!   doesn't leave the receiver guessing.
+//! - [`core::combinators::EventStreamExt`][]: `chunks_timeout`,
+//!   `debounce`, and `throttle` combinators for consumers that
+//!   aggregate or rate-limit events instead of reacting to every one.
+//! - [`core::bus::EventBus`]: a fan-out bus, built on
+//!   `tokio::sync::broadcast`, where every subscriber receives every
+//!   event independently of the others, with a configurable
+//!   [`api::OverflowPolicy`] for subscribers that fall behind.
+//! - [`core::merge::merge_streams`] / [`core::merge::create_priority_stream`]:
+//!   combine several `EventReceiver`s into one, either fairly (first
+//!   ready wins) or with an explicit priority per source.
+//! - [`core::combinators::EventStreamExt::window_tumbling`]/
+//!   [`core::combinators::EventStreamExt::window_sliding`][]: time-based
+//!   windows over arrival time, aggregated with
+//!   [`core::window::TumblingWindow::aggregate`]/
+//!   [`core::window::SlidingWindow::aggregate`], with
+//!   [`core::window::TumblingWindow::with_grace`] admitting briefly
+//!   late events instead of dropping them.
+//! - [`core::combinators::EventStreamExt::take_until_cancelled`]: ends a
+//!   stream once a `rustboot_async::CancellationToken` shared with the
+//!   rest of the app is cancelled, instead of running until the
+//!   upstream happens to close.
+
+pub mod api;
+pub mod core;
+
+pub use api::{BusError, OverflowPolicy, OverflowStrategy, SendError, SendTimeoutError, StreamItem};
+pub use core::bus::{BroadcastStream, EventBus};
+pub use core::channel::{channel, named_channel, EventReceiver, EventSender, SendGuard};
+pub use core::combinators::EventStreamExt;
+pub use core::merge::{create_priority_stream, merge_streams, PrioritySource};
+pub use core::window::{SlidingWindow, TumblingWindow};
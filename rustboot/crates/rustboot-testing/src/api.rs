@@ -0,0 +1,29 @@
+//! Public types for the testing module.
+
+use std::collections::HashMap;
+
+/// One row returned by a [`crate::spi::Database`] query, keyed by column
+/// name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Row(pub HashMap<String, serde_json::Value>);
+
+impl Row {
+    /// Builds a row from `(column, value)` pairs.
+    pub fn new(columns: impl IntoIterator<Item = (impl Into<String>, serde_json::Value)>) -> Self {
+        Self(columns.into_iter().map(|(name, value)| (name.into(), value)).collect())
+    }
+
+    /// The value in `column`, if present.
+    pub fn get(&self, column: &str) -> Option<&serde_json::Value> {
+        self.0.get(column)
+    }
+}
+
+/// Errors from a [`crate::spi::Database`] implementation.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum DatabaseError {
+    /// [`crate::core::database::MockDatabase`] was asked to run a
+    /// statement no test scripted a result for.
+    #[error("no scripted result for statement: {0}")]
+    NoScriptedResult(String),
+}
@@ -0,0 +1,96 @@
+//! Generic fixture-construction helpers: a fluent builder for any
+//! `Default` type, and a sequence generator for unique test values.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A fluent builder over any `T: Default`, so tests don't need a
+/// hand-written builder for every fixture type.
+///
+/// ```
+/// use rustboot_testing::Builder;
+///
+/// #[derive(Default)]
+/// struct Widget {
+///     name: String,
+///     quantity: u32,
+/// }
+///
+/// let widget = Builder::<Widget>::new()
+///     .with(|w| w.name = "bolt".to_string())
+///     .with(|w| w.quantity = 10)
+///     .build();
+///
+/// assert_eq!(widget.name, "bolt");
+/// assert_eq!(widget.quantity, 10);
+/// ```
+pub struct Builder<T> {
+    value: T,
+}
+
+impl<T: Default> Builder<T> {
+    /// Starts from `T::default()`.
+    pub fn new() -> Self {
+        Self { value: T::default() }
+    }
+
+    /// Applies `edit` to the value under construction.
+    pub fn with(mut self, edit: impl FnOnce(&mut T)) -> Self {
+        edit(&mut self.value);
+        self
+    }
+
+    /// Finishes construction, returning the built value.
+    pub fn build(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Default> Default for Builder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A thread-safe counter for generating unique test values (ids,
+/// emails, ...) without hardcoding values that collide across tests.
+#[derive(Default)]
+pub struct Sequence {
+    next: AtomicU64,
+}
+
+impl Sequence {
+    /// Creates a sequence starting at `1`.
+    pub fn new() -> Self {
+        Self { next: AtomicU64::new(1) }
+    }
+
+    /// Returns the next value in the sequence, starting at `1`.
+    pub fn next(&self) -> u64 {
+        self.next.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default, PartialEq, Debug)]
+    struct Widget {
+        name: String,
+        quantity: u32,
+    }
+
+    #[test]
+    fn builder_applies_edits_over_the_default_value() {
+        let widget = Builder::<Widget>::new().with(|w| w.name = "bolt".to_string()).with(|w| w.quantity = 5).build();
+        assert_eq!(widget, Widget { name: "bolt".to_string(), quantity: 5 });
+    }
+
+    #[test]
+    fn sequence_yields_increasing_unique_values() {
+        let sequence = Sequence::new();
+        assert_eq!(sequence.next(), 1);
+        assert_eq!(sequence.next(), 2);
+        assert_eq!(sequence.next(), 3);
+    }
+}
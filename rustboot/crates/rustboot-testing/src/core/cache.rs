@@ -0,0 +1,104 @@
+//! A [`rustboot_cache::Cache`] test double that records every call made
+//! to it, on top of [`rustboot_cache::InMemoryCache`]'s real semantics.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use rustboot_cache::{Cache, CacheError, InMemoryCache, Ttl};
+
+/// A [`Cache`] backed by an [`InMemoryCache`], recording the name of
+/// every method called so a test can assert on cache interactions
+/// (e.g. "the lookup happened exactly once").
+#[derive(Default)]
+pub struct MockCache {
+    inner: InMemoryCache,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockCache {
+    /// Creates an empty cache with no recorded calls.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of every method called, in call order.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: &str) {
+        self.calls.lock().unwrap().push(call.to_string());
+    }
+}
+
+#[async_trait]
+impl Cache for MockCache {
+    async fn get(&self, key: &str) -> Result<Option<serde_json::Value>, CacheError> {
+        self.record("get");
+        self.inner.get(key).await
+    }
+
+    async fn set(&self, key: &str, value: serde_json::Value, ttl: Ttl) -> Result<(), CacheError> {
+        self.record("set");
+        self.inner.set(key, value, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, CacheError> {
+        self.record("delete");
+        self.inner.delete(key).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, CacheError> {
+        self.record("exists");
+        self.inner.exists(key).await
+    }
+
+    async fn clear(&self) -> Result<(), CacheError> {
+        self.record("clear");
+        self.inner.clear().await
+    }
+
+    async fn incr(&self, key: &str, delta: i64) -> Result<i64, CacheError> {
+        self.record("incr");
+        self.inner.incr(key, delta).await
+    }
+
+    async fn set_if_absent(&self, key: &str, value: serde_json::Value, ttl: Ttl) -> Result<bool, CacheError> {
+        self.record("set_if_absent");
+        self.inner.set_if_absent(key, value, ttl).await
+    }
+
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        expected: &serde_json::Value,
+        new: serde_json::Value,
+        ttl: Ttl,
+    ) -> Result<bool, CacheError> {
+        self.record("compare_and_swap");
+        self.inner.compare_and_swap(key, expected, new, ttl).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn behaves_like_a_real_cache() {
+        let cache = MockCache::new();
+        cache.set("k", json!("v"), None).await.unwrap();
+        assert_eq!(cache.get("k").await.unwrap(), Some(json!("v")));
+    }
+
+    #[tokio::test]
+    async fn records_calls_in_order() {
+        let cache = MockCache::new();
+        cache.set("k", json!(1), None).await.unwrap();
+        cache.get("k").await.unwrap();
+        cache.delete("k").await.unwrap();
+
+        assert_eq!(cache.calls(), vec!["set", "get", "delete"]);
+    }
+}
@@ -0,0 +1,67 @@
+//! A controllable [`crate::spi::Clock`] test double.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rustboot_datetime::Timestamp;
+
+use crate::spi::Clock;
+
+/// A [`Clock`] whose time is set explicitly by a test, rather than
+/// following the wall clock.
+pub struct MockClock {
+    now: Mutex<Timestamp>,
+}
+
+impl MockClock {
+    /// Creates a clock starting at `start`.
+    pub fn new(start: Timestamp) -> Self {
+        Self { now: Mutex::new(start) }
+    }
+
+    /// Sets the clock to `timestamp`.
+    pub fn set(&self, timestamp: Timestamp) {
+        *self.now.lock().unwrap() = timestamp;
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now = Timestamp::from_unix_millis(now.to_unix_millis() + duration.as_millis() as i64)
+            .expect("advancing by a reasonable duration stays in range");
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Timestamp {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_time_it_was_set_to() {
+        let start = Timestamp::from_unix_seconds(1_700_000_000).unwrap();
+        let clock = MockClock::new(start);
+        assert_eq!(clock.now(), start);
+    }
+
+    #[test]
+    fn advance_moves_time_forward() {
+        let start = Timestamp::from_unix_seconds(1_700_000_000).unwrap();
+        let clock = MockClock::new(start);
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(clock.now(), Timestamp::from_unix_seconds(1_700_000_060).unwrap());
+    }
+
+    #[test]
+    fn set_overrides_the_current_time() {
+        let clock = MockClock::new(Timestamp::from_unix_seconds(0).unwrap());
+        let later = Timestamp::from_unix_seconds(1_000).unwrap();
+        clock.set(later);
+        assert_eq!(clock.now(), later);
+    }
+}
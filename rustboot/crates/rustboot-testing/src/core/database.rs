@@ -0,0 +1,77 @@
+//! A scripted [`crate::spi::Database`] test double.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::api::{DatabaseError, Row};
+use crate::spi::Database;
+
+/// A [`Database`] returning pre-scripted rows for exact statement text,
+/// instead of running against a real database.
+#[derive(Default)]
+pub struct MockDatabase {
+    scripted: Mutex<HashMap<String, Vec<Row>>>,
+    queries: Mutex<Vec<String>>,
+}
+
+impl MockDatabase {
+    /// Creates a database with nothing scripted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scripts `rows` to be returned for exactly `statement`.
+    pub fn script(&self, statement: impl Into<String>, rows: Vec<Row>) {
+        self.scripted.lock().unwrap().insert(statement.into(), rows);
+    }
+
+    /// Every statement run so far, in call order.
+    pub fn queries(&self) -> Vec<String> {
+        self.queries.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Database for MockDatabase {
+    async fn query(&self, statement: &str) -> Result<Vec<Row>, DatabaseError> {
+        self.queries.lock().unwrap().push(statement.to_string());
+        self.scripted
+            .lock()
+            .unwrap()
+            .get(statement)
+            .cloned()
+            .ok_or_else(|| DatabaseError::NoScriptedResult(statement.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn returns_the_scripted_rows() {
+        let db = MockDatabase::new();
+        db.script("SELECT * FROM widgets", vec![Row::new([("id", json!(1))])]);
+
+        let rows = db.query("SELECT * FROM widgets").await.unwrap();
+        assert_eq!(rows[0].get("id"), Some(&json!(1)));
+    }
+
+    #[tokio::test]
+    async fn an_unscripted_statement_errors() {
+        let db = MockDatabase::new();
+        assert!(db.query("SELECT 1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn records_every_statement_run() {
+        let db = MockDatabase::new();
+        db.script("SELECT 1", vec![]);
+        db.query("SELECT 1").await.unwrap();
+
+        assert_eq!(db.queries(), vec!["SELECT 1"]);
+    }
+}
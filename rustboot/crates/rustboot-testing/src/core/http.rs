@@ -0,0 +1,108 @@
+//! A scripted [`rustboot_http_client::HttpClient`] test double: register
+//! a canned response or error for a method/URL pair, then assert on the
+//! requests actually made.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use http::Method;
+use rustboot_http_client::{HttpClient, HttpClientError, HttpRequest, HttpResponse};
+
+type ScriptKey = (Method, String);
+
+/// An [`HttpClient`] returning pre-scripted responses instead of making
+/// real requests.
+///
+/// Responses are scripted per `(method, url)` pair and consumed in the
+/// order they were scripted; a request with nothing left scripted for
+/// it fails with [`HttpClientError::Transport`].
+#[derive(Default)]
+pub struct MockHttpClient {
+    scripted: Mutex<HashMap<ScriptKey, VecDeque<Result<HttpResponse, HttpClientError>>>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockHttpClient {
+    /// Creates a client with nothing scripted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scripts `response` to be returned the next time `method url` is
+    /// requested.
+    pub fn script(&self, method: Method, url: impl Into<String>, response: HttpResponse) {
+        self.push(method, url, Ok(response));
+    }
+
+    /// Scripts `error` to be returned the next time `method url` is
+    /// requested.
+    pub fn script_error(&self, method: Method, url: impl Into<String>, error: HttpClientError) {
+        self.push(method, url, Err(error));
+    }
+
+    fn push(&self, method: Method, url: impl Into<String>, result: Result<HttpResponse, HttpClientError>) {
+        self.scripted.lock().unwrap().entry((method, url.into())).or_default().push_back(result);
+    }
+
+    /// Every request made, in call order.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockHttpClient {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpClientError> {
+        let key = (request.method.clone(), request.url.clone());
+        self.requests.lock().unwrap().push(request.clone());
+
+        let next = self.scripted.lock().unwrap().get_mut(&key).and_then(VecDeque::pop_front);
+        next.unwrap_or_else(|| Err(HttpClientError::Transport(format!("no scripted response for {} {}", key.0, key.1))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16) -> HttpResponse {
+        HttpResponse { status, headers: HashMap::new(), body: Vec::new() }
+    }
+
+    #[tokio::test]
+    async fn returns_the_scripted_response() {
+        let client = MockHttpClient::new();
+        client.script(Method::GET, "https://example.test/widgets", response(200));
+
+        let result = client.get("https://example.test/widgets").await.unwrap();
+        assert_eq!(result.status, 200);
+    }
+
+    #[tokio::test]
+    async fn scripted_responses_are_consumed_in_order() {
+        let client = MockHttpClient::new();
+        client.script(Method::GET, "https://example.test/widgets", response(200));
+        client.script(Method::GET, "https://example.test/widgets", response(500));
+
+        assert_eq!(client.get("https://example.test/widgets").await.unwrap().status, 200);
+        assert_eq!(client.get("https://example.test/widgets").await.unwrap().status, 500);
+    }
+
+    #[tokio::test]
+    async fn an_unscripted_request_errors() {
+        let client = MockHttpClient::new();
+        assert!(client.get("https://example.test/missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn records_every_request_made() {
+        let client = MockHttpClient::new();
+        client.script(Method::GET, "https://example.test/widgets", response(200));
+        client.get("https://example.test/widgets").await.unwrap();
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "https://example.test/widgets");
+    }
+}
@@ -0,0 +1,81 @@
+//! A [`rustboot_messaging::Broker`] test double that captures every
+//! published message instead of sending it anywhere.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use rustboot_messaging::{Broker, Message, MessagingError, QueueConfig};
+
+/// A [`Broker`] that captures published messages in memory, for
+/// asserting what a test subject published without a real broker.
+#[derive(Default)]
+pub struct MockPublisher {
+    published: Mutex<Vec<Message>>,
+    connected: AtomicBool,
+}
+
+impl MockPublisher {
+    /// Creates a publisher with nothing published and not connected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every message published so far, in publish order.
+    pub fn published(&self) -> Vec<Message> {
+        self.published.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Broker for MockPublisher {
+    async fn connect(&self) -> Result<(), MessagingError> {
+        self.connected.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), MessagingError> {
+        self.connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn publish(&self, message: Message) -> Result<(), MessagingError> {
+        self.published.lock().unwrap().push(message);
+        Ok(())
+    }
+
+    async fn declare_queue(&self, _config: QueueConfig) -> Result<(), MessagingError> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn captures_published_messages_in_order() {
+        let publisher = MockPublisher::new();
+        publisher.publish(Message::new("orders", "first")).await.unwrap();
+        publisher.publish(Message::new("orders", "second")).await.unwrap();
+
+        let published = publisher.published();
+        assert_eq!(published.len(), 2);
+        assert_eq!(published[0].payload, b"first");
+        assert_eq!(published[1].payload, b"second");
+    }
+
+    #[tokio::test]
+    async fn tracks_connection_state() {
+        let publisher = MockPublisher::new();
+        assert!(!publisher.is_connected());
+        publisher.connect().await.unwrap();
+        assert!(publisher.is_connected());
+        publisher.disconnect().await.unwrap();
+        assert!(!publisher.is_connected());
+    }
+}
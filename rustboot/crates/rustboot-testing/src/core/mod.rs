@@ -0,0 +1,12 @@
+//! Implementation details for the testing module.
+
+pub mod builder;
+pub mod cache;
+pub mod clock;
+#[cfg(feature = "testcontainers")]
+pub mod containers;
+pub mod database;
+pub mod http;
+pub mod messaging;
+pub mod query_tracing;
+pub mod snapshot;
@@ -0,0 +1,137 @@
+//! Query tracing for any [`crate::spi::Database`] implementation, so a
+//! service gets per-query duration and row counts in its APM traces
+//! without hand-instrumenting every call site.
+//!
+//! rustboot has no production database driver of its own (see the
+//! module docs on [`crate::spi::Database`]) — this wraps whatever
+//! `Database` implementation a service actually uses (a real driver's
+//! own wrapper, [`crate::core::database::MockDatabase`] in tests, ...).
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use regex::Regex;
+
+use rustboot_observability::api::{SpanOutcome, SpanRecord};
+use rustboot_observability::core::recorder::record;
+
+use crate::api::{DatabaseError, Row};
+use crate::spi::Database;
+
+/// Wraps a [`Database`] so every query emits a [`SpanRecord`] carrying
+/// its duration, row count, and a [`sanitize_statement`]d statement,
+/// gated by `enabled` so tracing can be toggled from config without
+/// swapping the database implementation.
+pub struct TracingDatabase<D> {
+    inner: D,
+    enabled: bool,
+}
+
+impl<D: Database> TracingDatabase<D> {
+    /// Wraps `inner`, emitting a span for every query only when
+    /// `enabled` is `true`.
+    pub fn new(inner: D, enabled: bool) -> Self {
+        Self { inner, enabled }
+    }
+}
+
+#[async_trait]
+impl<D: Database> Database for TracingDatabase<D> {
+    async fn query(&self, statement: &str) -> Result<Vec<Row>, DatabaseError> {
+        if !self.enabled {
+            return self.inner.query(statement).await;
+        }
+
+        let start = Instant::now();
+        let result = self.inner.query(statement).await;
+
+        let mut fields = BTreeMap::new();
+        fields.insert("db.statement".to_string(), sanitize_statement(statement));
+        fields.insert(
+            "db.rows_affected".to_string(),
+            result.as_ref().map(Vec::len).unwrap_or(0).to_string(),
+        );
+
+        record(SpanRecord {
+            function: "query",
+            module: module_path!(),
+            args: None,
+            duration: start.elapsed(),
+            outcome: if result.is_ok() { SpanOutcome::Success } else { SpanOutcome::Failure },
+            fields,
+        });
+
+        result
+    }
+}
+
+fn string_literal_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"'(?:[^']|'')*'").unwrap())
+}
+
+fn numeric_literal_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\b\d+(?:\.\d+)?\b").unwrap())
+}
+
+/// Replaces quoted string literals and standalone numeric literals in
+/// `statement` with `?`, so a traced statement carries the query shape
+/// without leaking the parameter values (PII, secrets, ...) that were
+/// inlined into it.
+pub fn sanitize_statement(statement: &str) -> String {
+    let without_strings = string_literal_pattern().replace_all(statement, "?");
+    numeric_literal_pattern().replace_all(&without_strings, "?").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::core::database::MockDatabase;
+
+    #[test]
+    fn sanitize_statement_strips_string_and_numeric_literals() {
+        let sanitized = sanitize_statement("SELECT * FROM users WHERE email = 'alice@example.com' AND age > 21");
+        assert_eq!(sanitized, "SELECT * FROM users WHERE email = ? AND age > ?");
+    }
+
+    #[test]
+    fn sanitize_statement_leaves_identifiers_and_keywords_alone() {
+        let sanitized = sanitize_statement("SELECT id FROM widgets2 LIMIT 10");
+        assert_eq!(sanitized, "SELECT id FROM widgets2 LIMIT ?");
+    }
+
+    #[tokio::test]
+    async fn disabled_tracing_still_forwards_the_query_and_result() {
+        let db = MockDatabase::new();
+        db.script("SELECT 1", vec![Row::new([("one", json!(1))])]);
+        let traced = TracingDatabase::new(db, false);
+
+        let rows = traced.query("SELECT 1").await.unwrap();
+
+        assert_eq!(rows[0].get("one"), Some(&json!(1)));
+    }
+
+    #[tokio::test]
+    async fn enabled_tracing_still_forwards_the_query_and_result() {
+        let db = MockDatabase::new();
+        db.script("SELECT 1", vec![Row::new([("one", json!(1))])]);
+        let traced = TracingDatabase::new(db, true);
+
+        let rows = traced.query("SELECT 1").await.unwrap();
+
+        assert_eq!(rows[0].get("one"), Some(&json!(1)));
+    }
+
+    #[tokio::test]
+    async fn enabled_tracing_still_propagates_errors() {
+        let db = MockDatabase::new();
+        let traced = TracingDatabase::new(db, true);
+
+        assert!(traced.query("SELECT 1").await.is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Golden-file snapshot assertions, so response shapes and generated
+//! specs (OpenAPI, ...) can be regression-tested without hand-writing
+//! the expected value in the test itself.
+//!
+//! Timestamps and UUIDs are redacted before comparison, since those
+//! fields change on every run and would otherwise make snapshots
+//! useless. Set the `UPDATE_SNAPSHOTS` environment variable to any
+//! value to (re)write snapshots to disk instead of comparing against
+//! them.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+fn uuid_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}").unwrap()
+    })
+}
+
+fn timestamp_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})").unwrap()
+    })
+}
+
+/// Replaces UUID- and RFC3339-timestamp-shaped strings anywhere in
+/// `value` with fixed placeholders, so snapshots stay stable across
+/// runs.
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::String(string) => *string = redact_string(string),
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        Value::Object(fields) => fields.values_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Applies the same redaction as [`redact`] to a single string.
+pub fn redact_string(input: &str) -> String {
+    let redacted = uuid_pattern().replace_all(input, "<uuid>");
+    timestamp_pattern().replace_all(&redacted, "<timestamp>").into_owned()
+}
+
+/// Asserts that `value`, redacted and rendered as pretty JSON, matches
+/// the snapshot at `path`. With `UPDATE_SNAPSHOTS` set, writes the
+/// rendered value to `path` instead.
+pub fn assert_json_snapshot(path: &str, value: &Value) {
+    let mut redacted = value.clone();
+    redact(&mut redacted);
+    let rendered = serde_json::to_string_pretty(&redacted).expect("value must serialize to JSON");
+    assert_snapshot(path, &rendered);
+}
+
+/// Asserts that `value`, redacted and rendered as YAML, matches the
+/// snapshot at `path`. With `UPDATE_SNAPSHOTS` set, writes the
+/// rendered value to `path` instead.
+pub fn assert_yaml_snapshot(path: &str, value: &Value) {
+    let mut redacted = value.clone();
+    redact(&mut redacted);
+    let rendered = serde_yaml::to_string(&redacted).expect("value must serialize to YAML");
+    assert_snapshot(path, &rendered);
+}
+
+fn assert_snapshot(path: &str, rendered: &str) {
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        if let Some(parent) = std::path::Path::new(path).parent() {
+            std::fs::create_dir_all(parent).expect("must be able to create snapshot directory");
+        }
+        std::fs::write(path, rendered).expect("must be able to write snapshot");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(path).unwrap_or_else(|_| {
+        panic!(
+            "snapshot {path} does not exist yet; rerun with UPDATE_SNAPSHOTS=1 to create it:\n{rendered}"
+        )
+    });
+    assert_eq!(
+        expected, rendered,
+        "snapshot {path} is out of date; rerun with UPDATE_SNAPSHOTS=1 to update it"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use serde_json::json;
+
+    // `UPDATE_SNAPSHOTS` is process-wide state, so every test that
+    // depends on its absence (or sets it) has to run under this lock -
+    // otherwise tests running on other threads race on it.
+    static UPDATE_SNAPSHOTS_LOCK: Mutex<()> = Mutex::new(());
+
+    fn scratch_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("rustboot_testing_snapshot_{name}_{:?}.snap", std::thread::current().id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn redact_replaces_uuids_and_timestamps_but_not_other_strings() {
+        let mut value = json!({
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "created_at": "2024-01-02T03:04:05.678Z",
+            "name": "widget",
+        });
+        redact(&mut value);
+        assert_eq!(value["id"], json!("<uuid>"));
+        assert_eq!(value["created_at"], json!("<timestamp>"));
+        assert_eq!(value["name"], json!("widget"));
+    }
+
+    #[test]
+    fn json_snapshot_round_trips_through_update_and_compare() {
+        let _guard = UPDATE_SNAPSHOTS_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = scratch_path("json_round_trip");
+        let _ = std::fs::remove_file(&path);
+        let value = json!({"id": "550e8400-e29b-41d4-a716-446655440000", "status": "ok"});
+
+        std::env::set_var("UPDATE_SNAPSHOTS", "1");
+        assert_json_snapshot(&path, &value);
+        std::env::remove_var("UPDATE_SNAPSHOTS");
+
+        assert_json_snapshot(&path, &value);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist yet")]
+    fn json_snapshot_panics_when_missing() {
+        let _guard = UPDATE_SNAPSHOTS_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = scratch_path("json_missing");
+        let _ = std::fs::remove_file(&path);
+        assert_json_snapshot(&path, &json!({"status": "ok"}));
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of date")]
+    fn yaml_snapshot_panics_when_stale() {
+        let _guard = UPDATE_SNAPSHOTS_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = scratch_path("yaml_stale");
+        std::fs::write(&path, "status: stale\n").unwrap();
+        assert_yaml_snapshot(&path, &json!({"status": "ok"}));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
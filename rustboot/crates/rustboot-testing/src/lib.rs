@@ -0,0 +1,49 @@
+//! Test doubles and data builders for the rustboot framework, so
+//! downstream tests don't each reinvent fakes.
+//!
+//! - [`MockCache`]: a [`rustboot_cache::Cache`] backed by
+//!   [`rustboot_cache::InMemoryCache`] that also records every call
+//!   made to it.
+//! - [`MockHttpClient`]: a scripted [`rustboot_http_client::HttpClient`]
+//!   returning canned responses per method/URL and recording every
+//!   request made.
+//! - [`MockPublisher`]: a [`rustboot_messaging::Broker`] that captures
+//!   published messages in memory instead of sending them.
+//! - [`MockDatabase`]/[`spi::Database`]: a scripted query interface,
+//!   since rustboot has no production database abstraction to mock
+//!   against directly.
+//! - [`core::query_tracing::TracingDatabase`]/[`core::query_tracing::sanitize_statement`]:
+//!   wraps any [`spi::Database`] to emit a per-query tracing span
+//!   (duration, row count, sanitized statement) via
+//!   `rustboot_observability::core::recorder`, gated by a config flag.
+//! - [`MockClock`]/[`spi::Clock`]: a controllable time source, since
+//!   rustboot has no production clock abstraction to mock against
+//!   directly.
+//! - [`Builder`]/[`Sequence`]: a generic fluent builder over any
+//!   `Default` type, and a counter for generating unique test values.
+//! - [`core::containers`] (`testcontainers` feature): starts real
+//!   Postgres/Redis/RabbitMQ containers for integration tests, with
+//!   automatic teardown. Only Redis comes back fully wired to a
+//!   [`rustboot_cache::Cache`]; Postgres and RabbitMQ only expose
+//!   connection details, since rustboot has no database crate or AMQP
+//!   transport to wire them into.
+//! - [`assert_json_snapshot`]/[`assert_yaml_snapshot`]: golden-file
+//!   assertions with timestamp/UUID redaction, updated by setting
+//!   `UPDATE_SNAPSHOTS`.
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{DatabaseError, Row};
+pub use core::builder::{Builder, Sequence};
+pub use core::cache::MockCache;
+pub use core::clock::MockClock;
+#[cfg(feature = "testcontainers")]
+pub use core::containers::{PostgresContainer, RabbitMqContainer, RedisClientTransport, RedisContainer};
+pub use core::database::MockDatabase;
+pub use core::http::MockHttpClient;
+pub use core::messaging::MockPublisher;
+pub use core::query_tracing::{sanitize_statement, TracingDatabase};
+pub use core::snapshot::{assert_json_snapshot, assert_yaml_snapshot, redact, redact_string};
+pub use spi::{Clock, Database};
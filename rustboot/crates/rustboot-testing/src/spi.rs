@@ -0,0 +1,27 @@
+//! Service provider interfaces defined by the testing module itself,
+//! for framework surfaces with no existing pluggable abstraction
+//! ([`Database`], [`Clock`]).
+
+use async_trait::async_trait;
+
+use crate::api::{DatabaseError, Row};
+
+/// A minimal query interface, implemented by
+/// [`crate::core::database::MockDatabase`] for tests. The rustboot
+/// framework has no production `Database` abstraction yet — real
+/// database access goes through a specific driver directly — so this
+/// trait exists only to give test code something to depend on and mock
+/// against.
+#[async_trait]
+pub trait Database: Send + Sync {
+    /// Runs `statement` and returns the rows it produced.
+    async fn query(&self, statement: &str) -> Result<Vec<Row>, DatabaseError>;
+}
+
+/// Supplies the current time, implemented by
+/// [`crate::core::clock::MockClock`] so tests can control what "now" is
+/// without sleeping or depending on wall-clock time.
+pub trait Clock: Send + Sync {
+    /// The current instant, as this clock sees it.
+    fn now(&self) -> rustboot_datetime::Timestamp;
+}
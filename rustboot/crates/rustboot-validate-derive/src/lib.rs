@@ -0,0 +1,160 @@
+//! Derive macro for `rustboot_validate::Validate`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, LitInt, LitStr};
+
+/// Derives `Validate` for a struct from `#[validate(...)]` field
+/// attributes, generating the same `rustboot_validate::Validator` calls
+/// you'd otherwise write by hand:
+///
+/// ```ignore
+/// #[derive(Validate)]
+/// struct SignupForm {
+///     #[validate(email)]
+///     email: String,
+///     #[validate(length(min = 8, max = 72))]
+///     password: String,
+///     #[validate(range(min = 13, max = 150))]
+///     age: u8,
+///     #[validate(regex = "^[a-z0-9_]+$")]
+///     username: String,
+///     #[validate(nested)]
+///     address: Address,
+///     #[validate(custom = "check_password_strength")]
+///     password_again: String,
+/// }
+/// ```
+///
+/// `custom = "fn_path"` calls `fn_path(&field)`, which must return
+/// `Result<(), String>`.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input, "Validate can only be derived for structs")
+            .to_compile_error()
+            .into();
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return syn::Error::new_spanned(&input, "Validate requires a struct with named fields")
+            .to_compile_error()
+            .into();
+    };
+
+    let mut checks = Vec::new();
+    for field in &fields.named {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_name = field_ident.to_string();
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("validate") {
+                continue;
+            }
+            match rules_for(attr, field_ident, &field_name) {
+                Ok(rules) => checks.extend(rules),
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+    }
+
+    let expanded = quote! {
+        impl ::rustboot_validate::Validate for #name {
+            fn validate(&self) -> ::std::result::Result<(), ::rustboot_validate::ValidationErrors> {
+                let mut __validator = ::rustboot_validate::Validator::new();
+                #(#checks)*
+                __validator.finish()
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Expands one field's `#[validate(...)]` attribute into one
+/// `__validator.field(...)....` statement per rule it lists.
+fn rules_for(attr: &syn::Attribute, field_ident: &syn::Ident, field_name: &str) -> syn::Result<Vec<TokenStream2>> {
+    let mut rules = Vec::new();
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("email") {
+            rules.push(quote! {
+                __validator.field(#field_name).email(&self.#field_ident);
+            });
+            Ok(())
+        } else if meta.path.is_ident("nested") {
+            rules.push(quote! {
+                __validator.field(#field_name).nested(&self.#field_ident);
+            });
+            Ok(())
+        } else if meta.path.is_ident("length") {
+            let (min, max) = parse_min_max::<LitInt>(&meta)?;
+            rules.push(quote! {
+                __validator.field(#field_name).length(&self.#field_ident, #min, #max);
+            });
+            Ok(())
+        } else if meta.path.is_ident("range") {
+            let (min, max) = parse_min_max::<Lit>(&meta)?;
+            rules.push(quote! {
+                __validator.field(#field_name).range(self.#field_ident, #min, #max);
+            });
+            Ok(())
+        } else if meta.path.is_ident("regex") {
+            let pattern: LitStr = meta.value()?.parse()?;
+            rules.push(quote! {
+                {
+                    static __PATTERN: ::std::sync::LazyLock<::regex::Regex> = ::std::sync::LazyLock::new(|| {
+                        ::regex::Regex::new(#pattern).expect("invalid #[validate(regex = ...)] pattern")
+                    });
+                    __validator.field(#field_name).matches(&self.#field_ident, &__PATTERN);
+                }
+            });
+            Ok(())
+        } else if meta.path.is_ident("custom") {
+            let fn_path_lit: LitStr = meta.value()?.parse()?;
+            let fn_path: syn::Path = fn_path_lit
+                .parse_with(syn::Path::parse_mod_style)
+                .map_err(|_| meta.error("`custom` must be a valid function path"))?;
+            rules.push(quote! {
+                __validator.field(#field_name).custom(|| #fn_path(&self.#field_ident));
+            });
+            Ok(())
+        } else {
+            Err(meta.error("unsupported `#[validate(...)]` rule"))
+        }
+    })?;
+
+    Ok(rules)
+}
+
+/// Parses a `min = .., max = ..` pair from a nested `#[validate(rule(...))]`
+/// list, rendering either bound as `Some(literal)` or `None` when absent.
+fn parse_min_max<L: syn::parse::Parse + quote::ToTokens>(
+    meta: &syn::meta::ParseNestedMeta<'_>,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let mut min = None;
+    let mut max = None;
+
+    meta.parse_nested_meta(|inner| {
+        if inner.path.is_ident("min") {
+            min = Some(inner.value()?.parse::<L>()?);
+            Ok(())
+        } else if inner.path.is_ident("max") {
+            max = Some(inner.value()?.parse::<L>()?);
+            Ok(())
+        } else {
+            Err(inner.error("expected `min` or `max`"))
+        }
+    })?;
+
+    Ok((option_tokens(min), option_tokens(max)))
+}
+
+fn option_tokens<L: quote::ToTokens>(value: Option<L>) -> TokenStream2 {
+    match value {
+        Some(lit) => quote! { Some(#lit) },
+        None => quote! { None },
+    }
+}
@@ -0,0 +1,184 @@
+//! Public types for the validation module.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::spi::MessageCatalog;
+
+/// Named interpolation values for a validation message template, e.g.
+/// `"min" -> "3"` for the `{min}` placeholder in "must be at least
+/// {min} characters long".
+pub type Params = BTreeMap<String, String>;
+
+/// A rule's code, default English message, and interpolation params,
+/// returned by [`crate::core::validators`] and [`crate::core::collections`]
+/// functions before a field name is known. [`FieldValidator`] and
+/// `#[derive(Validate)]` attach the field name to turn this into a
+/// [`ValidationError`].
+///
+/// [`FieldValidator`]: crate::core::builder::FieldValidator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleFailure {
+    pub code: String,
+    pub message: String,
+    pub params: Params,
+}
+
+impl RuleFailure {
+    /// Creates a rule failure with no interpolation params.
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            params: Params::new(),
+        }
+    }
+
+    /// Attaches an interpolation param (e.g. `"min"` -> `3`).
+    pub fn with_param(mut self, name: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+}
+
+/// A single field validation failure.
+///
+/// `code` is a stable, machine-readable identifier (`"length.min"`,
+/// `"email"`, ...) that a caller can match on without parsing
+/// `message`, or look up in a [`MessageCatalog`] via [`Self::localize`]
+/// to render in a language other than the default English `message`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+    pub params: Params,
+}
+
+impl ValidationError {
+    /// Creates a validation error for `field` with no interpolation
+    /// params.
+    pub fn new(field: impl Into<String>, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code: code.into(),
+            message: message.into(),
+            params: Params::new(),
+        }
+    }
+
+    /// Creates a validation error for `field` from a [`RuleFailure`].
+    pub fn from_failure(field: impl Into<String>, failure: RuleFailure) -> Self {
+        Self {
+            field: field.into(),
+            code: failure.code,
+            message: failure.message,
+            params: failure.params,
+        }
+    }
+
+    /// Renders this error's message for `locale` using `catalog`,
+    /// falling back to the default English [`Self::message`] if the
+    /// catalog has no template for this error's `code` and `locale`.
+    pub fn localize(&self, locale: &str, catalog: &dyn MessageCatalog) -> String {
+        catalog
+            .message(locale, &self.code, &self.params)
+            .unwrap_or_else(|| self.message.clone())
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// One or more [`ValidationError`]s collected from validating a value.
+///
+/// Validation always runs every rule and reports every failure, rather
+/// than stopping at the first one, so a caller can show a user all of
+/// the problems with a submitted form at once.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors(Vec<ValidationError>);
+
+impl ValidationErrors {
+    /// An empty set of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error to the set.
+    pub fn push(&mut self, error: ValidationError) {
+        self.0.push(error);
+    }
+
+    /// Whether any errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The number of errors recorded.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Iterates over the recorded errors.
+    pub fn iter(&self) -> impl Iterator<Item = &ValidationError> {
+        self.0.iter()
+    }
+
+    /// Merges `other`'s errors into `self`, prefixing each field name
+    /// with `prefix.`, so a nested value's errors (e.g. `street`) show
+    /// up under the containing field (e.g. `address.street`).
+    pub fn merge_nested(&mut self, prefix: &str, other: ValidationErrors) {
+        for mut error in other.0 {
+            error.field = format!("{prefix}.{}", error.field);
+            self.0.push(error);
+        }
+    }
+
+    /// Converts this into `Ok(())` if empty, or `Err(self)` otherwise.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl IntoIterator for ValidationErrors {
+    type Item = ValidationError;
+    type IntoIter = std::vec::IntoIter<ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl FromIterator<ValidationError> for ValidationErrors {
+    fn from_iter<I: IntoIterator<Item = ValidationError>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Implemented by types whose invariants can be checked all at once,
+/// reporting every violation rather than failing on the first.
+///
+/// `#[derive(Validate)]` implements this from `#[validate(...)]` field
+/// attributes, calling the same rules as [`crate::core::validators`]
+/// and [`crate::Validator`] so the two never drift apart.
+pub trait Validate {
+    /// Checks every validation rule on `self`, returning every failure
+    /// found, or `Ok(())` if none were.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
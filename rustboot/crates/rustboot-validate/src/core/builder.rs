@@ -0,0 +1,250 @@
+//! A fluent builder for validating several fields of a value at once.
+
+use std::fmt;
+use std::hash::Hash;
+
+use regex::Regex;
+use rustboot_datetime::Timestamp;
+
+use crate::api::{RuleFailure, Validate, ValidationError, ValidationErrors};
+use crate::core::{collections, validators};
+
+/// Collects validation failures across one or more fields.
+///
+/// ```
+/// use rustboot_validate::Validator;
+///
+/// struct SignupForm {
+///     email: String,
+///     age: u8,
+/// }
+///
+/// fn validate(form: &SignupForm) -> Result<(), rustboot_validate::ValidationErrors> {
+///     let mut validator = Validator::new();
+///     validator.field("email").email(&form.email);
+///     validator.field("age").range(form.age, Some(13), None);
+///     validator.finish()
+/// }
+///
+/// let form = SignupForm { email: "not-an-email".to_string(), age: 30 };
+/// assert!(validate(&form).is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: ValidationErrors,
+}
+
+impl Validator {
+    /// An empty validator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts checking rules against `field`. Every rule chained off the
+    /// returned [`FieldValidator`] that fails is recorded against this
+    /// field name.
+    pub fn field<'v>(&'v mut self, field: &str) -> FieldValidator<'v> {
+        FieldValidator {
+            field: field.to_string(),
+            validator: self,
+        }
+    }
+
+    /// Finishes validation, returning every recorded failure.
+    pub fn finish(self) -> Result<(), ValidationErrors> {
+        self.errors.into_result()
+    }
+}
+
+/// Chains validation rules against a single field, recording a
+/// [`ValidationError`] on the owning [`Validator`] for each one that
+/// fails.
+pub struct FieldValidator<'v> {
+    field: String,
+    validator: &'v mut Validator,
+}
+
+impl<'v> FieldValidator<'v> {
+    fn fail(&mut self, failure: RuleFailure) {
+        self.validator.errors.push(ValidationError::from_failure(&self.field, failure));
+    }
+
+    /// Checks that `value` looks like an email address.
+    pub fn email(mut self, value: &str) -> Self {
+        if let Err(failure) = validators::email(value) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value`'s character count is within `[min, max]`.
+    pub fn length(mut self, value: &str, min: Option<usize>, max: Option<usize>) -> Self {
+        if let Err(failure) = validators::length(value, min, max) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` is within `[min, max]`.
+    pub fn range<T: PartialOrd + fmt::Display>(mut self, value: T, min: Option<T>, max: Option<T>) -> Self {
+        if let Err(failure) = validators::range(value, min, max) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` matches `pattern`.
+    pub fn matches(mut self, value: &str, pattern: &Regex) -> Self {
+        if let Err(failure) = validators::matches(value, pattern) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` parses as an absolute URL.
+    pub fn url(mut self, value: &str) -> Self {
+        if let Err(failure) = validators::url(value) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` parses as a UUID.
+    pub fn uuid(mut self, value: &str) -> Self {
+        if let Err(failure) = validators::uuid(value) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` is earlier than now.
+    pub fn date_in_past(mut self, value: Timestamp) -> Self {
+        if let Err(failure) = validators::date_in_past(value) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` is later than now.
+    pub fn date_in_future(mut self, value: Timestamp) -> Self {
+        if let Err(failure) = validators::date_in_future(value) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `value` is one of `options`.
+    pub fn one_of<T: PartialEq + fmt::Display>(mut self, value: &T, options: &[T]) -> Self {
+        if let Err(failure) = validators::one_of(value, options) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `items` contains at least `min` elements.
+    pub fn min_items<T>(mut self, items: &[T], min: usize) -> Self {
+        if let Err(failure) = collections::min_items(items, min) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `items` contains at most `max` elements.
+    pub fn max_items<T>(mut self, items: &[T], max: usize) -> Self {
+        if let Err(failure) = collections::max_items(items, max) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Checks that `items` contains no duplicate elements.
+    pub fn unique_items<T: Eq + Hash>(mut self, items: &[T]) -> Self {
+        if let Err(failure) = collections::unique_items(items) {
+            self.fail(failure);
+        }
+        self
+    }
+
+    /// Validates a nested value, merging its errors in under this
+    /// field's name (e.g. `address.street`).
+    pub fn nested<T: Validate>(self, value: &T) -> Self {
+        if let Err(errors) = value.validate() {
+            self.validator.errors.merge_nested(&self.field, errors);
+        }
+        self
+    }
+
+    /// Runs an arbitrary check, recording its error message if it
+    /// returns `Err`.
+    pub fn custom<F: FnOnce() -> Result<(), String>>(mut self, check: F) -> Self {
+        if let Err(message) = check() {
+            self.fail(RuleFailure::new("custom", message));
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finish_is_ok_when_nothing_failed() {
+        let mut validator = Validator::new();
+        validator.field("email").email("user@example.com");
+        assert!(validator.finish().is_ok());
+    }
+
+    #[test]
+    fn finish_collects_every_failure() {
+        let mut validator = Validator::new();
+        validator.field("email").email("not-an-email");
+        validator.field("age").range(5, Some(18), None);
+        let errors = validator.finish().unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn url_and_uuid_checks_chain_like_other_rules() {
+        let mut validator = Validator::new();
+        validator.field("homepage").url("not a url");
+        validator.field("id").uuid("not-a-uuid");
+        let errors = validator.finish().unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn one_of_and_collection_checks_chain_like_other_rules() {
+        let mut validator = Validator::new();
+        validator.field("role").one_of(&"owner".to_string(), &["admin".to_string(), "member".to_string()]);
+        validator.field("tags").min_items(&["a"], 2);
+        validator.field("tags").unique_items(&["a", "a"]);
+        let errors = validator.finish().unwrap_err();
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn nested_prefixes_the_field_name() {
+        struct Leaf;
+        impl Validate for Leaf {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                let mut validator = Validator::new();
+                validator.field("street").length("", Some(1), None);
+                validator.finish()
+            }
+        }
+
+        let mut validator = Validator::new();
+        validator.field("address").nested(&Leaf);
+        let errors = validator.finish().unwrap_err();
+        assert_eq!(errors.iter().next().unwrap().field, "address.street");
+    }
+
+    #[test]
+    fn custom_records_the_returned_message() {
+        let mut validator = Validator::new();
+        validator.field("password").custom(|| Err("too weak".to_string()));
+        let errors = validator.finish().unwrap_err();
+        assert_eq!(errors.iter().next().unwrap().message, "too weak");
+    }
+}
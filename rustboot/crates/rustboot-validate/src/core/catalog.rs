@@ -0,0 +1,88 @@
+//! An in-memory [`MessageCatalog`].
+
+use std::collections::HashMap;
+
+use crate::api::Params;
+use crate::spi::MessageCatalog;
+
+/// A [`MessageCatalog`] backed by an in-memory table of
+/// `(locale, code) -> template` strings, with `{name}` placeholders
+/// substituted from a [`ValidationError`]'s params.
+///
+/// ```
+/// use rustboot_validate::{MessageCatalog, StaticCatalog};
+/// use std::collections::BTreeMap;
+///
+/// let catalog = StaticCatalog::new()
+///     .with_message("es", "length.min", "debe tener al menos {min} caracteres");
+///
+/// let mut params = BTreeMap::new();
+/// params.insert("min".to_string(), "3".to_string());
+/// assert_eq!(
+///     catalog.message("es", "length.min", &params),
+///     Some("debe tener al menos 3 caracteres".to_string())
+/// );
+/// assert_eq!(catalog.message("fr", "length.min", &params), None);
+/// ```
+///
+/// [`ValidationError`]: crate::ValidationError
+#[derive(Debug, Default)]
+pub struct StaticCatalog {
+    templates: HashMap<(String, String), String>,
+}
+
+impl StaticCatalog {
+    /// An empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a message template for `code` in `locale`.
+    pub fn with_message(mut self, locale: impl Into<String>, code: impl Into<String>, template: impl Into<String>) -> Self {
+        self.templates.insert((locale.into(), code.into()), template.into());
+        self
+    }
+}
+
+impl MessageCatalog for StaticCatalog {
+    fn message(&self, locale: &str, code: &str, params: &Params) -> Option<String> {
+        let template = self.templates.get(&(locale.to_string(), code.to_string()))?;
+        Some(interpolate(template, params))
+    }
+}
+
+/// Substitutes each `{name}` placeholder in `template` with its value
+/// from `params`. A placeholder with no matching param is left as-is.
+fn interpolate(template: &str, params: &Params) -> String {
+    let mut rendered = template.to_string();
+    for (name, value) in params {
+        rendered = rendered.replace(&format!("{{{name}}}"), value);
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_registered_template() {
+        let catalog = StaticCatalog::new().with_message("es", "email", "debe ser un correo valido");
+        assert_eq!(catalog.message("es", "email", &Params::new()), Some("debe ser un correo valido".to_string()));
+    }
+
+    #[test]
+    fn substitutes_every_placeholder() {
+        let catalog = StaticCatalog::new().with_message("es", "range.max", "debe ser como maximo {max}");
+        let mut params = Params::new();
+        params.insert("max".to_string(), "10".to_string());
+        assert_eq!(catalog.message("es", "range.max", &params), Some("debe ser como maximo 10".to_string()));
+    }
+
+    #[test]
+    fn returns_none_for_an_unregistered_locale_or_code() {
+        let catalog = StaticCatalog::new().with_message("es", "email", "debe ser un correo valido");
+        assert_eq!(catalog.message("fr", "email", &Params::new()), None);
+        assert_eq!(catalog.message("es", "url", &Params::new()), None);
+    }
+}
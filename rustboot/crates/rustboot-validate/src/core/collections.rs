@@ -0,0 +1,57 @@
+//! Validation rules for slices, `Vec`s, and other item collections.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::api::RuleFailure;
+
+/// Checks that `items` contains at least `min` elements.
+pub fn min_items<T>(items: &[T], min: usize) -> Result<(), RuleFailure> {
+    if items.len() < min {
+        Err(RuleFailure::new("min_items", format!("must contain at least {min} items")).with_param("min", min))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `items` contains at most `max` elements.
+pub fn max_items<T>(items: &[T], max: usize) -> Result<(), RuleFailure> {
+    if items.len() > max {
+        Err(RuleFailure::new("max_items", format!("must contain at most {max} items")).with_param("max", max))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `items` contains no duplicate elements.
+pub fn unique_items<T: Eq + Hash>(items: &[T]) -> Result<(), RuleFailure> {
+    let unique: HashSet<&T> = items.iter().collect();
+    if unique.len() == items.len() {
+        Ok(())
+    } else {
+        Err(RuleFailure::new("unique_items", "must not contain duplicate items"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_items_enforces_the_minimum() {
+        assert!(min_items(&[1, 2], 3).is_err());
+        assert!(min_items(&[1, 2, 3], 3).is_ok());
+    }
+
+    #[test]
+    fn max_items_enforces_the_maximum() {
+        assert!(max_items(&[1, 2, 3, 4], 3).is_err());
+        assert!(max_items(&[1, 2, 3], 3).is_ok());
+    }
+
+    #[test]
+    fn unique_items_detects_duplicates() {
+        assert!(unique_items(&[1, 2, 3]).is_ok());
+        assert!(unique_items(&[1, 2, 2]).is_err());
+    }
+}
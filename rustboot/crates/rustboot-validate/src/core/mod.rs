@@ -0,0 +1,6 @@
+//! Implementation details for the validation module.
+
+pub mod builder;
+pub mod catalog;
+pub mod collections;
+pub mod validators;
@@ -0,0 +1,201 @@
+//! Standalone validation rules, shared by [`crate::Validator`] and by the
+//! code `#[derive(Validate)]` generates, so the two never check a field
+//! against different rules.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use rustboot_datetime::Timestamp;
+use rustboot_identifiers::Uuid;
+
+use crate::api::RuleFailure;
+
+/// A reasonably strict, dependency-free email shape check: one `@`,
+/// something on both sides, and at least one `.` after it. Not a full
+/// RFC 5322 validator — just enough to catch typos in a form field.
+static EMAIL_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").expect("EMAIL_PATTERN is a valid regex")
+});
+
+/// Checks that `value` looks like an email address.
+pub fn email(value: &str) -> Result<(), RuleFailure> {
+    if EMAIL_PATTERN.is_match(value) {
+        Ok(())
+    } else {
+        Err(RuleFailure::new("email", "must be a valid email address"))
+    }
+}
+
+/// Checks that `value`'s character count is within `[min, max]`
+/// (either bound optional).
+pub fn length(value: &str, min: Option<usize>, max: Option<usize>) -> Result<(), RuleFailure> {
+    let len = value.chars().count();
+    if let Some(min) = min {
+        if len < min {
+            return Err(RuleFailure::new("length.min", format!("must be at least {min} characters long")).with_param("min", min));
+        }
+    }
+    if let Some(max) = max {
+        if len > max {
+            return Err(RuleFailure::new("length.max", format!("must be at most {max} characters long")).with_param("max", max));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `value` is within `[min, max]` (either bound optional).
+pub fn range<T: PartialOrd + fmt::Display>(value: T, min: Option<T>, max: Option<T>) -> Result<(), RuleFailure> {
+    if let Some(min) = &min {
+        if value < *min {
+            return Err(RuleFailure::new("range.min", format!("must be at least {min}")).with_param("min", min));
+        }
+    }
+    if let Some(max) = &max {
+        if value > *max {
+            return Err(RuleFailure::new("range.max", format!("must be at most {max}")).with_param("max", max));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `value` matches `pattern`.
+pub fn matches(value: &str, pattern: &Regex) -> Result<(), RuleFailure> {
+    if pattern.is_match(value) {
+        Ok(())
+    } else {
+        Err(RuleFailure::new("regex", "does not match the required pattern"))
+    }
+}
+
+/// Checks that `value` parses as an absolute URL.
+pub fn url(value: &str) -> Result<(), RuleFailure> {
+    url::Url::parse(value)
+        .map(|_| ())
+        .map_err(|_| RuleFailure::new("url", "must be a valid URL"))
+}
+
+/// Checks that `value` parses as a UUID.
+pub fn uuid(value: &str) -> Result<(), RuleFailure> {
+    value
+        .parse::<Uuid>()
+        .map(|_| ())
+        .map_err(|_| RuleFailure::new("uuid", "must be a valid UUID"))
+}
+
+/// Checks that `value` is earlier than now.
+pub fn date_in_past(value: Timestamp) -> Result<(), RuleFailure> {
+    if value < Timestamp::now() {
+        Ok(())
+    } else {
+        Err(RuleFailure::new("date_in_past", "must be in the past"))
+    }
+}
+
+/// Checks that `value` is later than now.
+pub fn date_in_future(value: Timestamp) -> Result<(), RuleFailure> {
+    if value > Timestamp::now() {
+        Ok(())
+    } else {
+        Err(RuleFailure::new("date_in_future", "must be in the future"))
+    }
+}
+
+/// Checks that `value` is one of `options`.
+pub fn one_of<T: PartialEq + fmt::Display>(value: &T, options: &[T]) -> Result<(), RuleFailure> {
+    if options.contains(value) {
+        Ok(())
+    } else {
+        let rendered: Vec<String> = options.iter().map(ToString::to_string).collect();
+        let joined = rendered.join(", ");
+        Err(RuleFailure::new("one_of", format!("must be one of: {joined}")).with_param("options", joined))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn email_accepts_a_plausible_address() {
+        assert!(email("user@example.com").is_ok());
+    }
+
+    #[test]
+    fn email_rejects_a_missing_at_sign() {
+        assert!(email("user.example.com").is_err());
+    }
+
+    #[test]
+    fn email_rejects_a_missing_domain_dot() {
+        assert!(email("user@localhost").is_err());
+    }
+
+    #[test]
+    fn length_enforces_the_minimum() {
+        let failure = length("ab", Some(3), None).unwrap_err();
+        assert_eq!(failure.code, "length.min");
+        assert_eq!(failure.params.get("min"), Some(&"3".to_string()));
+        assert!(length("abc", Some(3), None).is_ok());
+    }
+
+    #[test]
+    fn length_enforces_the_maximum() {
+        let failure = length("abcd", None, Some(3)).unwrap_err();
+        assert_eq!(failure.code, "length.max");
+        assert!(length("abc", None, Some(3)).is_ok());
+    }
+
+    #[test]
+    fn range_enforces_both_bounds() {
+        assert!(range(5, Some(0), Some(10)).is_ok());
+        assert_eq!(range(-1, Some(0), Some(10)).unwrap_err().code, "range.min");
+        assert_eq!(range(11, Some(0), Some(10)).unwrap_err().code, "range.max");
+    }
+
+    #[test]
+    fn matches_checks_the_pattern() {
+        let pattern = Regex::new(r"^[a-z]+$").unwrap();
+        assert!(matches("hello", &pattern).is_ok());
+        assert!(matches("Hello", &pattern).is_err());
+    }
+
+    #[test]
+    fn url_accepts_an_absolute_url() {
+        assert!(url("https://example.com/path").is_ok());
+    }
+
+    #[test]
+    fn url_rejects_a_relative_path() {
+        assert!(url("/path").is_err());
+    }
+
+    #[test]
+    fn uuid_accepts_a_valid_uuid() {
+        assert!(uuid("00000000-0000-0000-0000-000000000000").is_ok());
+    }
+
+    #[test]
+    fn uuid_rejects_garbage() {
+        assert!(uuid("not-a-uuid").is_err());
+    }
+
+    #[test]
+    fn date_in_past_rejects_a_future_timestamp() {
+        let future = Timestamp::from_unix_seconds(Timestamp::now().to_unix_seconds() + 3600).unwrap();
+        assert!(date_in_past(future).is_err());
+    }
+
+    #[test]
+    fn date_in_future_accepts_a_future_timestamp() {
+        let future = Timestamp::from_unix_seconds(Timestamp::now().to_unix_seconds() + 3600).unwrap();
+        assert!(date_in_future(future).is_ok());
+    }
+
+    #[test]
+    fn one_of_checks_membership() {
+        let options = ["red".to_string(), "green".to_string(), "blue".to_string()];
+        assert!(one_of(&"green".to_string(), &options).is_ok());
+        assert!(one_of(&"purple".to_string(), &options).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+//! Struct validation for the rustboot framework.
+//!
+//! - [`Validator`]/[`FieldValidator`]: a fluent builder for checking
+//!   several fields of a value and collecting every failure, with
+//!   rules covering scalars ([`core::validators`]: `email`, `length`,
+//!   `range`, `regex`, `url`, `uuid`, `date_in_past`, `date_in_future`,
+//!   `one_of`) and collections ([`core::collections`]: `min_items`,
+//!   `max_items`, `unique_items`), so a typical API payload check
+//!   doesn't need a hand-rolled `.custom()` closure.
+//! - [`Validate`] / `#[derive(Validate)]`: implement `Validate` from
+//!   `#[validate(...)]` field attributes — `email`, `length(min, max)`,
+//!   `range(min, max)`, `regex = "..."`, `nested`, and
+//!   `custom = "fn_path"` — instead of writing the builder calls by
+//!   hand. The generated code calls the same [`core::validators`]
+//!   functions the builder does, so the two can't drift apart.
+//! - [`ValidationError`]/[`ValidationErrors`]: a field name, a stable
+//!   `code` (e.g. `"length.min"`), interpolation `params` (e.g.
+//!   `min` -> `"3"`), and a default English `message`, collected
+//!   rather than short-circuited so a caller can report every problem
+//!   with a submitted value at once.
+//! - [`ValidationError::localize`] / [`spi::MessageCatalog`]: render a
+//!   translated message for an error's `code` and `params` instead of
+//!   the default English one. [`StaticCatalog`] is a basic in-memory
+//!   implementation; a caller backed by a real translation system
+//!   implements [`spi::MessageCatalog`] directly.
+//!
+//! # Example
+//!
+//! ```
+//! use rustboot_validate::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SignupForm {
+//!     #[validate(email)]
+//!     email: String,
+//!     #[validate(length(min = 8, max = 72))]
+//!     password: String,
+//!     #[validate(range(min = 13, max = 150))]
+//!     age: u8,
+//! }
+//!
+//! let form = SignupForm {
+//!     email: "not-an-email".to_string(),
+//!     password: "short".to_string(),
+//!     age: 5,
+//! };
+//! let errors = form.validate().unwrap_err();
+//! assert_eq!(errors.len(), 3);
+//! ```
+
+pub mod api;
+pub mod core;
+pub mod spi;
+
+pub use api::{Params, RuleFailure, Validate, ValidationError, ValidationErrors};
+pub use core::builder::{FieldValidator, Validator};
+pub use core::catalog::StaticCatalog;
+pub use rustboot_validate_derive::Validate;
+pub use spi::MessageCatalog;
@@ -0,0 +1,16 @@
+//! Service provider interfaces for the validation module.
+
+use crate::api::Params;
+
+/// A pluggable source of localized, interpolated validation messages.
+///
+/// Looked up by [`crate::ValidationError::localize`] so a front-end can
+/// render validation failures in the user's language without this
+/// crate needing to bundle every locale's translations itself.
+pub trait MessageCatalog: Send + Sync {
+    /// Renders the message template for `code` in `locale`, with
+    /// `{name}` placeholders substituted from `params`. Returns `None`
+    /// if `locale` or `code` isn't in the catalog, so the caller can
+    /// fall back to the default English message.
+    fn message(&self, locale: &str, code: &str, params: &Params) -> Option<String>;
+}
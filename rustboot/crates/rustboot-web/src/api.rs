@@ -0,0 +1,73 @@
+//! Public types for the web module.
+
+use http::StatusCode;
+
+/// A typed HTTP error carrying both what's safe to show a caller and what
+/// should only ever reach internal logs.
+#[derive(Debug, Clone)]
+pub struct WebError {
+    /// HTTP status to respond with.
+    pub status: StatusCode,
+    /// Stable machine-readable error code (e.g. `"order_not_found"`).
+    pub code: String,
+    /// Message safe to return to API callers.
+    pub public_message: String,
+    /// Internal diagnostic detail (stack context, upstream error, ...).
+    /// Never rendered to callers in [`RenderMode::Production`].
+    pub internal_detail: Option<String>,
+    /// Correlation/request id to aid cross-referencing logs, if known.
+    pub correlation_id: Option<String>,
+}
+
+impl WebError {
+    /// Creates a new `WebError` with no internal detail or correlation id.
+    pub fn new(status: StatusCode, code: impl Into<String>, public_message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code: code.into(),
+            public_message: public_message.into(),
+            internal_detail: None,
+            correlation_id: None,
+        }
+    }
+
+    /// Attaches internal diagnostic detail, logged but never shown to
+    /// callers in production.
+    pub fn with_internal_detail(mut self, detail: impl Into<String>) -> Self {
+        self.internal_detail = Some(detail.into());
+        self
+    }
+
+    /// Attaches a correlation/request id for cross-referencing logs.
+    pub fn with_correlation_id(mut self, id: impl Into<String>) -> Self {
+        self.correlation_id = Some(id.into());
+        self
+    }
+}
+
+/// Converts an application error into a [`WebError`].
+///
+/// Implement this on your application's error enums instead of
+/// hand-writing a status-code match at every handler call site.
+pub trait IntoWebError {
+    /// Converts `self` into a [`WebError`].
+    fn into_web_error(self) -> WebError;
+}
+
+impl IntoWebError for WebError {
+    fn into_web_error(self) -> WebError {
+        self
+    }
+}
+
+/// Controls whether [`crate::core::problem_json::render`] includes
+/// [`WebError::internal_detail`] in the response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    /// Internal detail is included in the response body, for local
+    /// debugging only.
+    Development,
+    /// Internal detail is withheld from the response body and only
+    /// available via logs.
+    Production,
+}
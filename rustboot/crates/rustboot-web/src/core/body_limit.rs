@@ -0,0 +1,292 @@
+//! Request body size limits and decompression-bomb protection: rejects
+//! oversized bodies before they're buffered, and caps how much a
+//! `gzip`/`deflate` body is allowed to decompress to, so a small
+//! compressed payload can't exhaust memory on the way to a handler.
+
+use std::io::Read;
+
+use http::StatusCode;
+
+use crate::api::{IntoWebError, WebError};
+
+/// How large, in both compressed and decompressed form, a request body
+/// is allowed to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLimitConfig {
+    /// Maximum size of the body as received on the wire, in bytes.
+    pub max_body_bytes: u64,
+    /// Maximum size of the body after decompression, in bytes. Checked
+    /// while decompressing, not just on the final result, so a bomb is
+    /// rejected before it's fully inflated.
+    pub max_decompressed_bytes: u64,
+}
+
+impl BodyLimitConfig {
+    /// Creates a config with the same limit applied before and after
+    /// decompression.
+    pub fn new(max_bytes: u64) -> Self {
+        Self {
+            max_body_bytes: max_bytes,
+            max_decompressed_bytes: max_bytes,
+        }
+    }
+}
+
+/// The `Content-Encoding` of a request body this module knows how to
+/// safely decompress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncoding {
+    /// No encoding; the body is used as-is.
+    Identity,
+    /// `Content-Encoding: gzip`.
+    Gzip,
+    /// `Content-Encoding: deflate`.
+    Deflate,
+}
+
+impl ContentEncoding {
+    /// Parses a `Content-Encoding` header value.
+    pub fn from_header_value(value: &str) -> Option<Self> {
+        match value.trim() {
+            "" => Some(ContentEncoding::Identity),
+            "identity" => Some(ContentEncoding::Identity),
+            "gzip" => Some(ContentEncoding::Gzip),
+            "deflate" => Some(ContentEncoding::Deflate),
+            _ => None,
+        }
+    }
+}
+
+/// Errors from enforcing a [`BodyLimitConfig`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BodyLimitError {
+    /// The `Content-Length` header alone already exceeds
+    /// [`BodyLimitConfig::max_body_bytes`].
+    DeclaredLengthTooLarge {
+        /// The configured limit.
+        limit: u64,
+        /// The `Content-Length` the caller sent.
+        declared: u64,
+    },
+    /// The body as received exceeds [`BodyLimitConfig::max_body_bytes`],
+    /// regardless of what `Content-Length` declared.
+    BodyTooLarge {
+        /// The configured limit.
+        limit: u64,
+    },
+    /// The decompressed body would exceed
+    /// [`BodyLimitConfig::max_decompressed_bytes`].
+    DecompressedBodyTooLarge {
+        /// The configured limit.
+        limit: u64,
+    },
+    /// The `Content-Encoding` isn't one this module can decompress.
+    UnsupportedEncoding(String),
+    /// The body could not be decompressed as declared (corrupt or
+    /// truncated stream).
+    MalformedEncoding(String),
+}
+
+impl IntoWebError for BodyLimitError {
+    fn into_web_error(self) -> WebError {
+        match self {
+            BodyLimitError::DeclaredLengthTooLarge { limit, declared } => WebError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "body_too_large",
+                "Request body too large",
+            )
+            .with_internal_detail(format!("Content-Length {declared} exceeds limit {limit}")),
+            BodyLimitError::BodyTooLarge { limit } => WebError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "body_too_large",
+                "Request body too large",
+            )
+            .with_internal_detail(format!("body exceeds limit {limit}")),
+            BodyLimitError::DecompressedBodyTooLarge { limit } => WebError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "body_too_large",
+                "Request body too large",
+            )
+            .with_internal_detail(format!("decompressed body exceeds limit {limit}")),
+            BodyLimitError::UnsupportedEncoding(encoding) => WebError::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "unsupported_content_encoding",
+                "Unsupported Content-Encoding",
+            )
+            .with_internal_detail(encoding),
+            BodyLimitError::MalformedEncoding(detail) => WebError::new(
+                StatusCode::BAD_REQUEST,
+                "malformed_content_encoding",
+                "Request body could not be decoded",
+            )
+            .with_internal_detail(detail),
+        }
+    }
+}
+
+/// Rejects a request whose declared `Content-Length` already exceeds the
+/// limit, before any of the body is read off the wire.
+pub fn check_declared_length(
+    content_length: Option<u64>,
+    config: &BodyLimitConfig,
+) -> Result<(), BodyLimitError> {
+    if let Some(declared) = content_length {
+        if declared > config.max_body_bytes {
+            return Err(BodyLimitError::DeclaredLengthTooLarge {
+                limit: config.max_body_bytes,
+                declared,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Rejects `body` if it exceeds [`BodyLimitConfig::max_body_bytes`].
+///
+/// Call this even when [`check_declared_length`] already passed:
+/// `Content-Length` is caller-supplied and not binding on the actual
+/// byte count received.
+pub fn enforce_body_limit(body: &[u8], config: &BodyLimitConfig) -> Result<(), BodyLimitError> {
+    if body.len() as u64 > config.max_body_bytes {
+        return Err(BodyLimitError::BodyTooLarge {
+            limit: config.max_body_bytes,
+        });
+    }
+    Ok(())
+}
+
+const DECOMPRESS_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Decodes `body` per `encoding`, rejecting it once the decompressed
+/// size would exceed [`BodyLimitConfig::max_decompressed_bytes`].
+///
+/// Reads the decompressor in bounded chunks rather than all at once, so
+/// a small, highly-compressed body (a decompression bomb) is rejected
+/// partway through instead of first being fully inflated into memory.
+pub fn decode_body(
+    body: &[u8],
+    encoding: ContentEncoding,
+    config: &BodyLimitConfig,
+) -> Result<Vec<u8>, BodyLimitError> {
+    let reader: Box<dyn Read + '_> = match encoding {
+        ContentEncoding::Identity => {
+            enforce_body_limit(body, config)?;
+            return Ok(body.to_vec());
+        }
+        ContentEncoding::Gzip => Box::new(flate2::read::GzDecoder::new(body)),
+        ContentEncoding::Deflate => Box::new(flate2::read::DeflateDecoder::new(body)),
+    };
+
+    read_bounded(reader, config.max_decompressed_bytes)
+}
+
+fn read_bounded(mut reader: Box<dyn Read + '_>, limit: u64) -> Result<Vec<u8>, BodyLimitError> {
+    let mut out = Vec::new();
+    let mut chunk = [0u8; DECOMPRESS_CHUNK_BYTES];
+    loop {
+        let read = reader
+            .read(&mut chunk)
+            .map_err(|err| BodyLimitError::MalformedEncoding(err.to_string()))?;
+        if read == 0 {
+            break;
+        }
+        if out.len() as u64 + read as u64 > limit {
+            return Err(BodyLimitError::DecompressedBodyTooLarge { limit });
+        }
+        out.extend_from_slice(&chunk[..read]);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn content_encoding_parses_known_values() {
+        assert_eq!(ContentEncoding::from_header_value("gzip"), Some(ContentEncoding::Gzip));
+        assert_eq!(ContentEncoding::from_header_value("deflate"), Some(ContentEncoding::Deflate));
+        assert_eq!(ContentEncoding::from_header_value(""), Some(ContentEncoding::Identity));
+        assert_eq!(ContentEncoding::from_header_value("br"), None);
+    }
+
+    #[test]
+    fn check_declared_length_rejects_an_oversized_content_length() {
+        let config = BodyLimitConfig::new(100);
+        assert_eq!(
+            check_declared_length(Some(200), &config),
+            Err(BodyLimitError::DeclaredLengthTooLarge { limit: 100, declared: 200 })
+        );
+    }
+
+    #[test]
+    fn check_declared_length_allows_a_missing_header_through() {
+        let config = BodyLimitConfig::new(100);
+        assert_eq!(check_declared_length(None, &config), Ok(()));
+    }
+
+    #[test]
+    fn enforce_body_limit_rejects_a_body_larger_than_declared() {
+        let config = BodyLimitConfig::new(4);
+        assert_eq!(
+            enforce_body_limit(b"too long", &config),
+            Err(BodyLimitError::BodyTooLarge { limit: 4 })
+        );
+    }
+
+    #[test]
+    fn decode_body_passes_identity_bodies_through_the_size_check() {
+        let config = BodyLimitConfig::new(5);
+        assert_eq!(
+            decode_body(b"hello", ContentEncoding::Identity, &config),
+            Ok(b"hello".to_vec())
+        );
+        assert_eq!(
+            decode_body(b"too long", ContentEncoding::Identity, &config),
+            Err(BodyLimitError::BodyTooLarge { limit: 5 })
+        );
+    }
+
+    #[test]
+    fn decode_body_inflates_a_gzip_body_within_the_limit() {
+        let payload = b"hello world".repeat(100);
+        let compressed = gzip(&payload);
+        let config = BodyLimitConfig {
+            max_body_bytes: compressed.len() as u64,
+            max_decompressed_bytes: payload.len() as u64,
+        };
+        assert_eq!(decode_body(&compressed, ContentEncoding::Gzip, &config), Ok(payload));
+    }
+
+    #[test]
+    fn decode_body_rejects_a_decompression_bomb() {
+        let payload = vec![0u8; 10 * 1024 * 1024];
+        let compressed = gzip(&payload);
+        let config = BodyLimitConfig {
+            max_body_bytes: compressed.len() as u64 + 1,
+            max_decompressed_bytes: 1024,
+        };
+        assert_eq!(
+            decode_body(&compressed, ContentEncoding::Gzip, &config),
+            Err(BodyLimitError::DecompressedBodyTooLarge { limit: 1024 })
+        );
+    }
+
+    #[test]
+    fn decode_body_rejects_a_truncated_gzip_stream() {
+        let compressed = gzip(b"hello world");
+        let truncated = &compressed[..compressed.len() - 4];
+        let config = BodyLimitConfig::new(1024);
+        assert!(matches!(
+            decode_body(truncated, ContentEncoding::Gzip, &config),
+            Err(BodyLimitError::MalformedEncoding(_))
+        ));
+    }
+}
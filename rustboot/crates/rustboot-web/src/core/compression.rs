@@ -0,0 +1,274 @@
+//! Response compression: decides whether a response is worth compressing
+//! (size threshold, content-type allowlist, client support via
+//! `Accept-Encoding`) and streams the body through a `gzip`/`deflate`
+//! encoder a chunk at a time, so a large response body isn't buffered
+//! in full before compression starts.
+//!
+//! `br`/`zstd` aren't supported: this module sticks to the encodings
+//! [`crate::core::body_limit`] already decodes on the request side with
+//! `flate2`, rather than pulling in another compression dependency.
+
+use std::io::{self, Write};
+
+use http::StatusCode;
+
+use crate::api::{IntoWebError, WebError};
+use crate::core::body_limit::ContentEncoding;
+
+/// Controls when a response is eligible for compression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressionConfig {
+    /// Responses smaller than this are sent uncompressed: compressing a
+    /// tiny body rarely pays for the CPU and framing overhead.
+    pub min_body_bytes: u64,
+    /// `Content-Type` prefixes eligible for compression (e.g.
+    /// `"text/"`, `"application/json"`). Already-compressed formats
+    /// (images, video, archives) should be left off this list.
+    pub compressible_content_types: Vec<String>,
+}
+
+impl CompressionConfig {
+    /// Creates a config with a size threshold and content-type allowlist.
+    pub fn new(min_body_bytes: u64, compressible_content_types: Vec<String>) -> Self {
+        Self {
+            min_body_bytes,
+            compressible_content_types,
+        }
+    }
+
+    fn allows_content_type(&self, content_type: &str) -> bool {
+        self.compressible_content_types
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix.as_str()))
+    }
+}
+
+/// Errors from streaming a response body through a compressor.
+#[derive(Debug)]
+pub enum CompressionError {
+    /// The underlying encoder failed (should not happen for an in-memory
+    /// writer, but `flate2` surfaces it as an `io::Error`).
+    Encode(io::Error),
+}
+
+impl IntoWebError for CompressionError {
+    fn into_web_error(self) -> WebError {
+        match self {
+            CompressionError::Encode(err) => WebError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "response_compression_failed",
+                "Failed to prepare response",
+            )
+            .with_internal_detail(err.to_string()),
+        }
+    }
+}
+
+/// Picks the best encoding this module supports out of a client's
+/// `Accept-Encoding` header, or `None` if the client accepts none of
+/// them (the response should then be sent uncompressed).
+///
+/// A bare `*` is treated as accepting `gzip`, since that's the encoding
+/// this function prefers when several are available.
+pub fn negotiate_encoding(accept_encoding: Option<&str>) -> Option<ContentEncoding> {
+    let header = accept_encoding?;
+    let mut best: Option<(ContentEncoding, f32)> = None;
+    for offer in header.split(',') {
+        let mut parts = offer.split(';');
+        let coding = parts.next()?.trim();
+        let quality = parts
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if quality <= 0.0 {
+            continue;
+        }
+        let encoding = match coding {
+            "gzip" | "*" => ContentEncoding::Gzip,
+            "deflate" => ContentEncoding::Deflate,
+            _ => continue,
+        };
+        if best.map(|(_, best_q)| quality > best_q).unwrap_or(true) {
+            best = Some((encoding, quality));
+        }
+    }
+    best.map(|(encoding, _)| encoding)
+}
+
+/// Decides whether a response body is worth compressing, given its
+/// `Content-Type`, its size, and the negotiated client encoding.
+///
+/// Returns `Some(encoding)` to use, or `None` to send the body as-is.
+pub fn should_compress(
+    content_type: Option<&str>,
+    body_len: u64,
+    accept_encoding: Option<&str>,
+    config: &CompressionConfig,
+) -> Option<ContentEncoding> {
+    if body_len < config.min_body_bytes {
+        return None;
+    }
+    let content_type = content_type?;
+    if !config.allows_content_type(content_type) {
+        return None;
+    }
+    negotiate_encoding(accept_encoding)
+}
+
+/// Streams a response body through a `gzip`/`deflate` encoder a chunk at
+/// a time via [`StreamingEncoder::write_chunk`], rather than requiring
+/// the whole uncompressed body in memory before compression can start.
+pub struct StreamingEncoder {
+    inner: EncoderKind,
+}
+
+enum EncoderKind {
+    Gzip(flate2::write::GzEncoder<Vec<u8>>),
+    Deflate(flate2::write::DeflateEncoder<Vec<u8>>),
+}
+
+impl StreamingEncoder {
+    /// Creates an encoder for `encoding`. Panics if asked for
+    /// [`ContentEncoding::Identity`]: callers should skip compression
+    /// entirely in that case instead of wrapping the body in a no-op
+    /// encoder.
+    pub fn new(encoding: ContentEncoding) -> Self {
+        let inner = match encoding {
+            ContentEncoding::Gzip => {
+                EncoderKind::Gzip(flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default()))
+            }
+            ContentEncoding::Deflate => EncoderKind::Deflate(flate2::write::DeflateEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            )),
+            ContentEncoding::Identity => {
+                panic!("StreamingEncoder does not support ContentEncoding::Identity")
+            }
+        };
+        Self { inner }
+    }
+
+    /// Feeds the next chunk of the uncompressed body through the
+    /// encoder. Call this as each chunk of the response becomes
+    /// available, rather than assembling the full body first.
+    pub fn write_chunk(&mut self, chunk: &[u8]) -> Result<(), CompressionError> {
+        let result = match &mut self.inner {
+            EncoderKind::Gzip(encoder) => encoder.write_all(chunk),
+            EncoderKind::Deflate(encoder) => encoder.write_all(chunk),
+        };
+        result.map_err(CompressionError::Encode)
+    }
+
+    /// Finalizes the stream and returns the compressed bytes.
+    pub fn finish(self) -> Result<Vec<u8>, CompressionError> {
+        match self.inner {
+            EncoderKind::Gzip(encoder) => encoder.finish(),
+            EncoderKind::Deflate(encoder) => encoder.finish(),
+        }
+        .map_err(CompressionError::Encode)
+    }
+}
+
+/// Compresses `chunks` as `encoding` in one call, for callers that
+/// already have every chunk on hand but still want to avoid
+/// concatenating them into a single buffer first.
+pub fn compress_chunks(
+    chunks: &[&[u8]],
+    encoding: ContentEncoding,
+) -> Result<Vec<u8>, CompressionError> {
+    let mut encoder = StreamingEncoder::new(encoding);
+    for chunk in chunks {
+        encoder.write_chunk(chunk)?;
+    }
+    encoder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> CompressionConfig {
+        CompressionConfig::new(256, vec!["text/".to_string(), "application/json".to_string()])
+    }
+
+    #[test]
+    fn negotiate_encoding_prefers_higher_quality() {
+        assert_eq!(
+            negotiate_encoding(Some("deflate;q=0.5, gzip;q=0.8")),
+            Some(ContentEncoding::Gzip)
+        );
+    }
+
+    #[test]
+    fn negotiate_encoding_ignores_zero_quality_offers() {
+        assert_eq!(negotiate_encoding(Some("gzip;q=0")), None);
+    }
+
+    #[test]
+    fn negotiate_encoding_treats_star_as_gzip() {
+        assert_eq!(negotiate_encoding(Some("*")), Some(ContentEncoding::Gzip));
+    }
+
+    #[test]
+    fn negotiate_encoding_returns_none_for_unsupported_codings() {
+        assert_eq!(negotiate_encoding(Some("br")), None);
+    }
+
+    #[test]
+    fn negotiate_encoding_returns_none_with_no_header() {
+        assert_eq!(negotiate_encoding(None), None);
+    }
+
+    #[test]
+    fn should_compress_skips_small_bodies() {
+        assert_eq!(
+            should_compress(Some("text/plain"), 10, Some("gzip"), &config()),
+            None
+        );
+    }
+
+    #[test]
+    fn should_compress_skips_disallowed_content_types() {
+        assert_eq!(
+            should_compress(Some("image/png"), 10_000, Some("gzip"), &config()),
+            None
+        );
+    }
+
+    #[test]
+    fn should_compress_skips_when_content_type_is_missing() {
+        assert_eq!(should_compress(None, 10_000, Some("gzip"), &config()), None);
+    }
+
+    #[test]
+    fn should_compress_picks_the_negotiated_encoding() {
+        assert_eq!(
+            should_compress(Some("application/json"), 10_000, Some("deflate"), &config()),
+            Some(ContentEncoding::Deflate)
+        );
+    }
+
+    #[test]
+    fn streaming_encoder_matches_one_shot_compression() {
+        let body = b"hello world".repeat(50);
+        let mut encoder = StreamingEncoder::new(ContentEncoding::Gzip);
+        for chunk in body.chunks(17) {
+            encoder.write_chunk(chunk).unwrap();
+        }
+        let streamed = encoder.finish().unwrap();
+
+        let mut decoder = flate2::read::GzDecoder::new(streamed.as_slice());
+        let mut decompressed = Vec::new();
+        io::Read::read_to_end(&mut decoder, &mut decompressed).unwrap();
+        assert_eq!(decompressed, body);
+    }
+
+    #[test]
+    fn compress_chunks_round_trips_through_deflate() {
+        let compressed = compress_chunks(&[b"foo", b"bar", b"baz"], ContentEncoding::Deflate).unwrap();
+        let mut decoder = flate2::read::DeflateDecoder::new(compressed.as_slice());
+        let mut decompressed = Vec::new();
+        io::Read::read_to_end(&mut decoder, &mut decompressed).unwrap();
+        assert_eq!(decompressed, b"foobarbaz");
+    }
+}
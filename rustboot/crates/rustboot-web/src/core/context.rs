@@ -0,0 +1,156 @@
+//! Per-request context passed through a middleware pipeline to handlers.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+/// A type-keyed map of arbitrary values, one slot per type.
+///
+/// Used by [`HttpContext`] to let middleware attach strongly-typed data
+/// (an auth principal, a tenant, a request ID) for downstream middleware
+/// and handlers to read, without stringly-typed headers or globals.
+#[derive(Default)]
+pub struct Extensions {
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+    /// Creates an empty extension map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts `value`, returning the previous value of type `T`, if any.
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) -> Option<T> {
+        self.values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|boxed| boxed.downcast::<T>().ok())
+            .map(|boxed| *boxed)
+    }
+
+    /// Returns a reference to the stored value of type `T`, if any.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast_ref::<T>())
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, if any.
+    pub fn get_mut<T: Any + Send + Sync>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast_mut::<T>())
+    }
+
+    /// Removes and returns the stored value of type `T`, if any.
+    pub fn remove<T: Any + Send + Sync>(&mut self) -> Option<T> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast::<T>().ok())
+            .map(|boxed| *boxed)
+    }
+
+    /// Returns whether a value of type `T` is stored.
+    pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+}
+
+/// Request-scoped state threaded through a middleware pipeline.
+///
+/// Middleware calls [`HttpContext::insert`] to attach typed data (for
+/// example, the principal extracted from a JWT) and downstream
+/// middleware or the final handler calls [`HttpContext::get`] to read
+/// it back, rather than round-tripping through headers or a global.
+#[derive(Default)]
+pub struct HttpContext {
+    extensions: Extensions,
+}
+
+impl HttpContext {
+    /// Creates a context with no extensions set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts `value` into the context, returning the previous value
+    /// of type `T`, if any.
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) -> Option<T> {
+        self.extensions.insert(value)
+    }
+
+    /// Returns a reference to the value of type `T`, if one was set.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
+
+    /// Returns a mutable reference to the value of type `T`, if one was set.
+    pub fn get_mut<T: Any + Send + Sync>(&mut self) -> Option<&mut T> {
+        self.extensions.get_mut::<T>()
+    }
+
+    /// Removes and returns the value of type `T`, if one was set.
+    pub fn remove<T: Any + Send + Sync>(&mut self) -> Option<T> {
+        self.extensions.remove::<T>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Principal {
+        id: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TenantId(String);
+
+    #[test]
+    fn inserts_and_retrieves_by_type() {
+        let mut ctx = HttpContext::new();
+        ctx.insert(Principal { id: "alice".into() });
+        ctx.insert(TenantId("acme".into()));
+
+        assert_eq!(ctx.get::<Principal>(), Some(&Principal { id: "alice".into() }));
+        assert_eq!(ctx.get::<TenantId>(), Some(&TenantId("acme".into())));
+    }
+
+    #[test]
+    fn missing_type_returns_none() {
+        let ctx = HttpContext::new();
+        assert_eq!(ctx.get::<Principal>(), None);
+    }
+
+    #[test]
+    fn insert_of_same_type_overwrites_and_returns_previous() {
+        let mut ctx = HttpContext::new();
+        ctx.insert(TenantId("acme".into()));
+        let previous = ctx.insert(TenantId("globex".into()));
+
+        assert_eq!(previous, Some(TenantId("acme".into())));
+        assert_eq!(ctx.get::<TenantId>(), Some(&TenantId("globex".into())));
+    }
+
+    #[test]
+    fn remove_takes_the_value_out() {
+        let mut ctx = HttpContext::new();
+        ctx.insert(TenantId("acme".into()));
+
+        assert_eq!(ctx.remove::<TenantId>(), Some(TenantId("acme".into())));
+        assert_eq!(ctx.get::<TenantId>(), None);
+    }
+
+    #[test]
+    fn distinct_types_do_not_collide() {
+        #[derive(Debug, PartialEq)]
+        struct RequestId(u64);
+
+        let mut ctx = HttpContext::new();
+        ctx.insert(RequestId(42));
+        ctx.insert(TenantId("acme".into()));
+
+        assert_eq!(ctx.get::<RequestId>(), Some(&RequestId(42)));
+        assert_eq!(ctx.get::<TenantId>(), Some(&TenantId("acme".into())));
+    }
+}
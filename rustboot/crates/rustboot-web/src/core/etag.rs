@@ -0,0 +1,184 @@
+//! ETag computation and conditional request evaluation
+//! ([RFC 7232](https://www.rfc-editor.org/rfc/rfc7232)), so handlers don't
+//! have to hand-roll `If-None-Match`/`If-Match` comparisons to support
+//! bandwidth-sensitive conditional GETs.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use http::{header, Response, StatusCode};
+
+/// Computes a strong ETag for `body`: two responses with the same strong
+/// ETag are required to be byte-for-byte identical.
+pub fn strong_etag(body: &[u8]) -> String {
+    format!("\"{:016x}\"", hash_bytes(body))
+}
+
+/// Computes a weak ETag for `body`: suitable when only semantic
+/// equivalence (not byte-for-byte identity) is guaranteed.
+pub fn weak_etag(body: &[u8]) -> String {
+    format!("W/\"{:016x}\"", hash_bytes(body))
+}
+
+fn hash_bytes(body: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn strip_weak(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+fn candidates(header_value: &str) -> impl Iterator<Item = &str> {
+    header_value.split(',').map(str::trim)
+}
+
+/// Evaluates an `If-None-Match` header against `etag` using weak
+/// comparison, as the spec requires for this header.
+fn if_none_match_matches(header_value: &str, etag: &str) -> bool {
+    header_value.trim() == "*" || candidates(header_value).any(|c| strip_weak(c) == strip_weak(etag))
+}
+
+/// Evaluates an `If-Match` header against `etag` using strong comparison,
+/// as the spec requires for this header: a weak tag never matches.
+fn if_match_matches(header_value: &str, etag: &str) -> bool {
+    header_value.trim() == "*" || candidates(header_value).any(|c| c == etag)
+}
+
+/// The result of evaluating conditional request headers against a
+/// resource's current ETag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionalOutcome {
+    /// Neither header ruled out serving the resource; proceed normally.
+    Proceed,
+    /// `If-None-Match` matched; respond `304 Not Modified`.
+    NotModified,
+    /// `If-Match` did not match; respond `412 Precondition Failed`.
+    PreconditionFailed,
+}
+
+/// Evaluates `If-Match` and `If-None-Match` against `etag`.
+///
+/// `If-Match` is checked first, matching the precedence in RFC 7232 §6.
+pub fn evaluate_conditional(
+    if_match: Option<&str>,
+    if_none_match: Option<&str>,
+    etag: &str,
+) -> ConditionalOutcome {
+    if let Some(header_value) = if_match {
+        if !if_match_matches(header_value, etag) {
+            return ConditionalOutcome::PreconditionFailed;
+        }
+    }
+    if let Some(header_value) = if_none_match {
+        if if_none_match_matches(header_value, etag) {
+            return ConditionalOutcome::NotModified;
+        }
+    }
+    ConditionalOutcome::Proceed
+}
+
+/// Builds the appropriate response for `body` given the caller's
+/// conditional headers: `200` with the body and `ETag` set, `304 Not
+/// Modified`, or `412 Precondition Failed`, per [`evaluate_conditional`].
+pub fn conditional_response(
+    etag: &str,
+    content_type: &str,
+    body: Vec<u8>,
+    if_match: Option<&str>,
+    if_none_match: Option<&str>,
+) -> Response<Vec<u8>> {
+    let (status, body) = match evaluate_conditional(if_match, if_none_match, etag) {
+        ConditionalOutcome::Proceed => (StatusCode::OK, body),
+        ConditionalOutcome::NotModified => (StatusCode::NOT_MODIFIED, Vec::new()),
+        ConditionalOutcome::PreconditionFailed => (StatusCode::PRECONDITION_FAILED, Vec::new()),
+    };
+
+    let mut builder = Response::builder().status(status).header(header::ETAG, etag);
+    if status == StatusCode::OK {
+        builder = builder.header(header::CONTENT_TYPE, content_type);
+    }
+
+    builder.body(body).unwrap_or_else(|_| {
+        let mut response = Response::new(Vec::new());
+        *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        response
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strong_etag_is_deterministic_and_content_dependent() {
+        assert_eq!(strong_etag(b"hello"), strong_etag(b"hello"));
+        assert_ne!(strong_etag(b"hello"), strong_etag(b"world"));
+    }
+
+    #[test]
+    fn weak_etag_is_prefixed() {
+        assert!(weak_etag(b"hello").starts_with("W/\""));
+    }
+
+    #[test]
+    fn if_none_match_returns_not_modified_on_match() {
+        let etag = strong_etag(b"hello");
+        let outcome = evaluate_conditional(None, Some(&etag), &etag);
+        assert_eq!(outcome, ConditionalOutcome::NotModified);
+    }
+
+    #[test]
+    fn if_none_match_star_always_matches() {
+        let etag = strong_etag(b"hello");
+        assert_eq!(
+            evaluate_conditional(None, Some("*"), &etag),
+            ConditionalOutcome::NotModified
+        );
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        let strong = strong_etag(b"hello");
+        let weak = format!("W/{strong}");
+        assert_eq!(
+            evaluate_conditional(None, Some(&weak), &strong),
+            ConditionalOutcome::NotModified
+        );
+    }
+
+    #[test]
+    fn if_match_fails_precondition_on_mismatch() {
+        let etag = strong_etag(b"hello");
+        let outcome = evaluate_conditional(Some("\"stale\""), None, &etag);
+        assert_eq!(outcome, ConditionalOutcome::PreconditionFailed);
+    }
+
+    #[test]
+    fn if_match_rejects_weak_tags_via_strong_comparison() {
+        let strong = strong_etag(b"hello");
+        let weak = format!("W/{strong}");
+        assert_eq!(
+            evaluate_conditional(Some(&weak), None, &strong),
+            ConditionalOutcome::PreconditionFailed
+        );
+    }
+
+    #[test]
+    fn conditional_response_returns_304_with_empty_body() {
+        let etag = strong_etag(b"hello");
+        let response = conditional_response(&etag, "text/plain", b"hello".to_vec(), None, Some(&etag));
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert!(response.body().is_empty());
+    }
+
+    #[test]
+    fn conditional_response_proceeds_with_body_and_content_type() {
+        let etag = strong_etag(b"hello");
+        let response = conditional_response(&etag, "text/plain", b"hello".to_vec(), None, None);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), b"hello");
+        assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "text/plain");
+    }
+}
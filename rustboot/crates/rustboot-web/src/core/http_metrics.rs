@@ -0,0 +1,115 @@
+//! RED (rate, errors, duration) metrics for HTTP handlers, so wiring in
+//! [`HttpMetricsMiddleware`] gets request/error counts and a duration
+//! histogram for every route without instrumenting each handler by
+//! hand.
+
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use rustboot_observability::{increment_counter, observe_histogram};
+
+const REQUESTS_TOTAL: &str = "http_requests_total";
+const ERRORS_TOTAL: &str = "http_request_errors_total";
+const DURATION_SECONDS: &str = "http_request_duration_seconds";
+
+/// Records RED metrics for HTTP requests, labeled by route template,
+/// method, and status, into whatever
+/// [`rustboot_observability::Metrics`] backend is installed via
+/// [`rustboot_observability::core::metrics_registry::install_global_metrics`].
+///
+/// This crate has no request-handling pipeline of its own to hook into
+/// automatically (see the module docs on [`crate::core::request_id`]) —
+/// call [`HttpMetricsMiddleware::record`] once per request, after the
+/// response status and elapsed time are known, from wherever a
+/// request/response passes through your own framework glue.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpMetricsMiddleware;
+
+impl HttpMetricsMiddleware {
+    /// Records one completed request: increments `http_requests_total`,
+    /// increments `http_request_errors_total` if `status` is a server
+    /// error (5xx), and observes `duration` (in seconds) into
+    /// `http_request_duration_seconds` — all labeled by `route` (the
+    /// route template, e.g. `/orders/:id`, not the raw path, so a
+    /// high-cardinality path segment doesn't blow up label
+    /// cardinality), `method`, and `status`.
+    pub fn record(&self, route: &str, method: &Method, status: StatusCode, duration: Duration) {
+        let status = status.as_u16().to_string();
+        let labels = [("route", route), ("method", method.as_str()), ("status", status.as_str())];
+
+        increment_counter(REQUESTS_TOTAL, &labels, 1);
+        if status.starts_with('5') {
+            increment_counter(ERRORS_TOTAL, &labels, 1);
+        }
+        observe_histogram(DURATION_SECONDS, &labels, duration.as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use rustboot_observability::install_global_metrics;
+    use rustboot_observability::Metrics;
+
+    use super::*;
+
+    type RecordedLabels = Vec<(String, String)>;
+
+    #[derive(Default)]
+    struct CollectingMetrics {
+        counters: Mutex<Vec<(String, RecordedLabels, u64)>>,
+        histograms: Mutex<Vec<(String, RecordedLabels, f64)>>,
+    }
+
+    impl Metrics for CollectingMetrics {
+        fn counter(&self, name: &str, labels: &[(&str, &str)], delta: u64) {
+            let labels = labels.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+            self.counters.lock().unwrap().push((name.to_string(), labels, delta));
+        }
+        fn gauge(&self, _name: &str, _labels: &[(&str, &str)], _value: f64) {}
+        fn histogram(&self, name: &str, labels: &[(&str, &str)], value: f64) {
+            let labels = labels.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+            self.histograms.lock().unwrap().push((name.to_string(), labels, value));
+        }
+    }
+
+    // `install_global_metrics` only takes effect on its first call for
+    // the whole test binary, so every scenario below shares a single
+    // installed `CollectingMetrics` rather than each installing its
+    // own (a second install would silently be ignored and the test
+    // would observe nothing).
+    #[test]
+    fn records_request_count_error_count_and_duration_labeled_by_route_method_and_status() {
+        let metrics = Arc::new(CollectingMetrics::default());
+        install_global_metrics(metrics.clone());
+        let middleware = HttpMetricsMiddleware;
+
+        middleware.record("/orders/:id", &Method::GET, StatusCode::OK, Duration::from_millis(50));
+        middleware.record(
+            "/orders/:id",
+            &Method::POST,
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Duration::from_millis(5),
+        );
+        middleware.record("/orders/:id", &Method::GET, StatusCode::NOT_FOUND, Duration::from_millis(1));
+
+        let counters = metrics.counters.lock().unwrap();
+        assert_eq!(counters.iter().filter(|(name, ..)| name == "http_requests_total").count(), 3);
+        assert!(counters
+            .iter()
+            .any(|(name, labels, delta)| name == "http_requests_total"
+                && *delta == 1
+                && labels.contains(&("route".to_string(), "/orders/:id".to_string()))
+                && labels.contains(&("method".to_string(), "GET".to_string()))
+                && labels.contains(&("status".to_string(), "200".to_string()))));
+
+        let error_counters: Vec<_> =
+            counters.iter().filter(|(name, ..)| name == "http_request_errors_total").collect();
+        assert_eq!(error_counters.len(), 1);
+        assert!(error_counters[0].1.contains(&("status".to_string(), "500".to_string())));
+
+        let histograms = metrics.histograms.lock().unwrap();
+        assert_eq!(histograms.iter().filter(|(name, ..)| name == "http_request_duration_seconds").count(), 3);
+    }
+}
@@ -0,0 +1,37 @@
+//! A ready-made `/metrics` handler serving a
+//! [`PrometheusMetrics`] registry in the Prometheus text exposition
+//! format.
+
+use http::{header, Response};
+use rustboot_observability::PrometheusMetrics;
+
+/// Renders `metrics` as a complete `text/plain` response suitable for a
+/// Prometheus scrape target, typically mounted at `GET /metrics`.
+pub fn render_metrics(metrics: &PrometheusMetrics) -> Response<Vec<u8>> {
+    let body = metrics.render().into_bytes();
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")
+        .body(body)
+        .unwrap_or_else(|_| Response::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_registry_as_plain_text() {
+        let metrics = PrometheusMetrics::default();
+        metrics.increment_counter("requests_total", Default::default());
+
+        let response = render_metrics(&metrics);
+
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/plain; version=0.0.4; charset=utf-8"
+        );
+        let body = String::from_utf8(response.body().clone()).unwrap();
+        assert!(body.contains("requests_total 1"));
+    }
+}
@@ -0,0 +1,14 @@
+//! Implementation details for the web module.
+
+pub mod body_limit;
+pub mod compression;
+pub mod context;
+pub mod etag;
+pub mod http_metrics;
+pub mod metrics;
+pub mod problem_json;
+pub mod query;
+pub mod range;
+pub mod rate_limit_headers;
+pub mod request_id;
+pub mod shutdown;
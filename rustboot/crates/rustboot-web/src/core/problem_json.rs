@@ -0,0 +1,106 @@
+//! Renders [`WebError`] as an [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807)
+//! `application/problem+json` response.
+
+use http::{header, Response, StatusCode};
+use serde::Serialize;
+
+use crate::api::{RenderMode, WebError};
+
+/// An RFC 7807 problem details document, extended with rustboot's stable
+/// error `code` and an optional log `correlation_id`.
+#[derive(Debug, Serialize)]
+pub struct ProblemJson {
+    #[serde(rename = "type")]
+    type_: &'static str,
+    title: String,
+    status: u16,
+    code: String,
+    detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    correlation_id: Option<String>,
+}
+
+/// Builds the problem+json document for `error`.
+///
+/// In [`RenderMode::Production`], `detail` is always `error.public_message`;
+/// [`WebError::internal_detail`] is never serialized into the response.
+/// In [`RenderMode::Development`] the internal detail, if any, is appended
+/// to ease local debugging.
+pub fn to_problem_json(error: &WebError, mode: RenderMode) -> ProblemJson {
+    let detail = match (mode, &error.internal_detail) {
+        (RenderMode::Development, Some(internal)) => {
+            format!("{} (internal: {internal})", error.public_message)
+        }
+        _ => error.public_message.clone(),
+    };
+
+    ProblemJson {
+        type_: "about:blank",
+        title: error
+            .status
+            .canonical_reason()
+            .unwrap_or("Error")
+            .to_string(),
+        status: error.status.as_u16(),
+        code: error.code.clone(),
+        detail,
+        correlation_id: error.correlation_id.clone(),
+    }
+}
+
+/// Renders `error` as a complete `application/problem+json` HTTP
+/// response.
+pub fn render(error: &WebError, mode: RenderMode) -> Response<Vec<u8>> {
+    let problem = to_problem_json(error, mode);
+    let body = serde_json::to_vec(&problem).unwrap_or_else(|_| b"{}".to_vec());
+
+    Response::builder()
+        .status(error.status)
+        .header(header::CONTENT_TYPE, "application/problem+json")
+        .body(body)
+        .unwrap_or_else(|_| {
+            let mut response = Response::new(Vec::new());
+            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+            response
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_error() -> WebError {
+        WebError::new(StatusCode::NOT_FOUND, "order_not_found", "Order not found")
+            .with_internal_detail("row missing in orders table: id=42")
+            .with_correlation_id("req-123")
+    }
+
+    #[test]
+    fn production_mode_hides_internal_detail() {
+        let problem = to_problem_json(&sample_error(), RenderMode::Production);
+        assert_eq!(problem.detail, "Order not found");
+        assert!(!problem.detail.contains("orders table"));
+    }
+
+    #[test]
+    fn development_mode_includes_internal_detail() {
+        let problem = to_problem_json(&sample_error(), RenderMode::Development);
+        assert!(problem.detail.contains("orders table"));
+    }
+
+    #[test]
+    fn render_sets_problem_json_content_type_and_status() {
+        let response = render(&sample_error(), RenderMode::Production);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+    }
+
+    #[test]
+    fn correlation_id_is_preserved_in_body() {
+        let problem = to_problem_json(&sample_error(), RenderMode::Production);
+        assert_eq!(problem.correlation_id.as_deref(), Some("req-123"));
+    }
+}
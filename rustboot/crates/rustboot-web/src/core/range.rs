@@ -0,0 +1,273 @@
+//! `Range` header parsing and `206 Partial Content` responses
+//! ([RFC 7233](https://www.rfc-editor.org/rfc/rfc7233)), including
+//! `multipart/byteranges` for multi-range requests, so large media
+//! downloads can resume after an interruption instead of restarting from
+//! zero.
+
+use http::{header, Response, StatusCode};
+
+use crate::api::{IntoWebError, WebError};
+
+/// An inclusive byte range, already clamped to the resource's length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    /// First byte of the range, inclusive.
+    pub start: u64,
+    /// Last byte of the range, inclusive.
+    pub end: u64,
+}
+
+impl ByteRange {
+    /// Number of bytes covered by this range.
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Whether this range covers zero bytes. Always `false`: a `ByteRange`
+    /// that survives [`parse_range`] always covers at least one byte.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+/// Errors from parsing a `Range` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// The header value doesn't follow the `bytes=start-end[,start-end]`
+    /// syntax.
+    Malformed,
+    /// Every requested range falls outside the resource, given its
+    /// `total_len`.
+    Unsatisfiable {
+        /// The resource's total length in bytes.
+        total_len: u64,
+    },
+}
+
+impl IntoWebError for RangeError {
+    fn into_web_error(self) -> WebError {
+        match self {
+            RangeError::Malformed => {
+                WebError::new(StatusCode::BAD_REQUEST, "invalid_range", "Malformed Range header")
+            }
+            RangeError::Unsatisfiable { total_len } => WebError::new(
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                "range_not_satisfiable",
+                "Requested range not satisfiable",
+            )
+            .with_internal_detail(format!("resource length: {total_len}")),
+        }
+    }
+}
+
+/// Parses a `Range: bytes=...` header against a resource of `total_len`
+/// bytes, returning one [`ByteRange`] per comma-separated spec.
+///
+/// Supports `start-end`, open-ended `start-`, and suffix `-length` forms.
+/// Ranges are clamped to `total_len`; a request where every range falls
+/// outside the resource is rejected as [`RangeError::Unsatisfiable`].
+pub fn parse_range(header_value: &str, total_len: u64) -> Result<Vec<ByteRange>, RangeError> {
+    let spec = header_value.strip_prefix("bytes=").ok_or(RangeError::Malformed)?;
+
+    let mut ranges = Vec::new();
+    for part in spec.split(',') {
+        let part = part.trim();
+        let (start_str, end_str) = part.split_once('-').ok_or(RangeError::Malformed)?;
+
+        let (start, end) = if start_str.is_empty() {
+            let suffix_len: u64 = end_str.parse().map_err(|_| RangeError::Malformed)?;
+            if suffix_len == 0 || total_len == 0 {
+                return Err(RangeError::Unsatisfiable { total_len });
+            }
+            (total_len.saturating_sub(suffix_len), total_len - 1)
+        } else {
+            let start: u64 = start_str.parse().map_err(|_| RangeError::Malformed)?;
+            let end = if end_str.is_empty() {
+                total_len.saturating_sub(1)
+            } else {
+                end_str.parse().map_err(|_| RangeError::Malformed)?
+            };
+            (start, end)
+        };
+
+        if start > end || start >= total_len {
+            return Err(RangeError::Unsatisfiable { total_len });
+        }
+
+        ranges.push(ByteRange {
+            start,
+            end: end.min(total_len - 1),
+        });
+    }
+
+    if ranges.is_empty() {
+        return Err(RangeError::Malformed);
+    }
+    Ok(ranges)
+}
+
+/// Builds a `206 Partial Content` response for `ranges` taken from `body`.
+///
+/// A single range produces a plain body with `Content-Range` set; more
+/// than one produces a `multipart/byteranges` body, using `boundary` as
+/// the MIME boundary (callers supply it so this module stays free of a
+/// random-generation dependency).
+pub fn partial_content_response(
+    ranges: &[ByteRange],
+    total_len: u64,
+    content_type: &str,
+    body: &[u8],
+    boundary: &str,
+) -> Response<Vec<u8>> {
+    match ranges {
+        [range] => single_range_response(range, total_len, content_type, body),
+        _ => multipart_range_response(ranges, total_len, content_type, body, boundary),
+    }
+}
+
+fn single_range_response(
+    range: &ByteRange,
+    total_len: u64,
+    content_type: &str,
+    body: &[u8],
+) -> Response<Vec<u8>> {
+    let slice = body[range.start as usize..=range.end as usize].to_vec();
+
+    Response::builder()
+        .status(StatusCode::PARTIAL_CONTENT)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(
+            header::CONTENT_RANGE,
+            format!("bytes {}-{}/{total_len}", range.start, range.end),
+        )
+        .header(header::CONTENT_LENGTH, slice.len().to_string())
+        .body(slice)
+        .unwrap_or_else(|_| error_response())
+}
+
+fn multipart_range_response(
+    ranges: &[ByteRange],
+    total_len: u64,
+    content_type: &str,
+    body: &[u8],
+    boundary: &str,
+) -> Response<Vec<u8>> {
+    let mut payload = Vec::new();
+    for range in ranges {
+        payload.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Type: {content_type}\r\nContent-Range: bytes {}-{}/{total_len}\r\n\r\n",
+                range.start, range.end
+            )
+            .as_bytes(),
+        );
+        payload.extend_from_slice(&body[range.start as usize..=range.end as usize]);
+        payload.extend_from_slice(b"\r\n");
+    }
+    payload.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+    Response::builder()
+        .status(StatusCode::PARTIAL_CONTENT)
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/byteranges; boundary={boundary}"),
+        )
+        .header(header::CONTENT_LENGTH, payload.len().to_string())
+        .body(payload)
+        .unwrap_or_else(|_| error_response())
+}
+
+fn error_response() -> Response<Vec<u8>> {
+    let mut response = Response::new(Vec::new());
+    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_start_end_range() {
+        let ranges = parse_range("bytes=0-99", 1000).unwrap();
+        assert_eq!(ranges, vec![ByteRange { start: 0, end: 99 }]);
+    }
+
+    #[test]
+    fn parses_open_ended_range() {
+        let ranges = parse_range("bytes=900-", 1000).unwrap();
+        assert_eq!(ranges, vec![ByteRange { start: 900, end: 999 }]);
+    }
+
+    #[test]
+    fn parses_suffix_range() {
+        let ranges = parse_range("bytes=-100", 1000).unwrap();
+        assert_eq!(ranges, vec![ByteRange { start: 900, end: 999 }]);
+    }
+
+    #[test]
+    fn parses_multiple_ranges() {
+        let ranges = parse_range("bytes=0-99,200-299", 1000).unwrap();
+        assert_eq!(
+            ranges,
+            vec![ByteRange { start: 0, end: 99 }, ByteRange { start: 200, end: 299 }]
+        );
+    }
+
+    #[test]
+    fn clamps_end_to_resource_length() {
+        let ranges = parse_range("bytes=900-1999", 1000).unwrap();
+        assert_eq!(ranges, vec![ByteRange { start: 900, end: 999 }]);
+    }
+
+    #[test]
+    fn rejects_range_starting_past_resource_length() {
+        let result = parse_range("bytes=1000-1099", 1000);
+        assert_eq!(result, Err(RangeError::Unsatisfiable { total_len: 1000 }));
+    }
+
+    #[test]
+    fn rejects_malformed_header() {
+        assert_eq!(parse_range("0-99", 1000), Err(RangeError::Malformed));
+        assert_eq!(parse_range("bytes=abc-99", 1000), Err(RangeError::Malformed));
+    }
+
+    #[test]
+    fn single_range_response_sets_content_range_header() {
+        let body = (0u8..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let ranges = parse_range("bytes=0-99", body.len() as u64).unwrap();
+
+        let response =
+            partial_content_response(&ranges, body.len() as u64, "application/octet-stream", &body, "ignored");
+
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(
+            response.headers().get(header::CONTENT_RANGE).unwrap(),
+            "bytes 0-99/1000"
+        );
+        assert_eq!(response.body().len(), 100);
+    }
+
+    #[test]
+    fn multipart_response_contains_every_range_part() {
+        let body = (0u8..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let ranges = parse_range("bytes=0-9,500-509", body.len() as u64).unwrap();
+
+        let response = partial_content_response(
+            &ranges,
+            body.len() as u64,
+            "application/octet-stream",
+            &body,
+            "BOUNDARY",
+        );
+
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "multipart/byteranges; boundary=BOUNDARY"
+        );
+        let rendered = String::from_utf8_lossy(response.body());
+        assert!(rendered.contains("Content-Range: bytes 0-9/1000"));
+        assert!(rendered.contains("Content-Range: bytes 500-509/1000"));
+        assert!(rendered.contains("--BOUNDARY--"));
+    }
+}
@@ -0,0 +1,42 @@
+//! Renders a [`QuotaStatus`] as the draft IETF `RateLimit-*` response
+//! headers, so a `RateLimitMiddleware` doesn't have to hand-format them
+//! at every call site.
+//!
+//! See <https://www.ietf.org/archive/id/draft-ietf-httpapi-ratelimit-headers-07.html>.
+
+use http::response::Builder;
+
+use rustboot_resilience::QuotaStatus;
+
+/// Adds `RateLimit-Limit`, `RateLimit-Remaining`, and `RateLimit-Reset`
+/// headers to `builder`, reflecting `status`.
+pub fn with_rate_limit_headers(builder: Builder, status: &QuotaStatus) -> Builder {
+    builder
+        .header("RateLimit-Limit", status.limit.to_string())
+        .header("RateLimit-Remaining", status.remaining.to_string())
+        .header("RateLimit-Reset", status.reset.as_secs().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::Response;
+    use std::time::Duration;
+
+    #[test]
+    fn sets_all_three_headers() {
+        let status = QuotaStatus {
+            limit: 100,
+            remaining: 42,
+            reset: Duration::from_secs(30),
+        };
+
+        let response = with_rate_limit_headers(Response::builder(), &status)
+            .body(Vec::<u8>::new())
+            .unwrap();
+
+        assert_eq!(response.headers().get("RateLimit-Limit").unwrap(), "100");
+        assert_eq!(response.headers().get("RateLimit-Remaining").unwrap(), "42");
+        assert_eq!(response.headers().get("RateLimit-Reset").unwrap(), "30");
+    }
+}
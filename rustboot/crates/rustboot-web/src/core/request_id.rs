@@ -0,0 +1,140 @@
+//! Request id and W3C trace propagation: assigns or reuses an
+//! `X-Request-Id`, extracts/derives a `traceparent`, stores both on the
+//! [`HttpContext`] for handlers to read, and echoes them back on the
+//! response.
+//!
+//! There's no tracing-span library in this workspace yet, so
+//! "injecting into the tracing span" means [`TraceContext`] (and, via
+//! `#[timed]`/`#[traced]`, [`rustboot_observability::SpanRecord`])
+//! rather than a `tracing::Span`. Once a real span-creation API lands
+//! here, thread [`RequestContext::trace`] into it there instead of
+//! introducing a `tracing` dependency from this crate.
+
+use http::header::HeaderName;
+use http::{HeaderValue, Response};
+use rustboot_identifiers::Uuid;
+use rustboot_observability::TraceContext;
+
+use crate::core::context::HttpContext;
+
+/// Request id header name (`x-request-id`).
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+/// W3C `traceparent` header name.
+pub static TRACEPARENT_HEADER: HeaderName = HeaderName::from_static("traceparent");
+
+/// The request id and trace context resolved for a single request.
+/// Stored on [`HttpContext`] so downstream middleware and handlers can
+/// read it back without re-parsing headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestContext {
+    /// This request's id: reused from an incoming `X-Request-Id` if the
+    /// caller supplied one, otherwise a freshly generated UUIDv7.
+    pub request_id: Uuid,
+    /// This request's position in a distributed trace: a child of the
+    /// incoming `traceparent` if one was supplied and valid, otherwise
+    /// the root of a new trace.
+    pub trace: TraceContext,
+}
+
+impl RequestContext {
+    /// Resolves a `RequestContext` for an incoming request from its
+    /// `X-Request-Id` and `traceparent` header values, if present.
+    pub fn extract_or_start(request_id: Option<&str>, traceparent: Option<&str>) -> Self {
+        let request_id = request_id
+            .and_then(|value| value.parse::<Uuid>().ok())
+            .unwrap_or_else(Uuid::new_v7);
+        let trace = traceparent
+            .and_then(|value| TraceContext::parse(value).ok())
+            .map(|parent| parent.child())
+            .unwrap_or_else(TraceContext::new_root);
+        Self { request_id, trace }
+    }
+
+    /// Stores this context on `ctx` for downstream middleware/handlers
+    /// to retrieve with `ctx.get::<RequestContext>()`.
+    pub fn store(self, ctx: &mut HttpContext) {
+        ctx.insert(self);
+    }
+
+    /// Sets the `X-Request-Id` and `traceparent` response headers so the
+    /// caller (and the next hop, if this response is itself relayed)
+    /// can correlate against this request.
+    pub fn apply_response_headers<B>(&self, response: &mut Response<B>) {
+        let headers = response.headers_mut();
+        if let Ok(value) = HeaderValue::from_str(&self.request_id.to_string()) {
+            headers.insert(REQUEST_ID_HEADER.clone(), value);
+        }
+        if let Ok(value) = HeaderValue::from_str(&self.trace.to_header_value()) {
+            headers.insert(TRACEPARENT_HEADER.clone(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_a_request_id_when_none_was_supplied() {
+        let ctx = RequestContext::extract_or_start(None, None);
+        assert_ne!(ctx.request_id, Uuid::nil());
+    }
+
+    #[test]
+    fn reuses_a_supplied_request_id() {
+        let id = Uuid::new_v7();
+        let ctx = RequestContext::extract_or_start(Some(&id.to_string()), None);
+        assert_eq!(ctx.request_id, id);
+    }
+
+    #[test]
+    fn falls_back_to_a_new_id_when_the_supplied_one_is_unparseable() {
+        let ctx = RequestContext::extract_or_start(Some("not-a-uuid"), None);
+        assert_ne!(ctx.request_id, Uuid::nil());
+    }
+
+    #[test]
+    fn starts_a_new_trace_when_no_traceparent_was_supplied() {
+        let ctx = RequestContext::extract_or_start(None, None);
+        assert!(ctx.trace.sampled);
+    }
+
+    #[test]
+    fn derives_a_child_trace_from_a_valid_traceparent() {
+        let incoming = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let ctx = RequestContext::extract_or_start(None, Some(incoming));
+        let parent = TraceContext::parse(incoming).unwrap();
+        assert_eq!(ctx.trace.trace_id, parent.trace_id);
+        assert_ne!(ctx.trace.parent_id, parent.parent_id);
+    }
+
+    #[test]
+    fn starts_a_new_trace_when_the_traceparent_is_malformed() {
+        let ctx = RequestContext::extract_or_start(None, Some("garbage"));
+        assert!(ctx.trace.sampled);
+    }
+
+    #[test]
+    fn store_makes_the_context_retrievable_from_http_context() {
+        let ctx = RequestContext::extract_or_start(None, None);
+        let mut http_ctx = HttpContext::new();
+        ctx.store(&mut http_ctx);
+        assert_eq!(http_ctx.get::<RequestContext>(), Some(&ctx));
+    }
+
+    #[test]
+    fn apply_response_headers_sets_both_headers() {
+        let ctx = RequestContext::extract_or_start(None, None);
+        let mut response = Response::new(Vec::<u8>::new());
+        ctx.apply_response_headers(&mut response);
+
+        assert_eq!(
+            response.headers().get(&REQUEST_ID_HEADER).unwrap(),
+            &ctx.request_id.to_string()
+        );
+        assert_eq!(
+            response.headers().get(&TRACEPARENT_HEADER).unwrap(),
+            &ctx.trace.to_header_value()
+        );
+    }
+}
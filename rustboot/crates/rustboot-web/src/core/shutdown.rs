@@ -0,0 +1,49 @@
+//! Cooperative shutdown for the HTTP server itself, as distinct from
+//! the per-request middleware the rest of this crate provides.
+//!
+//! There's no server/listener type in this crate — that's the
+//! embedding app's `hyper`/`axum` glue — so this is deliberately just a
+//! future to hand to that server's own graceful-shutdown hook (e.g.
+//! `axum::serve(...).with_graceful_shutdown(...)`), tied to the same
+//! `rustboot_async::CancellationToken` the scheduler, messaging
+//! consumers, and stream tasks shut down on.
+
+use rustboot_async::CancellationToken;
+
+/// Resolves once `token` is cancelled. Pass the result to a server's
+/// graceful-shutdown hook so it stops accepting new connections as part
+/// of a wider, app-wide cooperative shutdown instead of only reacting to
+/// its own signal handler.
+pub async fn graceful_shutdown(token: CancellationToken) {
+    token.cancelled().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn resolves_once_the_token_is_cancelled() {
+        let token = CancellationToken::new();
+        let token_for_cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            token_for_cancel.cancel();
+        });
+
+        tokio::time::timeout(Duration::from_secs(1), graceful_shutdown(token))
+            .await
+            .expect("graceful_shutdown should resolve once the token is cancelled");
+    }
+
+    #[tokio::test]
+    async fn resolves_immediately_for_an_already_cancelled_token() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_millis(50), graceful_shutdown(token))
+            .await
+            .expect("graceful_shutdown should not block once the token is already cancelled");
+    }
+}
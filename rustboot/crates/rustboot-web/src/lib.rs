@@ -0,0 +1,59 @@
+//! HTTP server building blocks for the rustboot framework.
+//!
+//! - [`WebError`] / [`IntoWebError`]: typed application errors with a
+//!   stable code, a caller-safe public message, and internal detail that
+//!   never leaks in production.
+//! - [`core::problem_json`]: renders [`WebError`] as RFC 7807
+//!   `application/problem+json`.
+//! - [`core::query`]: [`Pagination`], [`SortBy`], and a safe filter
+//!   expression parser for list endpoints.
+//! - [`core::etag`]: ETag computation and `If-Match`/`If-None-Match`
+//!   evaluation for conditional requests.
+//! - [`core::range`]: `Range` header parsing and `206 Partial Content`
+//!   responses, including `multipart/byteranges`.
+//! - [`core::rate_limit_headers`]: renders a quota status as draft IETF
+//!   `RateLimit-*` response headers.
+//! - [`core::context`]: [`HttpContext`]'s type-keyed extensions map, so
+//!   middleware can pass strongly-typed data to handlers.
+//! - [`core::body_limit`]: per-route max body size and decompression-bomb
+//!   protection for `gzip`/`deflate` request bodies.
+//! - [`core::compression`]: response compression gating (size threshold,
+//!   content-type allowlist, `Accept-Encoding` negotiation) and a
+//!   streaming `gzip`/`deflate` encoder.
+//! - [`core::request_id`]: assigns/propagates `X-Request-Id` and W3C
+//!   `traceparent`, storing both on [`HttpContext`] and echoing them on
+//!   the response.
+//! - [`core::metrics`]: a ready-made `/metrics` handler serving a
+//!   `rustboot_observability::PrometheusMetrics` registry in the
+//!   Prometheus text exposition format.
+//! - [`core::http_metrics::HttpMetricsMiddleware`]: records RED (rate,
+//!   errors, duration) metrics per request into the
+//!   `rustboot_observability` global metrics backend.
+//! - [`core::shutdown::graceful_shutdown`]: a future for a server's
+//!   graceful-shutdown hook, tied to a `rustboot_async::CancellationToken`
+//!   shared with the rest of the app.
+
+pub mod api;
+pub mod core;
+
+pub use api::{IntoWebError, RenderMode, WebError};
+pub use core::body_limit::{
+    check_declared_length, decode_body, enforce_body_limit, BodyLimitConfig, BodyLimitError,
+    ContentEncoding,
+};
+pub use core::compression::{
+    compress_chunks, negotiate_encoding, should_compress, CompressionConfig, CompressionError,
+    StreamingEncoder,
+};
+pub use core::context::{Extensions, HttpContext};
+pub use core::etag::{conditional_response, evaluate_conditional, strong_etag, weak_etag, ConditionalOutcome};
+pub use core::http_metrics::HttpMetricsMiddleware;
+pub use core::metrics::render_metrics;
+pub use core::problem_json::{render, to_problem_json, ProblemJson};
+pub use core::query::{
+    parse_filters, FilterClause, FilterOp, Pagination, PaginationConfig, SortBy,
+};
+pub use core::range::{parse_range, partial_content_response, ByteRange, RangeError};
+pub use core::rate_limit_headers::with_rate_limit_headers;
+pub use core::request_id::{RequestContext, REQUEST_ID_HEADER, TRACEPARENT_HEADER};
+pub use core::shutdown::graceful_shutdown;